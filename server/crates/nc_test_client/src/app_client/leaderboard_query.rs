@@ -0,0 +1,37 @@
+#[derive(Debug, Default, serde::Serialize, Copy, Clone)]
+pub struct LeaderboardQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<usize>,
+}
+
+impl LeaderboardQuery {
+    pub fn new() -> Self {
+        LeaderboardQuery::default()
+    }
+
+    pub fn variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn order(mut self, order: &'static str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
@@ -0,0 +1,41 @@
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MatchmakingQuery {
+    variant: Option<&'static str>,
+    initial_seconds: Option<u32>,
+    increment_seconds: Option<u32>,
+}
+
+impl MatchmakingQuery {
+    pub fn new() -> Self {
+        MatchmakingQuery::default()
+    }
+
+    pub fn variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn initial_seconds(mut self, initial_seconds: u32) -> Self {
+        self.initial_seconds = Some(initial_seconds);
+        self
+    }
+
+    pub fn increment_seconds(mut self, increment_seconds: u32) -> Self {
+        self.increment_seconds = Some(increment_seconds);
+        self
+    }
+
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![];
+        if let Some(variant) = self.variant {
+            pairs.push(("variant", variant.to_string()));
+        }
+        if let Some(initial_seconds) = self.initial_seconds {
+            pairs.push(("initial_seconds", initial_seconds.to_string()));
+        }
+        if let Some(increment_seconds) = self.increment_seconds {
+            pairs.push(("increment_seconds", increment_seconds.to_string()));
+        }
+        pairs
+    }
+}
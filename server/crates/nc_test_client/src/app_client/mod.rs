@@ -1,22 +1,35 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use reqwest::header::AUTHORIZATION;
 use reqwest::Method;
 use tokio::net::TcpStream;
 use tokio_tungstenite::tungstenite::http::Response as TungsteniteResponse;
+use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::tungstenite::Result as TungsteniteResult;
 use tokio_tungstenite::{connect_async, WebSocketStream};
 use url::{ParseError, Url};
 use uuid::Uuid;
 
 pub use game_move::*;
+pub use leaderboard_query::*;
+pub use matchmaking_query::*;
+pub use room_list_query::*;
 
 use crate::app_client::routes::Route;
+use crate::response::{ChallengeEvent, MatchmakingEvent, RoomEvent};
 
 mod game_move;
+mod leaderboard_query;
+mod matchmaking_query;
+mod room_list_query;
 mod routes;
 
 pub struct AppClient {
     server_address: String,
     http_client: reqwest::Client,
     socket_connection: Option<WebSocketStream<TcpStream>>,
+    bearer_token: Option<String>,
 }
 
 impl AppClient {
@@ -25,9 +38,15 @@ impl AppClient {
             server_address,
             http_client: reqwest::Client::new(),
             socket_connection: None,
+            bearer_token: None,
         }
     }
 
+    /// Sends the token as a bearer token with every later request that does not carry its own
+    pub fn set_bearer_token(&mut self, token: &str) {
+        self.bearer_token = Some(token.to_string());
+    }
+
     fn http_request_base_url(&self, route: Route) -> Result<Url, ParseError> {
         Url::parse(
             format!(
@@ -47,7 +66,15 @@ impl AppClient {
         &self,
         request_builder: reqwest::RequestBuilder,
     ) -> reqwest::Response {
-        request_builder.send().await.unwrap()
+        let mut request = request_builder.build().unwrap();
+        if let Some(token) = &self.bearer_token {
+            if !request.headers().contains_key(AUTHORIZATION) {
+                request
+                    .headers_mut()
+                    .insert(AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+            }
+        }
+        self.http_client.execute(request).await.unwrap()
     }
 
     pub async fn status(&self) -> reqwest::Response {
@@ -59,6 +86,15 @@ impl AppClient {
         self.build_and_send_request(request).await
     }
 
+    pub async fn metrics(&self) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::Metrics).unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
     pub async fn create_room(&self, user_id: Uuid) -> reqwest::Response {
         let request = self
             .http_client
@@ -71,6 +107,118 @@ impl AppClient {
         self.build_and_send_request(request).await
     }
 
+    pub async fn create_private_room(&self, user_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::CreateRoom).unwrap(),
+            )
+            .json(&serde_json::json!({ "private": true }))
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn create_room_with_settings(
+        &self,
+        user_id: Uuid,
+        settings: serde_json::Value,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::CreateRoom).unwrap(),
+            )
+            .json(&serde_json::json!({ "settings": settings }))
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn update_room_settings(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        settings: serde_json::Value,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::PATCH,
+                self.http_request_base_url(Route::RoomSettings(room_id))
+                    .unwrap(),
+            )
+            .json(&settings)
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn change_room_role(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        role: &str,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::PUT,
+                self.http_request_base_url(Route::RoomRole(room_id))
+                    .unwrap(),
+            )
+            .json(&serde_json::json!({ "role": role }))
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn create_invite(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        single_use: bool,
+        expires_in_seconds: Option<u32>,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::Invites(room_id)).unwrap(),
+            )
+            .json(&serde_json::json!({
+                "single_use": single_use,
+                "expires_in_seconds": expires_in_seconds,
+            }))
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn list_rooms(&self, query: RoomListQuery) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::ListRooms).unwrap(),
+            )
+            .query(&query);
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn room_details(&self, room_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::RoomDetails(room_id))
+                .unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
     pub async fn join_room(
         &mut self,
         user_id: Uuid,
@@ -80,14 +228,143 @@ impl AppClient {
             .websockets_connection_url(Route::JoinRoom(room_id))
             .unwrap();
 
-        let (socket, response) = connect_async(
-            tokio_tungstenite::tungstenite::http::Request::builder()
-                .uri(connection_url.as_str())
-                .header("user-id", user_id.to_string())
-                .body(())
-                .unwrap(),
-        )
-        .await?;
+        self.connect_to_room(user_id, connection_url).await
+    }
+
+    pub async fn join_room_with_invite(
+        &mut self,
+        user_id: Uuid,
+        room_id: Uuid,
+        invite_code: &str,
+    ) -> TungsteniteResult<TungsteniteResponse<()>> {
+        let mut connection_url = self
+            .websockets_connection_url(Route::JoinRoom(room_id))
+            .unwrap();
+        connection_url
+            .query_pairs_mut()
+            .append_pair("invite", invite_code);
+
+        self.connect_to_room(user_id, connection_url).await
+    }
+
+    /// Joins a room using an invite link path, as returned when creating an invite
+    pub async fn join_room_with_link(
+        &mut self,
+        user_id: Uuid,
+        invite_link: &str,
+    ) -> TungsteniteResult<TungsteniteResponse<()>> {
+        let connection_url =
+            Url::parse(format!("ws://{}{}", self.server_address.as_str(), invite_link).as_str())
+                .unwrap();
+
+        self.connect_to_room(user_id, connection_url).await
+    }
+
+    pub async fn enter_matchmaking(
+        &mut self,
+        user_id: Uuid,
+        query: MatchmakingQuery,
+    ) -> TungsteniteResult<TungsteniteResponse<()>> {
+        let mut connection_url = self.websockets_connection_url(Route::Matchmaking).unwrap();
+        connection_url
+            .query_pairs_mut()
+            .extend_pairs(query.query_pairs());
+
+        self.connect_to_room(user_id, connection_url).await
+    }
+
+    pub async fn listen_for_challenges(
+        &mut self,
+        user_id: Uuid,
+    ) -> TungsteniteResult<TungsteniteResponse<()>> {
+        let connection_url = self
+            .websockets_connection_url(Route::ChallengeNotifications)
+            .unwrap();
+
+        self.connect_to_room(user_id, connection_url).await
+    }
+
+    pub async fn create_challenge(
+        &self,
+        user_id: Uuid,
+        challenge: serde_json::Value,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::Challenges).unwrap(),
+            )
+            .json(&challenge)
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn incoming_challenges(&self, user_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::IncomingChallenges)
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn outgoing_challenges(&self, user_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::OutgoingChallenges)
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn accept_challenge(&self, user_id: Uuid, challenge_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::AcceptChallenge(challenge_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn decline_challenge(&self, user_id: Uuid, challenge_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::DeclineChallenge(challenge_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    async fn connect_to_room(
+        &mut self,
+        user_id: Uuid,
+        connection_url: Url,
+    ) -> TungsteniteResult<TungsteniteResponse<()>> {
+        let mut request_builder = tokio_tungstenite::tungstenite::http::Request::builder()
+            .uri(connection_url.as_str())
+            .header("user-id", user_id.to_string());
+        if let Some(token) = &self.bearer_token {
+            request_builder = request_builder.header("authorization", format!("Bearer {}", token));
+        }
+        let (socket, response) = connect_async(request_builder.body(()).unwrap()).await?;
         self.socket_connection = Some(socket);
         Ok(response)
     }
@@ -137,6 +414,146 @@ impl AppClient {
         self.build_and_send_request(request).await
     }
 
+    pub async fn abandon_game(&self, user_id: Uuid, room_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::AbandonGame).unwrap(),
+            )
+            .header("user-id", user_id.to_string())
+            .header("room-id", room_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn game_history(&self, room_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::GameHistory(room_id))
+                .unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn room_game(&self, room_id: Uuid, game_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::RoomGame(room_id, game_id))
+                .unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn request_rematch(&self, user_id: Uuid, room_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::RematchRequest).unwrap(),
+            )
+            .header("user-id", user_id.to_string())
+            .header("room-id", room_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn configure_series(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        best_of: u8,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::PUT,
+                self.http_request_base_url(Route::Series(room_id)).unwrap(),
+            )
+            .json(&serde_json::json!({ "best_of": best_of }))
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn leave_room(&self, user_id: Uuid, room_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::DELETE,
+                self.http_request_base_url(Route::RoomMembers(room_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn kick_member(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        member_id: Uuid,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::DELETE,
+                self.http_request_base_url(Route::RoomMember(room_id, member_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn ban_user(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::PUT,
+                self.http_request_base_url(Route::RoomBan(room_id, banned_user_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn unban_user(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::DELETE,
+                self.http_request_base_url(Route::RoomBan(room_id, banned_user_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn series(&self, room_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::Series(room_id)).unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
     pub async fn register_user(&self, name: impl ToString) -> reqwest::Response {
         let request = self.http_client.request(
             Method::POST,
@@ -157,6 +574,275 @@ impl AppClient {
         self.build_and_send_request(request).await
     }
 
+    pub async fn user_profile(&self, user_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::UserProfile(user_id))
+                .unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn update_user_profile(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+        profile: serde_json::Value,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::PATCH,
+                self.http_request_base_url(Route::UserName(user_id.to_string()))
+                    .unwrap(),
+            )
+            .json(&profile)
+            .header("user-id", requester.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn create_guest(&self) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::POST,
+            self.http_request_base_url(Route::Guests).unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn upgrade_guest(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+        name: impl ToString,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::UpgradeGuest(user_id))
+                    .unwrap(),
+            )
+            .json(&serde_json::json!({ "name": name.to_string() }))
+            .header("user-id", requester.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn register_account(
+        &self,
+        username: impl ToString,
+        password: impl ToString,
+        name: Option<&str>,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::Accounts).unwrap(),
+            )
+            .json(&serde_json::json!({
+                "username": username.to_string(),
+                "password": password.to_string(),
+                "name": name,
+            }));
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn login(
+        &self,
+        username: impl ToString,
+        password: impl ToString,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::Sessions).unwrap(),
+            )
+            .json(&serde_json::json!({
+                "username": username.to_string(),
+                "password": password.to_string(),
+            }));
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn sessions(&self, token: &str) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::Sessions).unwrap(),
+            )
+            .bearer_auth(token);
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn revoke_session(&self, session_id: Uuid, token: &str) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::DELETE,
+                self.http_request_base_url(Route::Session(session_id))
+                    .unwrap(),
+            )
+            .bearer_auth(token);
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn export_user_data(&self, user_id: Uuid, requester: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::UserDataExport(user_id))
+                    .unwrap(),
+            )
+            .header("user-id", requester.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn delete_user(&self, user_id: Uuid, requester: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::DELETE,
+                self.http_request_base_url(Route::UserName(user_id.to_string()))
+                    .unwrap(),
+            )
+            .header("user-id", requester.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn user_stats(&self, user_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::UserStats(user_id))
+                .unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn leaderboard(&self, query: LeaderboardQuery) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::Leaderboard).unwrap(),
+            )
+            .query(&query);
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn leaderboard_rank(
+        &self,
+        user_id: Uuid,
+        query: LeaderboardQuery,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::GET,
+                self.http_request_base_url(Route::LeaderboardRank(user_id))
+                    .unwrap(),
+            )
+            .query(&query);
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn create_tournament(
+        &self,
+        user_id: Uuid,
+        tournament: serde_json::Value,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::Tournaments).unwrap(),
+            )
+            .json(&tournament)
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn tournament(&self, tournament_id: Uuid) -> reqwest::Response {
+        let request = self.http_client.request(
+            Method::GET,
+            self.http_request_base_url(Route::Tournament(tournament_id))
+                .unwrap(),
+        );
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn register_for_tournament(
+        &self,
+        user_id: Uuid,
+        tournament_id: Uuid,
+    ) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::TournamentParticipants(tournament_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn start_tournament(&self, user_id: Uuid, tournament_id: Uuid) -> reqwest::Response {
+        let request = self
+            .http_client
+            .request(
+                Method::POST,
+                self.http_request_base_url(Route::StartTournament(tournament_id))
+                    .unwrap(),
+            )
+            .header("user-id", user_id.to_string());
+
+        self.build_and_send_request(request).await
+    }
+
+    pub async fn next_room_event(&mut self) -> Option<RoomEvent> {
+        self.next_socket_message().await
+    }
+
+    pub async fn next_matchmaking_event(&mut self) -> Option<MatchmakingEvent> {
+        self.next_socket_message().await
+    }
+
+    pub async fn next_challenge_event(&mut self) -> Option<ChallengeEvent> {
+        self.next_socket_message().await
+    }
+
+    async fn next_socket_message<T: serde::de::DeserializeOwned>(&mut self) -> Option<T> {
+        let socket = self.socket_connection.as_mut()?;
+        loop {
+            let message = tokio::time::timeout(Duration::from_secs(1), socket.next())
+                .await
+                .ok()??
+                .ok()?;
+            if let Message::Text(text) = message {
+                return serde_json::from_str(text.as_str()).ok();
+            }
+        }
+    }
+
     pub async fn close_socket_connection(&mut self) {
         if let Some(socket) = &mut self.socket_connection {
             socket.close(None).await.unwrap();
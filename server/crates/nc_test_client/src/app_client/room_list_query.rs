@@ -0,0 +1,51 @@
+#[derive(Debug, Default, serde::Serialize, Copy, Clone)]
+pub struct RoomListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_seat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_members: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_members: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<usize>,
+}
+
+impl RoomListQuery {
+    pub fn new() -> Self {
+        RoomListQuery::default()
+    }
+
+    pub fn open_seat(mut self, open_seat: bool) -> Self {
+        self.open_seat = Some(open_seat);
+        self
+    }
+
+    pub fn min_members(mut self, min_members: usize) -> Self {
+        self.min_members = Some(min_members);
+        self
+    }
+
+    pub fn max_members(mut self, max_members: usize) -> Self {
+        self.max_members = Some(max_members);
+        self
+    }
+
+    pub fn variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
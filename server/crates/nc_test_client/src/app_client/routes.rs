@@ -4,20 +4,61 @@ use uuid::Uuid;
 
 pub(super) enum Route {
     Status,
+    Metrics,
     CreateRoom,
+    ListRooms,
+    RoomDetails(Uuid),
     JoinRoom(Uuid),
     StartNewGame,
     BecomePlayer,
     RegisterUser(String),
     UserName(String),
+    UserProfile(Uuid),
+    UserStats(Uuid),
+    UserDataExport(Uuid),
+    Guests,
+    UpgradeGuest(Uuid),
+    Accounts,
+    Sessions,
+    Session(Uuid),
     MakeGameMove,
+    AbandonGame,
+    GameHistory(Uuid),
+    RoomGame(Uuid, Uuid),
+    RematchRequest,
+    Series(Uuid),
+    RoomMembers(Uuid),
+    RoomMember(Uuid, Uuid),
+    RoomBan(Uuid, Uuid),
+    Invites(Uuid),
+    RoomSettings(Uuid),
+    RoomRole(Uuid),
+    Matchmaking,
+    Challenges,
+    IncomingChallenges,
+    OutgoingChallenges,
+    AcceptChallenge(Uuid),
+    DeclineChallenge(Uuid),
+    ChallengeNotifications,
+    Leaderboard,
+    LeaderboardRank(Uuid),
+    Tournaments,
+    Tournament(Uuid),
+    TournamentParticipants(Uuid),
+    StartTournament(Uuid),
 }
 
 impl Route {
     pub(crate) fn url_path(&self) -> UrlPath {
         match self {
             Route::Status => UrlPath::new().with_segment("admin").with_segment("status"),
+            Route::Metrics => UrlPath::new().with_segment("admin").with_segment("metrics"),
             Route::CreateRoom => UrlPath::new().with_segment("game").with_segment("rooms"),
+            Route::ListRooms => UrlPath::new().with_segment("game").with_segment("rooms"),
+            Route::RoomDetails(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id),
             Route::JoinRoom(room_id) => UrlPath::new()
                 .with_segment("game")
                 .with_segment("rooms")
@@ -32,8 +73,142 @@ impl Route {
                 .with_segment("game")
                 .with_segment("users")
                 .with_segment(user_id),
+            Route::UserProfile(user_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("users")
+                .with_segment(user_id)
+                .with_segment("profile"),
+            Route::UserStats(user_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("users")
+                .with_segment(user_id)
+                .with_segment("stats"),
+            Route::UserDataExport(user_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("users")
+                .with_segment(user_id)
+                .with_segment("export"),
+            Route::Guests => UrlPath::new().with_segment("game").with_segment("guests"),
+            Route::UpgradeGuest(user_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("users")
+                .with_segment(user_id)
+                .with_segment("upgrade"),
+            Route::Accounts => UrlPath::new().with_segment("game").with_segment("accounts"),
+            Route::Sessions => UrlPath::new().with_segment("game").with_segment("sessions"),
+            Route::Session(session_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("sessions")
+                .with_segment(session_id),
             Route::BecomePlayer => UrlPath::new().with_segment("game").with_segment("players"),
             Route::MakeGameMove => UrlPath::new().with_segment("game").with_segment("moves"),
+            Route::AbandonGame => UrlPath::new()
+                .with_segment("game")
+                .with_segment("abandon-votes"),
+            Route::GameHistory(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("games"),
+            Route::RoomGame(room_id, game_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("games")
+                .with_segment(game_id),
+            Route::RematchRequest => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rematch-requests"),
+            Route::Series(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("series"),
+            Route::RoomMembers(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("members"),
+            Route::RoomMember(room_id, member_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("members")
+                .with_segment(member_id),
+            Route::RoomBan(room_id, banned_user_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("bans")
+                .with_segment(banned_user_id),
+            Route::Invites(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("invites"),
+            Route::RoomSettings(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("settings"),
+            Route::RoomRole(room_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("rooms")
+                .with_segment(room_id)
+                .with_segment("role"),
+            Route::Matchmaking => UrlPath::new()
+                .with_segment("game")
+                .with_segment("matchmaking"),
+            Route::Challenges => UrlPath::new()
+                .with_segment("game")
+                .with_segment("challenges"),
+            Route::IncomingChallenges => UrlPath::new()
+                .with_segment("game")
+                .with_segment("challenges")
+                .with_segment("incoming"),
+            Route::OutgoingChallenges => UrlPath::new()
+                .with_segment("game")
+                .with_segment("challenges")
+                .with_segment("outgoing"),
+            Route::AcceptChallenge(challenge_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("challenges")
+                .with_segment(challenge_id)
+                .with_segment("accept"),
+            Route::DeclineChallenge(challenge_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("challenges")
+                .with_segment(challenge_id)
+                .with_segment("decline"),
+            Route::ChallengeNotifications => UrlPath::new()
+                .with_segment("game")
+                .with_segment("challenges")
+                .with_segment("notifications"),
+            Route::Leaderboard => UrlPath::new()
+                .with_segment("game")
+                .with_segment("leaderboards"),
+            Route::LeaderboardRank(user_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("leaderboards")
+                .with_segment("users")
+                .with_segment(user_id),
+            Route::Tournaments => UrlPath::new()
+                .with_segment("game")
+                .with_segment("tournaments"),
+            Route::Tournament(tournament_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("tournaments")
+                .with_segment(tournament_id),
+            Route::TournamentParticipants(tournament_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("tournaments")
+                .with_segment(tournament_id)
+                .with_segment("participants"),
+            Route::StartTournament(tournament_id) => UrlPath::new()
+                .with_segment("game")
+                .with_segment("tournaments")
+                .with_segment(tournament_id)
+                .with_segment("start"),
         }
     }
 }
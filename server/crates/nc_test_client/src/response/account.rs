@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AccountResponse {
+    id: Uuid,
+    name: String,
+    username: Option<String>,
+}
+
+impl AccountResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct LoginResponse {
+    session_id: Uuid,
+    user_id: Uuid,
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl LoginResponse {
+    pub fn session_id(&self) -> Uuid {
+        self.session_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn token(&self) -> &str {
+        self.token.as_str()
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SessionResponse {
+    session_id: Uuid,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    current: bool,
+}
+
+impl SessionResponse {
+    pub fn session_id(&self) -> Uuid {
+        self.session_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.current
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SessionListResponse {
+    user_id: Uuid,
+    sessions: Vec<SessionResponse>,
+}
+
+impl SessionListResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn sessions(&self) -> &[SessionResponse] {
+        &self.sessions
+    }
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::response::TimeControlResponse;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ChallengeResponse {
+    challenge_id: Uuid,
+    challenger: Uuid,
+    challenged: Uuid,
+    variant: String,
+    time_control: Option<TimeControlResponse>,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl ChallengeResponse {
+    pub fn challenge_id(&self) -> Uuid {
+        self.challenge_id
+    }
+
+    pub fn challenger(&self) -> Uuid {
+        self.challenger
+    }
+
+    pub fn challenged(&self) -> Uuid {
+        self.challenged
+    }
+
+    pub fn variant(&self) -> &str {
+        self.variant.as_str()
+    }
+
+    pub fn time_control(&self) -> Option<TimeControlResponse> {
+        self.time_control
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ChallengeListResponse {
+    challenges: Vec<ChallengeResponse>,
+}
+
+impl ChallengeListResponse {
+    pub fn challenges(&self) -> &Vec<ChallengeResponse> {
+        &self.challenges
+    }
+
+    pub fn challenge_ids(&self) -> Vec<Uuid> {
+        self.challenges
+            .iter()
+            .map(ChallengeResponse::challenge_id)
+            .collect()
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AcceptChallengeResponse {
+    room_id: Uuid,
+    game_id: Uuid,
+}
+
+impl AcceptChallengeResponse {
+    pub fn room_id(&self) -> Uuid {
+        self.room_id
+    }
+
+    pub fn game_id(&self) -> Uuid {
+        self.game_id
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ChallengeEvent {
+    Received {
+        challenge_id: Uuid,
+        challenger: Uuid,
+        expires_at: DateTime<Utc>,
+    },
+    Accepted {
+        challenge_id: Uuid,
+        room_id: Uuid,
+        game_id: Uuid,
+    },
+    Declined {
+        challenge_id: Uuid,
+    },
+    Withdrawn {
+        challenge_id: Uuid,
+    },
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::response::TimeControlResponse;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GameHistoryResponse {
+    games: Vec<GameSummaryResponse>,
+}
+
+impl GameHistoryResponse {
+    pub fn games(&self) -> &Vec<GameSummaryResponse> {
+        &self.games
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GameSummaryResponse {
+    game_id: Uuid,
+    players: Vec<Uuid>,
+    variant: String,
+    time_control: Option<TimeControlResponse>,
+    outcome: Option<GameOutcome>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl GameSummaryResponse {
+    pub fn game_id(&self) -> Uuid {
+        self.game_id
+    }
+
+    pub fn players(&self) -> &Vec<Uuid> {
+        &self.players
+    }
+
+    pub fn variant(&self) -> &str {
+        self.variant.as_str()
+    }
+
+    pub fn time_control(&self) -> Option<TimeControlResponse> {
+        self.time_control
+    }
+
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        self.outcome
+    }
+
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    pub fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GameResponse {
+    game_id: Uuid,
+    players: Vec<Uuid>,
+    variant: String,
+    time_control: Option<TimeControlResponse>,
+    outcome: Option<GameOutcome>,
+    moves: Vec<GameMoveResponse>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl GameResponse {
+    pub fn game_id(&self) -> Uuid {
+        self.game_id
+    }
+
+    pub fn players(&self) -> &Vec<Uuid> {
+        &self.players
+    }
+
+    pub fn variant(&self) -> &str {
+        self.variant.as_str()
+    }
+
+    pub fn time_control(&self) -> Option<TimeControlResponse> {
+        self.time_control
+    }
+
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        self.outcome
+    }
+
+    pub fn moves(&self) -> &Vec<GameMoveResponse> {
+        &self.moves
+    }
+
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    pub fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum GameOutcome {
+    Win { winner: Uuid },
+    WinOnTime { winner: Uuid },
+    Draw,
+    Abandoned,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GameMoveResponse {
+    user_id: Uuid,
+    position: GameMovePositionResponse,
+    made_at: DateTime<Utc>,
+}
+
+impl GameMoveResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn position(&self) -> (u8, u8) {
+        (self.position.x, self.position.y)
+    }
+
+    pub fn made_at(&self) -> DateTime<Utc> {
+        self.made_at
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GameMovePositionResponse {
+    x: u8,
+    y: u8,
+}
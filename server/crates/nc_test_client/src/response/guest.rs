@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GuestResponse {
+    id: Uuid,
+    name: String,
+}
+
+impl GuestResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
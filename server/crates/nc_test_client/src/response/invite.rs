@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct InviteResponse {
+    code: String,
+    link: String,
+    single_use: bool,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl InviteResponse {
+    pub fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    pub fn link(&self) -> &str {
+        self.link.as_str()
+    }
+
+    pub fn single_use(&self) -> bool {
+        self.single_use
+    }
+
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+}
@@ -0,0 +1,78 @@
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct LeaderboardResponse {
+    variant: String,
+    entries: Vec<LeaderboardEntryResponse>,
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+impl LeaderboardResponse {
+    pub fn variant(&self) -> &str {
+        self.variant.as_str()
+    }
+
+    pub fn entries(&self) -> &Vec<LeaderboardEntryResponse> {
+        &self.entries
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn per_page(&self) -> usize {
+        self.per_page
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct LeaderboardEntryResponse {
+    rank: usize,
+    user_id: Uuid,
+    name: String,
+    rating: i32,
+    deviation: i32,
+    provisional: bool,
+    games_played: u32,
+    wins: u32,
+}
+
+impl LeaderboardEntryResponse {
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn rating(&self) -> i32 {
+        self.rating
+    }
+
+    pub fn deviation(&self) -> i32 {
+        self.deviation
+    }
+
+    pub fn provisional(&self) -> bool {
+        self.provisional
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.games_played
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.wins
+    }
+}
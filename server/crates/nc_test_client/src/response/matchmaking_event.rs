@@ -0,0 +1,14 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum MatchmakingEvent {
+    Queued {
+        estimated_wait_seconds: Option<i64>,
+    },
+    MatchFound {
+        room_id: Uuid,
+        game_id: Uuid,
+        opponent: Uuid,
+    },
+}
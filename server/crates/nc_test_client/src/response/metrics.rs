@@ -0,0 +1,36 @@
+#[derive(Debug, serde::Deserialize)]
+pub struct MetricsResponse {
+    room_reaper: RoomReaperMetricsResponse,
+}
+
+impl MetricsResponse {
+    pub fn room_reaper(&self) -> &RoomReaperMetricsResponse {
+        &self.room_reaper
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RoomReaperMetricsResponse {
+    runs: u64,
+    empty_rooms_removed: u64,
+    idle_rooms_removed: u64,
+    games_archived: u64,
+}
+
+impl RoomReaperMetricsResponse {
+    pub fn runs(&self) -> u64 {
+        self.runs
+    }
+
+    pub fn empty_rooms_removed(&self) -> u64 {
+        self.empty_rooms_removed
+    }
+
+    pub fn idle_rooms_removed(&self) -> u64 {
+        self.idle_rooms_removed
+    }
+
+    pub fn games_archived(&self) -> u64 {
+        self.games_archived
+    }
+}
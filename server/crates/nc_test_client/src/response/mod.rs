@@ -1,7 +1,45 @@
+pub use account::*;
+pub use challenge::*;
+pub use challenge_event::*;
 pub use create_room::*;
+pub use game::*;
+pub use guest::*;
+pub use invite::*;
+pub use leaderboard::*;
+pub use matchmaking_event::*;
+pub use metrics::*;
 pub use register_user::*;
+pub use rematch::*;
+pub use room_details::*;
+pub use room_event::*;
+pub use room_list::*;
+pub use room_settings::*;
+pub use series::*;
 pub use simple_error::*;
+pub use tournament::*;
+pub use user_data_export::*;
+pub use user_profile::*;
+pub use user_stats::*;
 
+mod account;
+mod challenge;
+mod challenge_event;
 mod create_room;
+mod game;
+mod guest;
+mod invite;
+mod leaderboard;
+mod matchmaking_event;
+mod metrics;
 mod register_user;
+mod rematch;
+mod room_details;
+mod room_event;
+mod room_list;
+mod room_settings;
+mod series;
 mod simple_error;
+mod tournament;
+mod user_data_export;
+mod user_profile;
+mod user_stats;
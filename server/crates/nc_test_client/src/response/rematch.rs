@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RematchResponse {
+    game_id: Uuid,
+}
+
+impl RematchResponse {
+    pub fn game_id(&self) -> Uuid {
+        self.game_id
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::response::{RoomSettingsResponse, SeriesResponse};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RoomDetailsResponse {
+    room_id: Uuid,
+    private: bool,
+    host: Option<Uuid>,
+    settings: RoomSettingsResponse,
+    members: Vec<RoomMemberResponse>,
+    seated_players: Vec<Uuid>,
+    spectators: Vec<Uuid>,
+    active_game_id: Option<Uuid>,
+    game_lifecycle_state: String,
+    series: Option<SeriesResponse>,
+    created_at: DateTime<Utc>,
+}
+
+impl RoomDetailsResponse {
+    pub fn room_id(&self) -> Uuid {
+        self.room_id
+    }
+
+    pub fn private(&self) -> bool {
+        self.private
+    }
+
+    pub fn host(&self) -> Option<Uuid> {
+        self.host
+    }
+
+    pub fn settings(&self) -> &RoomSettingsResponse {
+        &self.settings
+    }
+
+    pub fn members(&self) -> &Vec<RoomMemberResponse> {
+        &self.members
+    }
+
+    pub fn seated_players(&self) -> &Vec<Uuid> {
+        &self.seated_players
+    }
+
+    pub fn spectators(&self) -> &Vec<Uuid> {
+        &self.spectators
+    }
+
+    pub fn active_game_id(&self) -> Option<Uuid> {
+        self.active_game_id
+    }
+
+    pub fn game_lifecycle_state(&self) -> &str {
+        self.game_lifecycle_state.as_str()
+    }
+
+    pub fn series(&self) -> Option<&SeriesResponse> {
+        self.series.as_ref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct RoomMemberResponse {
+    user_id: Uuid,
+    name: String,
+    role: String,
+    online: bool,
+}
+
+impl RoomMemberResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn role(&self) -> &str {
+        self.role.as_str()
+    }
+
+    /// Whether the member is connected to the room, as opposed to having dropped their connection
+    /// without leaving
+    pub fn online(&self) -> bool {
+        self.online
+    }
+}
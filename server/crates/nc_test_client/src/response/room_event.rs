@@ -0,0 +1,47 @@
+use uuid::Uuid;
+
+use crate::response::{RoomSettingsResponse, SeriesResponse};
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RoomEvent {
+    RematchRequested {
+        room_id: Uuid,
+        user_id: Uuid,
+    },
+    GameStarted {
+        room_id: Uuid,
+        game_id: Uuid,
+        players: Vec<Uuid>,
+        first_mover: Option<Uuid>,
+    },
+    SeriesUpdated {
+        room_id: Uuid,
+        series: SeriesResponse,
+    },
+    HostChanged {
+        room_id: Uuid,
+        host: Uuid,
+    },
+    MemberKicked {
+        room_id: Uuid,
+        user_id: Uuid,
+    },
+    MemberRenamed {
+        room_id: Uuid,
+        user_id: Uuid,
+        name: String,
+    },
+    SettingsUpdated {
+        room_id: Uuid,
+        settings: RoomSettingsResponse,
+    },
+    RoleChanged {
+        room_id: Uuid,
+        user_id: Uuid,
+        role: String,
+    },
+    RoomClosed {
+        room_id: Uuid,
+    },
+}
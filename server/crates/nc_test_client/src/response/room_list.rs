@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RoomListResponse {
+    rooms: Vec<RoomSummaryResponse>,
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+impl RoomListResponse {
+    pub fn rooms(&self) -> &Vec<RoomSummaryResponse> {
+        &self.rooms
+    }
+
+    pub fn room_ids(&self) -> Vec<Uuid> {
+        self.rooms
+            .iter()
+            .map(RoomSummaryResponse::room_id)
+            .collect()
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn per_page(&self) -> usize {
+        self.per_page
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RoomSummaryResponse {
+    room_id: Uuid,
+    member_count: usize,
+    seated_players: Vec<Uuid>,
+    open_seat: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl RoomSummaryResponse {
+    pub fn room_id(&self) -> Uuid {
+        self.room_id
+    }
+
+    pub fn member_count(&self) -> usize {
+        self.member_count
+    }
+
+    pub fn seated_players(&self) -> &Vec<Uuid> {
+        &self.seated_players
+    }
+
+    pub fn open_seat(&self) -> bool {
+        self.open_seat
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
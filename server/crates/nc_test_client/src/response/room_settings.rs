@@ -0,0 +1,46 @@
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct RoomSettingsResponse {
+    max_members: Option<usize>,
+    spectators_allowed: bool,
+    default_variant: String,
+    time_control: Option<TimeControlResponse>,
+    rated: bool,
+}
+
+impl RoomSettingsResponse {
+    pub fn max_members(&self) -> Option<usize> {
+        self.max_members
+    }
+
+    pub fn spectators_allowed(&self) -> bool {
+        self.spectators_allowed
+    }
+
+    pub fn default_variant(&self) -> &str {
+        self.default_variant.as_str()
+    }
+
+    pub fn time_control(&self) -> Option<TimeControlResponse> {
+        self.time_control
+    }
+
+    pub fn rated(&self) -> bool {
+        self.rated
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct TimeControlResponse {
+    initial_seconds: u32,
+    increment_seconds: u32,
+}
+
+impl TimeControlResponse {
+    pub fn initial_seconds(&self) -> u32 {
+        self.initial_seconds
+    }
+
+    pub fn increment_seconds(&self) -> u32 {
+        self.increment_seconds
+    }
+}
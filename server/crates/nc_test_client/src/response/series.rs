@@ -0,0 +1,60 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct SeriesResponse {
+    best_of: u8,
+    games_played: u8,
+    wins_needed: u8,
+    scores: Vec<SeriesScoreResponse>,
+    winner: Option<Uuid>,
+    finished: bool,
+}
+
+impl SeriesResponse {
+    pub fn best_of(&self) -> u8 {
+        self.best_of
+    }
+
+    pub fn games_played(&self) -> u8 {
+        self.games_played
+    }
+
+    pub fn wins_needed(&self) -> u8 {
+        self.wins_needed
+    }
+
+    pub fn scores(&self) -> &Vec<SeriesScoreResponse> {
+        &self.scores
+    }
+
+    pub fn wins(&self, user_id: Uuid) -> Option<u8> {
+        self.scores
+            .iter()
+            .find(|score| score.user_id == user_id)
+            .map(SeriesScoreResponse::wins)
+    }
+
+    pub fn winner(&self) -> Option<Uuid> {
+        self.winner
+    }
+
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct SeriesScoreResponse {
+    user_id: Uuid,
+    wins: u8,
+}
+
+impl SeriesScoreResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn wins(&self) -> u8 {
+        self.wins
+    }
+}
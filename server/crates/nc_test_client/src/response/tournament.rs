@@ -0,0 +1,194 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TournamentResponse {
+    tournament_id: Uuid,
+    name: String,
+    organiser: Uuid,
+    format: String,
+    status: String,
+    variant: String,
+    no_show_timeout_seconds: i64,
+    participants: Vec<TournamentParticipantResponse>,
+    rounds: Vec<TournamentRoundResponse>,
+    current_round: Option<usize>,
+    standings: Vec<StandingResponse>,
+    champion: Option<Uuid>,
+}
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+pub struct TournamentParticipantResponse {
+    user_id: Uuid,
+    seed: Option<usize>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TournamentRoundResponse {
+    round: usize,
+    best_of: u8,
+    matches: Vec<TournamentMatchResponse>,
+}
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+pub struct StandingResponse {
+    rank: usize,
+    user_id: Uuid,
+    points: f64,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+    buchholz: f64,
+    sonneborn_berger: f64,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TournamentMatchResponse {
+    players: [Option<Uuid>; 2],
+    room_id: Option<Uuid>,
+    winner: Option<Uuid>,
+    decided_by: Option<String>,
+}
+
+impl TournamentResponse {
+    pub fn tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn organiser(&self) -> Uuid {
+        self.organiser
+    }
+
+    pub fn format(&self) -> &str {
+        self.format.as_str()
+    }
+
+    pub fn status(&self) -> &str {
+        self.status.as_str()
+    }
+
+    pub fn variant(&self) -> &str {
+        self.variant.as_str()
+    }
+
+    pub fn no_show_timeout_seconds(&self) -> i64 {
+        self.no_show_timeout_seconds
+    }
+
+    pub fn participants(&self) -> &Vec<TournamentParticipantResponse> {
+        &self.participants
+    }
+
+    pub fn rounds(&self) -> &Vec<TournamentRoundResponse> {
+        &self.rounds
+    }
+
+    pub fn current_round(&self) -> Option<usize> {
+        self.current_round
+    }
+
+    pub fn standings(&self) -> &Vec<StandingResponse> {
+        &self.standings
+    }
+
+    pub fn standing_of(&self, user_id: Uuid) -> Option<&StandingResponse> {
+        self.standings
+            .iter()
+            .find(|standing| standing.user_id() == user_id)
+    }
+
+    pub fn champion(&self) -> Option<Uuid> {
+        self.champion
+    }
+
+    /// The undecided match the user has been given a room for, if any
+    pub fn current_match_of(&self, user_id: Uuid) -> Option<&TournamentMatchResponse> {
+        self.rounds
+            .iter()
+            .flat_map(|round| round.matches())
+            .find(|tournament_match| {
+                tournament_match.decided_by().is_none()
+                    && tournament_match.room_id().is_some()
+                    && tournament_match.players().contains(&Some(user_id))
+            })
+    }
+}
+
+impl TournamentParticipantResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn seed(&self) -> Option<usize> {
+        self.seed
+    }
+}
+
+impl StandingResponse {
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn points(&self) -> f64 {
+        self.points
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    pub fn losses(&self) -> u32 {
+        self.losses
+    }
+
+    pub fn buchholz(&self) -> f64 {
+        self.buchholz
+    }
+
+    pub fn sonneborn_berger(&self) -> f64 {
+        self.sonneborn_berger
+    }
+}
+
+impl TournamentRoundResponse {
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    pub fn best_of(&self) -> u8 {
+        self.best_of
+    }
+
+    pub fn matches(&self) -> &Vec<TournamentMatchResponse> {
+        &self.matches
+    }
+}
+
+impl TournamentMatchResponse {
+    pub fn players(&self) -> [Option<Uuid>; 2] {
+        self.players
+    }
+
+    pub fn room_id(&self) -> Option<Uuid> {
+        self.room_id
+    }
+
+    pub fn winner(&self) -> Option<Uuid> {
+        self.winner
+    }
+
+    pub fn decided_by(&self) -> Option<&str> {
+        self.decided_by.as_deref()
+    }
+}
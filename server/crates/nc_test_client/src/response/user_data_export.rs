@@ -0,0 +1,34 @@
+use crate::response::{
+    ChallengeResponse, GameResponse, SessionResponse, TournamentResponse, UserProfileResponse,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UserDataExportResponse {
+    profile: UserProfileResponse,
+    games: Vec<GameResponse>,
+    challenges: Vec<ChallengeResponse>,
+    tournaments: Vec<TournamentResponse>,
+    sessions: Vec<SessionResponse>,
+}
+
+impl UserDataExportResponse {
+    pub fn profile(&self) -> &UserProfileResponse {
+        &self.profile
+    }
+
+    pub fn games(&self) -> &Vec<GameResponse> {
+        &self.games
+    }
+
+    pub fn challenges(&self) -> &Vec<ChallengeResponse> {
+        &self.challenges
+    }
+
+    pub fn tournaments(&self) -> &Vec<TournamentResponse> {
+        &self.tournaments
+    }
+
+    pub fn sessions(&self) -> &Vec<SessionResponse> {
+        &self.sessions
+    }
+}
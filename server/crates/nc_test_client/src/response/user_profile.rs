@@ -0,0 +1,74 @@
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UserProfileResponse {
+    user_id: Uuid,
+    name: String,
+    bio: Option<String>,
+    guest: bool,
+    ratings: Vec<RatingResponse>,
+}
+
+impl UserProfileResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn bio(&self) -> Option<&str> {
+        self.bio.as_deref()
+    }
+
+    pub fn is_guest(&self) -> bool {
+        self.guest
+    }
+
+    pub fn ratings(&self) -> &Vec<RatingResponse> {
+        &self.ratings
+    }
+
+    pub fn rating(&self, variant: &str) -> Option<&RatingResponse> {
+        self.ratings
+            .iter()
+            .find(|rating| rating.variant() == variant)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct RatingResponse {
+    variant: String,
+    rating: i32,
+    deviation: i32,
+    provisional: bool,
+    games_played: u32,
+    wins: u32,
+}
+
+impl RatingResponse {
+    pub fn variant(&self) -> &str {
+        self.variant.as_str()
+    }
+
+    pub fn rating(&self) -> i32 {
+        self.rating
+    }
+
+    pub fn deviation(&self) -> i32 {
+        self.deviation
+    }
+
+    pub fn provisional(&self) -> bool {
+        self.provisional
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.games_played
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.wins
+    }
+}
@@ -0,0 +1,103 @@
+use uuid::Uuid;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UserStatsResponse {
+    user_id: Uuid,
+    games_played: u32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    by_symbol: SymbolStatsResponse,
+    by_move_order: MoveOrderStatsResponse,
+    current_win_streak: u32,
+    best_win_streak: u32,
+    average_move_seconds: Option<f64>,
+}
+
+impl UserStatsResponse {
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.games_played
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub fn losses(&self) -> u32 {
+        self.losses
+    }
+
+    pub fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    pub fn as_cross(&self) -> ResultCountsResponse {
+        self.by_symbol.cross
+    }
+
+    pub fn as_nought(&self) -> ResultCountsResponse {
+        self.by_symbol.nought
+    }
+
+    pub fn moving_first(&self) -> ResultCountsResponse {
+        self.by_move_order.first
+    }
+
+    pub fn moving_second(&self) -> ResultCountsResponse {
+        self.by_move_order.second
+    }
+
+    pub fn current_win_streak(&self) -> u32 {
+        self.current_win_streak
+    }
+
+    pub fn best_win_streak(&self) -> u32 {
+        self.best_win_streak
+    }
+
+    pub fn average_move_seconds(&self) -> Option<f64> {
+        self.average_move_seconds
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SymbolStatsResponse {
+    cross: ResultCountsResponse,
+    nought: ResultCountsResponse,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MoveOrderStatsResponse {
+    first: ResultCountsResponse,
+    second: ResultCountsResponse,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Deserialize)]
+pub struct ResultCountsResponse {
+    games_played: u32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+}
+
+impl ResultCountsResponse {
+    pub fn games_played(&self) -> u32 {
+        self.games_played
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub fn losses(&self) -> u32 {
+        self.losses
+    }
+
+    pub fn draws(&self) -> u32 {
+        self.draws
+    }
+}
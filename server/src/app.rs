@@ -4,27 +4,77 @@ use std::sync::Arc;
 use warp::Filter;
 
 use crate::application::ApplicationServiceImpl;
+use crate::domain::account::AccountManagerImpl;
+use crate::domain::challenge::ChallengeManagerImpl;
 use crate::domain::game::{GameManagerImpl, GamePlayServiceImpl};
+use crate::domain::matchmaking::{MatchmakingManagerImpl, MatchmakingPolicy};
+use crate::domain::rating::Glicko2RatingCalculator;
 use crate::domain::room::{RoomFactoryImpl, RoomManagerImpl};
+use crate::domain::tournament::TournamentManagerImpl;
 use crate::domain::user::UserFactoryImpl;
+use crate::ports::clock::SystemClockAdapter;
+use crate::ports::guest_reaper::{GuestReaperConfig, GuestReaperTask};
 use crate::ports::http::warp::{
-    app_status_filter, become_player_filter, create_room_filter, get_user_name_filter,
-    join_room_filter, make_game_move, register_user_filter, start_new_game_filter,
+    abandon_game_filter, accept_challenge_filter, app_status_filter, ban_user_filter,
+    become_player_filter, challenge_notifications_filter, change_room_role_filter,
+    configure_series_filter, create_challenge_filter, create_guest_filter, create_invite_filter,
+    create_room_filter, create_tournament_filter, decline_challenge_filter, delete_user_filter,
+    export_user_data_filter, game_history_filter, get_room_game_filter, get_series_filter,
+    get_tournament_filter, get_user_name_filter, incoming_challenges_filter, join_room_filter,
+    kick_member_filter, leaderboard_filter, leaderboard_rank_filter, leave_room_filter,
+    list_rooms_filter, list_sessions_filter, login_filter, make_game_move, matchmaking_filter,
+    metrics_filter, outgoing_challenges_filter, recover_unauthorized, register_account_filter,
+    register_for_tournament_filter, register_user_filter, request_rematch_filter,
+    revoke_session_filter, room_details_filter, start_new_game_filter, start_tournament_filter,
+    unban_user_filter, update_room_settings_filter, update_user_profile_filter,
+    upgrade_guest_filter, user_profile_filter, user_stats_filter, InviteLinkSigner,
+    WsChallengeClientProviderAdapter, WsMatchmakingClientProviderAdapter,
     WsUserClientProviderAdapter,
 };
+use crate::ports::matchmaker::{MatchmakerConfig, MatchmakerTask};
+use crate::ports::password_hasher::Argon2PasswordHasherAdapter;
 use crate::ports::persistence::map::{
-    MapGameRepositoryAdapter, MapRoomRepositoryAdapter, MapUserRepositoryAdapter,
+    MapChallengeRepositoryAdapter, MapGameRepositoryAdapter, MapLoginAttemptRepositoryAdapter,
+    MapMatchmakingQueueAdapter, MapRoomRepositoryAdapter, MapSessionRepositoryAdapter,
+    MapTournamentRepositoryAdapter, MapUserRepositoryAdapter,
 };
+use crate::ports::room_reaper::{RoomReaperConfig, RoomReaperMetrics, RoomReaperTask};
+use crate::ports::tournament_referee::{TournamentRefereeConfig, TournamentRefereeTask};
 
 type ApplicationServiceAlias = ApplicationServiceImpl<
     MapRoomRepositoryAdapter,
-    RoomFactoryImpl,
+    RoomFactoryImpl<SystemClockAdapter>,
     MapUserRepositoryAdapter,
-    UserFactoryImpl,
+    UserFactoryImpl<SystemClockAdapter>,
     RoomManagerImpl<
         MapUserRepositoryAdapter,
         MapRoomRepositoryAdapter,
-        GameManagerImpl<MapGameRepositoryAdapter, GamePlayServiceImpl>,
+        GameManagerImpl<MapGameRepositoryAdapter, GamePlayServiceImpl, SystemClockAdapter>,
+        WsUserClientProviderAdapter,
+        SystemClockAdapter,
+        Glicko2RatingCalculator,
+    >,
+    MatchmakingManagerImpl<
+        MapMatchmakingQueueAdapter,
+        WsMatchmakingClientProviderAdapter,
+        SystemClockAdapter,
+    >,
+    ChallengeManagerImpl<
+        MapChallengeRepositoryAdapter,
+        WsChallengeClientProviderAdapter,
+        SystemClockAdapter,
+    >,
+    TournamentManagerImpl<
+        MapTournamentRepositoryAdapter,
+        MapUserRepositoryAdapter,
+        SystemClockAdapter,
+    >,
+    AccountManagerImpl<
+        MapUserRepositoryAdapter,
+        MapSessionRepositoryAdapter,
+        MapLoginAttemptRepositoryAdapter,
+        Argon2PasswordHasherAdapter,
+        SystemClockAdapter,
     >,
 >;
 
@@ -38,34 +88,93 @@ impl App {
 
     pub async fn run(&self) {
         let user_client_provider = Arc::new(WsUserClientProviderAdapter::new());
-        let application_service = Self::application_service();
+        let matchmaking_client_provider = Arc::new(WsMatchmakingClientProviderAdapter::new());
+        let challenge_client_provider = Arc::new(WsChallengeClientProviderAdapter::new());
+        let invite_link_signer = Arc::new(InviteLinkSigner::from_env());
+        let application_service = Arc::new(Self::application_service(
+            user_client_provider.clone(),
+            matchmaking_client_provider.clone(),
+            challenge_client_provider.clone(),
+        ));
+        let room_reaper_metrics = Arc::new(RoomReaperMetrics::new());
+        RoomReaperTask::new(
+            application_service.clone(),
+            RoomReaperConfig::from_env(),
+            room_reaper_metrics.clone(),
+        )
+        .spawn();
+        GuestReaperTask::new(application_service.clone(), GuestReaperConfig::from_env()).spawn();
+        MatchmakerTask::new(application_service.clone(), MatchmakerConfig::from_env()).spawn();
+        TournamentRefereeTask::new(
+            application_service.clone(),
+            TournamentRefereeConfig::from_env(),
+        )
+        .spawn();
 
         let routes = warp::any()
-            .and(warp::path("admin").and(Self::admin_routes()))
-            .or(warp::path("game")
-                .and(Self::game_routes(application_service, user_client_provider)));
+            .and(warp::path("admin").and(Self::admin_routes(room_reaper_metrics)))
+            .or(warp::path("game").and(Self::game_routes(
+                application_service,
+                user_client_provider,
+                matchmaking_client_provider,
+                challenge_client_provider,
+                invite_link_signer,
+            )));
 
         let socket_address = SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 3030);
         warp::serve(routes).run(socket_address).await;
     }
 
-    fn admin_routes() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
-    {
+    fn admin_routes(
+        room_reaper_metrics: Arc<RoomReaperMetrics>,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
         let status = warp::path("status").and(app_status_filter());
+        let metrics = warp::path("metrics").and(metrics_filter(room_reaper_metrics));
 
-        warp::any().and(status)
+        warp::any().and(status).or(metrics)
     }
 
     fn game_routes(
-        application_service: ApplicationServiceAlias,
+        application_service: Arc<ApplicationServiceAlias>,
         user_client_provider: Arc<WsUserClientProviderAdapter>,
+        matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+        challenge_client_provider: Arc<WsChallengeClientProviderAdapter>,
+        invite_link_signer: Arc<InviteLinkSigner>,
     ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-        let application_service = Arc::new(application_service);
-
-        let create_room = create_room_filter(application_service.clone()).and(warp::path::end());
-        let join_room = join_room_filter(application_service.clone(), user_client_provider)
-            .and(warp::path::end());
-        let rooms = warp::path("rooms").and(create_room.or(join_room));
+        let create_room = create_room_filter(application_service.clone());
+        let list_rooms = list_rooms_filter(application_service.clone()).and(warp::path::end());
+        let join_room = join_room_filter(
+            application_service.clone(),
+            user_client_provider,
+            invite_link_signer.clone(),
+        )
+        .and(warp::path::end());
+        let leave_room = leave_room_filter(application_service.clone());
+        let invites = create_invite_filter(application_service.clone(), invite_link_signer);
+        let game_history = game_history_filter(application_service.clone());
+        let room_game = get_room_game_filter(application_service.clone());
+        let room_details = room_details_filter(application_service.clone());
+        let series = configure_series_filter(application_service.clone())
+            .or(get_series_filter(application_service.clone()));
+        let settings = update_room_settings_filter(application_service.clone());
+        let role = change_room_role_filter(application_service.clone());
+        let moderation = kick_member_filter(application_service.clone())
+            .or(ban_user_filter(application_service.clone()))
+            .or(unban_user_filter(application_service.clone()));
+        let rooms = warp::path("rooms").and(
+            create_room
+                .or(list_rooms)
+                .or(join_room)
+                .or(leave_room)
+                .or(game_history)
+                .or(room_game)
+                .or(room_details)
+                .or(series)
+                .or(settings)
+                .or(role)
+                .or(moderation)
+                .or(invites),
+        );
 
         let start_new_game =
             start_new_game_filter(application_service.clone()).and(warp::path::end());
@@ -73,33 +182,131 @@ impl App {
 
         let users = warp::path("users").and(
             register_user_filter(application_service.clone())
-                .or(get_user_name_filter(application_service.clone())),
+                .or(get_user_name_filter(application_service.clone()))
+                .or(user_profile_filter(application_service.clone()))
+                .or(update_user_profile_filter(application_service.clone()))
+                .or(export_user_data_filter(application_service.clone()))
+                .or(delete_user_filter(application_service.clone()))
+                .or(upgrade_guest_filter(application_service.clone()))
+                .or(user_stats_filter(application_service.clone())),
+        );
+
+        let guests = warp::path("guests").and(create_guest_filter(application_service.clone()));
+
+        let accounts =
+            warp::path("accounts").and(register_account_filter(application_service.clone()));
+
+        let sessions = warp::path("sessions").and(
+            login_filter(application_service.clone())
+                .or(list_sessions_filter(application_service.clone()))
+                .or(revoke_session_filter(application_service.clone())),
+        );
+
+        let matchmaking = warp::path("matchmaking")
+            .and(warp::path::end())
+            .and(matchmaking_filter(
+                application_service.clone(),
+                matchmaking_client_provider,
+            ));
+
+        let challenges = warp::path("challenges").and(
+            create_challenge_filter(application_service.clone())
+                .or(incoming_challenges_filter(application_service.clone()))
+                .or(outgoing_challenges_filter(application_service.clone()))
+                .or(accept_challenge_filter(application_service.clone()))
+                .or(decline_challenge_filter(application_service.clone()))
+                .or(challenge_notifications_filter(
+                    application_service.clone(),
+                    challenge_client_provider,
+                )),
+        );
+
+        let leaderboards = warp::path("leaderboards").and(
+            leaderboard_filter(application_service.clone())
+                .or(leaderboard_rank_filter(application_service.clone())),
+        );
+
+        let tournaments = warp::path("tournaments").and(
+            create_tournament_filter(application_service.clone())
+                .or(get_tournament_filter(application_service.clone()))
+                .or(register_for_tournament_filter(application_service.clone()))
+                .or(start_tournament_filter(application_service.clone())),
         );
 
         let players = warp::path("players").and(become_player_filter(application_service.clone()));
 
-        let game_moves = warp::path("moves").and(make_game_move(application_service));
+        let game_moves = warp::path("moves").and(make_game_move(application_service.clone()));
+
+        let abandon_votes =
+            warp::path("abandon-votes").and(abandon_game_filter(application_service.clone()));
+
+        let rematch_requests =
+            warp::path("rematch-requests").and(request_rematch_filter(application_service));
 
         warp::any()
             .and(users)
+            .or(guests)
+            .or(accounts)
+            .or(sessions)
             .or(rooms)
             .or(games)
+            .or(matchmaking)
+            .or(challenges)
+            .or(leaderboards)
+            .or(tournaments)
             .or(players)
             .or(game_moves)
+            .or(abandon_votes)
+            .or(rematch_requests)
+            .recover(recover_unauthorized)
     }
 
-    fn application_service() -> ApplicationServiceAlias {
+    fn application_service(
+        user_client_provider: Arc<WsUserClientProviderAdapter>,
+        matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+        challenge_client_provider: Arc<WsChallengeClientProviderAdapter>,
+    ) -> ApplicationServiceAlias {
         let room_repository = Arc::new(MapRoomRepositoryAdapter::new());
-        let room_factory = RoomFactoryImpl::new();
+        let room_factory = RoomFactoryImpl::new(SystemClockAdapter::new());
         let user_repository = Arc::new(MapUserRepositoryAdapter::new());
-        let user_factory = UserFactoryImpl::new();
+        let user_factory = UserFactoryImpl::new(SystemClockAdapter::new());
         let game_repository = MapGameRepositoryAdapter::new();
         let game_play_service = GamePlayServiceImpl::new();
-        let game_manager = GameManagerImpl::new(game_repository, game_play_service);
+        let game_manager = GameManagerImpl::new(
+            game_repository,
+            game_play_service,
+            SystemClockAdapter::new(),
+        );
         let room_manager = RoomManagerImpl::new(
             user_repository.clone(),
             room_repository.clone(),
             game_manager,
+            user_client_provider,
+            SystemClockAdapter::new(),
+            Glicko2RatingCalculator::new(),
+        );
+        let matchmaking_manager = MatchmakingManagerImpl::new(
+            MapMatchmakingQueueAdapter::new(),
+            matchmaking_client_provider,
+            SystemClockAdapter::new(),
+            MatchmakingPolicy::default(),
+        );
+        let challenge_manager = ChallengeManagerImpl::new(
+            MapChallengeRepositoryAdapter::new(),
+            challenge_client_provider,
+            SystemClockAdapter::new(),
+        );
+        let tournament_manager = TournamentManagerImpl::new(
+            MapTournamentRepositoryAdapter::new(),
+            user_repository.clone(),
+            SystemClockAdapter::new(),
+        );
+        let account_manager = AccountManagerImpl::new(
+            user_repository.clone(),
+            MapSessionRepositoryAdapter::new(),
+            MapLoginAttemptRepositoryAdapter::new(),
+            Argon2PasswordHasherAdapter::new(),
+            SystemClockAdapter::new(),
         );
         ApplicationServiceImpl::new(
             room_repository,
@@ -107,6 +314,10 @@ impl App {
             user_repository,
             user_factory,
             room_manager,
+            matchmaking_manager,
+            challenge_manager,
+            tournament_manager,
+            account_manager,
         )
     }
 }
@@ -1,25 +1,150 @@
+use std::collections::HashSet;
+use std::net::IpAddr;
 use std::sync::Arc;
 
+use chrono::Duration;
 use uuid::Uuid;
 
 use crate::application::error::RoomCreationError;
 use crate::application::{
-    ApplicationServiceGameMove, BecomePlayerError, GameMoveError, JoinRoomError, LeaveRoomError,
-    NewGameError, RegisterUserError,
+    AbandonGameError, AcceptChallengeError, AdmissionError, ApplicationServiceGameMove,
+    BecomePlayerError, ChangeRoomRoleError, ConfigureSeriesError, CreateChallengeError,
+    CreateInviteError, CreateTournamentError, DeclineChallengeError, DeleteUserError,
+    ExportUserDataError, GameHistoryError, GameMoveError, GetRoomGameError, GetSeriesError,
+    JoinRoomError, LeaderboardRankError, LeaveRoomError, MatchmakingError, ModerateMemberError,
+    NewGameError, RegisterAccountError, RegisterUserError, RematchError, RoomDetails,
+    RoomDetailsError, StartTournamentError, TournamentMatchError, TournamentRegistrationError,
+    UpdateRoomSettingsError, UpdateUserProfileError, UpgradeGuestError, UserDataExport,
+};
+use crate::domain::account::{
+    AccountManager, IdentifyUserError, InvalidSessionError, IssuedSession, LoginError,
+    RevokeSessionError, Session,
+};
+use crate::domain::challenge::{Challenge, ChallengeManager};
+use crate::domain::game::{Game, GameOutcome, GameVariant};
+use crate::domain::matchmaking::{Match, MatchPreferences, MatchmakingManager};
+use crate::domain::pagination::{Page, PageRequest};
+use crate::domain::rating::{LeaderboardEntry, LeaderboardOrder, NotRankedError};
+use crate::domain::room::{
+    NewGameError as DomainNewGameError, ReapSummary, Room, RoomExpiryPolicy, RoomFactory,
+    RoomInvite, RoomManager, RoomQuery, RoomRepository, RoomRole, RoomSettings, RoomSettingsUpdate,
+    RoomVisibility, Series, StoreRoomError,
+};
+use crate::domain::stats::PlayerStats;
+use crate::domain::tournament::{
+    GetTournamentError, ReadyMatch, Tournament, TournamentManager, TournamentSettings,
+};
+use crate::domain::user::{
+    validate_user_name, GetUserError, NotAccountOwnerError, RemoveUserError, User, UserFactory,
+    UserInRoomsError, UserProfileUpdate, UserRepository,
 };
-use crate::domain::room::{RoomFactory, RoomManager, RoomRepository};
-use crate::domain::user::{GetUserError, UserFactory, UserRepository};
 
 #[async_trait::async_trait]
 pub(crate) trait ApplicationService {
     async fn register_user(&self, user_name: String) -> Result<Uuid, RegisterUserError>;
 
+    /// Creates a user who logs in with a username and password, named after the username unless
+    /// given a name
+    async fn register_account(
+        &self,
+        username: String,
+        password: String,
+        name: Option<String>,
+    ) -> Result<User, RegisterAccountError>;
+
+    async fn login(
+        &self,
+        username: String,
+        password: String,
+        client: Option<IpAddr>,
+    ) -> Result<IssuedSession, LoginError>;
+
+    /// The active session the bearer token belongs to
+    async fn authenticate(&self, token: String) -> Result<Session, InvalidSessionError>;
+
+    /// The user a request acts on behalf of, from its `user-id` header and bearer token
+    async fn identify_user(
+        &self,
+        user_id: Option<Uuid>,
+        token: Option<String>,
+    ) -> Result<Uuid, IdentifyUserError>;
+
+    async fn sessions(&self, user_id: Uuid) -> Vec<Session>;
+
+    async fn revoke_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<(), RevokeSessionError>;
+
+    /// Creates a guest with a generated name, who is removed after a period of inactivity
+    async fn register_guest(&self) -> Result<User, RegisterUserError>;
+
+    /// Turns the requester's guest account into a full account with the same id
+    async fn upgrade_guest(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+        name: String,
+    ) -> Result<User, UpgradeGuestError>;
+
+    /// Deletes guests who have gone without activity for longer than the time to live, returning
+    /// how many were removed
+    async fn purge_expired_guests(&self, guest_ttl: Duration) -> usize;
+
+    /// Forgets sessions and failed logins that no longer matter, returning how many sessions were
+    /// removed
+    async fn prune_sessions(&self) -> usize;
+
     async fn get_user_name(&self, user_id: Uuid) -> Result<String, GetUserError>;
 
-    async fn create_room(&self) -> Result<Uuid, RoomCreationError>;
+    async fn user_profile(&self, user_id: Uuid) -> Result<User, GetUserError>;
+
+    /// Changes a user's own profile, letting the rooms they are in know if they were renamed
+    async fn update_user_profile(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+        update: UserProfileUpdate,
+    ) -> Result<User, UpdateUserProfileError>;
+
+    /// Everything stored about the user, for the user themselves
+    async fn export_user_data(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+    ) -> Result<UserDataExport, ExportUserDataError>;
+
+    /// Deletes the user's own account, anonymising their moves in the games they played
+    async fn delete_user(&self, user_id: Uuid, requester: Uuid) -> Result<(), DeleteUserError>;
+
+    async fn user_stats(&self, user_id: Uuid) -> Result<PlayerStats, GetUserError>;
+
+    async fn leaderboard(
+        &self,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+        page_request: PageRequest,
+    ) -> Page<LeaderboardEntry>;
+
+    async fn leaderboard_rank(
+        &self,
+        user_id: Uuid,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+    ) -> Result<LeaderboardEntry, LeaderboardRankError>;
+
+    async fn create_room(
+        &self,
+        host: Uuid,
+        visibility: RoomVisibility,
+        settings: RoomSettings,
+    ) -> Result<Uuid, RoomCreationError>;
 
     async fn start_new_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), NewGameError>;
 
+    async fn abandon_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), AbandonGameError>;
+
     async fn become_player(
         &self,
         room_id: Uuid,
@@ -34,7 +159,160 @@ pub(crate) trait ApplicationService {
 
     async fn join_room(&self, room_id: Uuid, user_id: Uuid) -> Result<(), JoinRoomError>;
 
-    async fn leave_room(&self, user_id: Uuid) -> Result<(), LeaveRoomError>;
+    async fn leave_room(&self, room_id: Uuid, user_id: Uuid) -> Result<(), LeaveRoomError>;
+
+    /// Marks the user offline in a room after their connection to it dropped, without giving up
+    /// their seat
+    async fn disconnect_from_room(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), LeaveRoomError>;
+
+    async fn game_history(&self, room_id: Uuid) -> Result<Vec<Game>, GameHistoryError>;
+
+    async fn get_game(&self, room_id: Uuid, game_id: Uuid) -> Result<Game, GetRoomGameError>;
+
+    async fn request_rematch(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>, RematchError>;
+
+    async fn configure_series(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        best_of: u8,
+    ) -> Result<(), ConfigureSeriesError>;
+
+    async fn series(&self, room_id: Uuid) -> Result<Series, GetSeriesError>;
+
+    async fn list_rooms(&self, query: RoomQuery, page_request: PageRequest) -> Page<Room>;
+
+    async fn room_details(&self, room_id: Uuid) -> Result<RoomDetails, RoomDetailsError>;
+
+    async fn kick_member(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        member_id: Uuid,
+    ) -> Result<(), ModerateMemberError>;
+
+    async fn ban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError>;
+
+    async fn unban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError>;
+
+    async fn create_invite(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        single_use: bool,
+        expires_in: Option<Duration>,
+    ) -> Result<RoomInvite, CreateInviteError>;
+
+    async fn admit(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        invite_code: Option<String>,
+    ) -> Result<(), AdmissionError>;
+
+    async fn update_room_settings(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        update: RoomSettingsUpdate,
+    ) -> Result<RoomSettings, UpdateRoomSettingsError>;
+
+    async fn change_room_role(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        role: RoomRole,
+    ) -> Result<(), ChangeRoomRoleError>;
+
+    async fn reap_rooms(&self, policy: RoomExpiryPolicy) -> ReapSummary;
+
+    /// Finishes games whose player to move has run out of time, returning how many were finished
+    async fn flag_games(&self) -> usize;
+
+    /// Queues a user for a quick match, starting one if a compatible opponent is already waiting
+    async fn enter_matchmaking(
+        &self,
+        user_id: Uuid,
+        preferences: MatchPreferences,
+    ) -> Result<Option<Match>, MatchmakingError>;
+
+    async fn leave_matchmaking(&self, user_id: Uuid) -> bool;
+
+    /// Starts matches for waiting users who have become close enough in rating, returning how many
+    /// were started
+    async fn match_waiting_players(&self) -> usize;
+
+    /// Removes challenges that expired unanswered, returning how many were removed
+    async fn purge_expired_challenges(&self) -> usize;
+
+    async fn create_challenge(
+        &self,
+        challenger: Uuid,
+        challenged: Uuid,
+        preferences: MatchPreferences,
+        expires_in: Duration,
+    ) -> Result<Challenge, CreateChallengeError>;
+
+    /// Starts a game between the challenger and the challenged user in a new private room
+    async fn accept_challenge(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Match, AcceptChallengeError>;
+
+    async fn decline_challenge(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DeclineChallengeError>;
+
+    async fn incoming_challenges(&self, user_id: Uuid) -> Result<Vec<Challenge>, GetUserError>;
+
+    async fn outgoing_challenges(&self, user_id: Uuid) -> Result<Vec<Challenge>, GetUserError>;
+
+    async fn create_tournament(
+        &self,
+        organiser: Uuid,
+        name: String,
+        settings: TournamentSettings,
+    ) -> Result<Tournament, CreateTournamentError>;
+
+    async fn tournament(&self, tournament_id: Uuid) -> Result<Tournament, GetTournamentError>;
+
+    async fn register_for_tournament(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, TournamentRegistrationError>;
+
+    /// Closes registration, seeds the bracket and starts the first round's matches
+    async fn start_tournament(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, StartTournamentError>;
+
+    /// Awards tournament matches whose players have not turned up in time to their opponents,
+    /// returning how many were forfeited
+    async fn forfeit_tournament_no_shows(&self) -> usize;
 }
 
 #[derive(Clone)]
@@ -44,23 +322,45 @@ pub(crate) struct ApplicationServiceImpl<
     UR: UserRepository,
     UF: UserFactory,
     RM: RoomManager,
+    MM: MatchmakingManager,
+    CM: ChallengeManager,
+    TM: TournamentManager,
+    AM: AccountManager,
 > {
     room_repository: Arc<RR>,
     room_factory: RF,
     user_repository: Arc<UR>,
     user_factory: UF,
     room_manager: RM,
+    matchmaking_manager: MM,
+    challenge_manager: CM,
+    tournament_manager: TM,
+    account_manager: AM,
 }
 
-impl<RR: RoomRepository, RF: RoomFactory, UR: UserRepository, UF: UserFactory, RM: RoomManager>
-    ApplicationServiceImpl<RR, RF, UR, UF, RM>
+impl<RR, RF, UR, UF, RM, MM, CM, TM, AM> ApplicationServiceImpl<RR, RF, UR, UF, RM, MM, CM, TM, AM>
+where
+    RR: RoomRepository,
+    RF: RoomFactory,
+    UR: UserRepository,
+    UF: UserFactory,
+    RM: RoomManager,
+    MM: MatchmakingManager,
+    CM: ChallengeManager,
+    TM: TournamentManager,
+    AM: AccountManager,
 {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         room_repository: Arc<RR>,
         room_factory: RF,
         user_repository: Arc<UR>,
         user_factory: UF,
         room_manager: RM,
+        matchmaking_manager: MM,
+        challenge_manager: CM,
+        tournament_manager: TM,
+        account_manager: AM,
     ) -> Self {
         ApplicationServiceImpl {
             room_repository,
@@ -68,33 +368,376 @@ impl<RR: RoomRepository, RF: RoomFactory, UR: UserRepository, UF: UserFactory, R
             user_repository,
             user_factory,
             room_manager,
+            matchmaking_manager,
+            challenge_manager,
+            tournament_manager,
+            account_manager,
+        }
+    }
+}
+
+impl<RR, RF, UR, UF, RM, MM, CM, TM, AM> ApplicationServiceImpl<RR, RF, UR, UF, RM, MM, CM, TM, AM>
+where
+    RR: RoomRepository + Send + Sync,
+    RF: RoomFactory + Send + Sync,
+    UR: UserRepository + Send + Sync,
+    UF: UserFactory + Send + Sync,
+    RM: RoomManager + Send + Sync,
+    MM: MatchmakingManager + Send + Sync,
+    CM: ChallengeManager + Send + Sync,
+    TM: TournamentManager + Send + Sync,
+    AM: AccountManager + Send + Sync,
+{
+    /// Removes the user, anonymising the games and tournaments they played in so that the records
+    /// stay intact
+    async fn erase_user(&self, user_id: Uuid) -> Result<(), RemoveUserError> {
+        self.matchmaking_manager.leave_queue(user_id).await;
+        self.challenge_manager.withdraw_all(user_id).await;
+        for tournament in self.tournament_manager.withdraw(user_id).await {
+            self.start_tournament_matches(&tournament).await;
+        }
+        self.room_manager.anonymise_player(user_id).await;
+        self.account_manager.end_sessions(user_id).await;
+        self.user_repository.remove(user_id).await
+    }
+
+    /// Creates a private room hosted by the first player and starts a game between the players in
+    /// it
+    async fn start_match<E>(
+        &self,
+        players: Vec<Uuid>,
+        preferences: MatchPreferences,
+    ) -> Result<Match, E>
+    where
+        E: From<StoreRoomError> + From<DomainNewGameError>,
+    {
+        let room = self.room_factory.create(
+            players[0],
+            RoomVisibility::Private,
+            preferences.room_settings(),
+        );
+        self.start_match_in_room(room, players).await
+    }
+
+    async fn start_match_in_room<E>(&self, room: Room, players: Vec<Uuid>) -> Result<Match, E>
+    where
+        E: From<StoreRoomError> + From<DomainNewGameError>,
+    {
+        self.room_repository.store(&room).await?;
+        let game = self
+            .room_manager
+            .start_match(room.id(), players.clone())
+            .await?;
+        Ok(Match::new(room.id(), game.id(), players))
+    }
+
+    /// Starts a match in a new room for each tournament match whose players are known, with the
+    /// room's series as long as the round's matches
+    async fn start_tournament_matches(&self, tournament: &Tournament) {
+        for ready in tournament.ready_matches() {
+            if let Err(err) = self.start_tournament_match(&ready).await {
+                log::warn!(
+                    "Could not start the match between {:?} in Tournament({}): {}",
+                    ready.players(),
+                    ready.tournament_id(),
+                    err
+                );
+            }
+        }
+    }
+
+    async fn start_tournament_match(&self, ready: &ReadyMatch) -> Result<(), TournamentMatchError> {
+        let players = ready.players();
+        let mut room = self.room_factory.create(
+            players[0],
+            RoomVisibility::Private,
+            ready.preferences().room_settings(),
+        );
+        room.set_series(Series::new(ready.best_of(), 0, vec![]));
+        let found = self
+            .start_match_in_room::<TournamentMatchError>(room, players)
+            .await?;
+        self.tournament_manager
+            .assign_room(ready.tournament_id(), ready.position(), found.room_id())
+            .await?;
+        Ok(())
+    }
+
+    /// Moves the winner of a tournament match on once the series in its room is over
+    async fn advance_tournament(&self, room_id: Uuid) {
+        let series = match self.room_repository.get(room_id).await {
+            Ok(room) => match room.series() {
+                Some(series) if series.is_finished() => series.clone(),
+                _ => return,
+            },
+            Err(_) => return,
+        };
+        self.decide_tournament_match(room_id, &series).await;
+    }
+
+    /// Awards the tournament match played in the room to the player with more wins, leaving a
+    /// level series to be decided by the tournament. Also used when the room is removed before
+    /// the series is over, so the tournament is not left waiting on it.
+    async fn decide_tournament_match(&self, room_id: Uuid, series: &Series) {
+        if let Some(tournament) = self
+            .tournament_manager
+            .record_result(room_id, series.leader())
+            .await
+        {
+            self.start_tournament_matches(&tournament).await;
         }
     }
+
+    /// A player drawn into the tournament match in the room who has not moved in its first game,
+    /// the one to move first if neither has
+    async fn absentee(&self, room_id: Uuid) -> Option<Uuid> {
+        let room = self.room_repository.get(room_id).await.ok()?;
+        let first_game_id = room
+            .game_history()
+            .first()
+            .copied()
+            .or_else(|| room.active_game_id())?;
+        let game = self
+            .room_manager
+            .get_game(room_id, first_game_id)
+            .await
+            .ok()?;
+        if game.outcome() == Some(GameOutcome::Abandoned) {
+            return None;
+        }
+        let moved: HashSet<Uuid> = game.moves().iter().map(|made| made.user_id()).collect();
+        let absent: Vec<Uuid> = self
+            .tournament_manager
+            .match_players(room_id)
+            .await
+            .into_iter()
+            .filter(|player| !moved.contains(player))
+            .collect();
+        absent
+            .iter()
+            .find(|player| game.crosses() == Some(**player))
+            .or_else(|| absent.first())
+            .copied()
+    }
 }
 
 #[async_trait::async_trait]
-impl<RR, RF, UR, UF, RM> ApplicationService for ApplicationServiceImpl<RR, RF, UR, UF, RM>
+impl<RR, RF, UR, UF, RM, MM, CM, TM, AM> ApplicationService
+    for ApplicationServiceImpl<RR, RF, UR, UF, RM, MM, CM, TM, AM>
 where
     RR: RoomRepository + Send + Sync,
     RF: RoomFactory + Send + Sync,
     UR: UserRepository + Send + Sync,
     UF: UserFactory + Send + Sync,
     RM: RoomManager + Send + Sync,
+    MM: MatchmakingManager + Send + Sync,
+    CM: ChallengeManager + Send + Sync,
+    TM: TournamentManager + Send + Sync,
+    AM: AccountManager + Send + Sync,
 {
     async fn register_user(&self, user_name: String) -> Result<Uuid, RegisterUserError> {
-        let user = self.user_factory.create(user_name);
+        let user = self.user_factory.create(user_name)?;
         self.user_repository.store(&user).await?;
         Ok(user.id())
     }
 
+    async fn register_account(
+        &self,
+        username: String,
+        password: String,
+        name: Option<String>,
+    ) -> Result<User, RegisterAccountError> {
+        let name = name.unwrap_or_else(|| username.clone());
+        validate_user_name(&name)?;
+        let credentials = self
+            .account_manager
+            .create_credentials(username, &password)
+            .await?;
+        let user = self.user_factory.create_account(name, credentials)?;
+        self.user_repository.store(&user).await?;
+        Ok(user)
+    }
+
+    async fn login(
+        &self,
+        username: String,
+        password: String,
+        client: Option<IpAddr>,
+    ) -> Result<IssuedSession, LoginError> {
+        self.account_manager
+            .login(&username, &password, client)
+            .await
+    }
+
+    async fn authenticate(&self, token: String) -> Result<Session, InvalidSessionError> {
+        self.account_manager.authenticate(&token).await
+    }
+
+    async fn identify_user(
+        &self,
+        user_id: Option<Uuid>,
+        token: Option<String>,
+    ) -> Result<Uuid, IdentifyUserError> {
+        self.account_manager
+            .identify(user_id, token.as_deref())
+            .await
+    }
+
+    async fn sessions(&self, user_id: Uuid) -> Vec<Session> {
+        self.account_manager.sessions(user_id).await
+    }
+
+    async fn revoke_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<(), RevokeSessionError> {
+        self.account_manager.revoke(user_id, session_id).await
+    }
+
+    async fn register_guest(&self) -> Result<User, RegisterUserError> {
+        let guest = self.user_factory.create_guest();
+        self.user_repository.store(&guest).await?;
+        Ok(guest)
+    }
+
+    async fn upgrade_guest(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+        name: String,
+    ) -> Result<User, UpgradeGuestError> {
+        let (user, _) = self
+            .user_repository
+            .update_with(user_id, |user| {
+                if requester != user.id() {
+                    return Err(NotAccountOwnerError::new(user_id, requester).into());
+                }
+                Ok::<_, UpgradeGuestError>(user.upgrade(name)?)
+            })
+            .await?;
+        self.room_manager.announce_rename(&user).await;
+        Ok(user)
+    }
+
+    async fn purge_expired_guests(&self, guest_ttl: Duration) -> usize {
+        let mut purged = 0;
+        for guest in self.room_manager.expired_guests(guest_ttl).await {
+            match self.erase_user(guest.id()).await {
+                Ok(_) => purged += 1,
+                Err(err) => log::warn!("Failed to purge guest User({}): {}", guest.id(), err),
+            }
+        }
+        purged
+    }
+
+    async fn prune_sessions(&self) -> usize {
+        self.account_manager.prune().await
+    }
+
     async fn get_user_name(&self, user_id: Uuid) -> Result<String, GetUserError> {
         let user = self.user_repository.get(user_id).await?;
 
         Ok(user.name().to_string())
     }
 
-    async fn create_room(&self) -> Result<Uuid, RoomCreationError> {
-        let room = self.room_factory.create();
+    async fn user_profile(&self, user_id: Uuid) -> Result<User, GetUserError> {
+        self.user_repository.get(user_id).await
+    }
+
+    async fn update_user_profile(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+        update: UserProfileUpdate,
+    ) -> Result<User, UpdateUserProfileError> {
+        let (user, renamed) = self
+            .user_repository
+            .update_with(user_id, |user| {
+                if requester != user.id() {
+                    return Err(NotAccountOwnerError::new(user_id, requester).into());
+                }
+                Ok::<_, UpdateUserProfileError>(update.apply_to(user)?)
+            })
+            .await?;
+
+        if renamed {
+            self.room_manager.announce_rename(&user).await;
+        }
+        Ok(user)
+    }
+
+    async fn export_user_data(
+        &self,
+        user_id: Uuid,
+        requester: Uuid,
+    ) -> Result<UserDataExport, ExportUserDataError> {
+        let user = self.user_repository.get(user_id).await?;
+        if requester != user.id() {
+            return Err(NotAccountOwnerError::new(user_id, requester).into());
+        }
+
+        let games = self.room_manager.games_involving(user.id()).await;
+        let challenges = self.challenge_manager.of_user(user.id()).await;
+        let tournaments = self.tournament_manager.of_user(user.id()).await;
+        let sessions = self.account_manager.sessions(user.id()).await;
+        Ok(UserDataExport::new(
+            user,
+            games,
+            challenges,
+            tournaments,
+            sessions,
+        ))
+    }
+
+    async fn delete_user(&self, user_id: Uuid, requester: Uuid) -> Result<(), DeleteUserError> {
+        let user = self.user_repository.get(user_id).await?;
+        if requester != user.id() {
+            return Err(NotAccountOwnerError::new(user_id, requester).into());
+        }
+        if !self.room_repository.with_member(user.id()).await.is_empty() {
+            return Err(UserInRoomsError(user.id()).into());
+        }
+
+        self.erase_user(user.id()).await?;
+        Ok(())
+    }
+
+    async fn user_stats(&self, user_id: Uuid) -> Result<PlayerStats, GetUserError> {
+        self.room_manager.player_stats(user_id).await
+    }
+
+    async fn leaderboard(
+        &self,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+        page_request: PageRequest,
+    ) -> Page<LeaderboardEntry> {
+        self.user_repository
+            .leaderboard(variant, order, page_request)
+            .await
+    }
+
+    async fn leaderboard_rank(
+        &self,
+        user_id: Uuid,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+    ) -> Result<LeaderboardEntry, LeaderboardRankError> {
+        self.user_repository
+            .leaderboard_entry(user_id, variant, order)
+            .await?
+            .ok_or_else(|| NotRankedError::new(user_id, variant).into())
+    }
+
+    async fn create_room(
+        &self,
+        host: Uuid,
+        visibility: RoomVisibility,
+        settings: RoomSettings,
+    ) -> Result<Uuid, RoomCreationError> {
+        settings.validate()?;
+        let room = self.room_factory.create(host, visibility, settings);
         self.room_repository.store(&room).await?;
         Ok(room.id())
     }
@@ -106,6 +749,13 @@ where
             .map_err(NewGameError::from)
     }
 
+    async fn abandon_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), AbandonGameError> {
+        self.room_manager
+            .abandon_game(room_id, user_id)
+            .await
+            .map_err(AbandonGameError::from)
+    }
+
     async fn become_player(
         &self,
         room_id: Uuid,
@@ -122,9 +772,12 @@ where
         room_id: Uuid,
         game_move: ApplicationServiceGameMove,
     ) -> Result<(), GameMoveError> {
-        self.room_manager
+        let result = self
+            .room_manager
             .make_game_move(room_id, game_move.into())
-            .await?;
+            .await;
+        self.advance_tournament(room_id).await;
+        result?;
         Ok(())
     }
 
@@ -135,10 +788,366 @@ where
             .map_err(JoinRoomError::from)
     }
 
-    async fn leave_room(&self, user_id: Uuid) -> Result<(), LeaveRoomError> {
+    async fn leave_room(&self, room_id: Uuid, user_id: Uuid) -> Result<(), LeaveRoomError> {
         self.room_manager
-            .leave_room(user_id)
+            .leave_room(user_id, room_id)
             .await
             .map_err(LeaveRoomError::from)
     }
+
+    async fn disconnect_from_room(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), LeaveRoomError> {
+        self.room_manager
+            .disconnect(user_id, room_id)
+            .await
+            .map_err(LeaveRoomError::from)
+    }
+
+    async fn game_history(&self, room_id: Uuid) -> Result<Vec<Game>, GameHistoryError> {
+        self.room_manager
+            .game_history(room_id)
+            .await
+            .map_err(GameHistoryError::from)
+    }
+
+    async fn get_game(&self, room_id: Uuid, game_id: Uuid) -> Result<Game, GetRoomGameError> {
+        self.room_manager
+            .get_game(room_id, game_id)
+            .await
+            .map_err(GetRoomGameError::from)
+    }
+
+    async fn request_rematch(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>, RematchError> {
+        self.room_manager
+            .request_rematch(room_id, user_id)
+            .await
+            .map_err(RematchError::from)
+    }
+
+    async fn configure_series(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        best_of: u8,
+    ) -> Result<(), ConfigureSeriesError> {
+        self.room_manager
+            .configure_series(room_id, user_id, best_of)
+            .await
+            .map_err(ConfigureSeriesError::from)
+    }
+
+    async fn series(&self, room_id: Uuid) -> Result<Series, GetSeriesError> {
+        self.room_manager
+            .series(room_id)
+            .await
+            .map_err(GetSeriesError::from)
+    }
+
+    async fn list_rooms(&self, query: RoomQuery, page_request: PageRequest) -> Page<Room> {
+        self.room_repository.find(&query, page_request).await
+    }
+
+    async fn room_details(&self, room_id: Uuid) -> Result<RoomDetails, RoomDetailsError> {
+        let room = self.room_repository.get(room_id).await?;
+
+        let mut members = Vec::with_capacity(room.members().len());
+        for member_id in room.members() {
+            members.push(self.user_repository.get(*member_id).await?);
+        }
+        members.sort_by(|a, b| a.name().cmp(b.name()).then_with(|| a.id().cmp(&b.id())));
+
+        Ok(RoomDetails::new(room, members))
+    }
+
+    async fn kick_member(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        member_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        self.room_manager
+            .kick_member(room_id, user_id, member_id)
+            .await
+            .map_err(ModerateMemberError::from)
+    }
+
+    async fn ban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        self.room_manager
+            .ban_user(room_id, user_id, banned_user_id)
+            .await
+            .map_err(ModerateMemberError::from)
+    }
+
+    async fn unban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        self.room_manager
+            .unban_user(room_id, user_id, banned_user_id)
+            .await
+            .map_err(ModerateMemberError::from)
+    }
+
+    async fn create_invite(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        single_use: bool,
+        expires_in: Option<Duration>,
+    ) -> Result<RoomInvite, CreateInviteError> {
+        self.room_manager
+            .create_invite(room_id, user_id, single_use, expires_in)
+            .await
+            .map_err(CreateInviteError::from)
+    }
+
+    async fn admit(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        invite_code: Option<String>,
+    ) -> Result<(), AdmissionError> {
+        self.room_manager
+            .admit(room_id, user_id, invite_code)
+            .await
+            .map_err(AdmissionError::from)
+    }
+
+    async fn update_room_settings(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        update: RoomSettingsUpdate,
+    ) -> Result<RoomSettings, UpdateRoomSettingsError> {
+        self.room_manager
+            .update_settings(room_id, user_id, update)
+            .await
+            .map_err(UpdateRoomSettingsError::from)
+    }
+
+    async fn change_room_role(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        role: RoomRole,
+    ) -> Result<(), ChangeRoomRoleError> {
+        self.room_manager
+            .change_role(room_id, user_id, role)
+            .await
+            .map_err(ChangeRoomRoleError::from)
+    }
+
+    async fn reap_rooms(&self, policy: RoomExpiryPolicy) -> ReapSummary {
+        let summary = self.room_manager.reap_rooms(policy).await;
+        for (room_id, series) in summary.removed_series() {
+            self.decide_tournament_match(*room_id, series).await;
+        }
+        summary
+    }
+
+    async fn flag_games(&self) -> usize {
+        let rooms = self.room_manager.flag_games().await;
+        for room_id in &rooms {
+            self.advance_tournament(*room_id).await;
+        }
+        rooms.len()
+    }
+
+    async fn enter_matchmaking(
+        &self,
+        user_id: Uuid,
+        preferences: MatchPreferences,
+    ) -> Result<Option<Match>, MatchmakingError> {
+        let user = self.user_repository.get(user_id).await?;
+        let rating = user.rating(preferences.variant()).value();
+        let pairing = match self
+            .matchmaking_manager
+            .enter_queue(user.id(), preferences, rating)
+            .await?
+        {
+            Some(pairing) => pairing,
+            None => return Ok(None),
+        };
+
+        let found = self
+            .start_match::<MatchmakingError>(pairing.players(), pairing.preferences())
+            .await?;
+        self.matchmaking_manager.announce_match(&found).await;
+        Ok(Some(found))
+    }
+
+    async fn leave_matchmaking(&self, user_id: Uuid) -> bool {
+        self.matchmaking_manager.leave_queue(user_id).await
+    }
+
+    async fn match_waiting_players(&self) -> usize {
+        let mut started = 0;
+        for pairing in self.matchmaking_manager.pair_waiting().await {
+            match self
+                .start_match::<MatchmakingError>(pairing.players(), pairing.preferences())
+                .await
+            {
+                Ok(found) => {
+                    self.matchmaking_manager.announce_match(&found).await;
+                    started += 1;
+                }
+                Err(err) => log::warn!(
+                    "Could not start a match between {:?}: {}",
+                    pairing.players(),
+                    err
+                ),
+            }
+        }
+        started
+    }
+
+    async fn purge_expired_challenges(&self) -> usize {
+        self.challenge_manager.purge_expired().await
+    }
+
+    async fn create_challenge(
+        &self,
+        challenger: Uuid,
+        challenged: Uuid,
+        preferences: MatchPreferences,
+        expires_in: Duration,
+    ) -> Result<Challenge, CreateChallengeError> {
+        let challenger = self.user_repository.get(challenger).await?;
+        let challenged = self.user_repository.get(challenged).await?;
+        let challenge = self
+            .challenge_manager
+            .issue(challenger.id(), challenged.id(), preferences, expires_in)
+            .await?;
+        Ok(challenge)
+    }
+
+    async fn accept_challenge(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Match, AcceptChallengeError> {
+        let challenge = self.challenge_manager.accept(challenge_id, user_id).await?;
+        let found = match self
+            .start_match::<AcceptChallengeError>(
+                vec![challenge.challenger(), challenge.challenged()],
+                challenge.preferences(),
+            )
+            .await
+        {
+            Ok(found) => found,
+            Err(err) => {
+                self.challenge_manager.reopen(&challenge).await;
+                return Err(err);
+            }
+        };
+        self.challenge_manager
+            .announce_acceptance(&challenge, &found)
+            .await;
+        Ok(found)
+    }
+
+    async fn decline_challenge(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DeclineChallengeError> {
+        self.challenge_manager
+            .decline(challenge_id, user_id)
+            .await
+            .map_err(DeclineChallengeError::from)
+    }
+
+    async fn incoming_challenges(&self, user_id: Uuid) -> Result<Vec<Challenge>, GetUserError> {
+        let user = self.user_repository.get(user_id).await?;
+        Ok(self.challenge_manager.incoming(user.id()).await)
+    }
+
+    async fn outgoing_challenges(&self, user_id: Uuid) -> Result<Vec<Challenge>, GetUserError> {
+        let user = self.user_repository.get(user_id).await?;
+        Ok(self.challenge_manager.outgoing(user.id()).await)
+    }
+
+    async fn create_tournament(
+        &self,
+        organiser: Uuid,
+        name: String,
+        settings: TournamentSettings,
+    ) -> Result<Tournament, CreateTournamentError> {
+        let organiser = self.user_repository.get(organiser).await?;
+        let tournament = self
+            .tournament_manager
+            .create(organiser.id(), name, settings)
+            .await?;
+        Ok(tournament)
+    }
+
+    async fn tournament(&self, tournament_id: Uuid) -> Result<Tournament, GetTournamentError> {
+        self.tournament_manager.get(tournament_id).await
+    }
+
+    async fn register_for_tournament(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, TournamentRegistrationError> {
+        let user = self.user_repository.get(user_id).await?;
+        let tournament = self
+            .tournament_manager
+            .register(tournament_id, user.id())
+            .await?;
+        Ok(tournament)
+    }
+
+    async fn start_tournament(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, StartTournamentError> {
+        let tournament = self
+            .tournament_manager
+            .start(tournament_id, user_id)
+            .await?;
+        self.start_tournament_matches(&tournament).await;
+        Ok(self
+            .tournament_manager
+            .get(tournament_id)
+            .await
+            .unwrap_or(tournament))
+    }
+
+    async fn forfeit_tournament_no_shows(&self) -> usize {
+        let mut forfeited = 0;
+        for room_id in self.tournament_manager.overdue_rooms().await {
+            let absentee = match self.absentee(room_id).await {
+                Some(absentee) => absentee,
+                None => {
+                    self.tournament_manager.confirm_attendance(room_id).await;
+                    continue;
+                }
+            };
+            if let Some(tournament) = self
+                .tournament_manager
+                .record_no_show(room_id, absentee)
+                .await
+            {
+                self.start_tournament_matches(&tournament).await;
+                forfeited += 1;
+            }
+        }
+        forfeited
+    }
 }
@@ -1,26 +1,146 @@
+use crate::domain::account::CreateCredentialsError;
+use crate::domain::challenge::{IssueChallengeError, RespondToChallengeError};
+use crate::domain::matchmaking::{AlreadyQueuedError, EnqueueError};
+use crate::domain::rating::NotRankedError;
 use crate::domain::room::{
-    AddPlayerError, JoinRoomError as DomainJoinRoomError, LeaveRoomError as DomainLeaveRoomError,
-    NewGameError as DomainNewGameError, StoreRoomError,
+    AbandonGameError as DomainAbandonGameError, AddPlayerError,
+    AdmissionError as DomainAdmissionError, ChangeRoleError as DomainChangeRoleError,
+    ConfigureSeriesError as DomainConfigureSeriesError,
+    CreateInviteError as DomainCreateInviteError, GameHistoryError as DomainGameHistoryError,
+    GetRoomError, GetRoomGameError as DomainGetRoomGameError,
+    GetSeriesError as DomainGetSeriesError, InvalidRoomSettingsError,
+    JoinRoomError as DomainJoinRoomError, LeaveRoomError as DomainLeaveRoomError,
+    ModerateMemberError as DomainModerateMemberError, NewGameError as DomainNewGameError,
+    RematchError as DomainRematchError, RoomNotFoundError, StoreRoomError,
+    UpdateSettingsError as DomainUpdateSettingsError,
+};
+use crate::domain::tournament::{
+    CreateTournamentError as DomainCreateTournamentError, RegisterForTournamentError,
+    StartTournamentError as DomainStartTournamentError, UpdateTournamentError,
+};
+use crate::domain::user::{
+    GetUserError, InvalidProfileError, NotAccountOwnerError, RemoveUserError, StoreUserError,
+    UpdateUserError, UpgradeGuestError as DomainUpgradeGuestError, UserInRoomsError,
+    UserNotFoundError,
 };
-use crate::domain::user::StoreUserError;
 
 #[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub(crate) struct RoomCreationError {
-    #[from]
-    cause: StoreRoomError,
+pub(crate) enum RoomCreationError {
+    #[error(transparent)]
+    Store(#[from] StoreRoomError),
+    #[error(transparent)]
+    InvalidSettings(#[from] InvalidRoomSettingsError),
 }
 
 #[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub(crate) struct RegisterUserError {
-    #[from]
-    cause: StoreUserError,
+pub(crate) enum RegisterUserError {
+    #[error(transparent)]
+    Store(#[from] StoreUserError),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidProfileError),
 }
 
-impl RegisterUserError {
-    pub(crate) fn cause(&self) -> &StoreUserError {
-        &self.cause
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RegisterAccountError {
+    #[error(transparent)]
+    InvalidCredentials(#[from] CreateCredentialsError),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidProfileError),
+    #[error(transparent)]
+    Store(#[from] StoreUserError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum UpdateUserProfileError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    NotAccountOwner(#[from] NotAccountOwnerError),
+    #[error(transparent)]
+    InvalidProfile(#[from] InvalidProfileError),
+}
+
+impl From<GetUserError> for UpdateUserProfileError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateUserError> for UpdateUserProfileError {
+    fn from(err: UpdateUserError) -> Self {
+        match err {
+            UpdateUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum UpgradeGuestError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    NotAccountOwner(#[from] NotAccountOwnerError),
+    #[error(transparent)]
+    Upgrade(#[from] DomainUpgradeGuestError),
+}
+
+impl From<GetUserError> for UpgradeGuestError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateUserError> for UpgradeGuestError {
+    fn from(err: UpdateUserError) -> Self {
+        match err {
+            UpdateUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ExportUserDataError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    NotAccountOwner(#[from] NotAccountOwnerError),
+}
+
+impl From<GetUserError> for ExportUserDataError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum DeleteUserError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    NotAccountOwner(#[from] NotAccountOwnerError),
+    #[error(transparent)]
+    InRooms(#[from] UserInRoomsError),
+}
+
+impl From<GetUserError> for DeleteUserError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<RemoveUserError> for DeleteUserError {
+    fn from(err: RemoveUserError) -> Self {
+        match err {
+            RemoveUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
     }
 }
 
@@ -44,6 +164,12 @@ pub(crate) struct LeaveRoomError {
     cause: DomainLeaveRoomError,
 }
 
+impl LeaveRoomError {
+    pub(crate) fn cause(&self) -> &DomainLeaveRoomError {
+        &self.cause
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub(crate) struct NewGameError {
@@ -82,3 +208,308 @@ impl GameMoveError {
         &self.cause
     }
 }
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct AbandonGameError {
+    #[from]
+    cause: DomainAbandonGameError,
+}
+
+impl AbandonGameError {
+    pub(crate) fn cause(&self) -> &DomainAbandonGameError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct GameHistoryError {
+    #[from]
+    cause: DomainGameHistoryError,
+}
+
+impl GameHistoryError {
+    pub(crate) fn cause(&self) -> &DomainGameHistoryError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct GetRoomGameError {
+    #[from]
+    cause: DomainGetRoomGameError,
+}
+
+impl GetRoomGameError {
+    pub(crate) fn cause(&self) -> &DomainGetRoomGameError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct RematchError {
+    #[from]
+    cause: DomainRematchError,
+}
+
+impl RematchError {
+    pub(crate) fn cause(&self) -> &DomainRematchError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct ConfigureSeriesError {
+    #[from]
+    cause: DomainConfigureSeriesError,
+}
+
+impl ConfigureSeriesError {
+    pub(crate) fn cause(&self) -> &DomainConfigureSeriesError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct GetSeriesError {
+    #[from]
+    cause: DomainGetSeriesError,
+}
+
+impl GetSeriesError {
+    pub(crate) fn cause(&self) -> &DomainGetSeriesError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct ModerateMemberError {
+    #[from]
+    cause: DomainModerateMemberError,
+}
+
+impl ModerateMemberError {
+    pub(crate) fn cause(&self) -> &DomainModerateMemberError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct CreateInviteError {
+    #[from]
+    cause: DomainCreateInviteError,
+}
+
+impl CreateInviteError {
+    pub(crate) fn cause(&self) -> &DomainCreateInviteError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct AdmissionError {
+    #[from]
+    cause: DomainAdmissionError,
+}
+
+impl AdmissionError {
+    pub(crate) fn cause(&self) -> &DomainAdmissionError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct UpdateRoomSettingsError {
+    #[from]
+    cause: DomainUpdateSettingsError,
+}
+
+impl UpdateRoomSettingsError {
+    pub(crate) fn cause(&self) -> &DomainUpdateSettingsError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct ChangeRoomRoleError {
+    #[from]
+    cause: DomainChangeRoleError,
+}
+
+impl ChangeRoomRoleError {
+    pub(crate) fn cause(&self) -> &DomainChangeRoleError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RoomDetailsError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    MemberNotFound(#[from] UserNotFoundError),
+}
+
+impl From<GetRoomError> for RoomDetailsError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetUserError> for RoomDetailsError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum MatchmakingError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    AlreadyQueued(#[from] AlreadyQueuedError),
+    #[error(transparent)]
+    StoreRoom(#[from] StoreRoomError),
+    #[error(transparent)]
+    NewGame(#[from] DomainNewGameError),
+}
+
+impl From<GetUserError> for MatchmakingError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<EnqueueError> for MatchmakingError {
+    fn from(err: EnqueueError) -> Self {
+        match err {
+            EnqueueError::AlreadyQueued(already_queued_error) => already_queued_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateChallengeError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    Issue(#[from] IssueChallengeError),
+}
+
+impl From<GetUserError> for CreateChallengeError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AcceptChallengeError {
+    #[error(transparent)]
+    Respond(#[from] RespondToChallengeError),
+    #[error(transparent)]
+    StoreRoom(#[from] StoreRoomError),
+    #[error(transparent)]
+    NewGame(#[from] DomainNewGameError),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct DeclineChallengeError {
+    #[from]
+    cause: RespondToChallengeError,
+}
+
+impl DeclineChallengeError {
+    pub(crate) fn cause(&self) -> &RespondToChallengeError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum LeaderboardRankError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    NotRanked(#[from] NotRankedError),
+}
+
+impl From<GetUserError> for LeaderboardRankError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateTournamentError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    Create(#[from] DomainCreateTournamentError),
+}
+
+impl From<GetUserError> for CreateTournamentError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum TournamentRegistrationError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    Register(#[from] RegisterForTournamentError),
+}
+
+impl From<GetUserError> for TournamentRegistrationError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub(crate) struct StartTournamentError {
+    #[from]
+    cause: DomainStartTournamentError,
+}
+
+impl StartTournamentError {
+    pub(crate) fn cause(&self) -> &DomainStartTournamentError {
+        &self.cause
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum TournamentMatchError {
+    #[error(transparent)]
+    StoreRoom(#[from] StoreRoomError),
+    #[error(transparent)]
+    NewGame(#[from] DomainNewGameError),
+    #[error(transparent)]
+    UpdateTournament(#[from] UpdateTournamentError),
+}
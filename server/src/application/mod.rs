@@ -1,7 +1,11 @@
 pub(crate) use application_service::*;
 pub(crate) use error::*;
 pub(crate) use game_move::*;
+pub(crate) use room_details::*;
+pub(crate) use user_data_export::*;
 
 mod application_service;
 mod error;
 mod game_move;
+mod room_details;
+mod user_data_export;
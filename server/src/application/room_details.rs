@@ -0,0 +1,23 @@
+use crate::domain::room::Room;
+use crate::domain::user::User;
+
+/// A room together with the users who are members of it
+pub(crate) struct RoomDetails {
+    room: Room,
+    members: Vec<User>,
+}
+
+impl RoomDetails {
+    pub(crate) fn new(room: Room, members: Vec<User>) -> Self {
+        RoomDetails { room, members }
+    }
+
+    pub(crate) fn room(&self) -> &Room {
+        &self.room
+    }
+
+    /// Members ordered by name
+    pub(crate) fn members(&self) -> &Vec<User> {
+        &self.members
+    }
+}
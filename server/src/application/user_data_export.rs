@@ -0,0 +1,56 @@
+use crate::domain::account::Session;
+use crate::domain::challenge::Challenge;
+use crate::domain::game::Game;
+use crate::domain::tournament::Tournament;
+use crate::domain::user::User;
+
+/// Everything stored about a user, gathered for them to take away
+pub(crate) struct UserDataExport {
+    user: User,
+    games: Vec<Game>,
+    challenges: Vec<Challenge>,
+    tournaments: Vec<Tournament>,
+    sessions: Vec<Session>,
+}
+
+impl UserDataExport {
+    pub(crate) fn new(
+        user: User,
+        games: Vec<Game>,
+        challenges: Vec<Challenge>,
+        tournaments: Vec<Tournament>,
+        sessions: Vec<Session>,
+    ) -> Self {
+        UserDataExport {
+            user,
+            games,
+            challenges,
+            tournaments,
+            sessions,
+        }
+    }
+
+    pub(crate) fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Games in any state the user took part in, oldest first
+    pub(crate) fn games(&self) -> &Vec<Game> {
+        &self.games
+    }
+
+    /// Challenges the user sent or received, oldest first
+    pub(crate) fn challenges(&self) -> &Vec<Challenge> {
+        &self.challenges
+    }
+
+    /// Tournaments the user organises or has entered, oldest first
+    pub(crate) fn tournaments(&self) -> &Vec<Tournament> {
+        &self.tournaments
+    }
+
+    /// Active login sessions of the user's account
+    pub(crate) fn sessions(&self) -> &Vec<Session> {
+        &self.sessions
+    }
+}
@@ -0,0 +1,268 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::account::{
+    generate_session_token, session_token_digest, CreateCredentialsError, IdentifyUserError,
+    InvalidLoginError, InvalidSessionError, LoginAttemptRepository, LoginError, PasswordHasher,
+    RevokeSessionError, Session, SessionNotFoundError, SessionRepository,
+    TooManyLoginAttemptsError,
+};
+use crate::domain::clock::Clock;
+use crate::domain::user::{validate_password, validate_username, Credentials, UserRepository};
+
+const SESSION_LIFETIME_DAYS: i64 = 30;
+const MAX_FAILED_LOGINS: usize = 5;
+const FAILED_LOGIN_WINDOW_MINUTES: i64 = 15;
+
+/// A session along with its token, which is only ever available when the session is created
+#[derive(Debug, Clone)]
+pub(crate) struct IssuedSession {
+    session: Session,
+    token: String,
+}
+
+impl IssuedSession {
+    pub(crate) fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub(crate) fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+#[async_trait::async_trait]
+pub(crate) trait AccountManager {
+    /// Checks a username and password are acceptable, hashing the password for storage
+    async fn create_credentials(
+        &self,
+        username: String,
+        password: &str,
+    ) -> Result<Credentials, CreateCredentialsError>;
+
+    /// Starts a session for the user with the username, unless too many logins as them from the
+    /// client's address have failed recently
+    async fn login(
+        &self,
+        username: &str,
+        password: &str,
+        client: Option<IpAddr>,
+    ) -> Result<IssuedSession, LoginError>;
+
+    /// The active session the token belongs to
+    async fn authenticate(&self, token: &str) -> Result<Session, InvalidSessionError>;
+
+    /// The user a request acts on behalf of, taken from its bearer token when it has one.
+    /// Without a token the named user is trusted, unless they have an account to log in to
+    async fn identify(
+        &self,
+        user_id: Option<Uuid>,
+        token: Option<&str>,
+    ) -> Result<Uuid, IdentifyUserError>;
+
+    /// The user's active sessions, oldest first
+    async fn sessions(&self, user_id: Uuid) -> Vec<Session>;
+
+    async fn revoke(&self, user_id: Uuid, session_id: Uuid) -> Result<(), RevokeSessionError>;
+
+    /// Forgets every session of a user whose account is being removed
+    async fn end_sessions(&self, user_id: Uuid);
+
+    /// Forgets sessions that have expired or been revoked, and failed logins too old to count
+    /// towards throttling, returning how many sessions were removed
+    async fn prune(&self) -> usize;
+}
+
+pub(crate) struct AccountManagerImpl<
+    UR: UserRepository,
+    SR: SessionRepository,
+    LAR: LoginAttemptRepository,
+    PH: PasswordHasher,
+    C: Clock,
+> {
+    user_repository: Arc<UR>,
+    session_repository: SR,
+    login_attempt_repository: LAR,
+    password_hasher: PH,
+    clock: C,
+}
+
+impl<UR, SR, LAR, PH, C> AccountManagerImpl<UR, SR, LAR, PH, C>
+where
+    UR: UserRepository + Send + Sync,
+    SR: SessionRepository + Send + Sync,
+    LAR: LoginAttemptRepository + Send + Sync,
+    PH: PasswordHasher + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub(crate) fn new(
+        user_repository: Arc<UR>,
+        session_repository: SR,
+        login_attempt_repository: LAR,
+        password_hasher: PH,
+        clock: C,
+    ) -> Self {
+        AccountManagerImpl {
+            user_repository,
+            session_repository,
+            login_attempt_repository,
+            password_hasher,
+            clock,
+        }
+    }
+
+    async fn verify(&self, username: &str, password: &str) -> Option<Uuid> {
+        let user = self.user_repository.by_username(username).await;
+        let credentials = match user.as_ref().and_then(|user| user.credentials()) {
+            Some(credentials) => credentials,
+            None => {
+                self.password_hasher.verify_against_dummy(password).await;
+                return None;
+            }
+        };
+        if self
+            .password_hasher
+            .verify(password, credentials.password_hash())
+            .await
+        {
+            user.map(|user| user.id())
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<UR, SR, LAR, PH, C> AccountManager for AccountManagerImpl<UR, SR, LAR, PH, C>
+where
+    UR: UserRepository + Send + Sync,
+    SR: SessionRepository + Send + Sync,
+    LAR: LoginAttemptRepository + Send + Sync,
+    PH: PasswordHasher + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    async fn create_credentials(
+        &self,
+        username: String,
+        password: &str,
+    ) -> Result<Credentials, CreateCredentialsError> {
+        validate_username(&username)?;
+        validate_password(password)?;
+        let password_hash = self.password_hasher.hash(password).await?;
+        Ok(Credentials::new(username, password_hash))
+    }
+
+    async fn login(
+        &self,
+        username: &str,
+        password: &str,
+        client: Option<IpAddr>,
+    ) -> Result<IssuedSession, LoginError> {
+        let now = self.clock.now();
+        let window_start = now - Duration::minutes(FAILED_LOGIN_WINDOW_MINUTES);
+        let failures = self
+            .login_attempt_repository
+            .failures_since(username, client, window_start)
+            .await;
+        if failures >= MAX_FAILED_LOGINS {
+            return Err(TooManyLoginAttemptsError(username.to_string()).into());
+        }
+
+        let user_id = match self.verify(username, password).await {
+            Some(user_id) => user_id,
+            None => {
+                self.login_attempt_repository
+                    .record_failure(username, client, now)
+                    .await;
+                return Err(InvalidLoginError.into());
+            }
+        };
+        self.login_attempt_repository.clear(username, client).await;
+
+        let token = generate_session_token();
+        let session = Session::new(
+            Uuid::new_v4(),
+            user_id,
+            session_token_digest(&token),
+            now,
+            now + Duration::days(SESSION_LIFETIME_DAYS),
+            None,
+        );
+        self.session_repository.store(&session).await?;
+        Ok(IssuedSession { session, token })
+    }
+
+    async fn authenticate(&self, token: &str) -> Result<Session, InvalidSessionError> {
+        let session = self
+            .session_repository
+            .by_token_digest(&session_token_digest(token))
+            .await
+            .ok_or(InvalidSessionError)?;
+        if !session.is_active(self.clock.now()) {
+            return Err(InvalidSessionError);
+        }
+        Ok(session)
+    }
+
+    async fn identify(
+        &self,
+        user_id: Option<Uuid>,
+        token: Option<&str>,
+    ) -> Result<Uuid, IdentifyUserError> {
+        match (user_id, token) {
+            (user_id, Some(token)) => {
+                let session = self.authenticate(token).await?;
+                match user_id {
+                    Some(user_id) if user_id != session.user_id() => {
+                        Err(IdentifyUserError::WrongUser(user_id))
+                    }
+                    _ => Ok(session.user_id()),
+                }
+            }
+            (Some(user_id), None) => match self.user_repository.get(user_id).await {
+                Ok(user) if user.credentials().is_some() => {
+                    Err(IdentifyUserError::TokenRequired(user_id))
+                }
+                _ => Ok(user_id),
+            },
+            (None, None) => Err(IdentifyUserError::Anonymous),
+        }
+    }
+
+    async fn sessions(&self, user_id: Uuid) -> Vec<Session> {
+        let now = self.clock.now();
+        self.session_repository
+            .of_user(user_id)
+            .await
+            .into_iter()
+            .filter(|session| session.is_active(now))
+            .collect()
+    }
+
+    async fn revoke(&self, user_id: Uuid, session_id: Uuid) -> Result<(), RevokeSessionError> {
+        let mut session = self.session_repository.get(session_id).await?;
+        // Other users' sessions are reported as missing so their ids cannot be probed for
+        if session.user_id() != user_id {
+            return Err(SessionNotFoundError(session_id).into());
+        }
+
+        session.revoke(self.clock.now());
+        self.session_repository.update(&session).await?;
+        Ok(())
+    }
+
+    async fn end_sessions(&self, user_id: Uuid) {
+        self.session_repository.remove_of_user(user_id).await;
+    }
+
+    async fn prune(&self) -> usize {
+        let now = self.clock.now();
+        self.login_attempt_repository
+            .remove_before(now - Duration::minutes(FAILED_LOGIN_WINDOW_MINUTES))
+            .await;
+        self.session_repository.remove_inactive(now).await
+    }
+}
@@ -0,0 +1,71 @@
+use crate::domain::account::{
+    GetSessionError, HashPasswordError, SessionNotFoundError, StoreSessionError, UpdateSessionError,
+};
+use uuid::Uuid;
+
+use crate::domain::user::InvalidCredentialsError;
+
+/// Deliberately vague, so that it does not reveal which usernames are in use
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Invalid username or password")]
+pub(crate) struct InvalidLoginError;
+
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Too many failed logins as {0}, try again later")]
+pub(crate) struct TooManyLoginAttemptsError(pub(crate) String);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("The session token is invalid, has expired or has been revoked")]
+pub(crate) struct InvalidSessionError;
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+pub(crate) enum IdentifyUserError {
+    #[error("The request does not say which user is making it")]
+    Anonymous,
+    #[error("Requests on behalf of user {0} need their bearer token")]
+    TokenRequired(Uuid),
+    #[error("The bearer token does not belong to user {0}")]
+    WrongUser(Uuid),
+    #[error(transparent)]
+    InvalidSession(#[from] InvalidSessionError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateCredentialsError {
+    #[error(transparent)]
+    Invalid(#[from] InvalidCredentialsError),
+    #[error(transparent)]
+    Hash(#[from] HashPasswordError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum LoginError {
+    #[error(transparent)]
+    InvalidLogin(#[from] InvalidLoginError),
+    #[error(transparent)]
+    TooManyAttempts(#[from] TooManyLoginAttemptsError),
+    #[error(transparent)]
+    Store(#[from] StoreSessionError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RevokeSessionError {
+    #[error(transparent)]
+    SessionNotFound(#[from] SessionNotFoundError),
+}
+
+impl From<GetSessionError> for RevokeSessionError {
+    fn from(err: GetSessionError) -> Self {
+        match err {
+            GetSessionError::NotFound(session_not_found_error) => session_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateSessionError> for RevokeSessionError {
+    fn from(err: UpdateSessionError) -> Self {
+        match err {
+            UpdateSessionError::NotFound(session_not_found_error) => session_not_found_error.into(),
+        }
+    }
+}
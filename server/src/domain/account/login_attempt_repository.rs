@@ -0,0 +1,25 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+
+/// Failed logins by username, ignoring case, and the address they came from, kept to slow down
+/// password guessing without letting anyone lock a user out from elsewhere
+#[async_trait::async_trait]
+pub(crate) trait LoginAttemptRepository {
+    async fn record_failure(&self, username: &str, client: Option<IpAddr>, at: DateTime<Utc>);
+
+    /// How many logins as the username from the address have failed since the cutoff
+    async fn failures_since(
+        &self,
+        username: &str,
+        client: Option<IpAddr>,
+        cutoff: DateTime<Utc>,
+    ) -> usize;
+
+    /// Forgets the failures of the username from the address, once someone there has logged in
+    /// as them
+    async fn clear(&self, username: &str, client: Option<IpAddr>);
+
+    /// Forgets every failure older than the cutoff, returning how many there were
+    async fn remove_before(&self, cutoff: DateTime<Utc>) -> usize;
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+pub(crate) use account_manager::*;
+pub(crate) use error::*;
+pub(crate) use login_attempt_repository::*;
+pub(crate) use password_hasher::*;
+pub(crate) use session_repository::*;
+
+mod account_manager;
+mod error;
+mod login_attempt_repository;
+mod password_hasher;
+mod session_repository;
+
+const SESSION_TOKEN_LENGTH: usize = 32;
+
+/// A login on one device, identified by a bearer token of which only a digest is kept
+#[derive(Debug, Clone)]
+pub(crate) struct Session {
+    id: Uuid,
+    user_id: Uuid,
+    token_digest: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub(crate) fn new(
+        id: Uuid,
+        user_id: Uuid,
+        token_digest: String,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        revoked_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Session {
+            id,
+            user_id,
+            token_digest,
+            created_at,
+            expires_at,
+            revoked_at,
+        }
+    }
+
+    pub(crate) fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub(crate) fn token_digest(&self) -> &str {
+        &self.token_digest
+    }
+
+    pub(crate) fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub(crate) fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub(crate) fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+
+    /// Revoking is idempotent, keeping the time of the first revocation
+    pub(crate) fn revoke(&mut self, at: DateTime<Utc>) {
+        self.revoked_at.get_or_insert(at);
+    }
+}
+
+/// Creates a new random bearer token
+pub(crate) fn generate_session_token() -> String {
+    let mut token = [0; SESSION_TOKEN_LENGTH];
+    rand::thread_rng().fill_bytes(&mut token);
+    base64::encode_config(token, base64::URL_SAFE_NO_PAD)
+}
+
+/// What is stored in place of a token, so that the session store cannot be used to log in
+pub(crate) fn session_token_digest(token: &str) -> String {
+    let digest = Sha256::digest(token.as_bytes());
+    base64::encode_config(digest, base64::URL_SAFE_NO_PAD)
+}
@@ -0,0 +1,16 @@
+/// Hashes passwords for storage, which is deliberately slow
+#[async_trait::async_trait]
+pub(crate) trait PasswordHasher {
+    async fn hash(&self, password: &str) -> Result<String, HashPasswordError>;
+
+    /// Whether the password is the one the hash was made from
+    async fn verify(&self, password: &str, password_hash: &str) -> bool;
+
+    /// Takes as long as verifying against a real hash, for logins as users who have none, so that
+    /// their response time does not give away which usernames are in use
+    async fn verify_against_dummy(&self, password: &str);
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Could not hash the password: {0}")]
+pub(crate) struct HashPasswordError(pub(crate) String);
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::account::Session;
+
+#[async_trait::async_trait]
+pub(crate) trait SessionRepository {
+    async fn store(&self, session: &Session) -> Result<(), StoreSessionError>;
+
+    async fn update(&self, session: &Session) -> Result<(), UpdateSessionError>;
+
+    async fn get(&self, session_id: Uuid) -> Result<Session, GetSessionError>;
+
+    async fn by_token_digest(&self, token_digest: &str) -> Option<Session>;
+
+    /// The user's sessions, including expired and revoked ones, oldest first
+    async fn of_user(&self, user_id: Uuid) -> Vec<Session>;
+
+    /// Removes every session of the user, returning how many there were
+    async fn remove_of_user(&self, user_id: Uuid) -> usize;
+
+    /// Removes every session that is no longer active at the time, returning how many there were
+    async fn remove_inactive(&self, now: DateTime<Utc>) -> usize;
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+pub(crate) enum GetSessionError {
+    #[error(transparent)]
+    NotFound(#[from] SessionNotFoundError),
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Could not find session with id: {0}")]
+pub(crate) struct SessionNotFoundError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum StoreSessionError {
+    #[error(transparent)]
+    AlreadyExists(#[from] SessionWithIdAlreadyExists),
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("A session with id {0} already exists in the repository")]
+pub(crate) struct SessionWithIdAlreadyExists(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum UpdateSessionError {
+    #[error(transparent)]
+    NotFound(#[from] SessionNotFoundError),
+}
@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::domain::challenge::ChallengeEvent;
+use crate::domain::user::{UserClientError, UserClientProviderError};
+
+#[async_trait::async_trait]
+pub(crate) trait ChallengeClient {
+    async fn notify(&self, event: &ChallengeEvent) -> Result<(), UserClientError>;
+}
+
+#[async_trait::async_trait]
+pub(crate) trait ChallengeClientProvider {
+    type ChallengeClient: ChallengeClient;
+
+    /// The client a user is listening for challenges with
+    async fn get(&self, user_id: Uuid) -> Result<Self::ChallengeClient, UserClientProviderError>;
+}
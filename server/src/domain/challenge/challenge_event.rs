@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub(crate) enum ChallengeEvent {
+    Received {
+        challenge_id: Uuid,
+        challenger: Uuid,
+        expires_at: DateTime<Utc>,
+    },
+    Accepted {
+        challenge_id: Uuid,
+        room_id: Uuid,
+        game_id: Uuid,
+    },
+    Declined {
+        challenge_id: Uuid,
+    },
+    Withdrawn {
+        challenge_id: Uuid,
+    },
+}
@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::challenge::{
+    AlreadyChallengedError, Challenge, ChallengeClient, ChallengeClientProvider, ChallengeEvent,
+    ChallengeExpiredError, ChallengeNotFoundError, ChallengeRepository, IssueChallengeError,
+    NotChallengedUserError, RespondToChallengeError, SelfChallengeError,
+};
+use crate::domain::clock::Clock;
+use crate::domain::matchmaking::{Match, MatchPreferences};
+
+/// How long an expired challenge is kept so that late answers are told it expired rather than
+/// that it never existed
+const EXPIRED_CHALLENGE_RETENTION_MINUTES: i64 = 5;
+
+#[async_trait::async_trait]
+pub(crate) trait ChallengeManager {
+    async fn issue(
+        &self,
+        challenger: Uuid,
+        challenged: Uuid,
+        preferences: MatchPreferences,
+        expires_in: Duration,
+    ) -> Result<Challenge, IssueChallengeError>;
+
+    /// Claims a challenge that is still open to the challenged user by removing it, so that it is
+    /// accepted or declined only once. If its match then fails to start, `reopen` puts it back so
+    /// that it can be answered again
+    async fn accept(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Challenge, RespondToChallengeError>;
+
+    async fn decline(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), RespondToChallengeError>;
+
+    /// Stores an accepted challenge again after its match could not be started
+    async fn reopen(&self, challenge: &Challenge);
+
+    /// Tells the challenger that their challenge was accepted and its match has started
+    async fn announce_acceptance(&self, challenge: &Challenge, found: &Match);
+
+    /// Removes every challenge the user has sent or received, telling the other user about those
+    /// that were still open
+    async fn withdraw_all(&self, user_id: Uuid);
+
+    /// Removes challenges that expired without an answer a while ago, returning how many there
+    /// were
+    async fn purge_expired(&self) -> usize;
+
+    /// Every challenge the user has sent or received that is still stored, oldest first
+    async fn of_user(&self, user_id: Uuid) -> Vec<Challenge>;
+
+    /// Open challenges sent to the user, oldest first
+    async fn incoming(&self, user_id: Uuid) -> Vec<Challenge>;
+
+    /// Open challenges sent by the user, oldest first
+    async fn outgoing(&self, user_id: Uuid) -> Vec<Challenge>;
+}
+
+pub(crate) struct ChallengeManagerImpl<
+    CR: ChallengeRepository,
+    CCP: ChallengeClientProvider,
+    C: Clock,
+> {
+    challenge_repository: CR,
+    challenge_client_provider: Arc<CCP>,
+    clock: C,
+}
+
+impl<CR, CCP, C> ChallengeManagerImpl<CR, CCP, C>
+where
+    CR: ChallengeRepository + Send + Sync,
+    CCP: ChallengeClientProvider + Send + Sync,
+    CCP::ChallengeClient: Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub(crate) fn new(
+        challenge_repository: CR,
+        challenge_client_provider: Arc<CCP>,
+        clock: C,
+    ) -> Self {
+        ChallengeManagerImpl {
+            challenge_repository,
+            challenge_client_provider,
+            clock,
+        }
+    }
+
+    async fn notify_user(&self, user_id: Uuid, event: ChallengeEvent) {
+        let result = match self.challenge_client_provider.get(user_id).await {
+            Ok(client) => client.notify(&event).await.map_err(|e| e.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        if let Err(err) = result {
+            log::debug!("Could not notify User({}) of {:?}: {}", user_id, event, err);
+        }
+    }
+
+    /// The challenge if the user is the one it was sent to and it is still open
+    async fn open_for(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Challenge, RespondToChallengeError> {
+        let challenge = self.challenge_repository.get(challenge_id).await?;
+        if challenge.challenged() != user_id {
+            return Err(NotChallengedUserError {
+                user_id,
+                challenge_id,
+            }
+            .into());
+        }
+        if challenge.is_expired(self.clock.now()) {
+            return Err(ChallengeExpiredError(challenge_id).into());
+        }
+        Ok(challenge)
+    }
+
+    /// Removes the challenge if it is still open to the user. Only one of several concurrent
+    /// answers can remove it, and that one alone goes on to act on it.
+    async fn claim(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Challenge, RespondToChallengeError> {
+        self.open_for(challenge_id, user_id).await?;
+        Ok(self
+            .challenge_repository
+            .remove(challenge_id)
+            .await
+            .ok_or(ChallengeNotFoundError(challenge_id))?)
+    }
+
+    fn open(&self, challenges: Vec<Challenge>) -> Vec<Challenge> {
+        let now = self.clock.now();
+        challenges
+            .into_iter()
+            .filter(|challenge| !challenge.is_expired(now))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl<CR, CCP, C> ChallengeManager for ChallengeManagerImpl<CR, CCP, C>
+where
+    CR: ChallengeRepository + Send + Sync,
+    CCP: ChallengeClientProvider + Send + Sync,
+    CCP::ChallengeClient: Send + Sync,
+    C: Clock + Send + Sync,
+{
+    async fn issue(
+        &self,
+        challenger: Uuid,
+        challenged: Uuid,
+        preferences: MatchPreferences,
+        expires_in: Duration,
+    ) -> Result<Challenge, IssueChallengeError> {
+        if challenger == challenged {
+            return Err(SelfChallengeError(challenger).into());
+        }
+        if self
+            .outgoing(challenger)
+            .await
+            .iter()
+            .any(|challenge| challenge.challenged() == challenged)
+        {
+            return Err(AlreadyChallengedError {
+                challenger,
+                challenged,
+            }
+            .into());
+        }
+
+        let now = self.clock.now();
+        let challenge = Challenge::new(
+            Uuid::new_v4(),
+            challenger,
+            challenged,
+            preferences,
+            now,
+            now + expires_in,
+        );
+        self.challenge_repository.store(&challenge).await?;
+        log::debug!(
+            "User({}) challenged User({}) with Challenge({})",
+            challenger,
+            challenged,
+            challenge.id()
+        );
+
+        self.notify_user(
+            challenged,
+            ChallengeEvent::Received {
+                challenge_id: challenge.id(),
+                challenger,
+                expires_at: challenge.expires_at(),
+            },
+        )
+        .await;
+        Ok(challenge)
+    }
+
+    async fn accept(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Challenge, RespondToChallengeError> {
+        self.claim(challenge_id, user_id).await
+    }
+
+    async fn decline(
+        &self,
+        challenge_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), RespondToChallengeError> {
+        let challenge = self.claim(challenge_id, user_id).await?;
+        self.notify_user(
+            challenge.challenger(),
+            ChallengeEvent::Declined { challenge_id },
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn reopen(&self, challenge: &Challenge) {
+        if let Err(err) = self.challenge_repository.store(challenge).await {
+            log::warn!("Could not reopen Challenge({}): {}", challenge.id(), err);
+        }
+    }
+
+    async fn announce_acceptance(&self, challenge: &Challenge, found: &Match) {
+        self.notify_user(
+            challenge.challenger(),
+            ChallengeEvent::Accepted {
+                challenge_id: challenge.id(),
+                room_id: found.room_id(),
+                game_id: found.game_id(),
+            },
+        )
+        .await;
+    }
+
+    async fn withdraw_all(&self, user_id: Uuid) {
+        let now = self.clock.now();
+        let sent = self.challenge_repository.sent_by(user_id).await;
+        let received = self.challenge_repository.sent_to(user_id).await;
+        for challenge in sent.into_iter().chain(received) {
+            if self
+                .challenge_repository
+                .remove(challenge.id())
+                .await
+                .is_none()
+                || challenge.is_expired(now)
+            {
+                continue;
+            }
+            let challenge_id = challenge.id();
+            if challenge.challenger() == user_id {
+                self.notify_user(
+                    challenge.challenged(),
+                    ChallengeEvent::Withdrawn { challenge_id },
+                )
+                .await;
+            } else {
+                self.notify_user(
+                    challenge.challenger(),
+                    ChallengeEvent::Declined { challenge_id },
+                )
+                .await;
+            }
+        }
+    }
+
+    async fn purge_expired(&self) -> usize {
+        let expired = self
+            .challenge_repository
+            .remove_expired(
+                self.clock.now() - Duration::minutes(EXPIRED_CHALLENGE_RETENTION_MINUTES),
+            )
+            .await;
+        for challenge in &expired {
+            log::debug!("Challenge({}) expired unanswered", challenge.id());
+        }
+        expired.len()
+    }
+
+    async fn of_user(&self, user_id: Uuid) -> Vec<Challenge> {
+        let mut challenges = self.challenge_repository.sent_by(user_id).await;
+        challenges.extend(self.challenge_repository.sent_to(user_id).await);
+        challenges.sort_by_key(Challenge::created_at);
+        challenges
+    }
+
+    async fn incoming(&self, user_id: Uuid) -> Vec<Challenge> {
+        self.open(self.challenge_repository.sent_to(user_id).await)
+    }
+
+    async fn outgoing(&self, user_id: Uuid) -> Vec<Challenge> {
+        self.open(self.challenge_repository.sent_by(user_id).await)
+    }
+}
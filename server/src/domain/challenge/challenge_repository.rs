@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::challenge::Challenge;
+
+#[async_trait::async_trait]
+pub(crate) trait ChallengeRepository {
+    async fn store(&self, challenge: &Challenge) -> Result<(), StoreChallengeError>;
+
+    async fn get(&self, challenge_id: Uuid) -> Result<Challenge, GetChallengeError>;
+
+    /// Removes the challenge, returning it if it was still stored
+    async fn remove(&self, challenge_id: Uuid) -> Option<Challenge>;
+
+    /// Removes every challenge that has expired by `now`, returning them
+    async fn remove_expired(&self, now: DateTime<Utc>) -> Vec<Challenge>;
+
+    /// Challenges sent by the user, oldest first
+    async fn sent_by(&self, user_id: Uuid) -> Vec<Challenge>;
+
+    /// Challenges sent to the user, oldest first
+    async fn sent_to(&self, user_id: Uuid) -> Vec<Challenge>;
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+pub(crate) enum GetChallengeError {
+    #[error(transparent)]
+    NotFound(#[from] ChallengeNotFoundError),
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Could not find challenge with id: {0}")]
+pub(crate) struct ChallengeNotFoundError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum StoreChallengeError {
+    #[error(transparent)]
+    AlreadyExists(#[from] ChallengeWithIdAlreadyExists),
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("A challenge with id {0} already exists in the repository")]
+pub(crate) struct ChallengeWithIdAlreadyExists(pub(crate) Uuid);
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::domain::challenge::{ChallengeNotFoundError, GetChallengeError, StoreChallengeError};
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User({0}) cannot challenge themselves")]
+pub(crate) struct SelfChallengeError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User({challenger}) already has an open challenge to User({challenged})")]
+pub(crate) struct AlreadyChallengedError {
+    pub(crate) challenger: Uuid,
+    pub(crate) challenged: Uuid,
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User({user_id}) is not the recipient of Challenge({challenge_id})")]
+pub(crate) struct NotChallengedUserError {
+    pub(crate) user_id: Uuid,
+    pub(crate) challenge_id: Uuid,
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Challenge({0}) has expired")]
+pub(crate) struct ChallengeExpiredError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum IssueChallengeError {
+    #[error(transparent)]
+    SelfChallenge(#[from] SelfChallengeError),
+    #[error(transparent)]
+    AlreadyChallenged(#[from] AlreadyChallengedError),
+    #[error(transparent)]
+    Store(#[from] StoreChallengeError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RespondToChallengeError {
+    #[error(transparent)]
+    ChallengeNotFound(#[from] ChallengeNotFoundError),
+    #[error(transparent)]
+    NotChallengedUser(#[from] NotChallengedUserError),
+    #[error(transparent)]
+    Expired(#[from] ChallengeExpiredError),
+}
+
+impl From<GetChallengeError> for RespondToChallengeError {
+    fn from(err: GetChallengeError) -> Self {
+        match err {
+            GetChallengeError::NotFound(challenge_not_found_error) => {
+                challenge_not_found_error.into()
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+pub(crate) use challenge_client::*;
+pub(crate) use challenge_event::*;
+pub(crate) use challenge_manager::*;
+pub(crate) use challenge_repository::*;
+pub(crate) use error::*;
+
+use crate::domain::matchmaking::MatchPreferences;
+
+mod challenge_client;
+mod challenge_event;
+mod challenge_manager;
+mod challenge_repository;
+mod error;
+
+/// An offer from one user to play another, open until it is answered or expires
+#[derive(Debug, Clone)]
+pub(crate) struct Challenge {
+    id: Uuid,
+    challenger: Uuid,
+    challenged: Uuid,
+    preferences: MatchPreferences,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl Challenge {
+    pub(crate) fn new(
+        id: Uuid,
+        challenger: Uuid,
+        challenged: Uuid,
+        preferences: MatchPreferences,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Challenge {
+            id,
+            challenger,
+            challenged,
+            preferences,
+            created_at,
+            expires_at,
+        }
+    }
+
+    pub(crate) fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub(crate) fn challenger(&self) -> Uuid {
+        self.challenger
+    }
+
+    pub(crate) fn challenged(&self) -> Uuid {
+        self.challenged
+    }
+
+    pub(crate) fn preferences(&self) -> MatchPreferences {
+        self.preferences
+    }
+
+    pub(crate) fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub(crate) fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub(crate) fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
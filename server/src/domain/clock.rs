@@ -0,0 +1,5 @@
+use chrono::{DateTime, Utc};
+
+pub(crate) trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
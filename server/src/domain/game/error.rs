@@ -7,3 +7,15 @@ pub(crate) struct PlayerCountExceededError;
 #[derive(Debug, Copy, Clone, thiserror::Error, Default)]
 #[error("User({0}) is not a player in game")]
 pub(crate) struct UserNotAPlayerInGameError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Game({0}) has already finished")]
+pub(crate) struct GameAlreadyFinishedError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Game({0}) cannot be played until both seats are filled")]
+pub(crate) struct SeatsNotFilledError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("It is not User({0})'s turn")]
+pub(crate) struct NotPlayersTurnError(pub(crate) Uuid);
@@ -2,57 +2,114 @@ use std::collections::HashSet;
 
 use uuid::Uuid;
 
+use crate::domain::clock::Clock;
 use crate::domain::game::{
-    Game, GameMove, GamePlayService, GameRepository, StoreGameError, UserNotAPlayerInGameError,
+    Game, GameAlreadyFinishedError, GameMove, GameOutcome, GamePlayService, GameRepository,
+    GameVariant, GetGameError, StoreGameError, TimeControl, UserNotAPlayerInGameError,
+};
+use crate::domain::room::{
+    AbandonGameError, AddPlayerError, FlagGameError, GameMoveError, NewGameError, ReapRoomError,
+    RemovePlayerError,
 };
-use crate::domain::room::{AddPlayerError, GameMoveError, NewGameError, RemovePlayerError};
 
 #[async_trait::async_trait]
 pub(crate) trait GameManager {
-    async fn start_new_game(&self) -> Result<Game, NewGameError>;
+    async fn start_new_game(
+        &self,
+        players: Vec<Uuid>,
+        first_mover: Option<Uuid>,
+        variant: GameVariant,
+        time_control: Option<TimeControl>,
+        rated: bool,
+    ) -> Result<Game, NewGameError>;
+
+    async fn get_game(&self, game_id: Uuid) -> Result<Game, GetGameError>;
+
+    /// Finished games the user played in, oldest first
+    async fn finished_games_of_player(&self, user_id: Uuid) -> Vec<Game>;
+
+    /// Games in any state that the user has held a seat in or made a move in, oldest first
+    async fn games_involving(&self, user_id: Uuid) -> Vec<Game>;
+
+    /// Attributes everything the user did in any game to the stand-in id
+    async fn anonymise_player(&self, user_id: Uuid, stand_in: Uuid);
 
     async fn make_game_move(
         &self,
         user_id: Uuid,
         game_id: Uuid,
         game_move: GameMove,
-    ) -> Result<(), GameMoveError>;
+    ) -> Result<Game, GameMoveError>;
+
+    async fn abandon_game(&self, user_id: Uuid, game_id: Uuid) -> Result<Game, AbandonGameError>;
 
     async fn add_player(&self, game_id: Uuid, user_id: Uuid) -> Result<Option<()>, AddPlayerError>;
 
+    /// Frees the user's seat in an unfinished game, leaving the record of finished games intact
     async fn remove_player(&self, user_id: Uuid, game_id: Uuid) -> Result<(), RemovePlayerError>;
+
+    /// Finishes a game that is still being played as abandoned, returning it if it was unfinished
+    async fn archive_game(&self, game_id: Uuid) -> Result<Option<Game>, ReapRoomError>;
+
+    /// Finishes a game whose player to move has run out of time, returning it if it was flagged
+    async fn flag_game(&self, game_id: Uuid) -> Result<Option<Game>, FlagGameError>;
 }
 
-pub(crate) struct GameManagerImpl<GR: GameRepository, GPS: GamePlayService> {
+pub(crate) struct GameManagerImpl<GR: GameRepository, GPS: GamePlayService, C: Clock> {
     game_repository: GR,
     game_play_service: GPS,
+    clock: C,
 }
 
-impl<GR, GPS> GameManagerImpl<GR, GPS>
+impl<GR, GPS, C> GameManagerImpl<GR, GPS, C>
 where
     GR: GameRepository,
     GPS: GamePlayService,
+    C: Clock,
 {
-    pub(crate) fn new(game_repository: GR, game_play_service: GPS) -> Self {
+    pub(crate) fn new(game_repository: GR, game_play_service: GPS, clock: C) -> Self {
         GameManagerImpl {
             game_repository,
             game_play_service,
+            clock,
         }
     }
 
     fn user_is_player(user_id: Uuid, game: &Game) -> bool {
-        game.players().contains(&user_id)
+        game.is_player(user_id)
     }
 }
 
 #[async_trait::async_trait]
-impl<GR, GPS> GameManager for GameManagerImpl<GR, GPS>
+impl<GR, GPS, C> GameManager for GameManagerImpl<GR, GPS, C>
 where
     GR: GameRepository + Send + Sync,
     GPS: GamePlayService + Send + Sync,
+    C: Clock + Send + Sync,
 {
-    async fn start_new_game(&self) -> Result<Game, NewGameError> {
-        let game = Game::new(Uuid::new_v4(), HashSet::new(), vec![]);
+    async fn start_new_game(
+        &self,
+        players: Vec<Uuid>,
+        first_mover: Option<Uuid>,
+        variant: GameVariant,
+        time_control: Option<TimeControl>,
+        rated: bool,
+    ) -> Result<Game, NewGameError> {
+        let crosses = players.first().copied();
+        let game = Game::new(
+            Uuid::new_v4(),
+            players,
+            crosses,
+            first_mover,
+            variant,
+            time_control,
+            rated,
+            vec![],
+            None,
+            HashSet::new(),
+            self.clock.now(),
+            None,
+        );
         match self.game_repository.store(&game).await {
             Ok(_) => Ok(game),
             Err(err) => match err {
@@ -61,21 +118,62 @@ where
         }
     }
 
+    async fn get_game(&self, game_id: Uuid) -> Result<Game, GetGameError> {
+        self.game_repository.get(game_id).await
+    }
+
+    async fn finished_games_of_player(&self, user_id: Uuid) -> Vec<Game> {
+        self.game_repository.finished_games_of_player(user_id).await
+    }
+
+    async fn games_involving(&self, user_id: Uuid) -> Vec<Game> {
+        self.game_repository.games_involving(user_id).await
+    }
+
+    async fn anonymise_player(&self, user_id: Uuid, stand_in: Uuid) {
+        for mut game in self.game_repository.games_involving(user_id).await {
+            game.anonymise_player(user_id, stand_in);
+            if let Err(err) = self.game_repository.update(&game).await {
+                log::warn!(
+                    "Could not anonymise User({}) in Game({}): {}",
+                    user_id,
+                    game.id(),
+                    err
+                );
+            }
+        }
+    }
+
     async fn make_game_move(
         &self,
         user_id: Uuid,
         game_id: Uuid,
         game_move: GameMove,
-    ) -> Result<(), GameMoveError> {
+    ) -> Result<Game, GameMoveError> {
         let mut game = self.game_repository.get(game_id).await?;
 
         if !Self::user_is_player(user_id, &game) {
             return Err(UserNotAPlayerInGameError(user_id).into());
         }
 
-        self.game_play_service.apply_move(&mut game, game_move)?;
+        self.game_play_service
+            .apply_move(&mut game, game_move, self.clock.now())?;
         self.game_repository.update(&game).await?;
-        Ok(())
+        Ok(game)
+    }
+
+    async fn abandon_game(&self, user_id: Uuid, game_id: Uuid) -> Result<Game, AbandonGameError> {
+        let mut game = self.game_repository.get(game_id).await?;
+
+        if !Self::user_is_player(user_id, &game) {
+            return Err(UserNotAPlayerInGameError(user_id).into());
+        } else if game.is_finished() {
+            return Err(GameAlreadyFinishedError(game_id).into());
+        }
+
+        game.vote_to_abandon(user_id, self.clock.now());
+        self.game_repository.update(&game).await?;
+        Ok(game)
     }
 
     async fn add_player(&self, game_id: Uuid, user_id: Uuid) -> Result<Option<()>, AddPlayerError> {
@@ -90,8 +188,32 @@ where
 
     async fn remove_player(&self, user_id: Uuid, game_id: Uuid) -> Result<(), RemovePlayerError> {
         let mut game = self.game_repository.get(game_id).await?;
+        if game.is_finished() {
+            return Ok(());
+        }
         game.remove_player(user_id);
         self.game_repository.update(&game).await?;
         Ok(())
     }
+
+    async fn archive_game(&self, game_id: Uuid) -> Result<Option<Game>, ReapRoomError> {
+        let mut game = self.game_repository.get(game_id).await?;
+        if game.is_finished() {
+            return Ok(None);
+        }
+
+        game.finish(GameOutcome::Abandoned, self.clock.now());
+        self.game_repository.update(&game).await?;
+        Ok(Some(game))
+    }
+
+    async fn flag_game(&self, game_id: Uuid) -> Result<Option<Game>, FlagGameError> {
+        let mut game = self.game_repository.get(game_id).await?;
+        if !self.game_play_service.flag(&mut game, self.clock.now()) {
+            return Ok(None);
+        }
+
+        self.game_repository.update(&game).await?;
+        Ok(Some(game))
+    }
 }
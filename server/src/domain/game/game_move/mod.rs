@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 pub(crate) use error::*;
@@ -25,3 +26,33 @@ impl GameMove {
         self.position
     }
 }
+
+/// A move that has been applied to a game, along with when it was made
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct RecordedGameMove {
+    game_move: GameMove,
+    made_at: DateTime<Utc>,
+}
+
+impl RecordedGameMove {
+    pub(crate) fn new(game_move: GameMove, made_at: DateTime<Utc>) -> Self {
+        RecordedGameMove { game_move, made_at }
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.game_move.user_id()
+    }
+
+    pub(crate) fn position(&self) -> GameMovePosition {
+        self.game_move.position()
+    }
+
+    pub(crate) fn made_at(&self) -> DateTime<Utc> {
+        self.made_at
+    }
+
+    /// The same move, made at the same time by a different user
+    pub(crate) fn made_by(self, user_id: Uuid) -> Self {
+        RecordedGameMove::new(GameMove::new(user_id, self.position()), self.made_at)
+    }
+}
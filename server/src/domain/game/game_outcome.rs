@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum GameOutcome {
+    Win(Uuid),
+    /// The opponent ran out of time on their clock
+    WinOnTime(Uuid),
+    Draw,
+    Abandoned,
+}
+
+impl GameOutcome {
+    pub(crate) fn winner(&self) -> Option<Uuid> {
+        match self {
+            GameOutcome::Win(winner) | GameOutcome::WinOnTime(winner) => Some(*winner),
+            GameOutcome::Draw | GameOutcome::Abandoned => None,
+        }
+    }
+
+    /// The same outcome with the user's win credited to the stand-in instead
+    pub(crate) fn attributed_to(self, user_id: Uuid, stand_in: Uuid) -> Self {
+        match self {
+            GameOutcome::Win(winner) if winner == user_id => GameOutcome::Win(stand_in),
+            GameOutcome::WinOnTime(winner) if winner == user_id => GameOutcome::WinOnTime(stand_in),
+            outcome => outcome,
+        }
+    }
+}
@@ -1,9 +1,38 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
 use crate::domain::game::{
-    Game, GameMove, GameMovePosition, PositionIsAlreadyOccupiedError, PositionOutOfBoundsError,
+    Game, GameAlreadyFinishedError, GameMove, GameMovePosition, GameOutcome, GameVariant,
+    NotPlayersTurnError, PositionIsAlreadyOccupiedError, PositionOutOfBoundsError,
+    RecordedGameMove, SeatsNotFilledError,
 };
 
+const WINNING_LINES: [[(u8, u8); 3]; 8] = [
+    [(0, 0), (1, 0), (2, 0)],
+    [(0, 1), (1, 1), (2, 1)],
+    [(0, 2), (1, 2), (2, 2)],
+    [(0, 0), (0, 1), (0, 2)],
+    [(1, 0), (1, 1), (1, 2)],
+    [(2, 0), (2, 1), (2, 2)],
+    [(0, 0), (1, 1), (2, 2)],
+    [(2, 0), (1, 1), (0, 2)],
+];
+
+const BOARD_POSITION_COUNT: usize = 9;
+
 pub(crate) trait GamePlayService {
-    fn apply_move(&self, game: &mut Game, game_move: GameMove) -> Result<(), ApplyMoveError>;
+    /// Applies a move to the game once both seats are filled. If the player to move has run out of
+    /// time, whoever makes the request, the game is lost on time instead.
+    fn apply_move(
+        &self,
+        game: &mut Game,
+        game_move: GameMove,
+        made_at: DateTime<Utc>,
+    ) -> Result<(), ApplyMoveError>;
+
+    /// Finishes the game as a loss on time for the player to move if their clock has run out,
+    /// returning whether it did
+    fn flag(&self, game: &mut Game, at: DateTime<Utc>) -> bool;
 }
 
 pub(crate) struct GamePlayServiceImpl;
@@ -14,7 +43,7 @@ impl GamePlayServiceImpl {
     }
 
     fn occupied_positions(game: &Game) -> Vec<GameMovePosition> {
-        game.moves.iter().map(GameMove::position).collect()
+        game.moves.iter().map(RecordedGameMove::position).collect()
     }
 
     fn position_is_occupied(game: &Game, position: &GameMovePosition) -> bool {
@@ -24,18 +53,94 @@ impl GamePlayServiceImpl {
     fn position_is_out_of_bounds(position: &GameMovePosition) -> bool {
         position.y() > 2 || position.x() > 2
     }
+
+    fn is_out_of_turn(game: &Game, game_move: &GameMove) -> bool {
+        match game.next_mover() {
+            Some(next_mover) => next_mover != game_move.user_id(),
+            None => false,
+        }
+    }
+
+    fn has_empty_seat(game: &Game) -> bool {
+        game.players().len() < 2
+    }
+
+    fn positions_of_user(game: &Game, user_id: Uuid) -> Vec<GameMovePosition> {
+        game.moves
+            .iter()
+            .filter(|game_move| game_move.user_id() == user_id)
+            .map(RecordedGameMove::position)
+            .collect()
+    }
+
+    fn has_winning_line(positions: &[GameMovePosition]) -> bool {
+        WINNING_LINES.iter().any(|line| {
+            line.iter()
+                .all(|(x, y)| positions.contains(&GameMovePosition::new(*x, *y)))
+        })
+    }
+
+    fn outcome(game: &Game, last_move: &GameMove) -> Option<GameOutcome> {
+        if Self::has_winning_line(&Self::positions_of_user(game, last_move.user_id())) {
+            match game.variant() {
+                GameVariant::Standard => Some(GameOutcome::Win(last_move.user_id())),
+                GameVariant::Misere => Some(
+                    game.opponent_of(last_move.user_id())
+                        .map_or(GameOutcome::Draw, GameOutcome::Win),
+                ),
+            }
+        } else if game.moves.len() == BOARD_POSITION_COUNT {
+            Some(GameOutcome::Draw)
+        } else {
+            None
+        }
+    }
 }
 
 impl GamePlayService for GamePlayServiceImpl {
-    fn apply_move(&self, game: &mut Game, game_move: GameMove) -> Result<(), ApplyMoveError> {
+    fn apply_move(
+        &self,
+        game: &mut Game,
+        game_move: GameMove,
+        made_at: DateTime<Utc>,
+    ) -> Result<(), ApplyMoveError> {
+        if game.is_finished() {
+            return Err(GameAlreadyFinishedError(game.id()).into());
+        } else if Self::has_empty_seat(game) {
+            return Err(SeatsNotFilledError(game.id()).into());
+        } else if self.flag(game, made_at) {
+            return Ok(());
+        } else if Self::is_out_of_turn(game, &game_move) {
+            return Err(NotPlayersTurnError(game_move.user_id()).into());
+        }
         if Self::position_is_out_of_bounds(&game_move.position()) {
             return Err(PositionOutOfBoundsError.into());
-        } else if Self::position_is_occupied(&game, &game_move.position()) {
+        } else if Self::position_is_occupied(game, &game_move.position()) {
             return Err(PositionIsAlreadyOccupiedError.into());
         }
-        game.append_move(game_move);
+        game.append_move(game_move, made_at);
+        if let Some(outcome) = Self::outcome(game, &game_move) {
+            game.finish(outcome, made_at);
+        }
         Ok(())
     }
+
+    fn flag(&self, game: &mut Game, at: DateTime<Utc>) -> bool {
+        if game.is_finished() {
+            return false;
+        }
+        let winner = game
+            .next_mover()
+            .filter(|next_mover| game.has_run_out_of_time(*next_mover, at))
+            .and_then(|next_mover| game.opponent_of(next_mover));
+        match winner {
+            Some(winner) => {
+                game.finish(GameOutcome::WinOnTime(winner), at);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -44,4 +149,10 @@ pub(crate) enum ApplyMoveError {
     PositionIsAlreadyOccupied(#[from] PositionIsAlreadyOccupiedError),
     #[error("Position is out of bounds")]
     PositionOutOfBounds(#[from] PositionOutOfBoundsError),
+    #[error(transparent)]
+    GameAlreadyFinished(#[from] GameAlreadyFinishedError),
+    #[error(transparent)]
+    NotPlayersTurn(#[from] NotPlayersTurnError),
+    #[error(transparent)]
+    SeatsNotFilled(#[from] SeatsNotFilledError),
 }
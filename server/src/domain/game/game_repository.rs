@@ -9,6 +9,12 @@ pub(crate) trait GameRepository {
     async fn store(&self, game: &Game) -> Result<(), StoreGameError>;
 
     async fn update(&self, game: &Game) -> Result<(), UpdateGameError>;
+
+    /// Finished games the user played in, oldest first
+    async fn finished_games_of_player(&self, user_id: Uuid) -> Vec<Game>;
+
+    /// Games in any state that the user has held a seat in or made a move in, oldest first
+    async fn games_involving(&self, user_id: Uuid) -> Vec<Game>;
 }
 
 #[derive(Debug, Copy, Clone, thiserror::Error)]
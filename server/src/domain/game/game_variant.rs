@@ -0,0 +1,12 @@
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum GameVariant {
+    /// Completing a line wins the game
+    #[default]
+    Standard,
+    /// Completing a line loses the game
+    Misere,
+}
+
+impl GameVariant {
+    pub(crate) const ALL: [GameVariant; 2] = [GameVariant::Standard, GameVariant::Misere];
+}
@@ -1,64 +1,260 @@
 use std::collections::HashSet;
 
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
 pub(crate) use error::*;
 pub(crate) use game_manager::*;
 pub(crate) use game_move::*;
+pub(crate) use game_outcome::*;
 pub(crate) use game_play_service::*;
 pub(crate) use game_repository::*;
+pub(crate) use game_variant::*;
+pub(crate) use time_control::*;
 
 mod error;
 mod game_manager;
 mod game_move;
+mod game_outcome;
 mod game_play_service;
 mod game_repository;
+mod game_variant;
+mod time_control;
 
 pub(crate) struct Game {
     id: Uuid,
-    players: HashSet<Uuid>,
-    moves: Vec<GameMove>,
+    players: Vec<Uuid>,
+    crosses: Option<Uuid>,
+    first_mover: Option<Uuid>,
+    variant: GameVariant,
+    time_control: Option<TimeControl>,
+    rated: bool,
+    moves: Vec<RecordedGameMove>,
+    outcome: Option<GameOutcome>,
+    abandon_votes: HashSet<Uuid>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
 }
 
 impl Game {
-    pub(crate) fn new(id: Uuid, players: HashSet<Uuid>, moves: Vec<GameMove>) -> Self {
-        Game { id, players, moves }
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        id: Uuid,
+        players: Vec<Uuid>,
+        crosses: Option<Uuid>,
+        first_mover: Option<Uuid>,
+        variant: GameVariant,
+        time_control: Option<TimeControl>,
+        rated: bool,
+        moves: Vec<RecordedGameMove>,
+        outcome: Option<GameOutcome>,
+        abandon_votes: HashSet<Uuid>,
+        started_at: DateTime<Utc>,
+        finished_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Game {
+            id,
+            players,
+            crosses,
+            first_mover,
+            variant,
+            time_control,
+            rated,
+            moves,
+            outcome,
+            abandon_votes,
+            started_at,
+            finished_at,
+        }
     }
 
     pub(crate) fn id(&self) -> Uuid {
         self.id
     }
 
-    pub(crate) fn players(&self) -> &HashSet<Uuid> {
+    /// Players in seat order
+    pub(crate) fn players(&self) -> &Vec<Uuid> {
         &self.players
     }
 
+    pub(crate) fn is_player(&self, user_id: Uuid) -> bool {
+        self.players.contains(&user_id)
+    }
+
     pub(crate) fn add_player(
         &mut self,
         user_id: Uuid,
     ) -> Result<Option<()>, PlayerCountExceededError> {
         if self.players.len() == 2 {
-            Err(PlayerCountExceededError::default())
-        } else if self.players.insert(user_id) {
-            Ok(Some(()))
-        } else {
+            Err(PlayerCountExceededError)
+        } else if self.is_player(user_id) {
             Ok(None)
+        } else {
+            self.players.push(user_id);
+            if self.crosses.is_none() {
+                self.crosses = Some(user_id);
+            }
+            Ok(Some(()))
         }
     }
 
+    /// Frees the player's seat, and with it their symbol for whoever sits down next
     pub(crate) fn remove_player(&mut self, user_id: Uuid) -> Option<()> {
-        if self.players.remove(&user_id) {
+        if self.is_player(user_id) {
+            self.players.retain(|player| *player != user_id);
+            if self.crosses == Some(user_id) {
+                self.crosses = None;
+            }
             Some(())
         } else {
             None
         }
     }
 
-    pub(crate) fn moves(&self) -> &Vec<GameMove> {
+    /// The player required to make the opening move, if one has been chosen
+    pub(crate) fn first_mover(&self) -> Option<Uuid> {
+        self.first_mover
+    }
+
+    /// The player who actually made (or must make) the opening move
+    pub(crate) fn opening_player(&self) -> Option<Uuid> {
+        self.moves
+            .first()
+            .map(RecordedGameMove::user_id)
+            .or(self.first_mover)
+    }
+
+    /// The player whose turn it is, once both seats are filled
+    pub(crate) fn next_mover(&self) -> Option<Uuid> {
+        if self.players.len() < 2 {
+            return None;
+        }
+        match self.moves.last() {
+            Some(last_move) => self.opponent_of(last_move.user_id()),
+            None => self.first_mover,
+        }
+    }
+
+    /// The player who plays crosses, fixed when they took their seat. Whoever else is seated plays
+    /// noughts, and either may open.
+    pub(crate) fn crosses(&self) -> Option<Uuid> {
+        self.crosses
+    }
+
+    pub(crate) fn opponent_of(&self, user_id: Uuid) -> Option<Uuid> {
+        self.players
+            .iter()
+            .find(|player| **player != user_id)
+            .copied()
+    }
+
+    pub(crate) fn variant(&self) -> GameVariant {
+        self.variant
+    }
+
+    pub(crate) fn time_control(&self) -> Option<TimeControl> {
+        self.time_control
+    }
+
+    /// Whether the result counts towards ratings, fixed when the game is created
+    pub(crate) fn rated(&self) -> bool {
+        self.rated
+    }
+
+    /// Time a player has left on their clock at the given instant, if the game is timed. Clocks
+    /// start once the opening move has been made.
+    pub(crate) fn remaining_time(&self, user_id: Uuid, at: DateTime<Utc>) -> Option<Duration> {
+        let time_control = self.time_control?;
+        let mut remaining = time_control.initial();
+        let mut previous_move_at = None;
+        for game_move in &self.moves {
+            if game_move.user_id() == user_id {
+                if let Some(previous_move_at) = previous_move_at {
+                    remaining = remaining - (game_move.made_at() - previous_move_at);
+                }
+                remaining = remaining + time_control.increment();
+            }
+            previous_move_at = Some(game_move.made_at());
+        }
+        if let (Some(previous_move_at), false) = (previous_move_at, self.is_finished()) {
+            if self.next_mover() == Some(user_id) {
+                remaining = remaining - (at - previous_move_at);
+            }
+        }
+        Some(remaining)
+    }
+
+    pub(crate) fn has_run_out_of_time(&self, user_id: Uuid, at: DateTime<Utc>) -> bool {
+        self.remaining_time(user_id, at)
+            .iter()
+            .any(|remaining| *remaining <= Duration::zero())
+    }
+
+    pub(crate) fn moves(&self) -> &Vec<RecordedGameMove> {
         &self.moves
     }
 
-    pub(crate) fn append_move(&mut self, game_move: GameMove) {
-        self.moves.push(game_move)
+    pub(crate) fn append_move(&mut self, game_move: GameMove, made_at: DateTime<Utc>) {
+        self.moves.push(RecordedGameMove::new(game_move, made_at))
+    }
+
+    pub(crate) fn outcome(&self) -> Option<GameOutcome> {
+        self.outcome
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    pub(crate) fn finish(&mut self, outcome: GameOutcome, finished_at: DateTime<Utc>) {
+        self.outcome = Some(outcome);
+        self.finished_at = Some(finished_at);
+    }
+
+    pub(crate) fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    pub(crate) fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+
+    pub(crate) fn abandon_votes(&self) -> &HashSet<Uuid> {
+        &self.abandon_votes
+    }
+
+    /// Replaces every reference to the user with a stand-in id, keeping the record of the game
+    /// intact while no longer identifying who played it
+    pub(crate) fn anonymise_player(&mut self, user_id: Uuid, stand_in: Uuid) {
+        let replace = |id: Uuid| if id == user_id { stand_in } else { id };
+        self.players = self.players.iter().copied().map(replace).collect();
+        self.crosses = self.crosses.map(replace);
+        self.first_mover = self.first_mover.map(replace);
+        self.moves = self
+            .moves
+            .iter()
+            .map(|recorded_move| {
+                if recorded_move.user_id() == user_id {
+                    recorded_move.made_by(stand_in)
+                } else {
+                    *recorded_move
+                }
+            })
+            .collect();
+        self.outcome = self
+            .outcome
+            .map(|outcome| outcome.attributed_to(user_id, stand_in));
+        self.abandon_votes = self.abandon_votes.iter().copied().map(replace).collect();
+    }
+
+    pub(crate) fn vote_to_abandon(&mut self, user_id: Uuid, voted_at: DateTime<Utc>) {
+        self.abandon_votes.insert(user_id);
+        if self
+            .players
+            .iter()
+            .all(|player| self.abandon_votes.contains(player))
+        {
+            self.finish(GameOutcome::Abandoned, voted_at);
+        }
     }
 }
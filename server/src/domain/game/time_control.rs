@@ -0,0 +1,34 @@
+use chrono::Duration;
+
+/// Clock settings for a game, where each player starts with an initial amount of time and gains
+/// an increment after each of their moves
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct TimeControl {
+    initial_seconds: u32,
+    increment_seconds: u32,
+}
+
+impl TimeControl {
+    pub(crate) fn new(initial_seconds: u32, increment_seconds: u32) -> Self {
+        TimeControl {
+            initial_seconds,
+            increment_seconds,
+        }
+    }
+
+    pub(crate) fn initial_seconds(&self) -> u32 {
+        self.initial_seconds
+    }
+
+    pub(crate) fn increment_seconds(&self) -> u32 {
+        self.increment_seconds
+    }
+
+    pub(crate) fn initial(&self) -> Duration {
+        Duration::seconds(self.initial_seconds.into())
+    }
+
+    pub(crate) fn increment(&self) -> Duration {
+        Duration::seconds(self.increment_seconds.into())
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::OwnedMutexGuard;
+use uuid::Uuid;
+
+/// A lock per id, for changes that read an entity, wait on other work and then write it back,
+/// which a repository cannot keep its own lock held across
+#[derive(Default)]
+pub(crate) struct KeyedLock {
+    locks: Mutex<HashMap<Uuid, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl KeyedLock {
+    pub(crate) fn new() -> Self {
+        KeyedLock::default()
+    }
+
+    /// Waits until nothing else holds the lock for the id, holding it until the guard is dropped
+    pub(crate) async fn lock(&self, id: Uuid) -> OwnedMutexGuard<()> {
+        let lock = self.locks.lock().entry(id).or_default().clone();
+        lock.lock_owned().await
+    }
+
+    /// Drops the lock of an entity that has been removed
+    pub(crate) fn forget(&self, id: Uuid) {
+        self.locks.lock().remove(&id);
+    }
+}
@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User({0}) is already waiting for a match")]
+pub(crate) struct AlreadyQueuedError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum EnqueueError {
+    #[error(transparent)]
+    AlreadyQueued(#[from] AlreadyQueuedError),
+}
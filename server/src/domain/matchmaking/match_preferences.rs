@@ -0,0 +1,35 @@
+use crate::domain::game::{GameVariant, TimeControl};
+use crate::domain::room::RoomSettings;
+
+/// What a user wants from a match; users are only paired with others wanting the same
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub(crate) struct MatchPreferences {
+    variant: GameVariant,
+    time_control: Option<TimeControl>,
+}
+
+impl MatchPreferences {
+    pub(crate) fn new(variant: GameVariant, time_control: Option<TimeControl>) -> Self {
+        MatchPreferences {
+            variant,
+            time_control,
+        }
+    }
+
+    pub(crate) fn variant(&self) -> GameVariant {
+        self.variant
+    }
+
+    pub(crate) fn time_control(&self) -> Option<TimeControl> {
+        self.time_control
+    }
+
+    pub(crate) fn is_compatible_with(&self, other: &MatchPreferences) -> bool {
+        self == other
+    }
+
+    /// Settings for the rated room a match with these preferences is played in
+    pub(crate) fn room_settings(&self) -> RoomSettings {
+        RoomSettings::new(None, true, self.variant(), self.time_control(), true)
+    }
+}
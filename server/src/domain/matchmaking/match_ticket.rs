@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::matchmaking::MatchPreferences;
+
+/// A user's place in the matchmaking queue
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct MatchTicket {
+    user_id: Uuid,
+    preferences: MatchPreferences,
+    rating: f64,
+    last_opponent: Option<Uuid>,
+    queued_at: DateTime<Utc>,
+}
+
+impl MatchTicket {
+    pub(crate) fn new(
+        user_id: Uuid,
+        preferences: MatchPreferences,
+        rating: f64,
+        last_opponent: Option<Uuid>,
+        queued_at: DateTime<Utc>,
+    ) -> Self {
+        MatchTicket {
+            user_id,
+            preferences,
+            rating,
+            last_opponent,
+            queued_at,
+        }
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub(crate) fn preferences(&self) -> MatchPreferences {
+        self.preferences
+    }
+
+    /// The user's rating in the variant they asked for
+    pub(crate) fn rating(&self) -> f64 {
+        self.rating
+    }
+
+    /// Who matchmaking last paired the user with, if anyone
+    pub(crate) fn last_opponent(&self) -> Option<Uuid> {
+        self.last_opponent
+    }
+
+    pub(crate) fn queued_at(&self) -> DateTime<Utc> {
+        self.queued_at
+    }
+}
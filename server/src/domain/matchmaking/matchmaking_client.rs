@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::domain::matchmaking::MatchmakingEvent;
+use crate::domain::user::{UserClientError, UserClientProviderError};
+
+#[async_trait::async_trait]
+pub(crate) trait MatchmakingClient {
+    async fn notify(&self, event: &MatchmakingEvent) -> Result<(), UserClientError>;
+}
+
+#[async_trait::async_trait]
+pub(crate) trait MatchmakingClientProvider {
+    type MatchmakingClient: MatchmakingClient;
+
+    /// The client a user is waiting for a match with
+    async fn get(&self, user_id: Uuid) -> Result<Self::MatchmakingClient, UserClientProviderError>;
+}
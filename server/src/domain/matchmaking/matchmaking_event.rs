@@ -0,0 +1,14 @@
+use chrono::Duration;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub(crate) enum MatchmakingEvent {
+    /// The user is waiting for an opponent, with a guess at how long that will take if there is
+    /// enough history to make one
+    Queued { estimated_wait: Option<Duration> },
+    MatchFound {
+        room_id: Uuid,
+        game_id: Uuid,
+        opponent: Uuid,
+    },
+}
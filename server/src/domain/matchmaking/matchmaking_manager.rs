@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::clock::Clock;
+use crate::domain::matchmaking::{
+    EnqueueError, Match, MatchPreferences, MatchTicket, MatchmakingClient,
+    MatchmakingClientProvider, MatchmakingEvent, MatchmakingPolicy, MatchmakingQueue, Pairing,
+};
+
+#[async_trait::async_trait]
+pub(crate) trait MatchmakingManager {
+    /// Queues a user for a match, pairing them straight away if a suitable opponent is already
+    /// waiting and otherwise telling them how long they can expect to wait
+    async fn enter_queue(
+        &self,
+        user_id: Uuid,
+        preferences: MatchPreferences,
+        rating: f64,
+    ) -> Result<Option<Pairing>, EnqueueError>;
+
+    /// Returns whether the user was waiting for a match
+    async fn leave_queue(&self, user_id: Uuid) -> bool;
+
+    /// Pairs users whose rating windows have widened enough while they waited
+    async fn pair_waiting(&self) -> Vec<Pairing>;
+
+    async fn announce_match(&self, found: &Match);
+}
+
+pub(crate) struct MatchmakingManagerImpl<
+    MQ: MatchmakingQueue,
+    MCP: MatchmakingClientProvider,
+    C: Clock,
+> {
+    matchmaking_queue: MQ,
+    matchmaking_client_provider: Arc<MCP>,
+    clock: C,
+    policy: MatchmakingPolicy,
+}
+
+impl<MQ, MCP, C> MatchmakingManagerImpl<MQ, MCP, C>
+where
+    MQ: MatchmakingQueue,
+    MCP: MatchmakingClientProvider,
+    C: Clock,
+{
+    pub(crate) fn new(
+        matchmaking_queue: MQ,
+        matchmaking_client_provider: Arc<MCP>,
+        clock: C,
+        policy: MatchmakingPolicy,
+    ) -> Self {
+        MatchmakingManagerImpl {
+            matchmaking_queue,
+            matchmaking_client_provider,
+            clock,
+            policy,
+        }
+    }
+
+    /// Mean of the recorded waits, once there are any
+    fn estimate_wait(recent_waits: &[Duration]) -> Option<Duration> {
+        if recent_waits.is_empty() {
+            return None;
+        }
+        let total = recent_waits
+            .iter()
+            .fold(Duration::zero(), |total, waited| total + *waited);
+        Some(total / recent_waits.len() as i32)
+    }
+}
+
+impl<MQ, MCP, C> MatchmakingManagerImpl<MQ, MCP, C>
+where
+    MQ: MatchmakingQueue + Send + Sync,
+    MCP: MatchmakingClientProvider + Send + Sync,
+    MCP::MatchmakingClient: Send + Sync,
+    C: Clock + Send + Sync,
+{
+    async fn take_pair(
+        &self,
+        first: &MatchTicket,
+        second: &MatchTicket,
+        now: DateTime<Utc>,
+    ) -> Option<Pairing> {
+        let (first, second) = self
+            .matchmaking_queue
+            .take_pair(first.user_id(), second.user_id(), now)
+            .await?;
+        for ticket in [&first, &second].iter() {
+            self.matchmaking_queue
+                .record_wait(ticket.preferences(), now - ticket.queued_at())
+                .await;
+        }
+        log::info!(
+            "Paired User({}) with User({}) after they waited {}s",
+            second.user_id(),
+            first.user_id(),
+            (now - first.queued_at()).num_seconds()
+        );
+        Some(Pairing::new(first, second))
+    }
+
+    /// Users paired longer ago than the repeat pairing delay may meet again straight away
+    async fn forget_past_opponents(&self, now: DateTime<Utc>) {
+        self.matchmaking_queue
+            .forget_opponents_paired_before(now - self.policy.repeat_pairing_delay())
+            .await;
+    }
+
+    async fn notify(&self, user_id: Uuid, event: MatchmakingEvent) {
+        let result = match self.matchmaking_client_provider.get(user_id).await {
+            Ok(client) => client.notify(&event).await.map_err(|e| e.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        if let Err(err) = result {
+            log::debug!("Could not notify User({}) of {:?}: {}", user_id, event, err);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<MQ, MCP, C> MatchmakingManager for MatchmakingManagerImpl<MQ, MCP, C>
+where
+    MQ: MatchmakingQueue + Send + Sync,
+    MCP: MatchmakingClientProvider + Send + Sync,
+    MCP::MatchmakingClient: Send + Sync,
+    C: Clock + Send + Sync,
+{
+    async fn enter_queue(
+        &self,
+        user_id: Uuid,
+        preferences: MatchPreferences,
+        rating: f64,
+    ) -> Result<Option<Pairing>, EnqueueError> {
+        let now = self.clock.now();
+        self.forget_past_opponents(now).await;
+        let last_opponent = self.matchmaking_queue.last_opponent(user_id).await;
+        let ticket = MatchTicket::new(user_id, preferences, rating, last_opponent, now);
+        self.matchmaking_queue.enqueue(ticket).await?;
+
+        for waiting in self.matchmaking_queue.waiting().await {
+            if self.policy.can_pair(&waiting, &ticket, now) {
+                if let Some(pairing) = self.take_pair(&waiting, &ticket, now).await {
+                    return Ok(Some(pairing));
+                }
+            }
+        }
+
+        let recent_waits = self.matchmaking_queue.recent_waits(preferences).await;
+        let estimated_wait = Self::estimate_wait(&recent_waits);
+        self.notify(user_id, MatchmakingEvent::Queued { estimated_wait })
+            .await;
+        Ok(None)
+    }
+
+    async fn leave_queue(&self, user_id: Uuid) -> bool {
+        self.matchmaking_queue.remove(user_id).await.is_some()
+    }
+
+    async fn pair_waiting(&self) -> Vec<Pairing> {
+        let now = self.clock.now();
+        self.forget_past_opponents(now).await;
+        let mut unpaired = self.matchmaking_queue.waiting().await;
+        let mut pairings = vec![];
+        while !unpaired.is_empty() {
+            let first = unpaired.remove(0);
+            let opponent = unpaired
+                .iter()
+                .position(|second| self.policy.can_pair(&first, second, now));
+            if let Some(index) = opponent {
+                let second = unpaired.remove(index);
+                if let Some(pairing) = self.take_pair(&first, &second, now).await {
+                    pairings.push(pairing);
+                }
+            }
+        }
+        pairings
+    }
+
+    async fn announce_match(&self, found: &Match) {
+        for player in found.players() {
+            let opponent = match found.opponent_of(*player) {
+                Some(opponent) => opponent,
+                None => continue,
+            };
+            let event = MatchmakingEvent::MatchFound {
+                room_id: found.room_id(),
+                game_id: found.game_id(),
+                opponent,
+            };
+            self.notify(*player, event).await;
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::matchmaking::MatchTicket;
+
+const INITIAL_RATING_WINDOW: f64 = 100.0;
+const RATING_WINDOW_GROWTH_PER_SECOND: f64 = 50.0;
+const MAX_RATING_WINDOW: f64 = 1000.0;
+const REPEAT_PAIRING_DELAY_SECONDS: i64 = 30;
+
+/// Decides which waiting users may be paired with each other
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct MatchmakingPolicy {
+    initial_rating_window: f64,
+    rating_window_growth_per_second: f64,
+    max_rating_window: f64,
+    repeat_pairing_delay: Duration,
+}
+
+impl MatchmakingPolicy {
+    pub(crate) fn new(
+        initial_rating_window: f64,
+        rating_window_growth_per_second: f64,
+        max_rating_window: f64,
+        repeat_pairing_delay: Duration,
+    ) -> Self {
+        MatchmakingPolicy {
+            initial_rating_window,
+            rating_window_growth_per_second,
+            max_rating_window,
+            repeat_pairing_delay,
+        }
+    }
+
+    /// How long users who were just paired with each other are kept apart
+    pub(crate) fn repeat_pairing_delay(&self) -> Duration {
+        self.repeat_pairing_delay
+    }
+
+    /// Furthest apart in rating two users may be after the longer waiting of them has waited this
+    /// long
+    pub(crate) fn rating_window(&self, waited: Duration) -> f64 {
+        let waited_seconds = waited.num_milliseconds().max(0) as f64 / 1000.0;
+        (self.initial_rating_window + waited_seconds * self.rating_window_growth_per_second)
+            .min(self.max_rating_window)
+    }
+
+    /// Users who were just paired with each other are kept apart until one of them has waited for
+    /// a while
+    pub(crate) fn can_pair(&self, a: &MatchTicket, b: &MatchTicket, now: DateTime<Utc>) -> bool {
+        if a.user_id() == b.user_id() || !a.preferences().is_compatible_with(&b.preferences()) {
+            return false;
+        }
+        let waited = now - a.queued_at().min(b.queued_at());
+        let is_repeat =
+            a.last_opponent() == Some(b.user_id()) || b.last_opponent() == Some(a.user_id());
+        if is_repeat && waited < self.repeat_pairing_delay {
+            return false;
+        }
+        (a.rating() - b.rating()).abs() <= self.rating_window(waited)
+    }
+}
+
+impl Default for MatchmakingPolicy {
+    fn default() -> Self {
+        MatchmakingPolicy::new(
+            INITIAL_RATING_WINDOW,
+            RATING_WINDOW_GROWTH_PER_SECOND,
+            MAX_RATING_WINDOW,
+            Duration::seconds(REPEAT_PAIRING_DELAY_SECONDS),
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::matchmaking::{EnqueueError, MatchPreferences, MatchTicket};
+
+#[async_trait::async_trait]
+pub(crate) trait MatchmakingQueue {
+    async fn enqueue(&self, ticket: MatchTicket) -> Result<(), EnqueueError>;
+
+    async fn remove(&self, user_id: Uuid) -> Option<MatchTicket>;
+
+    /// Tickets still waiting for a match, longest waiting first
+    async fn waiting(&self) -> Vec<MatchTicket>;
+
+    /// Takes both users' tickets out of the queue and remembers them as each other's last
+    /// opponent, or leaves the queue alone if either has already gone
+    async fn take_pair(
+        &self,
+        first: Uuid,
+        second: Uuid,
+        paired_at: DateTime<Utc>,
+    ) -> Option<(MatchTicket, MatchTicket)>;
+
+    async fn last_opponent(&self, user_id: Uuid) -> Option<Uuid>;
+
+    /// Stops remembering the last opponents of users who were paired before the cutoff
+    async fn forget_opponents_paired_before(&self, cutoff: DateTime<Utc>);
+
+    async fn record_wait(&self, preferences: MatchPreferences, waited: Duration);
+
+    /// How long recently matched users with these preferences waited, most recent last
+    async fn recent_waits(&self, preferences: MatchPreferences) -> Vec<Duration>;
+}
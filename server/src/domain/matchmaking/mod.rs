@@ -0,0 +1,78 @@
+use uuid::Uuid;
+
+pub(crate) use error::*;
+pub(crate) use match_preferences::*;
+pub(crate) use match_ticket::*;
+pub(crate) use matchmaking_client::*;
+pub(crate) use matchmaking_event::*;
+pub(crate) use matchmaking_manager::*;
+pub(crate) use matchmaking_policy::*;
+pub(crate) use matchmaking_queue::*;
+
+mod error;
+mod match_preferences;
+mod match_ticket;
+mod matchmaking_client;
+mod matchmaking_event;
+mod matchmaking_manager;
+mod matchmaking_policy;
+mod matchmaking_queue;
+
+/// Two queued users matchmaking has paired, longest waiting first
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Pairing {
+    first: MatchTicket,
+    second: MatchTicket,
+}
+
+impl Pairing {
+    pub(crate) fn new(first: MatchTicket, second: MatchTicket) -> Self {
+        Pairing { first, second }
+    }
+
+    pub(crate) fn players(&self) -> Vec<Uuid> {
+        vec![self.first.user_id(), self.second.user_id()]
+    }
+
+    pub(crate) fn preferences(&self) -> MatchPreferences {
+        self.first.preferences()
+    }
+}
+
+/// A pairing made by matchmaking, with the room and game created for it
+#[derive(Debug, Clone)]
+pub(crate) struct Match {
+    room_id: Uuid,
+    game_id: Uuid,
+    players: Vec<Uuid>,
+}
+
+impl Match {
+    pub(crate) fn new(room_id: Uuid, game_id: Uuid, players: Vec<Uuid>) -> Self {
+        Match {
+            room_id,
+            game_id,
+            players,
+        }
+    }
+
+    pub(crate) fn room_id(&self) -> Uuid {
+        self.room_id
+    }
+
+    pub(crate) fn game_id(&self) -> Uuid {
+        self.game_id
+    }
+
+    /// Players in seat order, longest waiting first
+    pub(crate) fn players(&self) -> &Vec<Uuid> {
+        &self.players
+    }
+
+    pub(crate) fn opponent_of(&self, user_id: Uuid) -> Option<Uuid> {
+        self.players
+            .iter()
+            .find(|player| **player != user_id)
+            .copied()
+    }
+}
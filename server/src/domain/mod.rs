@@ -1,3 +1,12 @@
+pub(crate) mod account;
+pub(crate) mod challenge;
+pub(crate) mod clock;
 pub(crate) mod game;
+pub(crate) mod keyed_lock;
+pub(crate) mod matchmaking;
+pub(crate) mod pagination;
+pub(crate) mod rating;
 pub(crate) mod room;
+pub(crate) mod stats;
+pub(crate) mod tournament;
 pub(crate) mod user;
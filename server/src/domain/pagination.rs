@@ -0,0 +1,55 @@
+/// A 1-based page of results to fetch from a repository
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct PageRequest {
+    page: usize,
+    per_page: usize,
+}
+
+impl PageRequest {
+    pub(crate) fn new(page: usize, per_page: usize) -> Self {
+        PageRequest { page, per_page }
+    }
+
+    pub(crate) fn page(&self) -> usize {
+        self.page
+    }
+
+    pub(crate) fn per_page(&self) -> usize {
+        self.per_page
+    }
+
+    /// Number of results that precede this page
+    pub(crate) fn offset(&self) -> usize {
+        self.page.saturating_sub(1) * self.per_page
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Page<T> {
+    items: Vec<T>,
+    request: PageRequest,
+    total: usize,
+}
+
+impl<T> Page<T> {
+    pub(crate) fn new(items: Vec<T>, request: PageRequest, total: usize) -> Self {
+        Page {
+            items,
+            request,
+            total,
+        }
+    }
+
+    pub(crate) fn items(&self) -> &Vec<T> {
+        &self.items
+    }
+
+    pub(crate) fn request(&self) -> PageRequest {
+        self.request
+    }
+
+    /// Number of results across all pages
+    pub(crate) fn total(&self) -> usize {
+        self.total
+    }
+}
@@ -0,0 +1,61 @@
+use uuid::Uuid;
+
+use crate::domain::game::GameVariant;
+use crate::domain::rating::Rating;
+
+/// What players are ranked by, with the other measure breaking ties
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum LeaderboardOrder {
+    #[default]
+    Rating,
+    Wins,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LeaderboardEntry {
+    rank: usize,
+    user_id: Uuid,
+    name: String,
+    rating: Rating,
+}
+
+impl LeaderboardEntry {
+    pub(crate) fn new(rank: usize, user_id: Uuid, name: String, rating: Rating) -> Self {
+        LeaderboardEntry {
+            rank,
+            user_id,
+            name,
+            rating,
+        }
+    }
+
+    /// 1-based position on the leaderboard
+    pub(crate) fn rank(&self) -> usize {
+        self.rank
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn rating(&self) -> Rating {
+        self.rating
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("User({user_id}) has not played a rated {variant:?} game")]
+pub(crate) struct NotRankedError {
+    user_id: Uuid,
+    variant: GameVariant,
+}
+
+impl NotRankedError {
+    pub(crate) fn new(user_id: Uuid, variant: GameVariant) -> Self {
+        NotRankedError { user_id, variant }
+    }
+}
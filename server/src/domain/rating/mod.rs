@@ -0,0 +1,89 @@
+pub(crate) use leaderboard::*;
+pub(crate) use rating_calculator::*;
+
+mod leaderboard;
+mod rating_calculator;
+
+const INITIAL_RATING: f64 = 1500.0;
+const INITIAL_DEVIATION: f64 = 350.0;
+const INITIAL_VOLATILITY: f64 = 0.06;
+/// Ratings are provisional until the system is reasonably confident in them
+const PROVISIONAL_DEVIATION: f64 = 110.0;
+
+/// A player's Glicko-2 rating, stored on the display scale
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Rating {
+    value: f64,
+    deviation: f64,
+    volatility: f64,
+    games_played: u32,
+    wins: u32,
+}
+
+impl Rating {
+    pub(crate) fn new(
+        value: f64,
+        deviation: f64,
+        volatility: f64,
+        games_played: u32,
+        wins: u32,
+    ) -> Self {
+        Rating {
+            value,
+            deviation,
+            volatility,
+            games_played,
+            wins,
+        }
+    }
+
+    pub(crate) fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub(crate) fn deviation(&self) -> f64 {
+        self.deviation
+    }
+
+    pub(crate) fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// Number of rated games played
+    pub(crate) fn games_played(&self) -> u32 {
+        self.games_played
+    }
+
+    /// Number of rated games won
+    pub(crate) fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub(crate) fn is_provisional(&self) -> bool {
+        self.deviation > PROVISIONAL_DEVIATION
+    }
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Rating::new(INITIAL_RATING, INITIAL_DEVIATION, INITIAL_VOLATILITY, 0, 0)
+    }
+}
+
+/// How a game went for one of its players
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum GameResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl GameResult {
+    fn score(&self) -> f64 {
+        match self {
+            GameResult::Win => 1.0,
+            GameResult::Draw => 0.5,
+            GameResult::Loss => 0.0,
+        }
+    }
+}
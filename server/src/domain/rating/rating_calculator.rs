@@ -0,0 +1,98 @@
+use std::f64::consts::PI;
+
+use crate::domain::rating::{GameResult, Rating, INITIAL_DEVIATION, INITIAL_RATING};
+
+/// Converts between the display scale and the Glicko-2 scale
+const GLICKO2_SCALE: f64 = 173.7178;
+/// Constrains how much volatility can change between games
+const SYSTEM_CONSTANT: f64 = 0.5;
+const CONVERGENCE_TOLERANCE: f64 = 0.000_001;
+
+pub(crate) trait RatingCalculator {
+    /// The player's rating after a single game against the opponent
+    fn rate(&self, player: Rating, opponent: Rating, result: GameResult) -> Rating;
+}
+
+/// Rates each game as its own Glicko-2 rating period
+pub(crate) struct Glicko2RatingCalculator;
+
+impl Glicko2RatingCalculator {
+    pub(crate) fn new() -> Self {
+        Glicko2RatingCalculator
+    }
+
+    fn g(phi: f64) -> f64 {
+        1.0 / (1.0 + 3.0 * phi.powi(2) / PI.powi(2)).sqrt()
+    }
+
+    fn expected_score(mu: f64, opponent_mu: f64, opponent_phi: f64) -> f64 {
+        1.0 / (1.0 + (-Self::g(opponent_phi) * (mu - opponent_mu)).exp())
+    }
+
+    /// Finds the new volatility using the Illinois algorithm from step 5 of the Glicko-2 paper
+    fn volatility(phi: f64, sigma: f64, variance: f64, delta: f64) -> f64 {
+        let a = sigma.powi(2).ln();
+        let f = |x: f64| {
+            let ex = x.exp();
+            let denominator = phi.powi(2) + variance + ex;
+            ex * (delta.powi(2) - denominator) / (2.0 * denominator.powi(2))
+                - (x - a) / SYSTEM_CONSTANT.powi(2)
+        };
+
+        let mut lower = a;
+        let mut upper = if delta.powi(2) > phi.powi(2) + variance {
+            (delta.powi(2) - phi.powi(2) - variance).ln()
+        } else {
+            let mut k = 1.0;
+            while f(a - k * SYSTEM_CONSTANT) < 0.0 {
+                k += 1.0;
+            }
+            a - k * SYSTEM_CONSTANT
+        };
+        let mut f_lower = f(lower);
+        let mut f_upper = f(upper);
+
+        while (upper - lower).abs() > CONVERGENCE_TOLERANCE {
+            let candidate = lower + (lower - upper) * f_lower / (f_upper - f_lower);
+            let f_candidate = f(candidate);
+            if f_candidate * f_upper <= 0.0 {
+                lower = upper;
+                f_lower = f_upper;
+            } else {
+                f_lower /= 2.0;
+            }
+            upper = candidate;
+            f_upper = f_candidate;
+        }
+
+        (lower / 2.0).exp()
+    }
+}
+
+impl RatingCalculator for Glicko2RatingCalculator {
+    fn rate(&self, player: Rating, opponent: Rating, result: GameResult) -> Rating {
+        let mu = (player.value() - INITIAL_RATING) / GLICKO2_SCALE;
+        let phi = player.deviation() / GLICKO2_SCALE;
+        let opponent_mu = (opponent.value() - INITIAL_RATING) / GLICKO2_SCALE;
+        let opponent_phi = opponent.deviation() / GLICKO2_SCALE;
+
+        let g = Self::g(opponent_phi);
+        let expected = Self::expected_score(mu, opponent_mu, opponent_phi);
+        let variance = 1.0 / (g.powi(2) * expected * (1.0 - expected));
+        let improvement = g * (result.score() - expected);
+        let delta = variance * improvement;
+
+        let volatility = Self::volatility(phi, player.volatility(), variance, delta);
+        let pre_period_phi = (phi.powi(2) + volatility.powi(2)).sqrt();
+        let new_phi = 1.0 / (1.0 / pre_period_phi.powi(2) + 1.0 / variance).sqrt();
+        let new_mu = mu + new_phi.powi(2) * improvement;
+
+        Rating::new(
+            INITIAL_RATING + new_mu * GLICKO2_SCALE,
+            (new_phi * GLICKO2_SCALE).min(INITIAL_DEVIATION),
+            volatility,
+            player.games_played() + 1,
+            player.wins() + u32::from(result == GameResult::Win),
+        )
+    }
+}
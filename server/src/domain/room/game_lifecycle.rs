@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum GameLifecycleState {
+    Waiting,
+    Ready,
+    InProgress,
+    Finished,
+}
+
+impl GameLifecycleState {
+    pub(crate) fn can_transition_to(&self, next: GameLifecycleState) -> bool {
+        use GameLifecycleState::*;
+
+        matches!(
+            (self, next),
+            (Waiting, Ready)
+                | (Ready, Ready)
+                | (Ready, InProgress)
+                | (Ready, Finished)
+                | (InProgress, InProgress)
+                | (InProgress, Finished)
+                | (Finished, Ready)
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Room({room_id}) cannot move from {from:?} to {to:?}")]
+pub(crate) struct IllegalGameLifecycleTransitionError {
+    room_id: Uuid,
+    from: GameLifecycleState,
+    to: GameLifecycleState,
+}
+
+impl IllegalGameLifecycleTransitionError {
+    pub(crate) fn new(room_id: Uuid, from: GameLifecycleState, to: GameLifecycleState) -> Self {
+        IllegalGameLifecycleTransitionError { room_id, from, to }
+    }
+}
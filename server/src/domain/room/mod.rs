@@ -1,27 +1,98 @@
 use std::collections::HashSet;
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::domain::game::GameOutcome;
+
+pub(crate) use game_lifecycle::*;
+pub(crate) use room_event::*;
+pub(crate) use room_expiry::*;
 pub(crate) use room_factory::*;
+pub(crate) use room_invite::*;
 pub(crate) use room_manager::*;
+pub(crate) use room_query::*;
 pub(crate) use room_repository::*;
+pub(crate) use room_role::*;
+pub(crate) use room_settings::*;
+pub(crate) use room_visibility::*;
+pub(crate) use series::*;
 
+mod game_lifecycle;
+mod room_event;
+mod room_expiry;
 mod room_factory;
+mod room_invite;
 mod room_manager;
+mod room_query;
 mod room_repository;
+mod room_role;
+mod room_settings;
+mod room_visibility;
+mod series;
+
+/// Number of players that can be seated in a game
+pub(crate) const SEAT_COUNT: usize = 2;
 
 pub(crate) struct Room {
     id: Uuid,
     active_game_id: Option<Uuid>,
+    visibility: RoomVisibility,
+    settings: RoomSettings,
+    invites: Vec<RoomInvite>,
+    host: Option<Uuid>,
     members: HashSet<Uuid>,
+    spectators: HashSet<Uuid>,
+    offline_members: HashSet<Uuid>,
+    banned_users: HashSet<Uuid>,
+    seated_players: Vec<Uuid>,
+    game_lifecycle_state: GameLifecycleState,
+    game_history: Vec<Uuid>,
+    rematch_requests: HashSet<Uuid>,
+    series: Option<Series>,
+    created_at: DateTime<Utc>,
+    last_active_at: DateTime<Utc>,
 }
 
 impl Room {
-    pub(crate) fn new(id: Uuid, active_game_id: Option<Uuid>, members: HashSet<Uuid>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        id: Uuid,
+        active_game_id: Option<Uuid>,
+        visibility: RoomVisibility,
+        settings: RoomSettings,
+        invites: Vec<RoomInvite>,
+        host: Option<Uuid>,
+        members: HashSet<Uuid>,
+        spectators: HashSet<Uuid>,
+        offline_members: HashSet<Uuid>,
+        banned_users: HashSet<Uuid>,
+        seated_players: Vec<Uuid>,
+        game_lifecycle_state: GameLifecycleState,
+        game_history: Vec<Uuid>,
+        rematch_requests: HashSet<Uuid>,
+        series: Option<Series>,
+        created_at: DateTime<Utc>,
+        last_active_at: DateTime<Utc>,
+    ) -> Self {
         Room {
             id,
             active_game_id,
+            visibility,
+            settings,
+            invites,
+            host,
             members,
+            spectators,
+            offline_members,
+            banned_users,
+            seated_players,
+            game_lifecycle_state,
+            game_history,
+            rematch_requests,
+            series,
+            created_at,
+            last_active_at,
         }
     }
 
@@ -33,23 +104,280 @@ impl Room {
         self.active_game_id
     }
 
-    pub(crate) fn set_active_game_id(&mut self, game_id: Uuid) {
+    /// Makes the game the active one, keeping a game it replaces before it finished in the history
+    pub(crate) fn set_active_game(&mut self, game_id: Uuid, seated_players: Vec<Uuid>) {
+        if let Some(previous_game_id) = self.active_game_id {
+            if !self.game_history.contains(&previous_game_id) {
+                self.game_history.push(previous_game_id);
+            }
+        }
         self.active_game_id = Some(game_id);
+        self.seated_players = seated_players;
+        self.rematch_requests.clear();
+    }
+
+    pub(crate) fn visibility(&self) -> RoomVisibility {
+        self.visibility
+    }
+
+    pub(crate) fn is_private(&self) -> bool {
+        self.visibility == RoomVisibility::Private
+    }
+
+    pub(crate) fn settings(&self) -> RoomSettings {
+        self.settings
+    }
+
+    pub(crate) fn set_settings(&mut self, settings: RoomSettings) {
+        self.settings = settings;
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.settings
+            .capacity()
+            .iter()
+            .any(|capacity| self.members.len() >= *capacity)
+    }
+
+    /// Whether there are more members than the settings allow to watch a game
+    pub(crate) fn has_disallowed_spectators(&self) -> bool {
+        !self.settings.spectators_allowed()
+            && (!self.spectators.is_empty() || self.members.len() > SEAT_COUNT)
+    }
+
+    pub(crate) fn invites(&self) -> &Vec<RoomInvite> {
+        &self.invites
+    }
+
+    pub(crate) fn add_invite(&mut self, invite: RoomInvite) {
+        self.invites.push(invite);
+    }
+
+    /// Checks an invite code, discarding expired invites and single-use invites once redeemed
+    pub(crate) fn redeem_invite(&mut self, code: &str, now: DateTime<Utc>) -> bool {
+        self.invites.retain(|invite| !invite.is_expired(now));
+        match self.invites.iter().position(|invite| invite.matches(code)) {
+            Some(index) => {
+                if self.invites[index].single_use() {
+                    self.invites.remove(index);
+                }
+                true
+            }
+            None => false,
+        }
     }
 
     pub(crate) fn members(&self) -> &HashSet<Uuid> {
         &self.members
     }
 
+    pub(crate) fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Removes a member, handing host status on if they held it
     pub(crate) fn remove_member(&mut self, user_id: Uuid) {
         self.members.remove(&user_id);
+        self.spectators.remove(&user_id);
+        self.offline_members.remove(&user_id);
+        self.seated_players.retain(|player| *player != user_id);
+        if self.is_host(user_id) {
+            self.host = self.next_host();
+        }
     }
 
+    /// Adds a member, or brings an offline member back online, making them host if the room has
+    /// none
     pub(crate) fn add_member(&mut self, user_id: Uuid) {
         self.members.insert(user_id);
+        self.offline_members.remove(&user_id);
+        if self.host.is_none() {
+            self.host = Some(user_id);
+        }
     }
 
     pub(crate) fn is_member(&self, user_id: Uuid) -> bool {
         self.members.contains(&user_id)
     }
+
+    pub(crate) fn spectators(&self) -> &HashSet<Uuid> {
+        &self.spectators
+    }
+
+    /// Members whose connection to the room dropped without them leaving it
+    pub(crate) fn offline_members(&self) -> &HashSet<Uuid> {
+        &self.offline_members
+    }
+
+    /// Keeps a member, along with their role and seat, while they are not connected
+    pub(crate) fn mark_offline(&mut self, user_id: Uuid) {
+        if self.is_member(user_id) {
+            self.offline_members.insert(user_id);
+        }
+    }
+
+    pub(crate) fn is_online(&self, user_id: Uuid) -> bool {
+        self.is_member(user_id) && !self.offline_members.contains(&user_id)
+    }
+
+    pub(crate) fn has_online_member(&self) -> bool {
+        self.members
+            .iter()
+            .any(|member| !self.offline_members.contains(member))
+    }
+
+    pub(crate) fn role(&self, user_id: Uuid) -> RoomRole {
+        if self.spectators.contains(&user_id) {
+            RoomRole::Spectator
+        } else {
+            RoomRole::Player
+        }
+    }
+
+    pub(crate) fn is_spectator(&self, user_id: Uuid) -> bool {
+        self.role(user_id) == RoomRole::Spectator
+    }
+
+    /// Changes a member's role, giving up their seat if they become a spectator
+    pub(crate) fn set_role(&mut self, user_id: Uuid, role: RoomRole) {
+        match role {
+            RoomRole::Player => {
+                self.spectators.remove(&user_id);
+            }
+            RoomRole::Spectator => {
+                self.spectators.insert(user_id);
+                self.seated_players.retain(|player| *player != user_id);
+            }
+        }
+    }
+
+    pub(crate) fn host(&self) -> Option<Uuid> {
+        self.host
+    }
+
+    pub(crate) fn is_host(&self, user_id: Uuid) -> bool {
+        self.host == Some(user_id)
+    }
+
+    /// Seated players take over as host first, then the remaining members in id order
+    fn next_host(&self) -> Option<Uuid> {
+        self.seated_players
+            .first()
+            .copied()
+            .or_else(|| self.members.iter().min().copied())
+    }
+
+    pub(crate) fn banned_users(&self) -> &HashSet<Uuid> {
+        &self.banned_users
+    }
+
+    pub(crate) fn is_banned(&self, user_id: Uuid) -> bool {
+        self.banned_users.contains(&user_id)
+    }
+
+    pub(crate) fn ban(&mut self, user_id: Uuid) {
+        self.banned_users.insert(user_id);
+    }
+
+    pub(crate) fn unban(&mut self, user_id: Uuid) {
+        self.banned_users.remove(&user_id);
+    }
+
+    /// Players seated in the active game, in seat order
+    pub(crate) fn seated_players(&self) -> &Vec<Uuid> {
+        &self.seated_players
+    }
+
+    pub(crate) fn seat_player(&mut self, user_id: Uuid) {
+        if !self.seated_players.contains(&user_id) {
+            self.seated_players.push(user_id);
+        }
+    }
+
+    pub(crate) fn has_open_seat(&self) -> bool {
+        self.seated_players.len() < SEAT_COUNT
+    }
+
+    pub(crate) fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub(crate) fn last_active_at(&self) -> DateTime<Utc> {
+        self.last_active_at
+    }
+
+    pub(crate) fn record_activity(&mut self, at: DateTime<Utc>) {
+        self.last_active_at = self.last_active_at.max(at);
+    }
+
+    pub(crate) fn game_lifecycle_state(&self) -> GameLifecycleState {
+        self.game_lifecycle_state
+    }
+
+    pub(crate) fn transition_game_lifecycle(
+        &mut self,
+        next: GameLifecycleState,
+    ) -> Result<(), IllegalGameLifecycleTransitionError> {
+        if !self.game_lifecycle_state.can_transition_to(next) {
+            return Err(IllegalGameLifecycleTransitionError::new(
+                self.id,
+                self.game_lifecycle_state,
+                next,
+            ));
+        }
+        if next == GameLifecycleState::Finished {
+            if let Some(game_id) = self.active_game_id {
+                self.game_history.push(game_id);
+            }
+        }
+        self.game_lifecycle_state = next;
+        Ok(())
+    }
+
+    pub(crate) fn game_history(&self) -> &Vec<Uuid> {
+        &self.game_history
+    }
+
+    pub(crate) fn has_game(&self, game_id: Uuid) -> bool {
+        self.active_game_id == Some(game_id) || self.game_history.contains(&game_id)
+    }
+
+    pub(crate) fn rematch_requests(&self) -> &HashSet<Uuid> {
+        &self.rematch_requests
+    }
+
+    pub(crate) fn request_rematch(&mut self, user_id: Uuid) {
+        self.rematch_requests.insert(user_id);
+    }
+
+    pub(crate) fn series(&self) -> Option<&Series> {
+        self.series.as_ref()
+    }
+
+    pub(crate) fn set_series(&mut self, series: Series) {
+        self.series = Some(series);
+    }
+
+    pub(crate) fn series_is_finished(&self) -> bool {
+        matches!(&self.series, Some(series) if series.is_finished())
+    }
+
+    /// Whether a series has had games counted towards it and has yet to be decided
+    pub(crate) fn series_is_in_progress(&self) -> bool {
+        matches!(&self.series, Some(series) if series.games_played() > 0 && !series.is_finished())
+    }
+
+    /// Returns the updated series if the game counted towards one
+    pub(crate) fn record_series_game(
+        &mut self,
+        players: &[Uuid],
+        outcome: GameOutcome,
+    ) -> Option<&Series> {
+        let series = self.series.as_mut()?;
+        if series.record_game(players, outcome) {
+            Some(series)
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::room::{RoomRole, RoomSettings, Series};
+
+#[derive(Debug, Clone)]
+pub(crate) enum RoomEvent {
+    RematchRequested {
+        room_id: Uuid,
+        user_id: Uuid,
+    },
+    GameStarted {
+        room_id: Uuid,
+        game_id: Uuid,
+        players: Vec<Uuid>,
+        first_mover: Option<Uuid>,
+    },
+    SeriesUpdated {
+        room_id: Uuid,
+        series: Series,
+    },
+    HostChanged {
+        room_id: Uuid,
+        host: Uuid,
+    },
+    MemberKicked {
+        room_id: Uuid,
+        user_id: Uuid,
+    },
+    MemberRenamed {
+        room_id: Uuid,
+        user_id: Uuid,
+        name: String,
+    },
+    SettingsUpdated {
+        room_id: Uuid,
+        settings: RoomSettings,
+    },
+    RoleChanged {
+        room_id: Uuid,
+        user_id: Uuid,
+        role: RoomRole,
+    },
+    RoomClosed {
+        room_id: Uuid,
+    },
+}
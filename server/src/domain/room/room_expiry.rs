@@ -0,0 +1,97 @@
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::room::{Room, Series};
+
+/// How long rooms may go without activity before they are removed
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct RoomExpiryPolicy {
+    empty_room_ttl: Duration,
+    idle_room_ttl: Duration,
+    unfinished_game_ttl: Duration,
+}
+
+impl RoomExpiryPolicy {
+    pub(crate) fn new(
+        empty_room_ttl: Duration,
+        idle_room_ttl: Duration,
+        unfinished_game_ttl: Duration,
+    ) -> Self {
+        RoomExpiryPolicy {
+            empty_room_ttl,
+            idle_room_ttl,
+            unfinished_game_ttl,
+        }
+    }
+
+    /// Time after which a room without members is removed
+    pub(crate) fn empty_room_ttl(&self) -> Duration {
+        self.empty_room_ttl
+    }
+
+    /// Time after which a room that still has members is removed
+    pub(crate) fn idle_room_ttl(&self) -> Duration {
+        self.idle_room_ttl
+    }
+
+    /// Time after which a room that still has members and a seated game that is not finished is
+    /// removed. Timed games are lost on time long before this, so it only catches untimed games
+    /// whose players stopped playing.
+    pub(crate) fn unfinished_game_ttl(&self) -> Duration {
+        self.unfinished_game_ttl
+    }
+
+    /// The shortest time after which any room can be removed
+    pub(crate) fn shortest_ttl(&self) -> Duration {
+        self.empty_room_ttl
+            .min(self.idle_room_ttl)
+            .min(self.unfinished_game_ttl)
+    }
+}
+
+/// Rooms removed by a single pass of the reaper
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub(crate) struct ReapSummary {
+    empty_rooms_removed: usize,
+    idle_rooms_removed: usize,
+    games_archived: usize,
+    removed_series: Vec<(Uuid, Series)>,
+}
+
+impl ReapSummary {
+    pub(crate) fn empty_rooms_removed(&self) -> usize {
+        self.empty_rooms_removed
+    }
+
+    pub(crate) fn idle_rooms_removed(&self) -> usize {
+        self.idle_rooms_removed
+    }
+
+    /// Unfinished games that were abandoned because their room was removed
+    pub(crate) fn games_archived(&self) -> usize {
+        self.games_archived
+    }
+
+    pub(crate) fn rooms_removed(&self) -> usize {
+        self.empty_rooms_removed + self.idle_rooms_removed
+    }
+
+    /// Series of the removed rooms by room id, so matches played in them can still be decided
+    pub(crate) fn removed_series(&self) -> &Vec<(Uuid, Series)> {
+        &self.removed_series
+    }
+
+    pub(crate) fn record_removed_room(&mut self, room: &Room, archived_game: bool) {
+        if let Some(series) = room.series() {
+            self.removed_series.push((room.id(), series.clone()));
+        }
+        if room.is_empty() {
+            self.empty_rooms_removed += 1;
+        } else {
+            self.idle_rooms_removed += 1;
+        }
+        if archived_game {
+            self.games_archived += 1;
+        }
+    }
+}
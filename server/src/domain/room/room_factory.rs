@@ -2,22 +2,44 @@ use std::collections::HashSet;
 
 use uuid::Uuid;
 
-use crate::domain::room::Room;
+use crate::domain::clock::Clock;
+use crate::domain::room::{GameLifecycleState, Room, RoomSettings, RoomVisibility};
 
 pub(crate) trait RoomFactory {
-    fn create(&self) -> Room;
+    fn create(&self, host: Uuid, visibility: RoomVisibility, settings: RoomSettings) -> Room;
 }
 
-pub(crate) struct RoomFactoryImpl;
+pub(crate) struct RoomFactoryImpl<C: Clock> {
+    clock: C,
+}
 
-impl RoomFactoryImpl {
-    pub(crate) fn new() -> Self {
-        RoomFactoryImpl
+impl<C: Clock> RoomFactoryImpl<C> {
+    pub(crate) fn new(clock: C) -> Self {
+        RoomFactoryImpl { clock }
     }
 }
 
-impl RoomFactory for RoomFactoryImpl {
-    fn create(&self) -> Room {
-        Room::new(Uuid::new_v4(), None, HashSet::new())
+impl<C: Clock> RoomFactory for RoomFactoryImpl<C> {
+    fn create(&self, host: Uuid, visibility: RoomVisibility, settings: RoomSettings) -> Room {
+        let now = self.clock.now();
+        Room::new(
+            Uuid::new_v4(),
+            None,
+            visibility,
+            settings,
+            vec![],
+            Some(host),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            vec![],
+            GameLifecycleState::Waiting,
+            vec![],
+            HashSet::new(),
+            None,
+            now,
+            now,
+        )
     }
 }
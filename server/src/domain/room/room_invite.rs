@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+
+/// Characters used in invite codes, leaving out ones that are easily confused when read aloud
+const INVITE_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const INVITE_CODE_LENGTH: usize = 8;
+
+#[derive(Debug, Clone)]
+pub(crate) struct RoomInvite {
+    code: String,
+    single_use: bool,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl RoomInvite {
+    pub(crate) fn new(code: String, single_use: bool, expires_at: Option<DateTime<Utc>>) -> Self {
+        RoomInvite {
+            code,
+            single_use,
+            expires_at,
+        }
+    }
+
+    /// Creates an invite with a new random code
+    pub(crate) fn generate(single_use: bool, expires_at: Option<DateTime<Utc>>) -> Self {
+        let mut rng = rand::thread_rng();
+        let code = (0..INVITE_CODE_LENGTH)
+            .map(|_| INVITE_CODE_ALPHABET[rng.gen_range(0..INVITE_CODE_ALPHABET.len())] as char)
+            .collect();
+        RoomInvite::new(code, single_use, expires_at)
+    }
+
+    pub(crate) fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    pub(crate) fn single_use(&self) -> bool {
+        self.single_use
+    }
+
+    pub(crate) fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    pub(crate) fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.iter().any(|expires_at| *expires_at <= now)
+    }
+
+    /// Codes are matched case-insensitively so they can be typed in by hand
+    pub(crate) fn matches(&self, code: &str) -> bool {
+        self.code.eq_ignore_ascii_case(code.trim())
+    }
+}
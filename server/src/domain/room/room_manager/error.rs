@@ -1,10 +1,13 @@
 use uuid::Uuid;
 
 use crate::domain::game::{
-    ApplyMoveError, GameNotFoundError, GetGameError, PlayerCountExceededError, UpdateGameError,
-    UserNotAPlayerInGameError,
+    ApplyMoveError, GameAlreadyFinishedError, GameNotFoundError, GetGameError,
+    PlayerCountExceededError, UpdateGameError, UserNotAPlayerInGameError,
+};
+use crate::domain::room::{
+    GetRoomError, IllegalGameLifecycleTransitionError, InvalidRoomSettingsError, RemoveRoomError,
+    RoomNotFoundError, UpdateRoomError,
 };
-use crate::domain::room::{GetRoomError, RoomNotFoundError, UpdateRoomError};
 use crate::domain::user::{GetUserError, UserNotFoundError};
 
 #[derive(Debug, thiserror::Error)]
@@ -20,6 +23,45 @@ impl UserNotInRoomError {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error("User({user_id}) is not the host of Room({room_id})")]
+pub(crate) struct NotRoomHostError {
+    user_id: Uuid,
+    room_id: Uuid,
+}
+
+impl NotRoomHostError {
+    pub(crate) fn new(user_id: Uuid, room_id: Uuid) -> Self {
+        NotRoomHostError { user_id, room_id }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("User({user_id}) is banned from Room({room_id})")]
+pub(crate) struct UserBannedFromRoomError {
+    user_id: Uuid,
+    room_id: Uuid,
+}
+
+impl UserBannedFromRoomError {
+    pub(crate) fn new(user_id: Uuid, room_id: Uuid) -> Self {
+        UserBannedFromRoomError { user_id, room_id }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("User({user_id}) is a spectator in Room({room_id})")]
+pub(crate) struct UserIsSpectatorError {
+    user_id: Uuid,
+    room_id: Uuid,
+}
+
+impl UserIsSpectatorError {
+    pub(crate) fn new(user_id: Uuid, room_id: Uuid) -> Self {
+        UserIsSpectatorError { user_id, room_id }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum NewGameError {
     #[error(transparent)]
@@ -28,6 +70,18 @@ pub(crate) enum NewGameError {
     RoomNotFound(#[from] RoomNotFoundError),
     #[error(transparent)]
     UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    NotRoomHost(#[from] NotRoomHostError),
+    #[error(transparent)]
+    UserIsSpectator(#[from] UserIsSpectatorError),
+    #[error(transparent)]
+    GameInProgress(#[from] GameInProgressError),
+    #[error(transparent)]
+    SeriesFinished(#[from] SeriesFinishedError),
+    #[error(transparent)]
+    SpectatorsNotAllowed(#[from] SpectatorsNotAllowedError),
+    #[error(transparent)]
+    IllegalLifecycleTransition(#[from] IllegalGameLifecycleTransitionError),
 }
 
 impl From<GetUserError> for NewGameError {
@@ -58,14 +112,16 @@ impl From<UpdateRoomError> for NewGameError {
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum JoinRoomError {
-    #[error("Users cannot be assigned to multiple rooms")]
-    AlreadyAssigned,
     #[error(transparent)]
     UserNotFound(#[from] UserNotFoundError),
     #[error(transparent)]
     RoomNotFound(#[from] RoomNotFoundError),
     #[error(transparent)]
     GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    Banned(#[from] UserBannedFromRoomError),
+    #[error(transparent)]
+    RoomFull(#[from] RoomFullError),
 }
 
 impl From<GetUserError> for JoinRoomError {
@@ -114,6 +170,8 @@ pub(crate) enum AddPlayerError {
     PlayerCountExceeded(#[from] PlayerCountExceededError),
     #[error(transparent)]
     UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    UserIsSpectator(#[from] UserIsSpectatorError),
 }
 
 impl From<GetUserError> for AddPlayerError {
@@ -140,6 +198,14 @@ impl From<GetGameError> for AddPlayerError {
     }
 }
 
+impl From<UpdateRoomError> for AddPlayerError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
 impl From<UpdateGameError> for AddPlayerError {
     fn from(err: UpdateGameError) -> Self {
         match err {
@@ -184,6 +250,16 @@ pub(crate) enum LeaveRoomError {
     GameNotFound(#[from] GameNotFoundError),
     #[error(transparent)]
     RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    UserNotInRoom(#[from] UserNotInRoomError),
+}
+
+impl From<GetRoomError> for LeaveRoomError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found) => LeaveRoomError::RoomNotFound(room_not_found),
+        }
+    }
 }
 
 impl From<GetUserError> for LeaveRoomError {
@@ -231,9 +307,15 @@ pub(crate) enum GameMoveError {
     #[error(transparent)]
     UserNotInRoom(#[from] UserNotInRoomError),
     #[error(transparent)]
+    UserIsSpectator(#[from] UserIsSpectatorError),
+    #[error(transparent)]
     UserNotPlayer(#[from] UserNotAPlayerInGameError),
     #[error(transparent)]
     GamePlayError(#[from] ApplyMoveError),
+    #[error(transparent)]
+    OutOfTime(#[from] PlayerOutOfTimeError),
+    #[error(transparent)]
+    IllegalLifecycleTransition(#[from] IllegalGameLifecycleTransitionError),
 }
 
 impl From<GetUserError> for GameMoveError {
@@ -268,6 +350,565 @@ impl From<UpdateGameError> for GameMoveError {
     }
 }
 
+impl From<UpdateRoomError> for GameMoveError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AbandonGameError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    NoActiveGameInRoom(#[from] NoActiveGameInRoomError),
+    #[error(transparent)]
+    UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    UserNotPlayer(#[from] UserNotAPlayerInGameError),
+    #[error(transparent)]
+    GameAlreadyFinished(#[from] GameAlreadyFinishedError),
+    #[error(transparent)]
+    IllegalLifecycleTransition(#[from] IllegalGameLifecycleTransitionError),
+}
+
+impl From<GetUserError> for AbandonGameError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for AbandonGameError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for AbandonGameError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetGameError> for AbandonGameError {
+    fn from(err: GetGameError) -> Self {
+        match err {
+            GetGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateGameError> for AbandonGameError {
+    fn from(err: UpdateGameError) -> Self {
+        match err {
+            UpdateGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum GameHistoryError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+}
+
+impl From<GetRoomError> for GameHistoryError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetGameError> for GameHistoryError {
+    fn from(err: GetGameError) -> Self {
+        match err {
+            GetGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum GetRoomGameError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    GameNotInRoom(#[from] GameNotInRoomError),
+}
+
+impl From<GetRoomError> for GetRoomGameError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetGameError> for GetRoomGameError {
+    fn from(err: GetGameError) -> Self {
+        match err {
+            GetGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Game({game_id}) is not part of Room({room_id})")]
+pub(crate) struct GameNotInRoomError {
+    game_id: Uuid,
+    room_id: Uuid,
+}
+
+impl GameNotInRoomError {
+    pub(crate) fn new(game_id: Uuid, room_id: Uuid) -> Self {
+        GameNotInRoomError { game_id, room_id }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RematchError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    NoFinishedGame(#[from] NoFinishedGameInRoomError),
+    #[error(transparent)]
+    UserNotPlayer(#[from] UserNotAPlayerInGameError),
+    #[error(transparent)]
+    SeriesFinished(#[from] SeriesFinishedError),
+    #[error(transparent)]
+    IllegalLifecycleTransition(#[from] IllegalGameLifecycleTransitionError),
+    #[error(transparent)]
+    NewGame(#[from] NewGameError),
+}
+
+impl From<GetUserError> for RematchError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for RematchError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for RematchError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetGameError> for RematchError {
+    fn from(err: GetGameError) -> Self {
+        match err {
+            GetGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ConfigureSeriesError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    NotRoomHost(#[from] NotRoomHostError),
+    #[error(transparent)]
+    GameInProgress(#[from] GameInProgressError),
+    #[error(transparent)]
+    SeriesInProgress(#[from] SeriesInProgressError),
+    #[error(transparent)]
+    InvalidSeriesLength(#[from] InvalidSeriesLengthError),
+}
+
+impl From<GetUserError> for ConfigureSeriesError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for ConfigureSeriesError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for ConfigureSeriesError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ModerateMemberError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    NotRoomHost(#[from] NotRoomHostError),
+    #[error(transparent)]
+    UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    CannotModerateSelf(#[from] CannotModerateSelfError),
+}
+
+impl From<GetUserError> for ModerateMemberError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for ModerateMemberError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for ModerateMemberError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<RemovePlayerError> for ModerateMemberError {
+    fn from(err: RemovePlayerError) -> Self {
+        match err {
+            RemovePlayerError::GameNotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateInviteError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    NotRoomHost(#[from] NotRoomHostError),
+}
+
+impl From<GetUserError> for CreateInviteError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for CreateInviteError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for CreateInviteError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AdmissionError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    InviteRequired(#[from] InviteRequiredError),
+    #[error(transparent)]
+    InvalidInvite(#[from] InvalidInviteError),
+    #[error(transparent)]
+    Banned(#[from] UserBannedFromRoomError),
+    #[error(transparent)]
+    RoomFull(#[from] RoomFullError),
+}
+
+impl From<GetRoomError> for AdmissionError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for AdmissionError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum UpdateSettingsError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    NotRoomHost(#[from] NotRoomHostError),
+    #[error(transparent)]
+    InvalidSettings(#[from] InvalidRoomSettingsError),
+}
+
+impl From<GetUserError> for UpdateSettingsError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for UpdateSettingsError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for UpdateSettingsError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ChangeRoleError {
+    #[error(transparent)]
+    UserNotFound(#[from] UserNotFoundError),
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    UserNotInRoom(#[from] UserNotInRoomError),
+    #[error(transparent)]
+    SpectatorsNotAllowed(#[from] SpectatorsNotAllowedError),
+}
+
+impl From<GetUserError> for ChangeRoleError {
+    fn from(err: GetUserError) -> Self {
+        match err {
+            GetUserError::NotFound(user_not_found_error) => user_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetRoomError> for ChangeRoleError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for ChangeRoleError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<RemovePlayerError> for ChangeRoleError {
+    fn from(err: RemovePlayerError) -> Self {
+        match err {
+            RemovePlayerError::GameNotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum GetSeriesError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    NoSeriesInRoom(#[from] NoSeriesInRoomError),
+}
+
+impl From<GetRoomError> for GetSeriesError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("There is no finished game to rematch in room with id: {0}")]
+pub(crate) struct NoFinishedGameInRoomError(pub(crate) Uuid);
+
 #[derive(Debug, thiserror::Error)]
 #[error("There is no currently active game for room with id: {0}")]
 pub(crate) struct NoActiveGameInRoomError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("The game in room with id {0} is still in progress")]
+pub(crate) struct GameInProgressError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("The match in room with id {0} has finished")]
+pub(crate) struct SeriesFinishedError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("The match in room with id {0} has started and is not finished yet")]
+pub(crate) struct SeriesInProgressError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("There is no match configured for room with id: {0}")]
+pub(crate) struct NoSeriesInRoomError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("A match must consist of at least one game")]
+pub(crate) struct InvalidSeriesLengthError;
+
+#[derive(Debug, thiserror::Error)]
+#[error("The host cannot kick or ban themselves")]
+pub(crate) struct CannotModerateSelfError;
+
+#[derive(Debug, thiserror::Error)]
+#[error("Room with id {0} is private and can only be joined with an invite")]
+pub(crate) struct InviteRequiredError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("The invite is not valid for room with id: {0}")]
+pub(crate) struct InvalidInviteError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("Room with id {0} is full")]
+pub(crate) struct RoomFullError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("Room with id {0} does not allow spectators")]
+pub(crate) struct SpectatorsNotAllowedError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+#[error("User({0}) has run out of time and lost the game")]
+pub(crate) struct PlayerOutOfTimeError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ReapRoomError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+}
+
+impl From<RemoveRoomError> for ReapRoomError {
+    fn from(err: RemoveRoomError) -> Self {
+        match err {
+            RemoveRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetGameError> for ReapRoomError {
+    fn from(err: GetGameError) -> Self {
+        match err {
+            GetGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateGameError> for ReapRoomError {
+    fn from(err: UpdateGameError) -> Self {
+        match err {
+            UpdateGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum FlagGameError {
+    #[error(transparent)]
+    RoomNotFound(#[from] RoomNotFoundError),
+    #[error(transparent)]
+    GameNotFound(#[from] GameNotFoundError),
+    #[error(transparent)]
+    IllegalLifecycleTransition(#[from] IllegalGameLifecycleTransitionError),
+}
+
+impl From<GetRoomError> for FlagGameError {
+    fn from(err: GetRoomError) -> Self {
+        match err {
+            GetRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateRoomError> for FlagGameError {
+    fn from(err: UpdateRoomError) -> Self {
+        match err {
+            UpdateRoomError::NotFound(room_not_found_error) => room_not_found_error.into(),
+        }
+    }
+}
+
+impl From<GetGameError> for FlagGameError {
+    fn from(err: GetGameError) -> Self {
+        match err {
+            GetGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
+
+impl From<UpdateGameError> for FlagGameError {
+    fn from(err: UpdateGameError) -> Self {
+        match err {
+            UpdateGameError::NotFound(game_not_found_error) => game_not_found_error.into(),
+        }
+    }
+}
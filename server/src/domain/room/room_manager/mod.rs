@@ -1,12 +1,22 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
 pub(crate) use error::*;
 
-use crate::domain::game::{GameManager, GameMove};
-use crate::domain::room::{Room, RoomRepository};
-use crate::domain::user::{User, UserRepository};
+use crate::domain::clock::Clock;
+use crate::domain::game::{Game, GameManager, GameMove, GameOutcome, UserNotAPlayerInGameError};
+use crate::domain::keyed_lock::KeyedLock;
+use crate::domain::rating::{GameResult, RatingCalculator};
+use crate::domain::room::{
+    GameLifecycleState, ReapSummary, Room, RoomEvent, RoomExpiryPolicy, RoomInvite, RoomRepository,
+    RoomRole, RoomSettings, RoomSettingsUpdate, Series, UpdateRoomError,
+};
+use crate::domain::stats::PlayerStats;
+use crate::domain::user::{
+    GetUserError, UpdateUserError, User, UserClient, UserClientProvider, UserRepository,
+};
 
 mod error;
 
@@ -14,79 +24,494 @@ mod error;
 pub(crate) trait RoomManager {
     async fn join_room(&self, user_id: Uuid, room_id: Uuid) -> Result<(), JoinRoomError>;
 
-    async fn leave_room(&self, user_id: Uuid) -> Result<(), LeaveRoomError>;
+    async fn leave_room(&self, user_id: Uuid, room_id: Uuid) -> Result<(), LeaveRoomError>;
+
+    /// Marks a member whose connection dropped as offline. They keep their role, their seat and
+    /// their place in the game until they leave or the room expires.
+    async fn disconnect(&self, user_id: Uuid, room_id: Uuid) -> Result<(), LeaveRoomError>;
 
     async fn start_new_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), NewGameError>;
 
+    /// Adds the players to the room and starts a game with them seated, the first player moving first
+    async fn start_match(&self, room_id: Uuid, players: Vec<Uuid>) -> Result<Game, NewGameError>;
+
     async fn make_game_move(&self, room_id: Uuid, game_move: GameMove)
         -> Result<(), GameMoveError>;
 
     async fn add_player(&self, room_id: Uuid, user_id: Uuid) -> Result<Option<()>, AddPlayerError>;
+
+    async fn abandon_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), AbandonGameError>;
+
+    async fn game_history(&self, room_id: Uuid) -> Result<Vec<Game>, GameHistoryError>;
+
+    async fn get_game(&self, room_id: Uuid, game_id: Uuid) -> Result<Game, GetRoomGameError>;
+
+    async fn request_rematch(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>, RematchError>;
+
+    async fn configure_series(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        best_of: u8,
+    ) -> Result<(), ConfigureSeriesError>;
+
+    async fn series(&self, room_id: Uuid) -> Result<Series, GetSeriesError>;
+
+    async fn kick_member(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        member_id: Uuid,
+    ) -> Result<(), ModerateMemberError>;
+
+    async fn ban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError>;
+
+    async fn unban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError>;
+
+    async fn create_invite(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        single_use: bool,
+        expires_in: Option<Duration>,
+    ) -> Result<RoomInvite, CreateInviteError>;
+
+    async fn update_settings(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        update: RoomSettingsUpdate,
+    ) -> Result<RoomSettings, UpdateSettingsError>;
+
+    /// Switches a member between playing and spectating, giving up any seat they held
+    async fn change_role(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        role: RoomRole,
+    ) -> Result<(), ChangeRoleError>;
+
+    /// Checks whether a user may enter a room, redeeming their invite code if it is private
+    async fn admit(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        invite_code: Option<String>,
+    ) -> Result<(), AdmissionError>;
+
+    /// Removes rooms that have gone without activity for longer than the policy allows, archiving
+    /// their unfinished games and notifying anyone still in them
+    async fn reap_rooms(&self, policy: RoomExpiryPolicy) -> ReapSummary;
+
+    /// Finishes games in which the player to move has run out of time as a loss on time, even if
+    /// nobody is making requests to them, returning the rooms they were played in
+    async fn flag_games(&self) -> Vec<Uuid>;
+
+    /// Tells everyone in the rooms the user is a member of that the user has a new name
+    async fn announce_rename(&self, user: &User);
+
+    /// Statistics derived from the user's finished games
+    async fn player_stats(&self, user_id: Uuid) -> Result<PlayerStats, GetUserError>;
+
+    /// Games in any state that the user has held a seat in or made a move in, oldest first
+    async fn games_involving(&self, user_id: Uuid) -> Vec<Game>;
+
+    /// Attributes everything the user did in any game to a newly generated id that is not linked
+    /// to them
+    async fn anonymise_player(&self, user_id: Uuid);
+
+    /// Guests who have been inactive for longer than the time to live and are not in any room
+    async fn expired_guests(&self, guest_ttl: Duration) -> Vec<User>;
 }
 
-pub(crate) struct RoomManagerImpl<UR: UserRepository, RR: RoomRepository, GM: GameManager> {
+pub(crate) struct RoomManagerImpl<
+    UR: UserRepository,
+    RR: RoomRepository,
+    GM: GameManager,
+    UCP: UserClientProvider,
+    C: Clock,
+    RC: RatingCalculator,
+> {
     user_repository: Arc<UR>,
     room_repository: Arc<RR>,
     game_manager: GM,
+    user_client_provider: Arc<UCP>,
+    clock: C,
+    rating_calculator: RC,
+    /// Held while a room is read, changed and stored again, so that concurrent changes to the
+    /// same room cannot overwrite each other
+    room_locks: KeyedLock,
 }
 
-impl<UR, RR, GM> RoomManagerImpl<UR, RR, GM>
+impl<UR, RR, GM, UCP, C, RC> RoomManagerImpl<UR, RR, GM, UCP, C, RC>
 where
     UR: UserRepository,
     RR: RoomRepository,
     GM: GameManager,
+    UCP: UserClientProvider + Send + Sync,
+    UCP::UserClient: Send + Sync,
+    C: Clock,
+    RC: RatingCalculator + Sync,
 {
     pub(crate) fn new(
         user_repository: Arc<UR>,
         room_repository: Arc<RR>,
         game_manager: GM,
+        user_client_provider: Arc<UCP>,
+        clock: C,
+        rating_calculator: RC,
     ) -> Self {
         RoomManagerImpl {
             user_repository,
             room_repository,
             game_manager,
+            user_client_provider,
+            clock,
+            rating_calculator,
+            room_locks: KeyedLock::new(),
+        }
+    }
+
+    /// Stores changes to a room, counting them as activity so that it is not reaped as idle
+    async fn update_room(&self, room: &mut Room) -> Result<(), UpdateRoomError> {
+        room.record_activity(self.clock.now());
+        self.room_repository.update(room).await
+    }
+
+    /// Joining rooms and playing moves keep guests from expiring
+    async fn record_user_activity(&self, user_id: Uuid) {
+        let result = self
+            .user_repository
+            .record_activity(user_id, self.clock.now())
+            .await;
+        if let Err(err) = result {
+            log::warn!("Could not record activity of User({}): {}", user_id, err);
+        }
+    }
+
+    async fn notify_members(&self, room: &Room, event: RoomEvent) {
+        self.notify_users(room.id(), room.members().iter(), &event)
+            .await;
+    }
+
+    async fn notify_users(
+        &self,
+        room_id: Uuid,
+        user_ids: impl Iterator<Item = &Uuid>,
+        event: &RoomEvent,
+    ) {
+        for member_id in user_ids {
+            let result = match self.user_client_provider.get(*member_id, room_id).await {
+                Ok(user_client) => user_client.notify(event).await.map_err(|e| e.to_string()),
+                Err(err) => Err(err.to_string()),
+            };
+            if let Err(err) = result {
+                log::debug!(
+                    "Could not notify User({}) of {:?}: {}",
+                    member_id,
+                    event,
+                    err
+                );
+            }
+        }
+    }
+
+    fn game_started_event(room_id: Uuid, game: &Game) -> RoomEvent {
+        RoomEvent::GameStarted {
+            room_id,
+            game_id: game.id(),
+            players: game.players().clone(),
+            first_mover: game.first_mover(),
+        }
+    }
+
+    fn series_updated_event(room: &Room) -> Option<RoomEvent> {
+        room.series().map(|series| RoomEvent::SeriesUpdated {
+            room_id: room.id(),
+            series: series.clone(),
+        })
+    }
+
+    /// Records a finished game against the room's series, returning an event if the score changed
+    fn record_series_game(room: &mut Room, game: &Game) -> Option<RoomEvent> {
+        let outcome = game.outcome()?;
+        room.record_series_game(game.players(), outcome)?;
+        Self::series_updated_event(room)
+    }
+
+    /// Updates both players' ratings once a rated game has been decided
+    async fn record_rated_game(&self, game: &Game) {
+        let outcome = match game.outcome() {
+            Some(outcome) if game.rated() && outcome != GameOutcome::Abandoned => outcome,
+            _ => return,
+        };
+        let (first, second) = match game.players().as_slice() {
+            [first, second] if first != second => (*first, *second),
+            _ => return,
+        };
+
+        let result = |player: Uuid| match outcome.winner() {
+            Some(winner) if winner == player => GameResult::Win,
+            Some(_) => GameResult::Loss,
+            None => GameResult::Draw,
+        };
+        let variant = game.variant();
+        let (first_rating, second_rating) = match (
+            self.user_repository.get(first).await,
+            self.user_repository.get(second).await,
+        ) {
+            (Ok(first_user), Ok(second_user)) => {
+                (first_user.rating(variant), second_user.rating(variant))
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                log::warn!("Could not rate Game({}): {}", game.id(), err);
+                return;
+            }
+        };
+
+        // Each player's own rating is re-read as it is changed, so that games finishing at the
+        // same time are both counted, while the opponent's is taken from before this game
+        let rating_calculator = &self.rating_calculator;
+        for (player, opponent_rating) in [(first, second_rating), (second, first_rating)].iter() {
+            let stored = self
+                .user_repository
+                .update_with(*player, |user| {
+                    let rating = rating_calculator.rate(
+                        user.rating(variant),
+                        *opponent_rating,
+                        result(*player),
+                    );
+                    user.set_rating(variant, rating);
+                    Ok::<_, UpdateUserError>(())
+                })
+                .await;
+            if let Err(err) = stored {
+                log::warn!("Could not store rating for User({}): {}", player, err);
+            }
+        }
+    }
+
+    fn host_changed_event(room: &Room, previous_host: Option<Uuid>) -> Option<RoomEvent> {
+        match room.host() {
+            Some(host) if room.host() != previous_host => Some(RoomEvent::HostChanged {
+                room_id: room.id(),
+                host,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Checks that the acting user is the room's host and is not targeting themselves
+    fn check_can_moderate(
+        room: &Room,
+        user_id: Uuid,
+        target_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        if !room.is_host(user_id) {
+            return Err(NotRoomHostError::new(user_id, room.id()).into());
+        } else if user_id == target_id {
+            return Err(CannotModerateSelfError.into());
+        }
+        Ok(())
+    }
+
+    /// Removes a member from the room and its active game, notifying them and the remaining members
+    async fn remove_member(
+        &self,
+        room: &mut Room,
+        member_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        let previous_host = room.host();
+        room.remove_member(member_id);
+        if let Some(game_id) = room.active_game_id() {
+            self.game_manager.remove_player(member_id, game_id).await?;
+        }
+        self.update_room(room).await?;
+
+        let kicked_event = RoomEvent::MemberKicked {
+            room_id: room.id(),
+            user_id: member_id,
+        };
+        self.notify_users(room.id(), std::iter::once(&member_id), &kicked_event)
+            .await;
+        self.notify_members(room, kicked_event).await;
+        if let Some(event) = Self::host_changed_event(room, previous_host) {
+            self.notify_members(room, event).await;
+        }
+        Ok(())
+    }
+
+    fn check_can_join<E>(room: &Room, user_id: Uuid) -> Result<(), E>
+    where
+        E: From<UserBannedFromRoomError> + From<RoomFullError>,
+    {
+        if room.is_banned(user_id) {
+            Err(UserBannedFromRoomError::new(user_id, room.id()).into())
+        } else if !room.is_member(user_id) && room.is_full() {
+            Err(RoomFullError(room.id()).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn has_expired(room: &Room, policy: &RoomExpiryPolicy, now: DateTime<Utc>) -> bool {
+        let ttl = if room.is_empty() {
+            policy.empty_room_ttl()
+        } else if Self::has_unfinished_game(room) {
+            policy.unfinished_game_ttl()
+        } else if !room.has_online_member() {
+            policy.empty_room_ttl()
+        } else {
+            policy.idle_room_ttl()
+        };
+        now - room.last_active_at() > ttl
+    }
+
+    /// Whether both seats of the room's game are filled and it has not been decided yet
+    fn has_unfinished_game(room: &Room) -> bool {
+        matches!(
+            room.game_lifecycle_state(),
+            GameLifecycleState::Ready | GameLifecycleState::InProgress
+        )
+    }
+
+    /// Finishes a game that is being replaced before it was played out as abandoned
+    async fn archive_unfinished_game(&self, room: &Room) {
+        if let Some(game_id) = room.active_game_id() {
+            if let Err(err) = self.game_manager.archive_game(game_id).await {
+                log::warn!(
+                    "Could not archive the replaced Game({}) in Room({}): {}",
+                    game_id,
+                    room.id(),
+                    err
+                );
+            }
         }
     }
 
+    /// Removes a room, returning whether an unfinished game had to be archived
+    async fn reap_room(&self, room: &Room) -> Result<bool, ReapRoomError> {
+        let archived_game = match room.active_game_id() {
+            Some(game_id) => self.game_manager.archive_game(game_id).await?.is_some(),
+            None => false,
+        };
+        self.room_repository.remove(room.id()).await?;
+
+        self.notify_members(room, RoomEvent::RoomClosed { room_id: room.id() })
+            .await;
+        Ok(archived_game)
+    }
+
+    async fn flag_game(&self, room_id: Uuid) -> Result<bool, FlagGameError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let mut room = self.room_repository.get(room_id).await?;
+        let game = match room.active_game_id() {
+            Some(game_id) => self.game_manager.flag_game(game_id).await?,
+            None => None,
+        };
+        let game = match game {
+            Some(game) => game,
+            None => return Ok(false),
+        };
+
+        room.transition_game_lifecycle(Self::game_lifecycle_state(&game))?;
+        let series_event = Self::record_series_game(&mut room, &game);
+        // A clock running out is not activity, so the room is stored without touching it
+        self.room_repository.update(&room).await?;
+        self.record_rated_game(&game).await;
+
+        if let Some(event) = series_event {
+            self.notify_members(&room, event).await;
+        }
+        Ok(true)
+    }
+
     fn user_is_in_room(user: &User, room: &Room) -> bool {
         room.is_member(user.id())
     }
+
+    fn game_lifecycle_state(game: &Game) -> GameLifecycleState {
+        if game.is_finished() {
+            GameLifecycleState::Finished
+        } else if game.moves().is_empty() {
+            GameLifecycleState::Ready
+        } else {
+            GameLifecycleState::InProgress
+        }
+    }
 }
 
 #[async_trait::async_trait]
-impl<UR, RR, GM> RoomManager for RoomManagerImpl<UR, RR, GM>
+impl<UR, RR, GM, UCP, C, RC> RoomManager for RoomManagerImpl<UR, RR, GM, UCP, C, RC>
 where
     UR: UserRepository + Send + Sync,
     RR: RoomRepository + Send + Sync,
     GM: GameManager + Send + Sync,
+    UCP: UserClientProvider + Send + Sync,
+    UCP::UserClient: Send + Sync,
+    C: Clock + Send + Sync,
+    RC: RatingCalculator + Send + Sync,
 {
     async fn join_room(&self, user_id: Uuid, room_id: Uuid) -> Result<(), JoinRoomError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
         let user = self.user_repository.get(user_id).await?;
-        if !self.room_repository.have_member(&user).await.is_empty() {
-            return Err(JoinRoomError::AlreadyAssigned);
-        }
         let mut room = self.room_repository.get(room_id).await?;
+        Self::check_can_join::<JoinRoomError>(&room, user.id())?;
         room.add_member(user.id());
-        self.room_repository.update(&room).await?;
+        self.update_room(&mut room).await?;
+        self.record_user_activity(user.id()).await;
         Ok(())
     }
 
-    async fn leave_room(&self, user_id: Uuid) -> Result<(), LeaveRoomError> {
+    async fn leave_room(&self, user_id: Uuid, room_id: Uuid) -> Result<(), LeaveRoomError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
         let user = self.user_repository.get(user_id).await?;
-        let mut rooms = self.room_repository.have_member(&user).await;
-        rooms
-            .iter_mut()
-            .for_each(|room| room.remove_member(user_id));
-        for room in &rooms {
-            if let Some(game_id) = room.active_game_id() {
-                self.game_manager.remove_player(user_id, game_id).await?;
-            }
-            self.room_repository.update(room).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+        if !Self::user_is_in_room(&user, &room) {
+            return Err(UserNotInRoomError::new(user_id, room_id).into());
+        }
+
+        let previous_host = room.host();
+        room.remove_member(user_id);
+        if let Some(game_id) = room.active_game_id() {
+            self.game_manager.remove_player(user_id, game_id).await?;
+        }
+        self.update_room(&mut room).await?;
+
+        if let Some(event) = Self::host_changed_event(&room, previous_host) {
+            self.notify_members(&room, event).await;
         }
         Ok(())
     }
 
+    async fn disconnect(&self, user_id: Uuid, room_id: Uuid) -> Result<(), LeaveRoomError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let mut room = self.room_repository.get(room_id).await?;
+        if !room.is_member(user_id) {
+            return Ok(());
+        }
+
+        room.mark_offline(user_id);
+        self.room_repository.update(&room).await?;
+        Ok(())
+    }
+
     async fn start_new_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), NewGameError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
         let user = self
             .user_repository
             .get(user_id)
@@ -100,51 +525,119 @@ where
 
         if !Self::user_is_in_room(&user, &room) {
             return Err(UserNotInRoomError::new(user_id, room_id).into());
+        } else if !room.is_host(user_id) {
+            return Err(NotRoomHostError::new(user_id, room_id).into());
+        } else if room.is_spectator(user_id) {
+            return Err(UserIsSpectatorError::new(user_id, room_id).into());
+        } else if room.game_lifecycle_state() == GameLifecycleState::InProgress {
+            return Err(GameInProgressError(room_id).into());
+        } else if room.series_is_finished() {
+            return Err(SeriesFinishedError(room_id).into());
+        } else if room.has_disallowed_spectators() {
+            return Err(SpectatorsNotAllowedError(room_id).into());
         }
 
-        let game = self.game_manager.start_new_game().await?;
-        room.set_active_game_id(game.id());
-        self.room_repository
-            .update(&room)
+        room.transition_game_lifecycle(GameLifecycleState::Ready)?;
+        self.archive_unfinished_game(&room).await;
+        let settings = room.settings();
+        let game = self
+            .game_manager
+            .start_new_game(
+                vec![],
+                None,
+                settings.default_variant(),
+                settings.time_control(),
+                settings.rated(),
+            )
+            .await?;
+        room.set_active_game(game.id(), vec![]);
+        self.update_room(&mut room)
             .await
             .map_err(NewGameError::from)?;
 
+        self.notify_members(&room, Self::game_started_event(room_id, &game))
+            .await;
         Ok(())
     }
 
+    async fn start_match(&self, room_id: Uuid, players: Vec<Uuid>) -> Result<Game, NewGameError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let mut room = self.room_repository.get(room_id).await?;
+        for player in players.iter() {
+            room.add_member(*player);
+        }
+
+        room.transition_game_lifecycle(GameLifecycleState::Ready)?;
+        let settings = room.settings();
+        let game = self
+            .game_manager
+            .start_new_game(
+                players.clone(),
+                players.first().copied(),
+                settings.default_variant(),
+                settings.time_control(),
+                settings.rated(),
+            )
+            .await?;
+        room.set_active_game(game.id(), players);
+        self.update_room(&mut room).await?;
+
+        self.notify_members(&room, Self::game_started_event(room_id, &game))
+            .await;
+        Ok(game)
+    }
+
     async fn make_game_move(
         &self,
         room_id: Uuid,
         game_move: GameMove,
     ) -> Result<(), GameMoveError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
         let user = self
             .user_repository
             .get(game_move.user_id())
             .await
             .map_err(GameMoveError::from)?;
-        let room = self.room_repository.get(room_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
 
         if !Self::user_is_in_room(&user, &room) {
             return Err(UserNotInRoomError::new(user.id(), room_id).into());
+        } else if room.is_spectator(user.id()) {
+            return Err(UserIsSpectatorError::new(user.id(), room_id).into());
         }
 
-        match room.active_game_id() {
-            Some(game_id) => {
-                self.game_manager
-                    .make_game_move(user.id(), game_id, game_move)
-                    .await
-            }
-            None => Err(NoActiveGameInRoomError(room_id).into()),
+        let game_id = room
+            .active_game_id()
+            .ok_or(NoActiveGameInRoomError(room_id))?;
+        let game = self
+            .game_manager
+            .make_game_move(user.id(), game_id, game_move)
+            .await?;
+        self.record_user_activity(user.id()).await;
+
+        room.transition_game_lifecycle(Self::game_lifecycle_state(&game))?;
+        let series_event = Self::record_series_game(&mut room, &game);
+        self.update_room(&mut room).await?;
+        self.record_rated_game(&game).await;
+
+        if let Some(event) = series_event {
+            self.notify_members(&room, event).await;
         }
+        if let Some(GameOutcome::WinOnTime(winner)) = game.outcome() {
+            let flagged = game.opponent_of(winner).unwrap_or_else(|| user.id());
+            return Err(PlayerOutOfTimeError(flagged).into());
+        }
+        Ok(())
     }
 
     async fn add_player(&self, room_id: Uuid, user_id: Uuid) -> Result<Option<()>, AddPlayerError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
         let user = self
             .user_repository
             .get(user_id)
             .await
             .map_err(AddPlayerError::from)?;
-        let room = self
+        let mut room = self
             .room_repository
             .get(room_id)
             .await
@@ -152,15 +645,409 @@ where
 
         if !Self::user_is_in_room(&user, &room) {
             return Err(UserNotInRoomError::new(user_id, room_id).into());
+        } else if room.is_spectator(user_id) {
+            return Err(UserIsSpectatorError::new(user_id, room_id).into());
         }
 
-        let game_id = self
-            .room_repository
-            .get(room_id)
-            .await?
-            .active_game_id
+        let game_id = room
+            .active_game_id()
+            .ok_or(NoActiveGameInRoomError(room_id))?;
+
+        let added = self.game_manager.add_player(game_id, user_id).await?;
+        room.seat_player(user_id);
+        self.update_room(&mut room).await?;
+        Ok(added)
+    }
+
+    async fn abandon_game(&self, room_id: Uuid, user_id: Uuid) -> Result<(), AbandonGameError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !Self::user_is_in_room(&user, &room) {
+            return Err(UserNotInRoomError::new(user_id, room_id).into());
+        }
+
+        let game_id = room
+            .active_game_id()
             .ok_or(NoActiveGameInRoomError(room_id))?;
+        let game = self.game_manager.abandon_game(user_id, game_id).await?;
+
+        room.transition_game_lifecycle(Self::game_lifecycle_state(&game))?;
+        let series_event = Self::record_series_game(&mut room, &game);
+        self.update_room(&mut room).await?;
+
+        if let Some(event) = series_event {
+            self.notify_members(&room, event).await;
+        }
+        Ok(())
+    }
+
+    async fn game_history(&self, room_id: Uuid) -> Result<Vec<Game>, GameHistoryError> {
+        let room = self.room_repository.get(room_id).await?;
+
+        let mut games = Vec::with_capacity(room.game_history().len());
+        for game_id in room.game_history() {
+            games.push(self.game_manager.get_game(*game_id).await?);
+        }
+        Ok(games)
+    }
+
+    async fn get_game(&self, room_id: Uuid, game_id: Uuid) -> Result<Game, GetRoomGameError> {
+        let room = self.room_repository.get(room_id).await?;
+
+        if !room.has_game(game_id) {
+            return Err(GameNotInRoomError::new(game_id, room_id).into());
+        }
+
+        Ok(self.game_manager.get_game(game_id).await?)
+    }
+
+    async fn request_rematch(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<Uuid>, RematchError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !Self::user_is_in_room(&user, &room) {
+            return Err(UserNotInRoomError::new(user_id, room_id).into());
+        }
+
+        let previous_game_id = match room.active_game_id() {
+            Some(game_id) if room.game_lifecycle_state() == GameLifecycleState::Finished => game_id,
+            _ => return Err(NoFinishedGameInRoomError(room_id).into()),
+        };
+        if room.series_is_finished() {
+            return Err(SeriesFinishedError(room_id).into());
+        }
+        let previous_game = self.game_manager.get_game(previous_game_id).await?;
+
+        if !previous_game.is_player(user_id) {
+            return Err(UserNotAPlayerInGameError(user_id).into());
+        }
+
+        room.request_rematch(user_id);
+        let all_players_agree = previous_game
+            .players()
+            .iter()
+            .all(|player| room.rematch_requests().contains(player));
+
+        if !all_players_agree {
+            self.update_room(&mut room).await?;
+            self.notify_members(&room, RoomEvent::RematchRequested { room_id, user_id })
+                .await;
+            return Ok(None);
+        }
+
+        let first_mover = previous_game
+            .opening_player()
+            .and_then(|opening_player| previous_game.opponent_of(opening_player));
+        room.transition_game_lifecycle(GameLifecycleState::Ready)?;
+        let settings = room.settings();
+        let game = self
+            .game_manager
+            .start_new_game(
+                previous_game.players().clone(),
+                first_mover,
+                settings.default_variant(),
+                settings.time_control(),
+                settings.rated(),
+            )
+            .await?;
+        room.set_active_game(game.id(), game.players().clone());
+        self.update_room(&mut room).await?;
+
+        self.notify_members(&room, Self::game_started_event(room_id, &game))
+            .await;
+        Ok(Some(game.id()))
+    }
+
+    async fn configure_series(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        best_of: u8,
+    ) -> Result<(), ConfigureSeriesError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !Self::user_is_in_room(&user, &room) {
+            return Err(UserNotInRoomError::new(user_id, room_id).into());
+        } else if !room.is_host(user_id) {
+            return Err(NotRoomHostError::new(user_id, room_id).into());
+        } else if room.game_lifecycle_state() == GameLifecycleState::InProgress {
+            return Err(GameInProgressError(room_id).into());
+        } else if room.series_is_in_progress() {
+            return Err(SeriesInProgressError(room_id).into());
+        } else if best_of == 0 {
+            return Err(InvalidSeriesLengthError.into());
+        }
+
+        room.set_series(Series::new(best_of, 0, vec![]));
+        self.update_room(&mut room).await?;
+
+        if let Some(event) = Self::series_updated_event(&room) {
+            self.notify_members(&room, event).await;
+        }
+        Ok(())
+    }
+
+    async fn series(&self, room_id: Uuid) -> Result<Series, GetSeriesError> {
+        let room = self.room_repository.get(room_id).await?;
+
+        room.series()
+            .cloned()
+            .ok_or_else(|| NoSeriesInRoomError(room_id).into())
+    }
+
+    async fn kick_member(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        member_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        Self::check_can_moderate(&room, user.id(), member_id)?;
+        if !room.is_member(member_id) {
+            return Err(UserNotInRoomError::new(member_id, room_id).into());
+        }
+
+        self.remove_member(&mut room, member_id).await
+    }
+
+    async fn ban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let banned_user = self.user_repository.get(banned_user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        Self::check_can_moderate(&room, user.id(), banned_user.id())?;
+
+        room.ban(banned_user.id());
+        if room.is_member(banned_user.id()) {
+            self.remove_member(&mut room, banned_user.id()).await
+        } else {
+            Ok(self.update_room(&mut room).await?)
+        }
+    }
 
-        self.game_manager.add_player(game_id, user_id).await
+    async fn unban_user(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        banned_user_id: Uuid,
+    ) -> Result<(), ModerateMemberError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        Self::check_can_moderate(&room, user.id(), banned_user_id)?;
+
+        room.unban(banned_user_id);
+        self.update_room(&mut room).await?;
+        Ok(())
+    }
+
+    async fn create_invite(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        single_use: bool,
+        expires_in: Option<Duration>,
+    ) -> Result<RoomInvite, CreateInviteError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !room.is_host(user.id()) {
+            return Err(NotRoomHostError::new(user_id, room_id).into());
+        }
+
+        let expires_at = expires_in.map(|expires_in| self.clock.now() + expires_in);
+        let invite = RoomInvite::generate(single_use, expires_at);
+        room.add_invite(invite.clone());
+        self.update_room(&mut room).await?;
+        Ok(invite)
+    }
+
+    async fn update_settings(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        update: RoomSettingsUpdate,
+    ) -> Result<RoomSettings, UpdateSettingsError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !room.is_host(user.id()) {
+            return Err(NotRoomHostError::new(user_id, room_id).into());
+        }
+
+        let settings = update.apply_to(room.settings());
+        settings.validate()?;
+        room.set_settings(settings);
+        self.update_room(&mut room).await?;
+
+        self.notify_members(&room, RoomEvent::SettingsUpdated { room_id, settings })
+            .await;
+        Ok(settings)
+    }
+
+    async fn change_role(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        role: RoomRole,
+    ) -> Result<(), ChangeRoleError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let user = self.user_repository.get(user_id).await?;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !Self::user_is_in_room(&user, &room) {
+            return Err(UserNotInRoomError::new(user_id, room_id).into());
+        } else if room.role(user_id) == role {
+            return Ok(());
+        } else if role == RoomRole::Spectator && !room.settings().spectators_allowed() {
+            return Err(SpectatorsNotAllowedError(room_id).into());
+        }
+
+        if role == RoomRole::Spectator && room.seated_players().contains(&user_id) {
+            if let Some(game_id) = room.active_game_id() {
+                self.game_manager.remove_player(user_id, game_id).await?;
+            }
+        }
+        room.set_role(user_id, role);
+        self.update_room(&mut room).await?;
+
+        self.notify_members(
+            &room,
+            RoomEvent::RoleChanged {
+                room_id,
+                user_id,
+                role,
+            },
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn admit(
+        &self,
+        room_id: Uuid,
+        user_id: Uuid,
+        invite_code: Option<String>,
+    ) -> Result<(), AdmissionError> {
+        let _room_lock = self.room_locks.lock(room_id).await;
+        let mut room = self.room_repository.get(room_id).await?;
+
+        if !room.is_private() || room.is_host(user_id) || room.is_member(user_id) {
+            return Ok(());
+        }
+
+        let invite_code = invite_code.ok_or(InviteRequiredError(room_id))?;
+        // A single-use invite must not be used up by someone who would then be turned away
+        Self::check_can_join::<AdmissionError>(&room, user_id)?;
+        let redeemed = room.redeem_invite(invite_code.as_str(), self.clock.now());
+        self.update_room(&mut room).await?;
+        if !redeemed {
+            return Err(InvalidInviteError(room_id).into());
+        }
+        Ok(())
+    }
+
+    async fn reap_rooms(&self, policy: RoomExpiryPolicy) -> ReapSummary {
+        let now = self.clock.now();
+        let cutoff = now - policy.shortest_ttl();
+        let mut summary = ReapSummary::default();
+
+        for room in self.room_repository.inactive_since(cutoff).await {
+            let _room_lock = self.room_locks.lock(room.id()).await;
+            // The room may have been used or removed while waiting for the lock
+            let room = match self.room_repository.get(room.id()).await {
+                Ok(room) if Self::has_expired(&room, &policy, now) => room,
+                _ => continue,
+            };
+            match self.reap_room(&room).await {
+                Ok(archived_game) => {
+                    self.room_locks.forget(room.id());
+                    log::info!(
+                        "Removed Room({}) after {} seconds without activity",
+                        room.id(),
+                        (now - room.last_active_at()).num_seconds()
+                    );
+                    summary.record_removed_room(&room, archived_game);
+                }
+                Err(err) => log::warn!("Failed to remove Room({}): {}", room.id(), err),
+            }
+        }
+        summary
+    }
+
+    async fn flag_games(&self) -> Vec<Uuid> {
+        let mut flagged = vec![];
+        for room in self.room_repository.with_game_in_progress().await {
+            match self.flag_game(room.id()).await {
+                Ok(true) => flagged.push(room.id()),
+                Ok(false) => {}
+                Err(err) => log::warn!("Failed to check clocks in Room({}): {}", room.id(), err),
+            }
+        }
+        flagged
+    }
+
+    async fn announce_rename(&self, user: &User) {
+        for room in self.room_repository.with_member(user.id()).await {
+            let event = RoomEvent::MemberRenamed {
+                room_id: room.id(),
+                user_id: user.id(),
+                name: user.name().to_string(),
+            };
+            self.notify_members(&room, event).await;
+        }
+    }
+
+    async fn player_stats(&self, user_id: Uuid) -> Result<PlayerStats, GetUserError> {
+        let user = self.user_repository.get(user_id).await?;
+        let games = self.game_manager.finished_games_of_player(user.id()).await;
+        Ok(PlayerStats::from_games(user.id(), &games))
+    }
+
+    async fn games_involving(&self, user_id: Uuid) -> Vec<Game> {
+        self.game_manager.games_involving(user_id).await
+    }
+
+    async fn anonymise_player(&self, user_id: Uuid) {
+        self.game_manager
+            .anonymise_player(user_id, Uuid::new_v4())
+            .await;
+    }
+
+    async fn expired_guests(&self, guest_ttl: Duration) -> Vec<User> {
+        let cutoff = self.clock.now() - guest_ttl;
+        let mut expired = vec![];
+        for guest in self.user_repository.guests_inactive_since(cutoff).await {
+            if self
+                .room_repository
+                .with_member(guest.id())
+                .await
+                .is_empty()
+            {
+                expired.push(guest);
+            }
+        }
+        expired
     }
 }
@@ -0,0 +1,43 @@
+use crate::domain::game::GameVariant;
+use crate::domain::room::Room;
+
+/// Criteria for finding public rooms, where unset criteria match every public room
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct RoomQuery {
+    open_seat: Option<bool>,
+    min_members: Option<usize>,
+    max_members: Option<usize>,
+    variant: Option<GameVariant>,
+}
+
+impl RoomQuery {
+    pub(crate) fn new(
+        open_seat: Option<bool>,
+        min_members: Option<usize>,
+        max_members: Option<usize>,
+        variant: Option<GameVariant>,
+    ) -> Self {
+        RoomQuery {
+            open_seat,
+            min_members,
+            max_members,
+            variant,
+        }
+    }
+
+    pub(crate) fn matches(&self, room: &Room) -> bool {
+        let member_count = room.members().len();
+
+        !room.is_private()
+            && self
+                .open_seat
+                .iter()
+                .all(|open_seat| room.has_open_seat() == *open_seat)
+            && self.min_members.iter().all(|min| member_count >= *min)
+            && self.max_members.iter().all(|max| member_count <= *max)
+            && self
+                .variant
+                .iter()
+                .all(|variant| room.settings().default_variant() == *variant)
+    }
+}
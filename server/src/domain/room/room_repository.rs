@@ -1,7 +1,8 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::room::Room;
-use crate::domain::user::User;
+use crate::domain::pagination::{Page, PageRequest};
+use crate::domain::room::{Room, RoomQuery};
 
 #[async_trait::async_trait]
 pub(crate) trait RoomRepository {
@@ -11,7 +12,19 @@ pub(crate) trait RoomRepository {
 
     async fn get(&self, room_id: Uuid) -> Result<Room, GetRoomError>;
 
-    async fn have_member(&self, user: &User) -> Vec<Room>;
+    async fn remove(&self, room_id: Uuid) -> Result<(), RemoveRoomError>;
+
+    /// Rooms that have had no activity since the cutoff
+    async fn inactive_since(&self, cutoff: DateTime<Utc>) -> Vec<Room>;
+
+    /// Rooms matching the query, newest first
+    async fn find(&self, query: &RoomQuery, page_request: PageRequest) -> Page<Room>;
+
+    /// Every room, public or private, that the user is a member of
+    async fn with_member(&self, user_id: Uuid) -> Vec<Room>;
+
+    /// Rooms whose active game has had its opening move and is not finished yet
+    async fn with_game_in_progress(&self) -> Vec<Room>;
 }
 
 #[derive(Debug, Copy, Clone, thiserror::Error)]
@@ -39,3 +52,9 @@ pub(crate) enum UpdateRoomError {
     #[error(transparent)]
     NotFound(#[from] RoomNotFoundError),
 }
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RemoveRoomError {
+    #[error(transparent)]
+    NotFound(#[from] RoomNotFoundError),
+}
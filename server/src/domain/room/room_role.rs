@@ -0,0 +1,7 @@
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum RoomRole {
+    /// Can start games and take a seat in them
+    Player,
+    /// Receives room events but cannot start games or make moves
+    Spectator,
+}
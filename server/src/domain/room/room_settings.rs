@@ -0,0 +1,128 @@
+use crate::domain::game::{GameVariant, TimeControl};
+use crate::domain::room::SEAT_COUNT;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct RoomSettings {
+    max_members: Option<usize>,
+    spectators_allowed: bool,
+    default_variant: GameVariant,
+    time_control: Option<TimeControl>,
+    rated: bool,
+}
+
+impl RoomSettings {
+    pub(crate) fn new(
+        max_members: Option<usize>,
+        spectators_allowed: bool,
+        default_variant: GameVariant,
+        time_control: Option<TimeControl>,
+        rated: bool,
+    ) -> Self {
+        RoomSettings {
+            max_members,
+            spectators_allowed,
+            default_variant,
+            time_control,
+            rated,
+        }
+    }
+
+    pub(crate) fn max_members(&self) -> Option<usize> {
+        self.max_members
+    }
+
+    pub(crate) fn spectators_allowed(&self) -> bool {
+        self.spectators_allowed
+    }
+
+    pub(crate) fn default_variant(&self) -> GameVariant {
+        self.default_variant
+    }
+
+    pub(crate) fn time_control(&self) -> Option<TimeControl> {
+        self.time_control
+    }
+
+    /// Whether finished games in the room count towards player ratings
+    pub(crate) fn rated(&self) -> bool {
+        self.rated
+    }
+
+    /// The most members the room can hold, taking into account whether spectators are allowed
+    pub(crate) fn capacity(&self) -> Option<usize> {
+        if self.spectators_allowed {
+            self.max_members
+        } else {
+            Some(
+                self.max_members
+                    .map_or(SEAT_COUNT, |max| max.min(SEAT_COUNT)),
+            )
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), InvalidRoomSettingsError> {
+        if self.max_members.iter().any(|max| *max < SEAT_COUNT) {
+            return Err(InvalidRoomSettingsError::MaxMembers(SEAT_COUNT));
+        } else if self
+            .time_control
+            .iter()
+            .any(|time_control| time_control.initial_seconds() == 0)
+        {
+            return Err(InvalidRoomSettingsError::TimeControl);
+        }
+        Ok(())
+    }
+}
+
+impl Default for RoomSettings {
+    fn default() -> Self {
+        RoomSettings::new(None, true, GameVariant::Standard, None, false)
+    }
+}
+
+/// Changes to room settings, where unset fields are left as they are
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct RoomSettingsUpdate {
+    max_members: Option<Option<usize>>,
+    spectators_allowed: Option<bool>,
+    default_variant: Option<GameVariant>,
+    time_control: Option<Option<TimeControl>>,
+    rated: Option<bool>,
+}
+
+impl RoomSettingsUpdate {
+    pub(crate) fn new(
+        max_members: Option<Option<usize>>,
+        spectators_allowed: Option<bool>,
+        default_variant: Option<GameVariant>,
+        time_control: Option<Option<TimeControl>>,
+        rated: Option<bool>,
+    ) -> Self {
+        RoomSettingsUpdate {
+            max_members,
+            spectators_allowed,
+            default_variant,
+            time_control,
+            rated,
+        }
+    }
+
+    pub(crate) fn apply_to(&self, settings: RoomSettings) -> RoomSettings {
+        RoomSettings::new(
+            self.max_members.unwrap_or(settings.max_members),
+            self.spectators_allowed
+                .unwrap_or(settings.spectators_allowed),
+            self.default_variant.unwrap_or(settings.default_variant),
+            self.time_control.unwrap_or(settings.time_control),
+            self.rated.unwrap_or(settings.rated),
+        )
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum InvalidRoomSettingsError {
+    #[error("max_members must be at least {0}")]
+    MaxMembers(usize),
+    #[error("A time control must give players at least one second")]
+    TimeControl,
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum RoomVisibility {
+    /// Listed publicly and open to anyone
+    Public,
+    /// Hidden from listings and only joinable with an invite
+    Private,
+}
@@ -0,0 +1,101 @@
+use uuid::Uuid;
+
+use crate::domain::game::GameOutcome;
+
+/// A best-of-N match played as consecutive games in a room
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Series {
+    best_of: u8,
+    games_played: u8,
+    scores: Vec<SeriesScore>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct SeriesScore {
+    user_id: Uuid,
+    wins: u8,
+}
+
+impl Series {
+    pub(crate) fn new(best_of: u8, games_played: u8, scores: Vec<SeriesScore>) -> Self {
+        Series {
+            best_of,
+            games_played,
+            scores,
+        }
+    }
+
+    pub(crate) fn best_of(&self) -> u8 {
+        self.best_of
+    }
+
+    pub(crate) fn games_played(&self) -> u8 {
+        self.games_played
+    }
+
+    /// Scores in the order players first appeared in the series
+    pub(crate) fn scores(&self) -> &Vec<SeriesScore> {
+        &self.scores
+    }
+
+    /// Number of wins needed to clinch the series
+    pub(crate) fn wins_needed(&self) -> u8 {
+        self.best_of / 2 + 1
+    }
+
+    pub(crate) fn winner(&self) -> Option<Uuid> {
+        self.scores
+            .iter()
+            .find(|score| score.wins >= self.wins_needed())
+            .map(SeriesScore::user_id)
+    }
+
+    /// The player with strictly more wins than anyone else, if there is one
+    pub(crate) fn leader(&self) -> Option<Uuid> {
+        let most_wins = self.scores.iter().map(SeriesScore::wins).max()?;
+        let mut leaders = self.scores.iter().filter(|score| score.wins == most_wins);
+        match (leaders.next(), leaders.next()) {
+            (Some(leader), None) => Some(leader.user_id),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.winner().is_some() || self.games_played >= self.best_of
+    }
+
+    /// Returns whether the game counted towards the series. Abandoned games are not counted and
+    /// games finished after the series was decided are ignored.
+    pub(crate) fn record_game(&mut self, players: &[Uuid], outcome: GameOutcome) -> bool {
+        if self.is_finished() || outcome == GameOutcome::Abandoned {
+            return false;
+        }
+
+        for player in players {
+            if !self.scores.iter().any(|score| score.user_id == *player) {
+                self.scores.push(SeriesScore::new(*player, 0));
+            }
+        }
+        if let Some(winner) = outcome.winner() {
+            if let Some(score) = self.scores.iter_mut().find(|score| score.user_id == winner) {
+                score.wins += 1;
+            }
+        }
+        self.games_played += 1;
+        true
+    }
+}
+
+impl SeriesScore {
+    pub(crate) fn new(user_id: Uuid, wins: u8) -> Self {
+        SeriesScore { user_id, wins }
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub(crate) fn wins(&self) -> u8 {
+        self.wins
+    }
+}
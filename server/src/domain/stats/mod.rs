@@ -0,0 +1,149 @@
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::game::{Game, GameOutcome};
+use crate::domain::rating::GameResult;
+
+/// Wins, losses and draws across a set of games
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ResultCounts {
+    wins: u32,
+    losses: u32,
+    draws: u32,
+}
+
+impl ResultCounts {
+    pub(crate) fn played(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    pub(crate) fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub(crate) fn losses(&self) -> u32 {
+        self.losses
+    }
+
+    pub(crate) fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    fn record(&mut self, result: GameResult) {
+        match result {
+            GameResult::Win => self.wins += 1,
+            GameResult::Loss => self.losses += 1,
+            GameResult::Draw => self.draws += 1,
+        }
+    }
+}
+
+/// A player's record over their finished games. Abandoned games have no result and games that
+/// never had two players were not really played, so both are left out.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PlayerStats {
+    overall: ResultCounts,
+    as_cross: ResultCounts,
+    as_nought: ResultCounts,
+    moving_first: ResultCounts,
+    moving_second: ResultCounts,
+    current_win_streak: u32,
+    best_win_streak: u32,
+    average_move_time: Option<Duration>,
+}
+
+impl PlayerStats {
+    /// Builds stats from the player's finished games, which must be ordered oldest first for the
+    /// streaks to be meaningful
+    pub(crate) fn from_games(user_id: Uuid, games: &[Game]) -> Self {
+        let mut stats = PlayerStats::default();
+        let mut total_move_time = Duration::zero();
+        let mut timed_moves = 0;
+
+        for game in games {
+            // A game that never had an opponent seated was not really played
+            if game.players().len() < 2 {
+                continue;
+            }
+            let result = match Self::result(user_id, game) {
+                Some(result) => result,
+                None => continue,
+            };
+            stats.overall.record(result);
+            if game.crosses() == Some(user_id) {
+                stats.as_cross.record(result);
+            } else {
+                stats.as_nought.record(result);
+            }
+            if game.opening_player() == Some(user_id) {
+                stats.moving_first.record(result);
+            } else {
+                stats.moving_second.record(result);
+            }
+
+            if result == GameResult::Win {
+                stats.current_win_streak += 1;
+                stats.best_win_streak = stats.best_win_streak.max(stats.current_win_streak);
+            } else {
+                stats.current_win_streak = 0;
+            }
+
+            for pair in game.moves().windows(2) {
+                if pair[1].user_id() == user_id && pair[0].user_id() != user_id {
+                    total_move_time = total_move_time + (pair[1].made_at() - pair[0].made_at());
+                    timed_moves += 1;
+                }
+            }
+        }
+
+        if timed_moves > 0 {
+            stats.average_move_time = Some(total_move_time / timed_moves);
+        }
+        stats
+    }
+
+    fn result(user_id: Uuid, game: &Game) -> Option<GameResult> {
+        match game.outcome()? {
+            GameOutcome::Abandoned => None,
+            outcome => match outcome.winner() {
+                Some(winner) if winner == user_id => Some(GameResult::Win),
+                Some(_) => Some(GameResult::Loss),
+                None => Some(GameResult::Draw),
+            },
+        }
+    }
+
+    pub(crate) fn overall(&self) -> ResultCounts {
+        self.overall
+    }
+
+    pub(crate) fn as_cross(&self) -> ResultCounts {
+        self.as_cross
+    }
+
+    pub(crate) fn as_nought(&self) -> ResultCounts {
+        self.as_nought
+    }
+
+    pub(crate) fn moving_first(&self) -> ResultCounts {
+        self.moving_first
+    }
+
+    pub(crate) fn moving_second(&self) -> ResultCounts {
+        self.moving_second
+    }
+
+    /// Consecutive wins up to and including the player's most recent game
+    pub(crate) fn current_win_streak(&self) -> u32 {
+        self.current_win_streak
+    }
+
+    pub(crate) fn best_win_streak(&self) -> u32 {
+        self.best_win_streak
+    }
+
+    /// Mean time the player took to reply to their opponent's move, if they have ever replied
+    pub(crate) fn average_move_time(&self) -> Option<Duration> {
+        self.average_move_time
+    }
+}
@@ -0,0 +1,72 @@
+use uuid::Uuid;
+
+use crate::domain::room::InvalidSeriesLengthError;
+use crate::domain::tournament::{
+    GetTournamentError, StoreTournamentError, TournamentNotFoundError,
+};
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Registration for Tournament({0}) has closed")]
+pub(crate) struct RegistrationClosedError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User({user_id}) is already registered for Tournament({tournament_id})")]
+pub(crate) struct AlreadyRegisteredError {
+    pub(crate) user_id: Uuid,
+    pub(crate) tournament_id: Uuid,
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User({user_id}) is not the organiser of Tournament({tournament_id})")]
+pub(crate) struct NotOrganiserError {
+    pub(crate) user_id: Uuid,
+    pub(crate) tournament_id: Uuid,
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Tournament({0}) has already started")]
+pub(crate) struct TournamentAlreadyStartedError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Tournament({0}) needs at least two participants to start")]
+pub(crate) struct NotEnoughParticipantsError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateTournamentError {
+    #[error(transparent)]
+    InvalidSeriesLength(#[from] InvalidSeriesLengthError),
+    #[error(transparent)]
+    Store(#[from] StoreTournamentError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RegisterForTournamentError {
+    #[error(transparent)]
+    TournamentNotFound(#[from] TournamentNotFoundError),
+    #[error(transparent)]
+    RegistrationClosed(#[from] RegistrationClosedError),
+    #[error(transparent)]
+    AlreadyRegistered(#[from] AlreadyRegisteredError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum StartTournamentError {
+    #[error(transparent)]
+    TournamentNotFound(#[from] TournamentNotFoundError),
+    #[error(transparent)]
+    NotOrganiser(#[from] NotOrganiserError),
+    #[error(transparent)]
+    AlreadyStarted(#[from] TournamentAlreadyStartedError),
+    #[error(transparent)]
+    NotEnoughParticipants(#[from] NotEnoughParticipantsError),
+}
+
+impl From<GetTournamentError> for StartTournamentError {
+    fn from(err: GetTournamentError) -> Self {
+        match err {
+            GetTournamentError::NotFound(tournament_not_found_error) => {
+                tournament_not_found_error.into()
+            }
+        }
+    }
+}
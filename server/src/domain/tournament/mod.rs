@@ -0,0 +1,379 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+pub(crate) use error::*;
+pub(crate) use standings::*;
+pub(crate) use tournament_manager::*;
+pub(crate) use tournament_repository::*;
+pub(crate) use tournament_round::*;
+pub(crate) use tournament_settings::*;
+
+mod error;
+mod round_robin;
+mod single_elimination;
+mod standings;
+mod swiss;
+mod tournament_manager;
+mod tournament_repository;
+mod tournament_round;
+mod tournament_settings;
+
+/// A competition that users register for before it starts and whose matches are played in rooms
+/// created by the server
+#[derive(Debug, Clone)]
+pub(crate) struct Tournament {
+    id: Uuid,
+    name: String,
+    organiser: Uuid,
+    settings: TournamentSettings,
+    status: TournamentStatus,
+    participants: Vec<Uuid>,
+    rounds: Vec<TournamentRound>,
+    champion: Option<Uuid>,
+    withdrawn: Vec<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum TournamentStatus {
+    Registration,
+    InProgress,
+    Finished,
+}
+
+impl Tournament {
+    pub(crate) fn new(
+        id: Uuid,
+        name: String,
+        organiser: Uuid,
+        settings: TournamentSettings,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Tournament {
+            id,
+            name,
+            organiser,
+            settings,
+            status: TournamentStatus::Registration,
+            participants: vec![],
+            rounds: vec![],
+            champion: None,
+            withdrawn: vec![],
+            created_at,
+        }
+    }
+
+    pub(crate) fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn organiser(&self) -> Uuid {
+        self.organiser
+    }
+
+    pub(crate) fn is_organiser(&self, user_id: Uuid) -> bool {
+        self.organiser == user_id
+    }
+
+    pub(crate) fn settings(&self) -> &TournamentSettings {
+        &self.settings
+    }
+
+    pub(crate) fn status(&self) -> TournamentStatus {
+        self.status
+    }
+
+    /// Participants in registration order until the tournament starts, then in seed order
+    pub(crate) fn participants(&self) -> &Vec<Uuid> {
+        &self.participants
+    }
+
+    pub(crate) fn is_registered(&self, user_id: Uuid) -> bool {
+        self.participants.contains(&user_id)
+    }
+
+    /// One-based seed of the participant, with the first seed being the strongest
+    pub(crate) fn seed_of(&self, user_id: Uuid) -> Option<usize> {
+        self.participants
+            .iter()
+            .position(|participant| *participant == user_id)
+            .map(|position| position + 1)
+    }
+
+    pub(crate) fn rounds(&self) -> &Vec<TournamentRound> {
+        &self.rounds
+    }
+
+    pub(crate) fn champion(&self) -> Option<Uuid> {
+        self.champion
+    }
+
+    pub(crate) fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub(crate) fn register(&mut self, user_id: Uuid) -> Result<(), RegisterForTournamentError> {
+        if self.status != TournamentStatus::Registration {
+            return Err(RegistrationClosedError(self.id).into());
+        } else if self.is_registered(user_id) {
+            return Err(AlreadyRegisteredError {
+                user_id,
+                tournament_id: self.id,
+            }
+            .into());
+        }
+        self.participants.push(user_id);
+        Ok(())
+    }
+
+    /// Takes the user out of the tournament. While registration is open they are simply dropped,
+    /// and afterwards they forfeit their remaining matches and are replaced in the records by the
+    /// stand-in.
+    pub(crate) fn withdraw(&mut self, user_id: Uuid, stand_in: Uuid) {
+        if self.organiser == user_id {
+            self.organiser = stand_in;
+        }
+        if self.status == TournamentStatus::Registration {
+            self.participants
+                .retain(|participant| *participant != user_id);
+            return;
+        } else if !self.is_registered(user_id) {
+            return;
+        }
+
+        let replace = |id: Uuid| if id == user_id { stand_in } else { id };
+        self.participants = self.participants.iter().copied().map(replace).collect();
+        self.champion = self.champion.map(replace);
+        for round in self.rounds.iter_mut() {
+            for tournament_match in round.matches_mut() {
+                tournament_match.replace_player(user_id, stand_in);
+            }
+        }
+        self.withdrawn.push(stand_in);
+        self.decide_forfeits();
+    }
+
+    /// Closes registration and draws the first round using the participants in seed order
+    pub(crate) fn start(&mut self, seeded: Vec<Uuid>) -> Result<(), StartTournamentError> {
+        if self.status != TournamentStatus::Registration {
+            return Err(TournamentAlreadyStartedError(self.id).into());
+        } else if seeded.len() < 2 {
+            return Err(NotEnoughParticipantsError(self.id).into());
+        }
+
+        self.participants = seeded;
+        self.status = TournamentStatus::InProgress;
+        match self.settings.format() {
+            TournamentFormat::SingleElimination => single_elimination::draw(self),
+            TournamentFormat::RoundRobin => round_robin::draw(self),
+            TournamentFormat::Swiss { .. } => swiss::draw(self),
+        }
+        Ok(())
+    }
+
+    /// Participants from first to last place on points scored so far
+    pub(crate) fn standings(&self) -> Vec<Standing> {
+        standings::standings(self)
+    }
+
+    /// The earliest round with matches still to be decided
+    pub(crate) fn current_round(&self) -> Option<usize> {
+        self.rounds.iter().position(|round| !round.is_complete())
+    }
+
+    /// Matches whose players are known but which have not been given a room yet. Knockout matches
+    /// are ready as soon as both players are through, whereas other formats play a round at a time.
+    pub(crate) fn ready_matches(&self) -> Vec<ReadyMatch> {
+        let current_round = self.current_round();
+        self.positions()
+            .filter_map(|position| {
+                let tournament_match = self.tournament_match(position)?;
+                let in_play = match self.settings.format() {
+                    TournamentFormat::SingleElimination => true,
+                    _ => current_round == Some(position.round()),
+                };
+                if !in_play || !tournament_match.is_ready() {
+                    return None;
+                }
+                Some(ReadyMatch::new(
+                    self.id,
+                    position,
+                    tournament_match.players(),
+                    self.settings.preferences(),
+                    self.rounds[position.round()].best_of(),
+                ))
+            })
+            .collect()
+    }
+
+    pub(crate) fn tournament_match(&self, position: MatchPosition) -> Option<&TournamentMatch> {
+        self.rounds
+            .get(position.round())
+            .and_then(|round| round.matches().get(position.index()))
+    }
+
+    pub(crate) fn position_of_room(&self, room_id: Uuid) -> Option<MatchPosition> {
+        self.positions().find(|position| {
+            self.tournament_match(*position)
+                .and_then(TournamentMatch::room_id)
+                == Some(room_id)
+        })
+    }
+
+    pub(crate) fn assign_room(
+        &mut self,
+        position: MatchPosition,
+        room_id: Uuid,
+        started_at: DateTime<Utc>,
+    ) {
+        if let Some(tournament_match) = self.tournament_match_mut(position) {
+            tournament_match.assign_room(room_id, started_at);
+        }
+    }
+
+    pub(crate) fn confirm_attendance(&mut self, position: MatchPosition) {
+        if let Some(tournament_match) = self.tournament_match_mut(position) {
+            tournament_match.confirm_attendance();
+        }
+    }
+
+    /// Rooms of matches whose players have had longer than the no-show timeout to turn up without
+    /// doing so
+    pub(crate) fn overdue_rooms(&self, now: DateTime<Utc>) -> Vec<Uuid> {
+        let timeout = self.settings.no_show_timeout();
+        self.positions()
+            .filter_map(|position| {
+                let tournament_match = self.tournament_match(position)?;
+                match (tournament_match.room_id(), tournament_match.started_at()) {
+                    (Some(room_id), Some(started_at))
+                        if tournament_match.awaits_attendance() && started_at + timeout <= now =>
+                    {
+                        Some(room_id)
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Decides an undecided match and moves the tournament on. A match without a winner counts as
+    /// a draw, except in a knockout where the higher seed goes through. Returns whether the match
+    /// was decided by this call.
+    pub(crate) fn decide_match(
+        &mut self,
+        position: MatchPosition,
+        winner: Option<Uuid>,
+        decision: MatchDecision,
+    ) -> bool {
+        let tournament_match = match self.tournament_match(position) {
+            Some(tournament_match) if !tournament_match.is_decided() => tournament_match,
+            _ => return false,
+        };
+        let winner = match self.settings.format() {
+            TournamentFormat::SingleElimination => {
+                winner.or_else(|| self.higher_seed(&tournament_match.players()))
+            }
+            _ => winner,
+        };
+
+        if let Some(tournament_match) = self.tournament_match_mut(position) {
+            tournament_match.decide(winner, decision);
+        }
+        match self.settings.format() {
+            TournamentFormat::SingleElimination => {
+                if let Some(winner) = winner {
+                    single_elimination::advance(self, position, winner);
+                }
+            }
+            TournamentFormat::RoundRobin if self.rounds[position.round()].is_complete() => {
+                round_robin::advance(self, position.round())
+            }
+            TournamentFormat::Swiss { .. } if self.rounds[position.round()].is_complete() => {
+                swiss::advance(self, position.round())
+            }
+            _ => {}
+        }
+        self.decide_forfeits();
+        true
+    }
+
+    /// Awards every undecided match against a withdrawn player to their opponent once the
+    /// opponent is known
+    fn decide_forfeits(&mut self) {
+        while let Some((position, winner)) = self.next_forfeit() {
+            self.decide_match(position, winner, MatchDecision::Withdrawal);
+        }
+    }
+
+    fn next_forfeit(&self) -> Option<(MatchPosition, Option<Uuid>)> {
+        if self.status != TournamentStatus::InProgress {
+            return None;
+        }
+        self.positions().find_map(|position| {
+            let tournament_match = self.tournament_match(position)?;
+            let players = tournament_match.players();
+            if tournament_match.is_decided()
+                || players.len() < 2
+                || !players.iter().any(|player| self.withdrawn.contains(player))
+            {
+                return None;
+            }
+            let winner = players
+                .into_iter()
+                .find(|player| !self.withdrawn.contains(player));
+            Some((position, winner))
+        })
+    }
+
+    /// Gives a bye to every player drawn without an opponent in the round
+    fn decide_byes(&mut self, round: usize) {
+        let byes: Vec<(MatchPosition, Uuid)> = self.rounds[round]
+            .matches()
+            .iter()
+            .enumerate()
+            .filter_map(
+                |(index, tournament_match)| match tournament_match.players()[..] {
+                    [player] => Some((MatchPosition::new(round, index), player)),
+                    _ => None,
+                },
+            )
+            .collect();
+        for (position, player) in byes {
+            self.decide_match(position, Some(player), MatchDecision::Bye);
+        }
+    }
+
+    fn finish_on_standings(&mut self) {
+        if let Some(leader) = self.standings().first() {
+            self.finish(leader.user_id());
+        }
+    }
+
+    fn higher_seed(&self, players: &[Uuid]) -> Option<Uuid> {
+        players
+            .iter()
+            .copied()
+            .min_by_key(|player| self.seed_of(*player).unwrap_or(usize::MAX))
+    }
+
+    fn finish(&mut self, champion: Uuid) {
+        self.champion = Some(champion);
+        self.status = TournamentStatus::Finished;
+    }
+
+    fn tournament_match_mut(&mut self, position: MatchPosition) -> Option<&mut TournamentMatch> {
+        self.rounds
+            .get_mut(position.round())
+            .and_then(|round| round.matches_mut().get_mut(position.index()))
+    }
+
+    fn positions(&self) -> impl Iterator<Item = MatchPosition> + '_ {
+        self.rounds.iter().enumerate().flat_map(|(round, matches)| {
+            (0..matches.matches().len()).map(move |index| MatchPosition::new(round, index))
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::domain::tournament::{Tournament, TournamentMatch, TournamentRound};
+
+/// Schedules every participant to meet every other once using the circle method. With an odd
+/// number of participants each of them sits out one round with a bye.
+pub(super) fn draw(tournament: &mut Tournament) {
+    let mut slots: Vec<Option<Uuid>> = tournament.participants.iter().copied().map(Some).collect();
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+
+    let slot_count = slots.len();
+    for round in 0..slot_count - 1 {
+        let matches = (0..slot_count / 2)
+            .map(|index| {
+                let (first, second) = (slots[index], slots[slot_count - 1 - index]);
+                if index == 0 && round % 2 == 1 {
+                    TournamentMatch::new([second, first])
+                } else {
+                    TournamentMatch::new([first, second])
+                }
+            })
+            .collect();
+        tournament.rounds.push(TournamentRound::new(
+            tournament.settings.best_of(round),
+            matches,
+        ));
+        slots[1..].rotate_right(1);
+    }
+    tournament.decide_byes(0);
+}
+
+/// Opens the next round once every match of the finished one is decided, or ends the tournament
+/// after the last round
+pub(super) fn advance(tournament: &mut Tournament, round: usize) {
+    if round + 1 < tournament.rounds.len() {
+        tournament.decide_byes(round + 1);
+    } else {
+        tournament.finish_on_standings();
+    }
+}
@@ -0,0 +1,58 @@
+use uuid::Uuid;
+
+use crate::domain::tournament::{MatchPosition, Tournament, TournamentMatch, TournamentRound};
+
+/// Builds the whole bracket, sized up to the next power of two. Seeds are placed so that the top
+/// seeds can only meet in the later rounds and the empty places, which go to the top seeds as
+/// byes, are resolved straight away.
+pub(super) fn draw(tournament: &mut Tournament) {
+    let bracket_size = tournament.participants.len().next_power_of_two();
+    let round_count = bracket_size.trailing_zeros() as usize;
+
+    let first_round = seed_order(bracket_size)
+        .chunks(2)
+        .map(|seeds| {
+            TournamentMatch::new([
+                tournament.participants.get(seeds[0] - 1).copied(),
+                tournament.participants.get(seeds[1] - 1).copied(),
+            ])
+        })
+        .collect();
+    tournament.rounds = vec![TournamentRound::new(
+        tournament.settings.best_of(0),
+        first_round,
+    )];
+    for round in 1..round_count {
+        let matches = vec![TournamentMatch::new([None, None]); bracket_size >> (round + 1)];
+        tournament.rounds.push(TournamentRound::new(
+            tournament.settings.best_of(round),
+            matches,
+        ));
+    }
+    tournament.decide_byes(0);
+}
+
+/// Moves the winner into their place in the next round, or crowns them after the final
+pub(super) fn advance(tournament: &mut Tournament, position: MatchPosition, winner: Uuid) {
+    let next_round = position.round() + 1;
+    match tournament.rounds.get_mut(next_round) {
+        Some(round) => {
+            round.matches_mut()[position.index() / 2].fill_slot(position.index() % 2, winner)
+        }
+        None => tournament.finish(winner),
+    }
+}
+
+/// Seeds in bracket order, so that consecutive pairs meet in the first round. Each pair of seeds
+/// adds up to one more than the bracket size.
+fn seed_order(bracket_size: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < bracket_size {
+        let size = order.len() * 2;
+        order = order
+            .into_iter()
+            .flat_map(|seed| vec![seed, size + 1 - seed])
+            .collect();
+    }
+    order
+}
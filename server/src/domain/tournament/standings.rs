@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::domain::tournament::{MatchDecision, Tournament};
+
+const WIN_POINTS: f64 = 1.0;
+const DRAW_POINTS: f64 = 0.5;
+
+/// A participant's place in a tournament by points, with ties broken by Buchholz, then
+/// Sonneborn-Berger, then seed
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Standing {
+    rank: usize,
+    user_id: Uuid,
+    points: f64,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+    buchholz: f64,
+    sonneborn_berger: f64,
+}
+
+impl Standing {
+    fn new(user_id: Uuid) -> Self {
+        Standing {
+            rank: 0,
+            user_id,
+            points: 0.0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            buchholz: 0.0,
+            sonneborn_berger: 0.0,
+        }
+    }
+
+    pub(crate) fn rank(&self) -> usize {
+        self.rank
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    /// One point per win or bye and half a point per draw
+    pub(crate) fn points(&self) -> f64 {
+        self.points
+    }
+
+    pub(crate) fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub(crate) fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    pub(crate) fn losses(&self) -> u32 {
+        self.losses
+    }
+
+    /// Sum of the points of every opponent faced
+    pub(crate) fn buchholz(&self) -> f64 {
+        self.buchholz
+    }
+
+    /// Sum of the points of every opponent beaten plus half the points of every opponent drawn with
+    pub(crate) fn sonneborn_berger(&self) -> f64 {
+        self.sonneborn_berger
+    }
+}
+
+/// Outcome of a decided match from one player's point of view
+#[derive(Copy, Clone)]
+enum PlayerResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+pub(super) fn standings(tournament: &Tournament) -> Vec<Standing> {
+    let mut standings: HashMap<Uuid, Standing> = tournament
+        .participants()
+        .iter()
+        .map(|participant| (*participant, Standing::new(*participant)))
+        .collect();
+    let mut results: HashMap<Uuid, Vec<(Uuid, PlayerResult)>> = HashMap::new();
+
+    let decided = tournament
+        .rounds()
+        .iter()
+        .flat_map(|round| round.matches())
+        .filter(|tournament_match| tournament_match.is_decided());
+    for tournament_match in decided {
+        let players = tournament_match.players();
+        if tournament_match.decision() == Some(MatchDecision::Bye) {
+            for player in players {
+                if let Some(standing) = standings.get_mut(&player) {
+                    standing.points += WIN_POINTS;
+                }
+            }
+            continue;
+        }
+
+        for player in players.iter() {
+            let opponent = match tournament_match.opponent_of(*player) {
+                Some(opponent) => opponent,
+                None => continue,
+            };
+            let result = match tournament_match.winner() {
+                Some(winner) if winner == *player => PlayerResult::Win,
+                Some(_) => PlayerResult::Loss,
+                None => PlayerResult::Draw,
+            };
+            if let Some(standing) = standings.get_mut(player) {
+                match result {
+                    PlayerResult::Win => {
+                        standing.points += WIN_POINTS;
+                        standing.wins += 1;
+                    }
+                    PlayerResult::Draw => {
+                        standing.points += DRAW_POINTS;
+                        standing.draws += 1;
+                    }
+                    PlayerResult::Loss => standing.losses += 1,
+                }
+            }
+            results.entry(*player).or_default().push((opponent, result));
+        }
+    }
+
+    let points: HashMap<Uuid, f64> = standings
+        .iter()
+        .map(|(user_id, standing)| (*user_id, standing.points))
+        .collect();
+    for (user_id, standing) in standings.iter_mut() {
+        for (opponent, result) in results.get(user_id).into_iter().flatten() {
+            let opponent_points = points.get(opponent).copied().unwrap_or_default();
+            standing.buchholz += opponent_points;
+            standing.sonneborn_berger += match result {
+                PlayerResult::Win => opponent_points,
+                PlayerResult::Draw => opponent_points * DRAW_POINTS,
+                PlayerResult::Loss => 0.0,
+            };
+        }
+    }
+
+    let mut standings: Vec<Standing> = standings.into_values().collect();
+    standings.sort_by(|a, b| {
+        descending(a.points, b.points)
+            .then_with(|| descending(a.buchholz, b.buchholz))
+            .then_with(|| descending(a.sonneborn_berger, b.sonneborn_berger))
+            .then_with(|| {
+                tournament
+                    .seed_of(a.user_id)
+                    .cmp(&tournament.seed_of(b.user_id))
+            })
+    });
+    for (position, standing) in standings.iter_mut().enumerate() {
+        standing.rank = position + 1;
+    }
+    standings
+}
+
+fn descending(a: f64, b: f64) -> Ordering {
+    b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+}
@@ -0,0 +1,96 @@
+use uuid::Uuid;
+
+use crate::domain::tournament::{
+    MatchDecision, Tournament, TournamentFormat, TournamentMatch, TournamentRound,
+};
+
+/// Pairs the first round, in which everyone is level so players meet in seed order
+pub(super) fn draw(tournament: &mut Tournament) {
+    pair_next_round(tournament);
+}
+
+/// Pairs the next round once every match of the finished one is decided, or ends the tournament
+/// after the last round
+pub(super) fn advance(tournament: &mut Tournament, round: usize) {
+    if round + 1 < round_count(tournament) {
+        pair_next_round(tournament);
+    } else {
+        tournament.finish_on_standings();
+    }
+}
+
+/// The configured number of rounds, or enough rounds for a single player to be able to win
+/// every match when none is configured
+fn round_count(tournament: &Tournament) -> usize {
+    match tournament.settings.format() {
+        TournamentFormat::Swiss {
+            rounds: Some(rounds),
+        } => rounds,
+        _ => tournament
+            .participants
+            .len()
+            .next_power_of_two()
+            .trailing_zeros() as usize,
+    }
+    .max(1)
+}
+
+/// Pairs each player, from the top of the standings down, with the next player below them they
+/// have not met yet. With an odd number of players the lowest placed one who has not had a bye
+/// gets one.
+fn pair_next_round(tournament: &mut Tournament) {
+    let mut waiting: Vec<Uuid> = tournament
+        .standings()
+        .iter()
+        .map(|standing| standing.user_id())
+        .collect();
+    let bye = if waiting.len() % 2 == 1 {
+        let index = waiting
+            .iter()
+            .rposition(|player| !had_bye(tournament, *player))
+            .unwrap_or(waiting.len() - 1);
+        Some(waiting.remove(index))
+    } else {
+        None
+    };
+
+    let mut matches = Vec::with_capacity(waiting.len() / 2 + 1);
+    while !waiting.is_empty() {
+        let player = waiting.remove(0);
+        let opponent_index = waiting
+            .iter()
+            .position(|opponent| !have_met(tournament, player, *opponent))
+            .unwrap_or(0);
+        let opponent = waiting.remove(opponent_index);
+        matches.push(TournamentMatch::new([Some(player), Some(opponent)]));
+    }
+    if let Some(player) = bye {
+        matches.push(TournamentMatch::new([Some(player), None]));
+    }
+
+    let round = tournament.rounds.len();
+    tournament.rounds.push(TournamentRound::new(
+        tournament.settings.best_of(round),
+        matches,
+    ));
+    tournament.decide_byes(round);
+}
+
+fn had_bye(tournament: &Tournament, player: Uuid) -> bool {
+    tournament
+        .rounds
+        .iter()
+        .flat_map(|round| round.matches())
+        .any(|tournament_match| {
+            tournament_match.decision() == Some(MatchDecision::Bye)
+                && tournament_match.players().contains(&player)
+        })
+}
+
+fn have_met(tournament: &Tournament, player: Uuid, opponent: Uuid) -> bool {
+    tournament
+        .rounds
+        .iter()
+        .flat_map(|round| round.matches())
+        .any(|tournament_match| tournament_match.opponent_of(player) == Some(opponent))
+}
@@ -0,0 +1,317 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::clock::Clock;
+use crate::domain::tournament::{
+    CreateTournamentError, GetTournamentError, MatchDecision, MatchPosition, NotOrganiserError,
+    RegisterForTournamentError, StartTournamentError, Tournament, TournamentRepository,
+    TournamentSettings, TournamentStatus, UpdateTournamentError,
+};
+use crate::domain::user::UserRepository;
+
+#[async_trait::async_trait]
+pub(crate) trait TournamentManager {
+    async fn create(
+        &self,
+        organiser: Uuid,
+        name: String,
+        settings: TournamentSettings,
+    ) -> Result<Tournament, CreateTournamentError>;
+
+    async fn get(&self, tournament_id: Uuid) -> Result<Tournament, GetTournamentError>;
+
+    async fn register(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, RegisterForTournamentError>;
+
+    /// Seeds the participants by their rating for the tournament's variant and draws the first
+    /// round
+    async fn start(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, StartTournamentError>;
+
+    async fn assign_room(
+        &self,
+        tournament_id: Uuid,
+        position: MatchPosition,
+        room_id: Uuid,
+    ) -> Result<(), UpdateTournamentError>;
+
+    /// Decides the match played in the room, returning the updated tournament if the room belongs
+    /// to one and its match was still undecided
+    async fn record_result(&self, room_id: Uuid, winner: Option<Uuid>) -> Option<Tournament>;
+
+    /// Players drawn into the match played in the room, empty if the room holds no tournament
+    /// match
+    async fn match_players(&self, room_id: Uuid) -> Vec<Uuid>;
+
+    /// Marks both players of the match in the room as having turned up
+    async fn confirm_attendance(&self, room_id: Uuid);
+
+    /// Awards the match in the room to the opponent of the player who did not turn up
+    async fn record_no_show(&self, room_id: Uuid, absentee: Uuid) -> Option<Tournament>;
+
+    /// Rooms of tournament matches whose players have not turned up in time
+    async fn overdue_rooms(&self) -> Vec<Uuid>;
+
+    /// Tournaments the user organises or has entered, oldest first
+    async fn of_user(&self, user_id: Uuid) -> Vec<Tournament>;
+
+    /// Takes the user out of every tournament they organise or have entered, attributing what
+    /// they did to a newly generated id. Returns the tournaments still in progress.
+    async fn withdraw(&self, user_id: Uuid) -> Vec<Tournament>;
+}
+
+pub(crate) struct TournamentManagerImpl<TR: TournamentRepository, UR: UserRepository, C: Clock> {
+    tournament_repository: TR,
+    user_repository: Arc<UR>,
+    clock: C,
+}
+
+impl<TR, UR, C> TournamentManagerImpl<TR, UR, C>
+where
+    TR: TournamentRepository + Send + Sync,
+    UR: UserRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    pub(crate) fn new(tournament_repository: TR, user_repository: Arc<UR>, clock: C) -> Self {
+        TournamentManagerImpl {
+            tournament_repository,
+            user_repository,
+            clock,
+        }
+    }
+
+    /// Ratings of the participants for the tournament's variant
+    async fn ratings(&self, tournament: &Tournament) -> HashMap<Uuid, f64> {
+        let variant = tournament.settings().preferences().variant();
+        let mut ratings = HashMap::with_capacity(tournament.participants().len());
+        for participant in tournament.participants() {
+            if let Ok(user) = self.user_repository.get(*participant).await {
+                ratings.insert(*participant, user.rating(variant).value());
+            }
+        }
+        ratings
+    }
+
+    /// Participants ordered from highest to lowest rating, keeping registration order for equal
+    /// ratings and putting those without a rating last
+    fn seeded(participants: &[Uuid], ratings: &HashMap<Uuid, f64>) -> Vec<Uuid> {
+        let rating_of = |participant: &Uuid| ratings.get(participant).copied().unwrap_or(f64::MIN);
+        let mut seeded = participants.to_vec();
+        seeded.sort_by(|a, b| {
+            rating_of(b)
+                .partial_cmp(&rating_of(a))
+                .unwrap_or(Ordering::Equal)
+        });
+        seeded
+    }
+
+    /// Decides the match played in the room, picking its winner from the tournament as it stands
+    /// when no other change to it can be made
+    async fn decide_room_match<W>(
+        &self,
+        room_id: Uuid,
+        winner: W,
+        decision: MatchDecision,
+    ) -> Option<Tournament>
+    where
+        W: FnOnce(&Tournament, MatchPosition) -> Option<Uuid> + Send,
+    {
+        let tournament_id = self.tournament_repository.by_room(room_id).await?.id();
+        let decided = self
+            .tournament_repository
+            .update_with(tournament_id, |tournament| {
+                let decided = match tournament.position_of_room(room_id) {
+                    Some(position) => {
+                        let winner = winner(tournament, position);
+                        tournament.decide_match(position, winner, decision)
+                    }
+                    None => false,
+                };
+                Ok::<_, UpdateTournamentError>(decided)
+            })
+            .await;
+        match decided {
+            Ok((tournament, true)) => Some(tournament),
+            Ok((_, false)) => None,
+            Err(err) => {
+                log::warn!("Could not record the result of Room({}): {}", room_id, err);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<TR, UR, C> TournamentManager for TournamentManagerImpl<TR, UR, C>
+where
+    TR: TournamentRepository + Send + Sync,
+    UR: UserRepository + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    async fn create(
+        &self,
+        organiser: Uuid,
+        name: String,
+        settings: TournamentSettings,
+    ) -> Result<Tournament, CreateTournamentError> {
+        settings.validate()?;
+        let tournament =
+            Tournament::new(Uuid::new_v4(), name, organiser, settings, self.clock.now());
+        self.tournament_repository.store(&tournament).await?;
+        Ok(tournament)
+    }
+
+    async fn get(&self, tournament_id: Uuid) -> Result<Tournament, GetTournamentError> {
+        self.tournament_repository.get(tournament_id).await
+    }
+
+    async fn register(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, RegisterForTournamentError> {
+        let (tournament, ()) = self
+            .tournament_repository
+            .update_with(tournament_id, |tournament| tournament.register(user_id))
+            .await?;
+        Ok(tournament)
+    }
+
+    async fn start(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Tournament, StartTournamentError> {
+        let registered = self.tournament_repository.get(tournament_id).await?;
+        let ratings = self.ratings(&registered).await;
+        let (tournament, ()) = self
+            .tournament_repository
+            .update_with(tournament_id, |tournament| {
+                if !tournament.is_organiser(user_id) {
+                    return Err(NotOrganiserError {
+                        user_id,
+                        tournament_id,
+                    }
+                    .into());
+                }
+                let seeded = Self::seeded(tournament.participants(), &ratings);
+                tournament.start(seeded)
+            })
+            .await?;
+        Ok(tournament)
+    }
+
+    async fn assign_room(
+        &self,
+        tournament_id: Uuid,
+        position: MatchPosition,
+        room_id: Uuid,
+    ) -> Result<(), UpdateTournamentError> {
+        let started_at = self.clock.now();
+        self.tournament_repository
+            .update_with(tournament_id, |tournament| {
+                tournament.assign_room(position, room_id, started_at);
+                Ok::<_, UpdateTournamentError>(())
+            })
+            .await
+            .map(|_| ())
+    }
+
+    async fn record_result(&self, room_id: Uuid, winner: Option<Uuid>) -> Option<Tournament> {
+        self.decide_room_match(room_id, |_, _| winner, MatchDecision::Played)
+            .await
+    }
+
+    async fn match_players(&self, room_id: Uuid) -> Vec<Uuid> {
+        let tournament = match self.tournament_repository.by_room(room_id).await {
+            Some(tournament) => tournament,
+            None => return vec![],
+        };
+        tournament
+            .position_of_room(room_id)
+            .and_then(|position| tournament.tournament_match(position))
+            .map(|tournament_match| tournament_match.players())
+            .unwrap_or_default()
+    }
+
+    async fn confirm_attendance(&self, room_id: Uuid) {
+        let tournament_id = match self.tournament_repository.by_room(room_id).await {
+            Some(tournament) => tournament.id(),
+            None => return,
+        };
+        let confirmed = self
+            .tournament_repository
+            .update_with(tournament_id, |tournament| {
+                if let Some(position) = tournament.position_of_room(room_id) {
+                    tournament.confirm_attendance(position);
+                }
+                Ok::<_, UpdateTournamentError>(())
+            })
+            .await;
+        if let Err(err) = confirmed {
+            log::warn!("Could not confirm attendance in Room({}): {}", room_id, err);
+        }
+    }
+
+    async fn record_no_show(&self, room_id: Uuid, absentee: Uuid) -> Option<Tournament> {
+        let opponent = |tournament: &Tournament, position| {
+            tournament
+                .tournament_match(position)?
+                .players()
+                .into_iter()
+                .find(|player| *player != absentee)
+        };
+        self.decide_room_match(room_id, opponent, MatchDecision::NoShow)
+            .await
+    }
+
+    async fn overdue_rooms(&self) -> Vec<Uuid> {
+        let now = self.clock.now();
+        self.tournament_repository
+            .in_progress()
+            .await
+            .iter()
+            .flat_map(|tournament| tournament.overdue_rooms(now))
+            .collect()
+    }
+
+    async fn of_user(&self, user_id: Uuid) -> Vec<Tournament> {
+        self.tournament_repository.of_user(user_id).await
+    }
+
+    async fn withdraw(&self, user_id: Uuid) -> Vec<Tournament> {
+        let stand_in = Uuid::new_v4();
+        let mut in_progress = vec![];
+        for entered in self.tournament_repository.of_user(user_id).await {
+            let withdrawn = self
+                .tournament_repository
+                .update_with(entered.id(), |tournament| {
+                    tournament.withdraw(user_id, stand_in);
+                    Ok::<_, UpdateTournamentError>(())
+                })
+                .await;
+            match withdrawn {
+                Ok((tournament, ())) if tournament.status() == TournamentStatus::InProgress => {
+                    in_progress.push(tournament)
+                }
+                Ok(_) => {}
+                Err(err) => log::warn!(
+                    "Could not withdraw User({}) from Tournament({}): {}",
+                    user_id,
+                    entered.id(),
+                    err
+                ),
+            }
+        }
+        in_progress
+    }
+}
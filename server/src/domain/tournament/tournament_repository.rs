@@ -0,0 +1,57 @@
+use uuid::Uuid;
+
+use crate::domain::tournament::Tournament;
+
+#[async_trait::async_trait]
+pub(crate) trait TournamentRepository {
+    async fn store(&self, tournament: &Tournament) -> Result<(), StoreTournamentError>;
+
+    /// Applies a change to the stored tournament while no other change to it can be made, storing
+    /// and returning the changed tournament unless the change fails
+    async fn update_with<T, E, F>(
+        &self,
+        tournament_id: Uuid,
+        change: F,
+    ) -> Result<(Tournament, T), E>
+    where
+        T: Send,
+        E: From<TournamentNotFoundError> + Send,
+        F: FnOnce(&mut Tournament) -> Result<T, E> + Send;
+
+    async fn get(&self, tournament_id: Uuid) -> Result<Tournament, GetTournamentError>;
+
+    /// The tournament with a match being played in the room, if there is one
+    async fn by_room(&self, room_id: Uuid) -> Option<Tournament>;
+
+    /// Tournaments that have started and not yet finished, oldest first
+    async fn in_progress(&self) -> Vec<Tournament>;
+
+    /// Tournaments the user organises or has entered, oldest first
+    async fn of_user(&self, user_id: Uuid) -> Vec<Tournament>;
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+pub(crate) enum GetTournamentError {
+    #[error(transparent)]
+    NotFound(#[from] TournamentNotFoundError),
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("Could not find tournament with id: {0}")]
+pub(crate) struct TournamentNotFoundError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum StoreTournamentError {
+    #[error(transparent)]
+    AlreadyExists(#[from] TournamentWithIdAlreadyExists),
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("A tournament with id {0} already exists in the repository")]
+pub(crate) struct TournamentWithIdAlreadyExists(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+pub(crate) enum UpdateTournamentError {
+    #[error(transparent)]
+    NotFound(#[from] TournamentNotFoundError),
+}
@@ -0,0 +1,202 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::matchmaking::MatchPreferences;
+
+#[derive(Debug, Clone)]
+pub(crate) struct TournamentRound {
+    best_of: u8,
+    matches: Vec<TournamentMatch>,
+}
+
+/// A pairing within a round. A slot stays empty until the player who fills it is known.
+#[derive(Debug, Clone)]
+pub(crate) struct TournamentMatch {
+    slots: [Option<Uuid>; 2],
+    room_id: Option<Uuid>,
+    started_at: Option<DateTime<Utc>>,
+    attended: bool,
+    winner: Option<Uuid>,
+    decision: Option<MatchDecision>,
+}
+
+/// How the winner of a tournament match was decided
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum MatchDecision {
+    Played,
+    Bye,
+    NoShow,
+    Withdrawal,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct MatchPosition {
+    round: usize,
+    index: usize,
+}
+
+/// A tournament match that is waiting for a room to be played in
+#[derive(Debug, Clone)]
+pub(crate) struct ReadyMatch {
+    tournament_id: Uuid,
+    position: MatchPosition,
+    players: Vec<Uuid>,
+    preferences: MatchPreferences,
+    best_of: u8,
+}
+
+impl TournamentRound {
+    pub(crate) fn new(best_of: u8, matches: Vec<TournamentMatch>) -> Self {
+        TournamentRound { best_of, matches }
+    }
+
+    pub(crate) fn best_of(&self) -> u8 {
+        self.best_of
+    }
+
+    pub(crate) fn matches(&self) -> &Vec<TournamentMatch> {
+        &self.matches
+    }
+
+    pub(crate) fn matches_mut(&mut self) -> &mut Vec<TournamentMatch> {
+        &mut self.matches
+    }
+
+    pub(crate) fn is_complete(&self) -> bool {
+        self.matches.iter().all(TournamentMatch::is_decided)
+    }
+}
+
+impl TournamentMatch {
+    pub(crate) fn new(slots: [Option<Uuid>; 2]) -> Self {
+        TournamentMatch {
+            slots,
+            room_id: None,
+            started_at: None,
+            attended: false,
+            winner: None,
+            decision: None,
+        }
+    }
+
+    pub(crate) fn slots(&self) -> [Option<Uuid>; 2] {
+        self.slots
+    }
+
+    pub(crate) fn fill_slot(&mut self, slot: usize, user_id: Uuid) {
+        self.slots[slot] = Some(user_id);
+    }
+
+    /// The players known so far, in slot order
+    pub(crate) fn players(&self) -> Vec<Uuid> {
+        self.slots.iter().flatten().copied().collect()
+    }
+
+    pub(crate) fn room_id(&self) -> Option<Uuid> {
+        self.room_id
+    }
+
+    pub(crate) fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.started_at
+    }
+
+    /// The player who won the match, or nobody if it was drawn or is undecided
+    pub(crate) fn winner(&self) -> Option<Uuid> {
+        self.winner
+    }
+
+    pub(crate) fn decision(&self) -> Option<MatchDecision> {
+        self.decision
+    }
+
+    pub(crate) fn is_decided(&self) -> bool {
+        self.decision.is_some()
+    }
+
+    pub(crate) fn is_ready(&self) -> bool {
+        self.players().len() == 2 && self.room_id.is_none() && !self.is_decided()
+    }
+
+    pub(crate) fn awaits_attendance(&self) -> bool {
+        self.room_id.is_some() && !self.attended && !self.is_decided()
+    }
+
+    pub(crate) fn opponent_of(&self, user_id: Uuid) -> Option<Uuid> {
+        if !self.slots.contains(&Some(user_id)) {
+            return None;
+        }
+        self.players().into_iter().find(|player| *player != user_id)
+    }
+
+    pub(crate) fn assign_room(&mut self, room_id: Uuid, started_at: DateTime<Utc>) {
+        self.room_id = Some(room_id);
+        self.started_at = Some(started_at);
+    }
+
+    pub(crate) fn confirm_attendance(&mut self) {
+        self.attended = true;
+    }
+
+    pub(crate) fn decide(&mut self, winner: Option<Uuid>, decision: MatchDecision) {
+        self.winner = winner;
+        self.decision = Some(decision);
+    }
+
+    pub(crate) fn replace_player(&mut self, user_id: Uuid, stand_in: Uuid) {
+        let replace = |id: Uuid| if id == user_id { stand_in } else { id };
+        self.slots = [self.slots[0].map(replace), self.slots[1].map(replace)];
+        self.winner = self.winner.map(replace);
+    }
+}
+
+impl MatchPosition {
+    pub(crate) fn new(round: usize, index: usize) -> Self {
+        MatchPosition { round, index }
+    }
+
+    pub(crate) fn round(&self) -> usize {
+        self.round
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl ReadyMatch {
+    pub(crate) fn new(
+        tournament_id: Uuid,
+        position: MatchPosition,
+        players: Vec<Uuid>,
+        preferences: MatchPreferences,
+        best_of: u8,
+    ) -> Self {
+        ReadyMatch {
+            tournament_id,
+            position,
+            players,
+            preferences,
+            best_of,
+        }
+    }
+
+    pub(crate) fn tournament_id(&self) -> Uuid {
+        self.tournament_id
+    }
+
+    pub(crate) fn position(&self) -> MatchPosition {
+        self.position
+    }
+
+    pub(crate) fn players(&self) -> Vec<Uuid> {
+        self.players.clone()
+    }
+
+    pub(crate) fn preferences(&self) -> MatchPreferences {
+        self.preferences
+    }
+
+    pub(crate) fn best_of(&self) -> u8 {
+        self.best_of
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::Duration;
+
+use crate::domain::matchmaking::MatchPreferences;
+use crate::domain::room::InvalidSeriesLengthError;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub(crate) enum TournamentFormat {
+    #[default]
+    SingleElimination,
+    RoundRobin,
+    /// Players meet others on the same score each round, for a fixed number of rounds
+    Swiss {
+        rounds: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct TournamentSettings {
+    format: TournamentFormat,
+    preferences: MatchPreferences,
+    best_of_by_round: Vec<u8>,
+    no_show_timeout: Duration,
+}
+
+impl TournamentSettings {
+    pub(crate) fn new(
+        format: TournamentFormat,
+        preferences: MatchPreferences,
+        best_of_by_round: Vec<u8>,
+        no_show_timeout: Duration,
+    ) -> Self {
+        TournamentSettings {
+            format,
+            preferences,
+            best_of_by_round,
+            no_show_timeout,
+        }
+    }
+
+    pub(crate) fn format(&self) -> TournamentFormat {
+        self.format
+    }
+
+    pub(crate) fn preferences(&self) -> MatchPreferences {
+        self.preferences
+    }
+
+    /// Match length for the zero-based round. Rounds past the end of the configured lengths use the
+    /// last one, and a single game is played when none are configured.
+    pub(crate) fn best_of(&self, round: usize) -> u8 {
+        self.best_of_by_round
+            .get(round)
+            .or_else(|| self.best_of_by_round.last())
+            .copied()
+            .unwrap_or(1)
+    }
+
+    /// How long the players of a match have to turn up once its room is ready
+    pub(crate) fn no_show_timeout(&self) -> Duration {
+        self.no_show_timeout
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), InvalidSeriesLengthError> {
+        if self.best_of_by_round.contains(&0) {
+            return Err(InvalidSeriesLengthError);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+const MIN_USERNAME_LENGTH: usize = 3;
+const MAX_USERNAME_LENGTH: usize = 32;
+const MIN_PASSWORD_LENGTH: usize = 8;
+const MAX_PASSWORD_LENGTH: usize = 128;
+
+/// What a user logs in with, where only a hash of the password is ever kept
+#[derive(Debug, Clone)]
+pub(crate) struct Credentials {
+    username: String,
+    password_hash: String,
+}
+
+impl Credentials {
+    pub(crate) fn new(username: String, password_hash: String) -> Self {
+        Credentials {
+            username,
+            password_hash,
+        }
+    }
+
+    pub(crate) fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub(crate) fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+}
+
+/// Usernames are matched without regard to case, so they are kept to characters where that is
+/// unambiguous
+pub(crate) fn validate_username(username: &str) -> Result<(), InvalidCredentialsError> {
+    let length = username.chars().count();
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length) {
+        Err(InvalidCredentialsError::UsernameLength(
+            MIN_USERNAME_LENGTH,
+            MAX_USERNAME_LENGTH,
+        ))
+    } else if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        Err(InvalidCredentialsError::UsernameCharacters)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn validate_password(password: &str) -> Result<(), InvalidCredentialsError> {
+    let length = password.chars().count();
+    if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&length) {
+        Err(InvalidCredentialsError::PasswordLength(
+            MIN_PASSWORD_LENGTH,
+            MAX_PASSWORD_LENGTH,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum InvalidCredentialsError {
+    #[error("Usernames must be between {0} and {1} characters long")]
+    UsernameLength(usize, usize),
+    #[error("Usernames may only contain letters, digits, underscores and hyphens")]
+    UsernameCharacters,
+    #[error("Passwords must be between {0} and {1} characters long")]
+    PasswordLength(usize, usize),
+}
@@ -0,0 +1,32 @@
+use uuid::Uuid;
+
+use crate::domain::user::InvalidProfileError;
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User {requester} does not own the account of User {user_id}")]
+pub(crate) struct NotAccountOwnerError {
+    user_id: Uuid,
+    requester: Uuid,
+}
+
+impl NotAccountOwnerError {
+    pub(crate) fn new(user_id: Uuid, requester: Uuid) -> Self {
+        NotAccountOwnerError { user_id, requester }
+    }
+}
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User {0} must leave every room before deleting their account")]
+pub(crate) struct UserInRoomsError(pub(crate) Uuid);
+
+#[derive(Debug, Copy, Clone, thiserror::Error)]
+#[error("User {0} is not a guest")]
+pub(crate) struct NotAGuestError(pub(crate) Uuid);
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum UpgradeGuestError {
+    #[error(transparent)]
+    NotAGuest(#[from] NotAGuestError),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidProfileError),
+}
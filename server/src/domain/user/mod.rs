@@ -1,21 +1,54 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+pub(crate) use credentials::*;
+pub(crate) use error::*;
 pub(crate) use user_client::*;
 pub(crate) use user_factory::*;
+pub(crate) use user_profile::*;
 pub(crate) use user_repository::*;
 
+use crate::domain::game::GameVariant;
+use crate::domain::rating::Rating;
+
+mod credentials;
+mod error;
 mod user_client;
 mod user_factory;
+mod user_profile;
 mod user_repository;
 
 pub(crate) struct User {
     id: Uuid,
     name: String,
+    bio: Option<String>,
+    guest: bool,
+    last_active_at: DateTime<Utc>,
+    credentials: Option<Credentials>,
+    ratings: HashMap<GameVariant, Rating>,
 }
 
 impl User {
-    pub(crate) fn new(id: uuid::Uuid, name: String) -> Self {
-        User { id, name }
+    pub(crate) fn new(
+        id: uuid::Uuid,
+        name: String,
+        bio: Option<String>,
+        guest: bool,
+        last_active_at: DateTime<Utc>,
+        credentials: Option<Credentials>,
+        ratings: HashMap<GameVariant, Rating>,
+    ) -> Self {
+        User {
+            id,
+            name,
+            bio,
+            guest,
+            last_active_at,
+            credentials,
+            ratings,
+        }
     }
 
     pub(crate) fn id(&self) -> uuid::Uuid {
@@ -25,4 +58,56 @@ impl User {
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
+
+    pub(crate) fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub(crate) fn bio(&self) -> Option<&str> {
+        self.bio.as_deref()
+    }
+
+    pub(crate) fn set_bio(&mut self, bio: Option<String>) {
+        self.bio = bio;
+    }
+
+    /// Guests are given a generated name and are removed once they have been inactive for a while
+    pub(crate) fn is_guest(&self) -> bool {
+        self.guest
+    }
+
+    /// Turns a guest into a full account with a name of their choosing, keeping everything else
+    pub(crate) fn upgrade(&mut self, name: String) -> Result<(), UpgradeGuestError> {
+        if !self.guest {
+            return Err(NotAGuestError(self.id).into());
+        }
+        validate_user_name(&name)?;
+        self.name = name;
+        self.guest = false;
+        Ok(())
+    }
+
+    pub(crate) fn last_active_at(&self) -> DateTime<Utc> {
+        self.last_active_at
+    }
+
+    /// Set for users who registered with a username and password
+    pub(crate) fn credentials(&self) -> Option<&Credentials> {
+        self.credentials.as_ref()
+    }
+
+    /// Ratings for the variants the user has played rated games of
+    pub(crate) fn ratings(&self) -> &HashMap<GameVariant, Rating> {
+        &self.ratings
+    }
+
+    /// The user's rating for a variant, which is the initial rating until they play a rated game
+    /// of it
+    pub(crate) fn rating(&self, variant: GameVariant) -> Rating {
+        self.ratings.get(&variant).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn set_rating(&mut self, variant: GameVariant, rating: Rating) {
+        self.ratings.insert(variant, rating);
+    }
 }
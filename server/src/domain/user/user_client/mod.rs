@@ -1,5 +1,16 @@
 pub(crate) use user_client_provider::*;
 
+use crate::domain::room::RoomEvent;
+
 mod user_client_provider;
 
-pub(crate) trait UserClient {}
+#[async_trait::async_trait]
+pub(crate) trait UserClient {
+    async fn notify(&self, event: &RoomEvent) -> Result<(), UserClientError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum UserClientError {
+    #[error("User client has disconnected")]
+    Disconnected,
+}
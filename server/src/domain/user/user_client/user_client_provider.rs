@@ -6,7 +6,12 @@ use crate::domain::user::UserClient;
 pub(crate) trait UserClientProvider {
     type UserClient: UserClient;
 
-    async fn get(&self, user_id: Uuid) -> Result<Self::UserClient, UserClientProviderError>;
+    /// The client a user is connected to a room with
+    async fn get(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+    ) -> Result<Self::UserClient, UserClientProviderError>;
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -1,21 +1,61 @@
+use std::collections::HashMap;
+
 use uuid::Uuid;
 
-use crate::domain::user::User;
+use crate::domain::clock::Clock;
+use crate::domain::user::{validate_user_name, Credentials, InvalidProfileError, User};
 
 pub(crate) trait UserFactory {
-    fn create<S: AsRef<str>>(&self, user_name: S) -> User;
+    fn create<S: AsRef<str>>(&self, user_name: S) -> Result<User, InvalidProfileError>;
+
+    /// A guest with a generated name
+    fn create_guest(&self) -> User;
+
+    /// A user who logs in with the credentials
+    fn create_account<S: AsRef<str>>(
+        &self,
+        user_name: S,
+        credentials: Credentials,
+    ) -> Result<User, InvalidProfileError>;
 }
 
-pub(crate) struct UserFactoryImpl;
+pub(crate) struct UserFactoryImpl<C: Clock> {
+    clock: C,
+}
 
-impl UserFactoryImpl {
-    pub(crate) fn new() -> Self {
-        UserFactoryImpl
+impl<C: Clock> UserFactoryImpl<C> {
+    pub(crate) fn new(clock: C) -> Self {
+        UserFactoryImpl { clock }
     }
 }
 
-impl UserFactory for UserFactoryImpl {
-    fn create<S: AsRef<str>>(&self, user_name: S) -> User {
-        User::new(Uuid::new_v4(), user_name.as_ref().to_string())
+impl<C: Clock> UserFactory for UserFactoryImpl<C> {
+    fn create<S: AsRef<str>>(&self, user_name: S) -> Result<User, InvalidProfileError> {
+        validate_user_name(user_name.as_ref())?;
+        Ok(User::new(
+            Uuid::new_v4(),
+            user_name.as_ref().to_string(),
+            None,
+            false,
+            self.clock.now(),
+            None,
+            HashMap::new(),
+        ))
+    }
+
+    fn create_guest(&self) -> User {
+        let id = Uuid::new_v4();
+        let name = format!("Guest {}", &id.to_simple().to_string()[..8]);
+        User::new(id, name, None, true, self.clock.now(), None, HashMap::new())
+    }
+
+    fn create_account<S: AsRef<str>>(
+        &self,
+        user_name: S,
+        credentials: Credentials,
+    ) -> Result<User, InvalidProfileError> {
+        let mut user = self.create(user_name)?;
+        user.credentials = Some(credentials);
+        Ok(user)
     }
 }
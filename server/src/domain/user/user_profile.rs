@@ -0,0 +1,69 @@
+use crate::domain::user::User;
+
+const MAX_NAME_LENGTH: usize = 32;
+const MAX_BIO_LENGTH: usize = 280;
+
+/// Checks a display name against the rules applied both at registration and on rename
+pub(crate) fn validate_user_name(name: &str) -> Result<(), InvalidProfileError> {
+    if name.trim().is_empty() {
+        Err(InvalidProfileError::BlankName)
+    } else if name.chars().count() > MAX_NAME_LENGTH {
+        Err(InvalidProfileError::NameTooLong(MAX_NAME_LENGTH))
+    } else if name.chars().any(char::is_control) {
+        Err(InvalidProfileError::NameControlCharacters)
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_bio(bio: &str) -> Result<(), InvalidProfileError> {
+    if bio.chars().count() > MAX_BIO_LENGTH {
+        Err(InvalidProfileError::BioTooLong(MAX_BIO_LENGTH))
+    } else {
+        Ok(())
+    }
+}
+
+/// Changes to a user's profile, where unset fields are left as they are
+#[derive(Debug, Default, Clone)]
+pub(crate) struct UserProfileUpdate {
+    name: Option<String>,
+    bio: Option<Option<String>>,
+}
+
+impl UserProfileUpdate {
+    pub(crate) fn new(name: Option<String>, bio: Option<Option<String>>) -> Self {
+        UserProfileUpdate { name, bio }
+    }
+
+    /// Validates and applies the changes, returning whether the user's name changed
+    pub(crate) fn apply_to(&self, user: &mut User) -> Result<bool, InvalidProfileError> {
+        if let Some(name) = &self.name {
+            validate_user_name(name)?;
+        }
+        if let Some(Some(bio)) = &self.bio {
+            validate_bio(bio)?;
+        }
+
+        let renamed = self.name.iter().any(|name| name != user.name());
+        if let Some(name) = &self.name {
+            user.set_name(name.clone());
+        }
+        if let Some(bio) = &self.bio {
+            user.set_bio(bio.clone());
+        }
+        Ok(renamed)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum InvalidProfileError {
+    #[error("Names must not be blank")]
+    BlankName,
+    #[error("Names must be at most {0} characters long")]
+    NameTooLong(usize),
+    #[error("Names must not contain control characters")]
+    NameControlCharacters,
+    #[error("Bios must be at most {0} characters long")]
+    BioTooLong(usize),
+}
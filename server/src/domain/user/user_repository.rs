@@ -1,14 +1,51 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::domain::game::GameVariant;
+use crate::domain::pagination::{Page, PageRequest};
+use crate::domain::rating::{LeaderboardEntry, LeaderboardOrder};
 use crate::domain::user::User;
 
 #[async_trait::async_trait]
 pub(crate) trait UserRepository {
     async fn store(&self, user: &User) -> Result<(), StoreUserError>;
 
-    async fn update(&self, user: &User) -> Result<(), UpdateUserError>;
+    /// Applies a change to the stored user while no other change to them can be made, storing
+    /// and returning the changed user unless the change fails
+    async fn update_with<T, E, F>(&self, id: Uuid, change: F) -> Result<(User, T), E>
+    where
+        T: Send,
+        E: From<UserNotFoundError> + Send,
+        F: FnOnce(&mut User) -> Result<T, E> + Send;
 
     async fn get(&self, id: Uuid) -> Result<User, GetUserError>;
+
+    /// The user who registered with the username, ignoring case
+    async fn by_username(&self, username: &str) -> Option<User>;
+
+    async fn remove(&self, id: Uuid) -> Result<(), RemoveUserError>;
+
+    /// Records that the user did something, leaving the rest of their account as it is
+    async fn record_activity(&self, id: Uuid, at: DateTime<Utc>) -> Result<(), UpdateUserError>;
+
+    /// Guests who have done nothing since the cutoff
+    async fn guests_inactive_since(&self, cutoff: DateTime<Utc>) -> Vec<User>;
+
+    /// Users who have played rated games of the variant, best first
+    async fn leaderboard(
+        &self,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+        page_request: PageRequest,
+    ) -> Page<LeaderboardEntry>;
+
+    /// The user's place on a leaderboard, if they have played rated games of the variant
+    async fn leaderboard_entry(
+        &self,
+        id: Uuid,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+    ) -> Result<Option<LeaderboardEntry>, GetUserError>;
 }
 
 #[derive(Debug, Copy, Clone, thiserror::Error)]
@@ -25,14 +62,26 @@ pub(crate) struct UserNotFoundError(pub(crate) Uuid);
 pub(crate) enum StoreUserError {
     #[error(transparent)]
     AlreadyExists(#[from] UserWithIdAlreadyExists),
+    #[error(transparent)]
+    UsernameTaken(#[from] UsernameTakenError),
 }
 
 #[derive(Debug, Copy, Clone, thiserror::Error)]
 #[error("A user with id {0} already exists in the repository")]
 pub(crate) struct UserWithIdAlreadyExists(pub(crate) Uuid);
 
+#[derive(Debug, thiserror::Error)]
+#[error("The username {0} is already taken")]
+pub(crate) struct UsernameTakenError(pub(crate) String);
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum UpdateUserError {
     #[error(transparent)]
     NotFound(#[from] UserNotFoundError),
 }
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RemoveUserError {
+    #[error(transparent)]
+    NotFound(#[from] UserNotFoundError),
+}
@@ -0,0 +1,3 @@
+pub(crate) use system_clock_adapter::*;
+
+mod system_clock_adapter;
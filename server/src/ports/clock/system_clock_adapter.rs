@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::clock::Clock;
+
+pub(crate) struct SystemClockAdapter;
+
+impl SystemClockAdapter {
+    pub(crate) fn new() -> Self {
+        SystemClockAdapter
+    }
+}
+
+impl Clock for SystemClockAdapter {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+/// Reads a positive number of seconds from the environment, falling back to the default when it is
+/// unset or invalid
+pub(crate) fn seconds_from_env(name: &str, default: u64) -> Duration {
+    let seconds = match std::env::var(name).map(|value| value.parse::<u64>()) {
+        Ok(Ok(seconds)) if seconds > 0 => seconds,
+        Ok(_) => {
+            log::warn!(
+                "{} must be a positive number of seconds, using {} instead",
+                name,
+                default
+            );
+            default
+        }
+        Err(_) => default,
+    };
+    Duration::from_secs(seconds)
+}
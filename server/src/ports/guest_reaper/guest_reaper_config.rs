@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use crate::ports::env::seconds_from_env;
+
+const INTERVAL_ENV_VAR: &str = "GUEST_REAPER_INTERVAL_SECONDS";
+const GUEST_TTL_ENV_VAR: &str = "GUEST_TTL_SECONDS";
+const DEFAULT_INTERVAL_SECONDS: u64 = 5 * 60;
+const DEFAULT_GUEST_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct GuestReaperConfig {
+    interval: Duration,
+    guest_ttl: Duration,
+}
+
+impl GuestReaperConfig {
+    pub(crate) fn new(interval: Duration, guest_ttl: Duration) -> Self {
+        GuestReaperConfig {
+            interval,
+            guest_ttl,
+        }
+    }
+
+    /// Reads each setting from the environment, falling back to its default when unset or invalid
+    pub(crate) fn from_env() -> Self {
+        GuestReaperConfig::new(
+            seconds_from_env(INTERVAL_ENV_VAR, DEFAULT_INTERVAL_SECONDS),
+            seconds_from_env(GUEST_TTL_ENV_VAR, DEFAULT_GUEST_TTL_SECONDS),
+        )
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Time without activity after which a guest is purged
+    pub(crate) fn guest_ttl(&self) -> chrono::Duration {
+        chrono::Duration::from_std(self.guest_ttl).unwrap()
+    }
+}
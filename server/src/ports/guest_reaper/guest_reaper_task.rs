@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::application::ApplicationService;
+use crate::ports::guest_reaper::GuestReaperConfig;
+
+/// Periodically purges guests who have gone without activity for too long, along with sessions
+/// and failed logins that no longer matter
+pub(crate) struct GuestReaperTask<AS: ApplicationService> {
+    application_service: Arc<AS>,
+    config: GuestReaperConfig,
+}
+
+impl<AS> GuestReaperTask<AS>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    pub(crate) fn new(application_service: Arc<AS>, config: GuestReaperConfig) -> Self {
+        GuestReaperTask {
+            application_service,
+            config,
+        }
+    }
+
+    pub(crate) fn spawn(self) {
+        tokio::spawn(self.run());
+    }
+
+    async fn run(self) {
+        let mut interval = tokio::time::interval(self.config.interval());
+        // The first tick completes immediately, and nothing can have expired at startup
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let purged = self
+                .application_service
+                .purge_expired_guests(self.config.guest_ttl())
+                .await;
+            if purged > 0 {
+                log::info!("Guest reaper purged {} inactive guests", purged);
+            } else {
+                log::debug!("Guest reaper found no expired guests");
+            }
+            let pruned = self.application_service.prune_sessions().await;
+            if pruned > 0 {
+                log::info!("Guest reaper pruned {} inactive sessions", pruned);
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) use guest_reaper_config::*;
+pub(crate) use guest_reaper_task::*;
+
+mod guest_reaper_config;
+mod guest_reaper_task;
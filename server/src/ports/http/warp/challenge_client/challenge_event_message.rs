@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::challenge::ChallengeEvent;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum ChallengeEventMessage {
+    Received {
+        challenge_id: Uuid,
+        challenger: Uuid,
+        expires_at: DateTime<Utc>,
+    },
+    Accepted {
+        challenge_id: Uuid,
+        room_id: Uuid,
+        game_id: Uuid,
+    },
+    Declined {
+        challenge_id: Uuid,
+    },
+    Withdrawn {
+        challenge_id: Uuid,
+    },
+}
+
+impl From<&ChallengeEvent> for ChallengeEventMessage {
+    fn from(event: &ChallengeEvent) -> Self {
+        match event.clone() {
+            ChallengeEvent::Received {
+                challenge_id,
+                challenger,
+                expires_at,
+            } => ChallengeEventMessage::Received {
+                challenge_id,
+                challenger,
+                expires_at,
+            },
+            ChallengeEvent::Accepted {
+                challenge_id,
+                room_id,
+                game_id,
+            } => ChallengeEventMessage::Accepted {
+                challenge_id,
+                room_id,
+                game_id,
+            },
+            ChallengeEvent::Declined { challenge_id } => {
+                ChallengeEventMessage::Declined { challenge_id }
+            }
+            ChallengeEvent::Withdrawn { challenge_id } => {
+                ChallengeEventMessage::Withdrawn { challenge_id }
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc::Sender;
+use uuid::Uuid;
+use warp::ws::Message;
+
+pub(crate) use challenge_event_message::*;
+pub(crate) use ws_challenge_client_provider_adapter::*;
+
+use crate::domain::challenge::{ChallengeClient, ChallengeEvent};
+use crate::domain::user::UserClientError;
+
+mod challenge_event_message;
+mod ws_challenge_client_provider_adapter;
+
+pub(crate) struct WsChallengeClientAdapter {
+    user_id: Uuid,
+    transmitter: Arc<Sender<Result<Message, warp::Error>>>,
+}
+
+impl WsChallengeClientAdapter {
+    pub(crate) fn new(
+        user_id: Uuid,
+        user_ws_tx: Arc<Sender<Result<Message, warp::Error>>>,
+    ) -> Self {
+        WsChallengeClientAdapter {
+            user_id,
+            transmitter: user_ws_tx,
+        }
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub(crate) fn transmitter(&self) -> Arc<Sender<Result<Message, warp::Error>>> {
+        self.transmitter.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl ChallengeClient for WsChallengeClientAdapter {
+    async fn notify(&self, event: &ChallengeEvent) -> Result<(), UserClientError> {
+        let message = serde_json::to_string(&ChallengeEventMessage::from(event))
+            .expect("Challenge event messages are always serializable");
+        self.transmitter
+            .send(Ok(Message::text(message)))
+            .await
+            .map_err(|_| UserClientError::Disconnected)
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::OwnedMutexGuard;
+use uuid::Uuid;
+use warp::ws::Message;
+
+use crate::domain::challenge::ChallengeClientProvider;
+use crate::domain::user::UserClientProviderError;
+use crate::ports::http::warp::WsChallengeClientAdapter;
+
+type Transmitter = Arc<Sender<Result<Message, warp::Error>>>;
+type ChallengeClientMap = Arc<Mutex<HashMap<Uuid, Transmitter>>>;
+type ConnectionLocks = Arc<Mutex<HashMap<Uuid, Arc<tokio::sync::Mutex<()>>>>>;
+
+/// Keeps the WebSocket connection each user listens for challenges on
+pub(crate) struct WsChallengeClientProviderAdapter {
+    inner: ChallengeClientMap,
+    connection_locks: ConnectionLocks,
+}
+
+impl WsChallengeClientProviderAdapter {
+    pub(crate) fn new() -> Self {
+        WsChallengeClientProviderAdapter {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+            connection_locks: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Serialises connecting and disconnecting a user so that a new connection is not removed by
+    /// the previous one closing
+    pub(crate) async fn lock_connections(&self, user_id: Uuid) -> OwnedMutexGuard<()> {
+        let lock = self
+            .connection_locks
+            .lock()
+            .entry(user_id)
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
+
+    pub(crate) async fn is_connected(&self, user_id: Uuid) -> bool {
+        let map = self.inner.lock();
+        map.contains_key(&user_id)
+    }
+
+    pub(crate) async fn put(&self, challenge_client: &WsChallengeClientAdapter) {
+        let mut map = self.inner.lock();
+        map.insert(challenge_client.user_id(), challenge_client.transmitter());
+    }
+
+    /// Removes the client unless it has already been replaced by a newer connection, returning
+    /// whether it was removed
+    pub(crate) async fn remove_if_current(
+        &self,
+        challenge_client: &WsChallengeClientAdapter,
+    ) -> bool {
+        let mut map = self.inner.lock();
+        let user_id = challenge_client.user_id();
+        let is_current = map
+            .get(&user_id)
+            .iter()
+            .any(|transmitter| Arc::ptr_eq(transmitter, &challenge_client.transmitter()));
+        if is_current {
+            map.remove(&user_id);
+        }
+        is_current
+    }
+}
+
+#[async_trait::async_trait]
+impl ChallengeClientProvider for WsChallengeClientProviderAdapter {
+    type ChallengeClient = WsChallengeClientAdapter;
+
+    async fn get(&self, user_id: Uuid) -> Result<Self::ChallengeClient, UserClientProviderError> {
+        let map = self.inner.lock();
+        let transmitter = map
+            .get(&user_id)
+            .ok_or(UserClientProviderError::UserClientNotAvailable)?
+            .clone();
+        Ok(WsChallengeClientAdapter::new(user_id, transmitter))
+    }
+}
@@ -0,0 +1,60 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{AbandonGameError, ApplicationService};
+use crate::domain::room::AbandonGameError as DomainAbandonGameError;
+use crate::ports::http::warp::responses::SimpleErrorResponse;
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn abandon_game_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(acting_user(application_service.clone()))
+        .and(warp::header("room-id"))
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    user_id: Uuid,
+    room_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<impl Reply, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.abandon_game(room_id, user_id).await {
+        Ok(_) => warp::reply::with_status(warp::reply(), StatusCode::ACCEPTED).into_response(),
+        Err(err) => abandon_game_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn abandon_game_error_response(err: AbandonGameError) -> Response {
+    let status_code = match err.cause() {
+        DomainAbandonGameError::NoActiveGameInRoom(_)
+        | DomainAbandonGameError::UserNotFound(_)
+        | DomainAbandonGameError::RoomNotFound(_) => StatusCode::NOT_FOUND,
+        DomainAbandonGameError::UserNotInRoom(_) | DomainAbandonGameError::UserNotPlayer(_) => {
+            StatusCode::NOT_ACCEPTABLE
+        }
+        DomainAbandonGameError::GameAlreadyFinished(_) => StatusCode::CONFLICT,
+        DomainAbandonGameError::GameNotFound(_)
+        | DomainAbandonGameError::IllegalLifecycleTransition(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code).into_response()
+}
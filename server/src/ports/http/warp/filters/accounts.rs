@@ -0,0 +1,260 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::header::WWW_AUTHENTICATE;
+use warp::http::StatusCode;
+use warp::reject::Reject;
+use warp::reply::Response;
+use warp::{Filter, Rejection, Reply};
+
+use crate::application::{ApplicationService, RegisterAccountError};
+use crate::domain::account::{
+    CreateCredentialsError, IdentifyUserError, InvalidSessionError, LoginError, RevokeSessionError,
+    Session,
+};
+use crate::domain::user::StoreUserError;
+use crate::ports::http::warp::requests::{Login, RegisterAccount};
+use crate::ports::http::warp::responses::{
+    AccountResponse, LoginResponse, SessionListResponse, SimpleErrorResponse,
+};
+use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// Rejection for requests whose acting user could not be identified, answered by
+/// [`recover_unauthorized`]
+#[derive(Debug)]
+struct Unauthorized(IdentifyUserError);
+
+impl Reject for Unauthorized {}
+
+/// The user a request acts on behalf of, named by its `user-id` header and proven by its bearer
+/// token when they have an account
+pub(crate) fn acting_user<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (Uuid,), Error = Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::header::optional::<Uuid>("user-id")
+        .and(bearer_token())
+        .and(with_application_service(application_service))
+        .and_then(
+            |user_id: Option<Uuid>, token: Option<String>, application_service: Arc<AS>| async move {
+                application_service
+                    .identify_user(user_id, token)
+                    .await
+                    .map_err(|err| warp::reject::custom(Unauthorized(err)))
+            },
+        )
+}
+
+/// Answers requests rejected by [`acting_user`] with 401 Unauthorized
+pub(crate) async fn recover_unauthorized(rejection: Rejection) -> Result<Response, Rejection> {
+    match rejection.find::<Unauthorized>() {
+        Some(Unauthorized(err)) => Ok(unauthorized_response(err)),
+        None => Err(rejection),
+    }
+}
+
+pub(crate) fn register_account_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::post()
+        .and(warp::path::end())
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(register_account_handler)
+}
+
+pub(crate) fn login_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::post()
+        .and(warp::path::end())
+        .and(json_body())
+        .and(warp::addr::remote())
+        .and(with_application_service(application_service))
+        .and_then(login_handler)
+}
+
+pub(crate) fn list_sessions_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::get()
+        .and(warp::path::end())
+        .and(bearer_token())
+        .and(with_application_service(application_service))
+        .and_then(list_sessions_handler)
+}
+
+pub(crate) fn revoke_session_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::delete()
+        .and(warp::path!(Uuid))
+        .and(bearer_token())
+        .and(with_application_service(application_service))
+        .and_then(revoke_session_handler)
+}
+
+async fn register_account_handler<AS: ApplicationService>(
+    request: RegisterAccount,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service
+        .register_account(
+            request.username().to_string(),
+            request.password().to_string(),
+            request.name().map(str::to_string),
+        )
+        .await
+    {
+        Ok(user) => json_reply_with_status(&AccountResponse::from(&user), StatusCode::CREATED),
+        Err(err) => register_account_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn login_handler<AS: ApplicationService>(
+    request: Login,
+    client: Option<SocketAddr>,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service
+        .login(
+            request.username().to_string(),
+            request.password().to_string(),
+            client.map(|address| address.ip()),
+        )
+        .await
+    {
+        Ok(issued) => json_reply_with_status(&LoginResponse::from(&issued), StatusCode::CREATED),
+        Err(err) => login_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn list_sessions_handler<AS: ApplicationService>(
+    token: Option<String>,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let current = match authenticate(token, application_service.as_ref()).await {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+
+    let sessions = application_service.sessions(current.user_id()).await;
+    Ok(json_reply_with_status(
+        &SessionListResponse::new(&current, &sessions),
+        StatusCode::OK,
+    ))
+}
+
+async fn revoke_session_handler<AS: ApplicationService>(
+    session_id: Uuid,
+    token: Option<String>,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let current = match authenticate(token, application_service.as_ref()).await {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+
+    let response = match application_service
+        .revoke_session(current.user_id(), session_id)
+        .await
+    {
+        Ok(_) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => revoke_session_error_response(err),
+    };
+
+    Ok(response)
+}
+
+/// The session of the request's bearer token, or the response to send when there is none
+async fn authenticate<AS: ApplicationService>(
+    token: Option<String>,
+    application_service: &AS,
+) -> Result<Session, Response> {
+    let token = token.ok_or_else(|| unauthorized_response(&InvalidSessionError))?;
+    application_service
+        .authenticate(token)
+        .await
+        .map_err(|err| unauthorized_response(&err))
+}
+
+fn register_account_error_response(err: RegisterAccountError) -> Response {
+    let status_code = match &err {
+        RegisterAccountError::InvalidCredentials(CreateCredentialsError::Invalid(_))
+        | RegisterAccountError::InvalidName(_) => StatusCode::BAD_REQUEST,
+        RegisterAccountError::Store(StoreUserError::UsernameTaken(_)) => StatusCode::CONFLICT,
+        RegisterAccountError::InvalidCredentials(CreateCredentialsError::Hash(_))
+        | RegisterAccountError::Store(StoreUserError::AlreadyExists(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn login_error_response(err: LoginError) -> Response {
+    let status_code = match &err {
+        LoginError::InvalidLogin(_) => StatusCode::UNAUTHORIZED,
+        LoginError::TooManyAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
+        LoginError::Store(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn revoke_session_error_response(err: RevokeSessionError) -> Response {
+    let status_code = match &err {
+        RevokeSessionError::SessionNotFound(_) => StatusCode::NOT_FOUND,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn unauthorized_response(err: &dyn std::error::Error) -> Response {
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    warp::reply::with_header(
+        json_reply_with_status(&error_body, StatusCode::UNAUTHORIZED),
+        WWW_AUTHENTICATE,
+        "Bearer",
+    )
+    .into_response()
+}
+
+/// The token from an `Authorization: Bearer <token>` header, if the request has one
+fn bearer_token() -> impl Filter<Extract = (Option<String>,), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("authorization").map(|header: Option<String>| {
+        header.and_then(|value| value.strip_prefix(BEARER_PREFIX).map(str::to_string))
+    })
+}
+
+fn json_body<T>() -> impl Filter<Extract = (T,), Error = warp::Rejection> + Clone
+where
+    T: serde::de::DeserializeOwned + Send,
+{
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
@@ -9,7 +9,7 @@ use warp::{Filter, Reply};
 use crate::application::{ApplicationService, BecomePlayerError};
 use crate::domain::room::AddPlayerError;
 use crate::ports::http::warp::responses::SimpleErrorResponse;
-use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
 
 pub(crate) fn become_player_filter<AS>(
     application_service: Arc<AS>,
@@ -18,7 +18,7 @@ where
     AS: ApplicationService + Send + Sync + 'static,
 {
     warp::put()
-        .and(warp::header("user-id"))
+        .and(acting_user(application_service.clone()))
         .and(warp::header("room-id"))
         .and(with_application_service(application_service))
         .and_then(handler)
@@ -52,6 +52,7 @@ fn become_player_error_response(err: BecomePlayerError) -> Response {
         | AddPlayerError::RoomNotFound(_) => StatusCode::NOT_FOUND,
         AddPlayerError::PlayerCountExceeded(_) => StatusCode::NOT_ACCEPTABLE,
         AddPlayerError::UserNotInRoom(_) => StatusCode::NOT_ACCEPTABLE,
+        AddPlayerError::UserIsSpectator(_) => StatusCode::FORBIDDEN,
         AddPlayerError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
     };
 
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use warp::Filter;
+
+use crate::ports::http::warp::WsChallengeClientProviderAdapter;
+
+pub(crate) fn with_challenge_client_provider(
+    challenge_client_provider: Arc<WsChallengeClientProviderAdapter>,
+) -> impl Filter<Extract = (Arc<WsChallengeClientProviderAdapter>,), Error = std::convert::Infallible>
+       + Clone {
+    warp::any().map(move || challenge_client_provider.clone())
+}
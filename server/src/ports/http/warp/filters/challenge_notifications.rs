@@ -0,0 +1,113 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use futures_util::{FutureExt, StreamExt};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::ws::{Message, WebSocket};
+use warp::{Filter, Reply};
+
+use crate::application::ApplicationService;
+use crate::domain::user::GetUserError;
+use crate::ports::http::warp::responses::SimpleErrorResponse;
+use crate::ports::http::warp::{
+    acting_user, json_reply_with_status, with_application_service, with_challenge_client_provider,
+    WsChallengeClientAdapter, WsChallengeClientProviderAdapter,
+};
+
+pub(crate) fn challenge_notifications_filter<AS>(
+    application_service: Arc<AS>,
+    challenge_client_provider: Arc<WsChallengeClientProviderAdapter>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::path!("notifications")
+        .and(acting_user(application_service.clone()))
+        .and(warp::ws())
+        .and(with_application_service(application_service))
+        .and(with_challenge_client_provider(challenge_client_provider))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    user_id: Uuid,
+    ws: warp::ws::Ws,
+    application_service: Arc<AS>,
+    challenge_client_provider: Arc<WsChallengeClientProviderAdapter>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    if let Err(err) = application_service.get_user_name(user_id).await {
+        return match err {
+            GetUserError::NotFound(_) => Ok(json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::NOT_FOUND,
+            )),
+        };
+    }
+
+    let (tx, rx) = mpsc::channel(10);
+    let challenge_client = WsChallengeClientAdapter::new(user_id, Arc::new(tx));
+
+    {
+        let _connection_lock = challenge_client_provider.lock_connections(user_id).await;
+        if challenge_client_provider.is_connected(user_id).await {
+            log::debug!("User({}) is already listening for challenges", user_id);
+            return Ok(
+                warp::reply::with_status(warp::reply(), StatusCode::CONFLICT).into_response(),
+            );
+        }
+        challenge_client_provider.put(&challenge_client).await;
+    }
+
+    let reply = ws
+        .on_upgrade(move |ws| user_connected(ws, rx, challenge_client, challenge_client_provider));
+
+    Ok(reply.into_response())
+}
+
+async fn user_connected(
+    ws: WebSocket,
+    rx: mpsc::Receiver<Result<Message, warp::Error>>,
+    challenge_client: WsChallengeClientAdapter,
+    challenge_client_provider: Arc<WsChallengeClientProviderAdapter>,
+) {
+    let user_id = challenge_client.user_id();
+    let (user_ws_tx, mut user_ws_rx) = ws.split();
+
+    let rx = ReceiverStream::new(rx);
+    tokio::task::spawn(rx.forward(user_ws_tx).map(move |result| {
+        if let Err(err) = result {
+            log::warn!(
+                "Error while sending WebSocket message to user({}): {}",
+                user_id,
+                err
+            );
+        }
+    }));
+
+    while let Some(result) = user_ws_rx.next().await {
+        match result {
+            Ok(msg) if msg.is_close() => break,
+            Ok(_) => {}
+            Err(err) => {
+                log::warn!(
+                    "WebSocket error while reading message from user({}): {}",
+                    user_id,
+                    err
+                );
+                break;
+            }
+        };
+    }
+
+    let _connection_lock = challenge_client_provider.lock_connections(user_id).await;
+    challenge_client_provider
+        .remove_if_current(&challenge_client)
+        .await;
+}
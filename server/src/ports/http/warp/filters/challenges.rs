@@ -0,0 +1,246 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{
+    AcceptChallengeError, ApplicationService, CreateChallengeError, DeclineChallengeError,
+};
+use crate::domain::challenge::{Challenge, IssueChallengeError, RespondToChallengeError};
+use crate::domain::user::GetUserError;
+use crate::ports::http::warp::requests::CreateChallenge;
+use crate::ports::http::warp::responses::{
+    AcceptChallengeResponse, ChallengeListResponse, ChallengeResponse, SimpleErrorResponse,
+};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn create_challenge_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path::end())
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(create_challenge_handler)
+}
+
+pub(crate) fn incoming_challenges_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!("incoming"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(incoming_challenges_handler)
+}
+
+pub(crate) fn outgoing_challenges_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!("outgoing"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(outgoing_challenges_handler)
+}
+
+pub(crate) fn accept_challenge_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path!(Uuid / "accept"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(accept_challenge_handler)
+}
+
+pub(crate) fn decline_challenge_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path!(Uuid / "decline"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(decline_challenge_handler)
+}
+
+async fn create_challenge_handler<AS>(
+    user_id: Uuid,
+    request: CreateChallenge,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let preferences = match request.preferences() {
+        Ok(preferences) => preferences,
+        Err(err) => return Ok(bad_request_response(err.to_string())),
+    };
+    let expires_in = match request.expires_in() {
+        Ok(expires_in) => expires_in,
+        Err(err) => return Ok(bad_request_response(err.to_string())),
+    };
+
+    let response = match application_service
+        .create_challenge(user_id, request.challenged(), preferences, expires_in)
+        .await
+    {
+        Ok(challenge) => {
+            json_reply_with_status(&ChallengeResponse::from(&challenge), StatusCode::CREATED)
+        }
+        Err(err) => create_challenge_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn incoming_challenges_handler<AS>(
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    Ok(challenge_list_response(
+        application_service.incoming_challenges(user_id).await,
+    ))
+}
+
+async fn outgoing_challenges_handler<AS>(
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    Ok(challenge_list_response(
+        application_service.outgoing_challenges(user_id).await,
+    ))
+}
+
+async fn accept_challenge_handler<AS>(
+    challenge_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .accept_challenge(challenge_id, user_id)
+        .await
+    {
+        Ok(found) => json_reply_with_status(
+            &AcceptChallengeResponse::new(found.room_id(), found.game_id()),
+            StatusCode::OK,
+        ),
+        Err(err) => accept_challenge_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn decline_challenge_handler<AS>(
+    challenge_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .decline_challenge(challenge_id, user_id)
+        .await
+    {
+        Ok(()) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => decline_challenge_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn bad_request_response(cause: String) -> Response {
+    json_reply_with_status(&SimpleErrorResponse::new(cause), StatusCode::BAD_REQUEST)
+}
+
+fn challenge_list_response(result: Result<Vec<Challenge>, GetUserError>) -> Response {
+    match result {
+        Ok(challenges) => {
+            json_reply_with_status(&ChallengeListResponse::from(challenges), StatusCode::OK)
+        }
+        Err(err) => match err {
+            GetUserError::NotFound(_) => json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        },
+    }
+}
+
+fn create_challenge_error_response(err: CreateChallengeError) -> Response {
+    let status_code = match &err {
+        CreateChallengeError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        CreateChallengeError::Issue(IssueChallengeError::SelfChallenge(_)) => {
+            StatusCode::BAD_REQUEST
+        }
+        CreateChallengeError::Issue(IssueChallengeError::AlreadyChallenged(_)) => {
+            StatusCode::CONFLICT
+        }
+        CreateChallengeError::Issue(IssueChallengeError::Store(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn accept_challenge_error_response(err: AcceptChallengeError) -> Response {
+    let status_code = match &err {
+        AcceptChallengeError::Respond(cause) => respond_to_challenge_status_code(cause),
+        AcceptChallengeError::StoreRoom(_) | AcceptChallengeError::NewGame(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn decline_challenge_error_response(err: DeclineChallengeError) -> Response {
+    let status_code = respond_to_challenge_status_code(err.cause());
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn respond_to_challenge_status_code(err: &RespondToChallengeError) -> StatusCode {
+    match err {
+        RespondToChallengeError::ChallengeNotFound(_) => StatusCode::NOT_FOUND,
+        RespondToChallengeError::NotChallengedUser(_) => StatusCode::FORBIDDEN,
+        RespondToChallengeError::Expired(_) => StatusCode::GONE,
+    }
+}
+
+fn json_body() -> impl Filter<Extract = (CreateChallenge,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
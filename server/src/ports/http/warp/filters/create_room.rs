@@ -3,13 +3,15 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 use warp::http::StatusCode;
+use warp::hyper::body::Bytes;
 use warp::reply::Response;
 use warp::{Filter, Reply};
 
 use crate::application::{ApplicationService, RoomCreationError};
 use crate::domain::user::GetUserError;
+use crate::ports::http::warp::requests::{from_optional_json, CreateRoom};
 use crate::ports::http::warp::responses::{CreateRoomResponse, SimpleErrorResponse};
-use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
 
 pub(crate) fn create_room_filter<AS>(
     application_service: Arc<AS>,
@@ -18,15 +20,31 @@ where
     AS: ApplicationService + Send + Sync + 'static,
 {
     warp::post()
-        .and(warp::header("user-id"))
+        .and(warp::path::end())
+        .and(acting_user(application_service.clone()))
+        .and(warp::body::bytes())
         .and(with_application_service(application_service))
         .and_then(handler)
 }
 
-async fn handler<AS>(user_id: Uuid, application_service: Arc<AS>) -> Result<impl Reply, Infallible>
+async fn handler<AS>(
+    user_id: Uuid,
+    body: Bytes,
+    application_service: Arc<AS>,
+) -> Result<impl Reply, Infallible>
 where
     AS: ApplicationService + Send + Sync + 'static,
 {
+    let request: CreateRoom = match from_optional_json(&body) {
+        Ok(request) => request,
+        Err(err) => {
+            return Ok(json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::BAD_REQUEST,
+            ))
+        }
+    };
+
     if let Err(err) = application_service.get_user_name(user_id).await {
         return match err {
             GetUserError::NotFound(_) => Ok(json_reply_with_status(
@@ -36,7 +54,10 @@ where
         };
     }
 
-    let response = match application_service.create_room().await {
+    let response = match application_service
+        .create_room(user_id, request.visibility(), request.settings())
+        .await
+    {
         Ok(room_id) => {
             json_reply_with_status(&CreateRoomResponse::new(room_id), StatusCode::CREATED)
         }
@@ -46,6 +67,15 @@ where
     Ok(response)
 }
 
-fn create_room_error_response(_err: RoomCreationError) -> Response {
-    warp::reply::with_status(warp::reply(), StatusCode::INTERNAL_SERVER_ERROR).into_response()
+fn create_room_error_response(err: RoomCreationError) -> Response {
+    match err {
+        RoomCreationError::InvalidSettings(_) => json_reply_with_status(
+            &SimpleErrorResponse::new(err.to_string()),
+            StatusCode::BAD_REQUEST,
+        ),
+        RoomCreationError::Store(_) => {
+            warp::reply::with_status(warp::reply(), StatusCode::INTERNAL_SERVER_ERROR)
+                .into_response()
+        }
+    }
 }
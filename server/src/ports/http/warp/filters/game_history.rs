@@ -0,0 +1,91 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, GameHistoryError, GetRoomGameError};
+use crate::domain::room::{
+    GameHistoryError as DomainGameHistoryError, GetRoomGameError as DomainGetRoomGameError,
+};
+use crate::ports::http::warp::responses::{GameHistoryResponse, GameResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+
+pub(crate) fn game_history_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!(Uuid / "games"))
+        .and(with_application_service(application_service))
+        .and_then(game_history_handler)
+}
+
+pub(crate) fn get_room_game_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!(Uuid / "games" / Uuid))
+        .and(with_application_service(application_service))
+        .and_then(get_room_game_handler)
+}
+
+async fn game_history_handler<AS>(
+    room_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.game_history(room_id).await {
+        Ok(games) => json_reply_with_status(&GameHistoryResponse::new(&games), StatusCode::OK),
+        Err(err) => game_history_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn get_room_game_handler<AS>(
+    room_id: Uuid,
+    game_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.get_game(room_id, game_id).await {
+        Ok(game) => json_reply_with_status(&GameResponse::from(&game), StatusCode::OK),
+        Err(err) => get_room_game_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn game_history_error_response(err: GameHistoryError) -> Response {
+    let status_code = match err.cause() {
+        DomainGameHistoryError::RoomNotFound(_) => StatusCode::NOT_FOUND,
+        DomainGameHistoryError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn get_room_game_error_response(err: GetRoomGameError) -> Response {
+    let status_code = match err.cause() {
+        DomainGetRoomGameError::RoomNotFound(_) | DomainGetRoomGameError::GameNotInRoom(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainGetRoomGameError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
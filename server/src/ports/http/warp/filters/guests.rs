@@ -0,0 +1,99 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, RegisterUserError, UpgradeGuestError};
+use crate::domain::user::{StoreUserError, UpgradeGuestError as DomainUpgradeGuestError};
+use crate::ports::http::warp::requests::UpgradeGuest;
+use crate::ports::http::warp::responses::{
+    GuestResponse, SimpleErrorResponse, UserProfileResponse,
+};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn create_guest_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::post()
+        .and(warp::path::end())
+        .and(with_application_service(application_service))
+        .and_then(create_guest_handler)
+}
+
+pub(crate) fn upgrade_guest_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::post()
+        .and(warp::path!(Uuid / "upgrade"))
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(upgrade_guest_handler)
+}
+
+async fn create_guest_handler<AS: ApplicationService>(
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service.register_guest().await {
+        Ok(guest) => json_reply_with_status(&GuestResponse::from(&guest), StatusCode::CREATED),
+        Err(err) => create_guest_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn upgrade_guest_handler<AS: ApplicationService>(
+    user_id: Uuid,
+    requester: Uuid,
+    request: UpgradeGuest,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service
+        .upgrade_guest(user_id, requester, request.name().to_string())
+        .await
+    {
+        Ok(user) => json_reply_with_status(&UserProfileResponse::from(&user), StatusCode::OK),
+        Err(err) => upgrade_guest_error_response(err),
+    };
+
+    Ok(response)
+}
+
+/// Guests are named and identified by the server, so any failure to store one is the server's
+fn create_guest_error_response(err: RegisterUserError) -> Response {
+    let status_code = match &err {
+        RegisterUserError::Store(StoreUserError::AlreadyExists(_))
+        | RegisterUserError::Store(StoreUserError::UsernameTaken(_))
+        | RegisterUserError::InvalidName(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn upgrade_guest_error_response(err: UpgradeGuestError) -> Response {
+    let status_code = match &err {
+        UpgradeGuestError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        UpgradeGuestError::NotAccountOwner(_) => StatusCode::FORBIDDEN,
+        UpgradeGuestError::Upgrade(DomainUpgradeGuestError::NotAGuest(_)) => StatusCode::CONFLICT,
+        UpgradeGuestError::Upgrade(DomainUpgradeGuestError::InvalidName(_)) => {
+            StatusCode::BAD_REQUEST
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn json_body() -> impl Filter<Extract = (UpgradeGuest,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
@@ -0,0 +1,11 @@
+use std::sync::Arc;
+
+use warp::Filter;
+
+use crate::ports::http::warp::InviteLinkSigner;
+
+pub(crate) fn with_invite_link_signer(
+    invite_link_signer: Arc<InviteLinkSigner>,
+) -> impl Filter<Extract = (Arc<InviteLinkSigner>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || invite_link_signer.clone())
+}
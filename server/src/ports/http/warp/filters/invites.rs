@@ -0,0 +1,82 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::hyper::body::Bytes;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, CreateInviteError};
+use crate::domain::room::CreateInviteError as DomainCreateInviteError;
+use crate::ports::http::warp::requests::{from_optional_json, CreateInvite};
+use crate::ports::http::warp::responses::{InviteResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{
+    acting_user, json_reply_with_status, with_application_service, with_invite_link_signer,
+    InviteLinkSigner,
+};
+
+pub(crate) fn create_invite_filter<AS>(
+    application_service: Arc<AS>,
+    invite_link_signer: Arc<InviteLinkSigner>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path!(Uuid / "invites"))
+        .and(acting_user(application_service.clone()))
+        .and(warp::body::bytes())
+        .and(with_application_service(application_service))
+        .and(with_invite_link_signer(invite_link_signer))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    room_id: Uuid,
+    user_id: Uuid,
+    body: Bytes,
+    application_service: Arc<AS>,
+    invite_link_signer: Arc<InviteLinkSigner>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let request: CreateInvite = match from_optional_json(&body) {
+        Ok(request) => request,
+        Err(err) => return Ok(bad_request_response(err.to_string())),
+    };
+    let expires_in = match request.expires_in() {
+        Ok(expires_in) => expires_in,
+        Err(err) => return Ok(bad_request_response(err.to_string())),
+    };
+
+    let response = match application_service
+        .create_invite(room_id, user_id, request.single_use(), expires_in)
+        .await
+    {
+        Ok(invite) => {
+            let link = invite_link_signer.link(room_id, invite.code());
+            json_reply_with_status(&InviteResponse::new(&invite, link), StatusCode::CREATED)
+        }
+        Err(err) => create_invite_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn bad_request_response(cause: String) -> Response {
+    json_reply_with_status(&SimpleErrorResponse::new(cause), StatusCode::BAD_REQUEST)
+}
+
+fn create_invite_error_response(err: CreateInviteError) -> Response {
+    let status_code = match err.cause() {
+        DomainCreateInviteError::UserNotFound(_) | DomainCreateInviteError::RoomNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainCreateInviteError::NotRoomHost(_) => StatusCode::FORBIDDEN,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
@@ -7,71 +7,105 @@ use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 use warp::http::StatusCode;
 use warp::reply::Response;
-use warp::ws::WebSocket;
+use warp::ws::{Message, WebSocket};
 use warp::{Filter, Reply};
 
-use crate::application::{ApplicationService, JoinRoomError};
-use crate::domain::room::JoinRoomError as DomainJoinRoomError;
+use crate::application::{AdmissionError, ApplicationService, JoinRoomError};
+use crate::domain::room::{
+    AdmissionError as DomainAdmissionError, JoinRoomError as DomainJoinRoomError,
+};
+use crate::ports::http::warp::requests::JoinRoomInvite;
 use crate::ports::http::warp::{
-    with_application_service, with_user_client_provider, WsUserClientAdapter,
-    WsUserClientProviderAdapter,
+    acting_user, with_application_service, with_invite_link_signer, with_user_client_provider,
+    InviteLinkSigner, WsUserClientAdapter, WsUserClientProviderAdapter,
 };
 
 pub(crate) fn join_room_filter<AS>(
     application_service: Arc<AS>,
     user_client_provider: Arc<WsUserClientProviderAdapter>,
+    invite_link_signer: Arc<InviteLinkSigner>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
 where
     AS: ApplicationService + Send + Sync + 'static,
 {
-    warp::any()
-        .and(warp::header("user-id"))
-        .and(warp::path!(Uuid / "members"))
+    warp::path!(Uuid / "members")
+        .and(acting_user(application_service.clone()))
+        .and(warp::query::<JoinRoomInvite>())
         .and(warp::ws())
         .and(with_application_service(application_service))
         .and(with_user_client_provider(user_client_provider))
+        .and(with_invite_link_signer(invite_link_signer))
         .and_then(handler)
 }
 
 async fn handler<AS>(
-    user_id: Uuid,
     room_id: Uuid,
+    user_id: Uuid,
+    invite: JoinRoomInvite,
     ws: warp::ws::Ws,
     application_service: Arc<AS>,
     user_client_provider: Arc<WsUserClientProviderAdapter>,
+    invite_link_signer: Arc<InviteLinkSigner>,
 ) -> Result<impl Reply, Infallible>
 where
     AS: ApplicationService + Send + Sync + 'static,
 {
-    let connected_application_service = application_service.clone();
+    let invite_code = match invite_code(room_id, &invite, &invite_link_signer) {
+        Ok(invite_code) => invite_code,
+        Err(_) => {
+            return Ok(
+                warp::reply::with_status(warp::reply(), StatusCode::FORBIDDEN).into_response(),
+            )
+        }
+    };
+    if let Err(err) = application_service
+        .admit(room_id, user_id, invite_code)
+        .await
+    {
+        log::debug!("User({}) was not admitted to room: {}", user_id, err);
+        return Ok(admission_error_response(err));
+    }
+
+    let (tx, rx) = mpsc::channel(10);
+    let user_client = WsUserClientAdapter::new(user_id, room_id, Arc::new(tx));
+
+    // the client is registered before joining so that the user is notified of anything that happens
+    // in the room while the socket is upgraded
+    if let Err(err) = join_room(
+        &user_client,
+        application_service.clone(),
+        user_client_provider.clone(),
+    )
+    .await
+    {
+        return Ok(join_room_error_response(err));
+    }
+
     let reply = ws.on_upgrade(move |ws| {
         user_connected(
             ws,
-            connected_application_service,
+            rx,
+            user_client,
+            application_service,
             user_client_provider,
-            user_id,
         )
     });
 
-    // room join should happen after socket upgrade to avoid trying to interact with user before client is ready
-    if let Err(err) = join_room(user_id, application_service, room_id).await {
-        return Ok(join_room_error_response(err));
-    }
-
     Ok(reply.into_response())
 }
 
 async fn user_connected<AS>(
     ws: WebSocket,
+    rx: mpsc::Receiver<Result<Message, warp::Error>>,
+    user_client: WsUserClientAdapter,
     application_service: Arc<AS>,
     user_client_provider: Arc<WsUserClientProviderAdapter>,
-    user_id: Uuid,
 ) where
     AS: ApplicationService + Send + Sync,
 {
+    let user_id = user_client.user_id();
     let (user_ws_tx, mut user_ws_rx) = ws.split();
 
-    let (tx, rx) = mpsc::channel(10);
     let rx = ReceiverStream::new(rx);
     tokio::task::spawn(rx.forward(user_ws_tx).map(move |result| {
         if let Err(err) = result {
@@ -83,9 +117,6 @@ async fn user_connected<AS>(
         }
     }));
 
-    let user_client = WsUserClientAdapter::new(user_id, Arc::new(tx));
-    user_client_provider.put(user_client).await;
-
     while let Some(result) = user_ws_rx.next().await {
         match result {
             Ok(msg) => {
@@ -106,48 +137,104 @@ async fn user_connected<AS>(
         };
     }
 
-    user_disconnected(application_service, user_id, user_client_provider).await;
+    user_disconnected(application_service, user_client, user_client_provider).await;
 }
 
 async fn user_disconnected<AS>(
     application_service: Arc<AS>,
-    user_id: Uuid,
+    user_client: WsUserClientAdapter,
     user_client_provider: Arc<WsUserClientProviderAdapter>,
 ) where
     AS: ApplicationService + Send + Sync,
 {
-    if let Err(err) = application_service.leave_room(user_id).await {
-        log::warn!("Error while disconnecting user({}): {}", user_id, err);
+    let user_id = user_client.user_id();
+    let room_id = user_client.room_id();
+    let _connection_lock = user_client_provider.lock_connections(user_id).await;
+    if !user_client_provider.remove_if_current(&user_client).await {
+        log::debug!(
+            "User({}) has already reconnected to room({})",
+            user_id,
+            room_id
+        );
+        return;
+    }
+    // A dropped connection keeps the user's seat, which is only given up by leaving the room
+    if let Err(err) = application_service
+        .disconnect_from_room(room_id, user_id)
+        .await
+    {
+        log::warn!(
+            "Error while disconnecting user({}) from room({}): {}",
+            user_id,
+            room_id,
+            err
+        );
+    }
+}
+
+/// Takes the invite code from a signed invite link token if one was given, otherwise from the plain code
+fn invite_code(
+    room_id: Uuid,
+    invite: &JoinRoomInvite,
+    invite_link_signer: &InviteLinkSigner,
+) -> Result<Option<String>, InvalidInviteLinkError> {
+    match invite.token() {
+        Some(token) => match invite_link_signer.verify(token) {
+            Some((link_room_id, invite_code)) if link_room_id == room_id => Ok(Some(invite_code)),
+            _ => Err(InvalidInviteLinkError),
+        },
+        None => Ok(invite.code().map(str::to_string)),
     }
-    user_client_provider.remove(user_id).await;
+}
+
+#[derive(Debug)]
+struct InvalidInviteLinkError;
+
+fn admission_error_response(err: AdmissionError) -> Response {
+    let status_code = match err.cause() {
+        DomainAdmissionError::RoomNotFound(_) => StatusCode::NOT_FOUND,
+        DomainAdmissionError::InviteRequired(_)
+        | DomainAdmissionError::InvalidInvite(_)
+        | DomainAdmissionError::Banned(_) => StatusCode::FORBIDDEN,
+        DomainAdmissionError::RoomFull(_) => StatusCode::CONFLICT,
+    };
+    warp::reply::with_status(warp::reply(), status_code).into_response()
 }
 
 fn join_room_error_response(err: JoinRoomError) -> Response {
     let status_code = match err.cause() {
-        DomainJoinRoomError::AlreadyAssigned => StatusCode::CONFLICT,
+        DomainJoinRoomError::RoomFull(_) => StatusCode::CONFLICT,
         DomainJoinRoomError::UserNotFound(_) | DomainJoinRoomError::RoomNotFound(_) => {
             StatusCode::NOT_FOUND
         }
+        DomainJoinRoomError::Banned(_) => StatusCode::FORBIDDEN,
         DomainJoinRoomError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
     };
     warp::reply::with_status(warp::reply(), status_code).into_response()
 }
 
+/// Joins the room with a new connection, replacing any connection the user already has to it
 async fn join_room<AS>(
-    user_id: Uuid,
+    user_client: &WsUserClientAdapter,
     application_service: Arc<AS>,
-    room_id: Uuid,
+    user_client_provider: Arc<WsUserClientProviderAdapter>,
 ) -> Result<(), JoinRoomError>
 where
     AS: ApplicationService + Send + Sync + 'static,
 {
-    if let Err(join_err) = application_service.join_room(room_id, user_id).await {
-        log::debug!("User({}) failed to join room: {}", user_id, join_err);
-        if let Err(leave_err) = application_service.leave_room(user_id).await {
-            log::warn!("User({}) failed to leave room: {}", user_id, leave_err);
-        }
+    let user_id = user_client.user_id();
+    let room_id = user_client.room_id();
+    let _connection_lock = user_client_provider.lock_connections(user_id).await;
 
-        return Err(join_err);
+    if user_client_provider.is_connected(user_id, room_id).await {
+        log::debug!("User({}) is reconnecting to room({})", user_id, room_id);
+    }
+
+    user_client_provider.put(user_client).await;
+    if let Err(err) = application_service.join_room(room_id, user_id).await {
+        log::debug!("User({}) failed to join room: {}", user_id, err);
+        user_client_provider.remove_if_current(user_client).await;
+        return Err(err);
     }
 
     Ok(())
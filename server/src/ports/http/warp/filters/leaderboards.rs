@@ -0,0 +1,97 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, LeaderboardRankError};
+use crate::ports::http::warp::requests::{GetLeaderboard, GetLeaderboardRank};
+use crate::ports::http::warp::responses::{
+    LeaderboardEntryResponse, LeaderboardResponse, SimpleErrorResponse,
+};
+use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+
+pub(crate) fn leaderboard_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path::end())
+        .and(warp::query::<GetLeaderboard>())
+        .and(with_application_service(application_service))
+        .and_then(leaderboard_handler)
+}
+
+pub(crate) fn leaderboard_rank_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!("users" / Uuid))
+        .and(warp::query::<GetLeaderboardRank>())
+        .and(with_application_service(application_service))
+        .and_then(leaderboard_rank_handler)
+}
+
+async fn leaderboard_handler<AS>(
+    request: GetLeaderboard,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let page_request = match request.page_request() {
+        Ok(page_request) => page_request,
+        Err(err) => {
+            return Ok(json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::BAD_REQUEST,
+            ))
+        }
+    };
+
+    let variant = request.variant();
+    let page = application_service
+        .leaderboard(variant, request.order(), page_request)
+        .await;
+    Ok(json_reply_with_status(
+        &LeaderboardResponse::new(variant, &page),
+        StatusCode::OK,
+    ))
+}
+
+async fn leaderboard_rank_handler<AS>(
+    user_id: Uuid,
+    request: GetLeaderboardRank,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .leaderboard_rank(user_id, request.variant(), request.order())
+        .await
+    {
+        Ok(entry) => {
+            json_reply_with_status(&LeaderboardEntryResponse::from(&entry), StatusCode::OK)
+        }
+        Err(err) => leaderboard_rank_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn leaderboard_rank_error_response(err: LeaderboardRankError) -> Response {
+    let status_code = match err {
+        LeaderboardRankError::UserNotFound(_) | LeaderboardRankError::NotRanked(_) => {
+            StatusCode::NOT_FOUND
+        }
+    };
+    json_reply_with_status(&SimpleErrorResponse::new(err.to_string()), status_code)
+}
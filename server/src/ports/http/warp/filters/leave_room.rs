@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, LeaveRoomError};
+use crate::domain::room::LeaveRoomError as DomainLeaveRoomError;
+use crate::ports::http::warp::responses::SimpleErrorResponse;
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+/// Leaves a room for good, giving up any seat, unlike a dropped connection which only marks the
+/// member offline
+pub(crate) fn leave_room_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::delete()
+        .and(warp::path!(Uuid / "members"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    room_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.leave_room(room_id, user_id).await {
+        Ok(_) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => leave_room_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn leave_room_error_response(err: LeaveRoomError) -> Response {
+    let status_code = match err.cause() {
+        DomainLeaveRoomError::UserNotFound(_) | DomainLeaveRoomError::RoomNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainLeaveRoomError::UserNotInRoom(_) => StatusCode::NOT_ACCEPTABLE,
+        DomainLeaveRoomError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
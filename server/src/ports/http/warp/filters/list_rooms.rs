@@ -0,0 +1,51 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::ApplicationService;
+use crate::ports::http::warp::requests::{ListRooms, ListRoomsRequestError};
+use crate::ports::http::warp::responses::{RoomListResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+
+pub(crate) fn list_rooms_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::query::<ListRooms>())
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    request: ListRooms,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let query = match request.room_query() {
+        Ok(query) => query,
+        Err(err) => return Ok(list_rooms_request_error_response(err)),
+    };
+    let page_request = match request.page_request() {
+        Ok(page_request) => page_request,
+        Err(err) => return Ok(list_rooms_request_error_response(err)),
+    };
+
+    let rooms = application_service.list_rooms(query, page_request).await;
+    Ok(json_reply_with_status(
+        &RoomListResponse::from(&rooms),
+        StatusCode::OK,
+    ))
+}
+
+fn list_rooms_request_error_response(err: ListRoomsRequestError) -> Response {
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, StatusCode::BAD_REQUEST)
+}
@@ -10,7 +10,7 @@ use crate::application::ApplicationService;
 use crate::domain::room::GameMoveError;
 use crate::ports::http::warp::requests::{application_game_move, GameMove, GameMoveRequestError};
 use crate::ports::http::warp::responses::SimpleErrorResponse;
-use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
 
 pub(crate) fn make_game_move<AS>(
     application_service: Arc<AS>,
@@ -19,7 +19,7 @@ where
     AS: ApplicationService + Send + Sync + 'static,
 {
     warp::post()
-        .and(warp::header("user-id"))
+        .and(acting_user(application_service.clone()))
         .and(warp::header("room-id"))
         .and(json_body())
         .and(with_application_service(application_service))
@@ -62,7 +62,11 @@ fn make_game_move_error_response(err: crate::application::GameMoveError) -> Resp
         | GameMoveError::UserNotInRoom(_)
         | GameMoveError::UserNotPlayer(_)
         | GameMoveError::GamePlayError(_) => StatusCode::NOT_ACCEPTABLE,
-        GameMoveError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        GameMoveError::UserIsSpectator(_) => StatusCode::FORBIDDEN,
+        GameMoveError::OutOfTime(_) => StatusCode::CONFLICT,
+        GameMoveError::GameNotFound(_) | GameMoveError::IllegalLifecycleTransition(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
     };
 
     let error_body = SimpleErrorResponse::new(err.to_string());
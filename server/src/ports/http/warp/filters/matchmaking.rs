@@ -0,0 +1,194 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use futures_util::{FutureExt, StreamExt};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::ws::{Message, WebSocket};
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, MatchmakingError};
+use crate::domain::matchmaking::MatchPreferences;
+use crate::ports::http::warp::requests::EnterMatchmaking;
+use crate::ports::http::warp::responses::SimpleErrorResponse;
+use crate::ports::http::warp::{
+    acting_user, json_reply_with_status, with_application_service,
+    with_matchmaking_client_provider, WsMatchmakingClientAdapter,
+    WsMatchmakingClientProviderAdapter,
+};
+
+pub(crate) fn matchmaking_filter<AS>(
+    application_service: Arc<AS>,
+    matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::any()
+        .and(acting_user(application_service.clone()))
+        .and(warp::query::<EnterMatchmaking>())
+        .and(warp::ws())
+        .and(with_application_service(application_service))
+        .and(with_matchmaking_client_provider(
+            matchmaking_client_provider,
+        ))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    user_id: Uuid,
+    request: EnterMatchmaking,
+    ws: warp::ws::Ws,
+    application_service: Arc<AS>,
+    matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let preferences = match request.preferences() {
+        Ok(preferences) => preferences,
+        Err(err) => {
+            let error_body = SimpleErrorResponse::new(err.to_string());
+            return Ok(json_reply_with_status(&error_body, StatusCode::BAD_REQUEST));
+        }
+    };
+
+    let (tx, rx) = mpsc::channel(10);
+    let matchmaking_client = WsMatchmakingClientAdapter::new(user_id, Arc::new(tx));
+
+    // the client is registered before queueing so that the user is told about a match that is made
+    // straight away
+    if let Err(err) = enter_matchmaking(
+        &matchmaking_client,
+        preferences,
+        application_service.clone(),
+        matchmaking_client_provider.clone(),
+    )
+    .await
+    {
+        return Ok(err);
+    }
+
+    let reply = ws.on_upgrade(move |ws| {
+        user_connected(
+            ws,
+            rx,
+            matchmaking_client,
+            application_service,
+            matchmaking_client_provider,
+        )
+    });
+
+    Ok(reply.into_response())
+}
+
+/// Queues the user with a new connection, refusing them if they are already waiting on another one
+async fn enter_matchmaking<AS>(
+    matchmaking_client: &WsMatchmakingClientAdapter,
+    preferences: MatchPreferences,
+    application_service: Arc<AS>,
+    matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+) -> Result<(), Response>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let user_id = matchmaking_client.user_id();
+    let _connection_lock = matchmaking_client_provider.lock_connections(user_id).await;
+
+    if matchmaking_client_provider.is_connected(user_id).await {
+        log::debug!("User({}) is already connected to matchmaking", user_id);
+        return Err(warp::reply::with_status(warp::reply(), StatusCode::CONFLICT).into_response());
+    }
+
+    matchmaking_client_provider.put(matchmaking_client).await;
+    if let Err(err) = application_service
+        .enter_matchmaking(user_id, preferences)
+        .await
+    {
+        log::debug!("User({}) failed to enter matchmaking: {}", user_id, err);
+        matchmaking_client_provider
+            .remove_if_current(matchmaking_client)
+            .await;
+        return Err(matchmaking_error_response(err));
+    }
+
+    Ok(())
+}
+
+async fn user_connected<AS>(
+    ws: WebSocket,
+    rx: mpsc::Receiver<Result<Message, warp::Error>>,
+    matchmaking_client: WsMatchmakingClientAdapter,
+    application_service: Arc<AS>,
+    matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+) where
+    AS: ApplicationService + Send + Sync,
+{
+    let user_id = matchmaking_client.user_id();
+    let (user_ws_tx, mut user_ws_rx) = ws.split();
+
+    let rx = ReceiverStream::new(rx);
+    tokio::task::spawn(rx.forward(user_ws_tx).map(move |result| {
+        if let Err(err) = result {
+            log::warn!(
+                "Error while sending WebSocket message to user({}): {}",
+                user_id,
+                err
+            );
+        }
+    }));
+
+    while let Some(result) = user_ws_rx.next().await {
+        match result {
+            Ok(msg) if msg.is_close() => break,
+            Ok(_) => {}
+            Err(err) => {
+                log::warn!(
+                    "WebSocket error while reading message from user({}): {}",
+                    user_id,
+                    err
+                );
+                break;
+            }
+        };
+    }
+
+    user_disconnected(
+        application_service,
+        matchmaking_client,
+        matchmaking_client_provider,
+    )
+    .await;
+}
+
+async fn user_disconnected<AS>(
+    application_service: Arc<AS>,
+    matchmaking_client: WsMatchmakingClientAdapter,
+    matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+) where
+    AS: ApplicationService + Send + Sync,
+{
+    let user_id = matchmaking_client.user_id();
+    let _connection_lock = matchmaking_client_provider.lock_connections(user_id).await;
+    if matchmaking_client_provider
+        .remove_if_current(&matchmaking_client)
+        .await
+        && application_service.leave_matchmaking(user_id).await
+    {
+        log::debug!("User({}) left matchmaking", user_id);
+    }
+}
+
+fn matchmaking_error_response(err: MatchmakingError) -> Response {
+    let status_code = match err {
+        MatchmakingError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        MatchmakingError::AlreadyQueued(_) => StatusCode::CONFLICT,
+        MatchmakingError::StoreRoom(_) | MatchmakingError::NewGame(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    warp::reply::with_status(warp::reply(), status_code).into_response()
+}
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use warp::Filter;
+
+use crate::ports::http::warp::WsMatchmakingClientProviderAdapter;
+
+pub(crate) fn with_matchmaking_client_provider(
+    matchmaking_client_provider: Arc<WsMatchmakingClientProviderAdapter>,
+) -> impl Filter<
+    Extract = (Arc<WsMatchmakingClientProviderAdapter>,),
+    Error = std::convert::Infallible,
+> + Clone {
+    warp::any().map(move || matchmaking_client_provider.clone())
+}
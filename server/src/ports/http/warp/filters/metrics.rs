@@ -0,0 +1,25 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::ports::http::warp::json_reply_with_status;
+use crate::ports::http::warp::responses::MetricsResponse;
+use crate::ports::room_reaper::RoomReaperMetrics;
+
+pub(crate) fn metrics_filter(
+    room_reaper_metrics: Arc<RoomReaperMetrics>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::get()
+        .and(warp::any().map(move || room_reaper_metrics.clone()))
+        .and_then(handler)
+}
+
+async fn handler(room_reaper_metrics: Arc<RoomReaperMetrics>) -> Result<Response, Infallible> {
+    Ok(json_reply_with_status(
+        &MetricsResponse::new(&room_reaper_metrics),
+        StatusCode::OK,
+    ))
+}
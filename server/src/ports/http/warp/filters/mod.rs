@@ -1,23 +1,73 @@
+pub(crate) use abandon_game::*;
+pub(crate) use accounts::*;
 pub(crate) use app_status::*;
 pub(crate) use application_service::with_application_service;
 pub(crate) use become_player::*;
+pub(crate) use challenge_client_provider::*;
+pub(crate) use challenge_notifications::*;
+pub(crate) use challenges::*;
 pub(crate) use create_room::*;
+pub(crate) use game_history::*;
 pub(crate) use get_user_name::*;
+pub(crate) use guests::*;
+pub(crate) use invite_link_signer::*;
+pub(crate) use invites::*;
 pub(crate) use join_room::*;
+pub(crate) use leaderboards::*;
+pub(crate) use leave_room::*;
+pub(crate) use list_rooms::*;
 pub(crate) use make_game_moves::*;
+pub(crate) use matchmaking::*;
+pub(crate) use matchmaking_client_provider::*;
+pub(crate) use metrics::*;
+pub(crate) use moderate_members::*;
 pub(crate) use percent_decoded::*;
 pub(crate) use register_user::*;
+pub(crate) use request_rematch::*;
+pub(crate) use room_details::*;
+pub(crate) use room_role::*;
+pub(crate) use room_settings::*;
+pub(crate) use series::*;
 pub(crate) use start_new_game::*;
+pub(crate) use tournaments::*;
 pub(crate) use user_client_provider::*;
+pub(crate) use user_data::*;
+pub(crate) use user_profile::*;
+pub(crate) use user_stats::*;
 
+mod abandon_game;
+mod accounts;
 mod app_status;
 mod application_service;
 mod become_player;
+mod challenge_client_provider;
+mod challenge_notifications;
+mod challenges;
 mod create_room;
+mod game_history;
 mod get_user_name;
+mod guests;
+mod invite_link_signer;
+mod invites;
 mod join_room;
+mod leaderboards;
+mod leave_room;
+mod list_rooms;
 mod make_game_moves;
+mod matchmaking;
+mod matchmaking_client_provider;
+mod metrics;
+mod moderate_members;
 mod percent_decoded;
 mod register_user;
+mod request_rematch;
+mod room_details;
+mod room_role;
+mod room_settings;
+mod series;
 mod start_new_game;
+mod tournaments;
 mod user_client_provider;
+mod user_data;
+mod user_profile;
+mod user_stats;
@@ -0,0 +1,118 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, ModerateMemberError};
+use crate::domain::room::ModerateMemberError as DomainModerateMemberError;
+use crate::ports::http::warp::responses::SimpleErrorResponse;
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn kick_member_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::delete()
+        .and(warp::path!(Uuid / "members" / Uuid))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(kick_member_handler)
+}
+
+pub(crate) fn ban_user_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::put()
+        .and(warp::path!(Uuid / "bans" / Uuid))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(ban_user_handler)
+}
+
+pub(crate) fn unban_user_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::delete()
+        .and(warp::path!(Uuid / "bans" / Uuid))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(unban_user_handler)
+}
+
+async fn kick_member_handler<AS>(
+    room_id: Uuid,
+    member_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let result = application_service
+        .kick_member(room_id, user_id, member_id)
+        .await;
+    Ok(moderate_member_response(result))
+}
+
+async fn ban_user_handler<AS>(
+    room_id: Uuid,
+    banned_user_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let result = application_service
+        .ban_user(room_id, user_id, banned_user_id)
+        .await;
+    Ok(moderate_member_response(result))
+}
+
+async fn unban_user_handler<AS>(
+    room_id: Uuid,
+    banned_user_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let result = application_service
+        .unban_user(room_id, user_id, banned_user_id)
+        .await;
+    Ok(moderate_member_response(result))
+}
+
+fn moderate_member_response(result: Result<(), ModerateMemberError>) -> Response {
+    match result {
+        Ok(_) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => moderate_member_error_response(err),
+    }
+}
+
+fn moderate_member_error_response(err: ModerateMemberError) -> Response {
+    let status_code = match err.cause() {
+        DomainModerateMemberError::UserNotFound(_) | DomainModerateMemberError::RoomNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainModerateMemberError::NotRoomHost(_) => StatusCode::FORBIDDEN,
+        DomainModerateMemberError::UserNotInRoom(_) => StatusCode::NOT_ACCEPTABLE,
+        DomainModerateMemberError::CannotModerateSelf(_) => StatusCode::BAD_REQUEST,
+        DomainModerateMemberError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
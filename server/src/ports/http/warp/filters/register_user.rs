@@ -7,7 +7,7 @@ use warp::Filter;
 
 use crate::application::{ApplicationService, RegisterUserError};
 use crate::domain::user::StoreUserError;
-use crate::ports::http::warp::responses::RegisterUserResponse;
+use crate::ports::http::warp::responses::{RegisterUserResponse, SimpleErrorResponse};
 use crate::ports::http::warp::{json_reply_with_status, with_application_service, PercentDecoded};
 
 pub(crate) fn register_user_filter<AS>(
@@ -39,7 +39,14 @@ async fn register_user_handler<AS: ApplicationService>(
 }
 
 fn register_user_error_response(err: RegisterUserError) -> Response {
-    match err.cause() {
-        StoreUserError::AlreadyExists(_) => unimplemented!(),
-    }
+    let status_code = match &err {
+        RegisterUserError::InvalidName(_) => StatusCode::BAD_REQUEST,
+        RegisterUserError::Store(StoreUserError::UsernameTaken(_)) => StatusCode::CONFLICT,
+        RegisterUserError::Store(StoreUserError::AlreadyExists(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
 }
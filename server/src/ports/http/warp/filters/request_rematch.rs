@@ -0,0 +1,65 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, RematchError};
+use crate::domain::room::RematchError as DomainRematchError;
+use crate::ports::http::warp::responses::{RematchResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn request_rematch_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(acting_user(application_service.clone()))
+        .and(warp::header("room-id"))
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    user_id: Uuid,
+    room_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<impl Reply, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.request_rematch(room_id, user_id).await {
+        Ok(Some(game_id)) => {
+            json_reply_with_status(&RematchResponse::new(game_id), StatusCode::CREATED)
+                .into_response()
+        }
+        Ok(None) => warp::reply::with_status(warp::reply(), StatusCode::ACCEPTED).into_response(),
+        Err(err) => request_rematch_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn request_rematch_error_response(err: RematchError) -> Response {
+    let status_code = match err.cause() {
+        DomainRematchError::UserNotFound(_) | DomainRematchError::RoomNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainRematchError::UserNotInRoom(_) | DomainRematchError::UserNotPlayer(_) => {
+            StatusCode::NOT_ACCEPTABLE
+        }
+        DomainRematchError::NoFinishedGame(_) | DomainRematchError::SeriesFinished(_) => {
+            StatusCode::CONFLICT
+        }
+        DomainRematchError::GameNotFound(_)
+        | DomainRematchError::IllegalLifecycleTransition(_)
+        | DomainRematchError::NewGame(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code).into_response()
+}
@@ -0,0 +1,45 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, RoomDetailsError};
+use crate::ports::http::warp::responses::{RoomDetailsResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+
+pub(crate) fn room_details_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!(Uuid))
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(room_id: Uuid, application_service: Arc<AS>) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.room_details(room_id).await {
+        Ok(details) => json_reply_with_status(&RoomDetailsResponse::from(&details), StatusCode::OK),
+        Err(err) => room_details_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn room_details_error_response(err: RoomDetailsError) -> Response {
+    let status_code = match err {
+        RoomDetailsError::RoomNotFound(_) => StatusCode::NOT_FOUND,
+        RoomDetailsError::MemberNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
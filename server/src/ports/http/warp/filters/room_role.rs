@@ -0,0 +1,65 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, ChangeRoomRoleError};
+use crate::domain::room::ChangeRoleError as DomainChangeRoleError;
+use crate::ports::http::warp::requests::ChangeRoomRole;
+use crate::ports::http::warp::responses::SimpleErrorResponse;
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn change_room_role_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::put()
+        .and(warp::path!(Uuid / "role"))
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    room_id: Uuid,
+    user_id: Uuid,
+    request: ChangeRoomRole,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .change_room_role(room_id, user_id, request.role())
+        .await
+    {
+        Ok(()) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => change_room_role_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn change_room_role_error_response(err: ChangeRoomRoleError) -> Response {
+    let status_code = match err.cause() {
+        DomainChangeRoleError::UserNotFound(_) | DomainChangeRoleError::RoomNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainChangeRoleError::UserNotInRoom(_) => StatusCode::NOT_ACCEPTABLE,
+        DomainChangeRoleError::SpectatorsNotAllowed(_) => StatusCode::CONFLICT,
+        DomainChangeRoleError::GameNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn json_body() -> impl Filter<Extract = (ChangeRoomRole,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
@@ -0,0 +1,66 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, UpdateRoomSettingsError};
+use crate::domain::room::{RoomSettingsUpdate, UpdateSettingsError as DomainUpdateSettingsError};
+use crate::ports::http::warp::requests::UpdateRoomSettings;
+use crate::ports::http::warp::responses::{RoomSettingsResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn update_room_settings_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::patch()
+        .and(warp::path!(Uuid / "settings"))
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(handler)
+}
+
+async fn handler<AS>(
+    room_id: Uuid,
+    user_id: Uuid,
+    request: UpdateRoomSettings,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .update_room_settings(room_id, user_id, RoomSettingsUpdate::from(&request))
+        .await
+    {
+        Ok(settings) => {
+            json_reply_with_status(&RoomSettingsResponse::from(settings), StatusCode::OK)
+        }
+        Err(err) => update_room_settings_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn update_room_settings_error_response(err: UpdateRoomSettingsError) -> Response {
+    let status_code = match err.cause() {
+        DomainUpdateSettingsError::UserNotFound(_) | DomainUpdateSettingsError::RoomNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DomainUpdateSettingsError::NotRoomHost(_) => StatusCode::FORBIDDEN,
+        DomainUpdateSettingsError::InvalidSettings(_) => StatusCode::BAD_REQUEST,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn json_body() -> impl Filter<Extract = (UpdateRoomSettings,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
@@ -0,0 +1,106 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, ConfigureSeriesError, GetSeriesError};
+use crate::domain::room::{
+    ConfigureSeriesError as DomainConfigureSeriesError, GetSeriesError as DomainGetSeriesError,
+};
+use crate::ports::http::warp::requests::ConfigureSeries;
+use crate::ports::http::warp::responses::{SeriesResponse, SimpleErrorResponse};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn configure_series_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::put()
+        .and(warp::path!(Uuid / "series"))
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(configure_series_handler)
+}
+
+pub(crate) fn get_series_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!(Uuid / "series"))
+        .and(with_application_service(application_service))
+        .and_then(get_series_handler)
+}
+
+async fn configure_series_handler<AS>(
+    room_id: Uuid,
+    user_id: Uuid,
+    request: ConfigureSeries,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .configure_series(room_id, user_id, request.best_of())
+        .await
+    {
+        Ok(_) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => configure_series_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn get_series_handler<AS>(
+    room_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.series(room_id).await {
+        Ok(series) => json_reply_with_status(&SeriesResponse::from(&series), StatusCode::OK),
+        Err(err) => get_series_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn configure_series_error_response(err: ConfigureSeriesError) -> Response {
+    let status_code = match err.cause() {
+        DomainConfigureSeriesError::UserNotFound(_)
+        | DomainConfigureSeriesError::RoomNotFound(_) => StatusCode::NOT_FOUND,
+        DomainConfigureSeriesError::UserNotInRoom(_) => StatusCode::NOT_ACCEPTABLE,
+        DomainConfigureSeriesError::NotRoomHost(_) => StatusCode::FORBIDDEN,
+        DomainConfigureSeriesError::GameInProgress(_)
+        | DomainConfigureSeriesError::SeriesInProgress(_) => StatusCode::CONFLICT,
+        DomainConfigureSeriesError::InvalidSeriesLength(_) => StatusCode::BAD_REQUEST,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn get_series_error_response(err: GetSeriesError) -> Response {
+    let status_code = match err.cause() {
+        DomainGetSeriesError::RoomNotFound(_) | DomainGetSeriesError::NoSeriesInRoom(_) => {
+            StatusCode::NOT_FOUND
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn json_body() -> impl Filter<Extract = (ConfigureSeries,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
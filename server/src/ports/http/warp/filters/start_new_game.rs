@@ -9,7 +9,7 @@ use warp::{Filter, Reply};
 use crate::application::{ApplicationService, NewGameError};
 use crate::domain::room::NewGameError as DomainNewGameError;
 use crate::ports::http::warp::responses::SimpleErrorResponse;
-use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
 
 pub(crate) fn start_new_game_filter<AS>(
     application_service: Arc<AS>,
@@ -18,7 +18,7 @@ where
     AS: ApplicationService + Send + Sync + 'static,
 {
     warp::post()
-        .and(warp::header("user-id"))
+        .and(acting_user(application_service.clone()))
         .and(warp::header("room-id"))
         .and(with_application_service(application_service))
         .and_then(handler)
@@ -46,6 +46,13 @@ fn new_game_error_response(err: NewGameError) -> Response {
             StatusCode::NOT_FOUND
         }
         DomainNewGameError::UserNotInRoom(_) => StatusCode::NOT_ACCEPTABLE,
+        DomainNewGameError::NotRoomHost(_) | DomainNewGameError::UserIsSpectator(_) => {
+            StatusCode::FORBIDDEN
+        }
+        DomainNewGameError::GameInProgress(_)
+        | DomainNewGameError::SeriesFinished(_)
+        | DomainNewGameError::SpectatorsNotAllowed(_) => StatusCode::CONFLICT,
+        DomainNewGameError::IllegalLifecycleTransition(_) => StatusCode::INTERNAL_SERVER_ERROR,
     };
     let error_body = SimpleErrorResponse::new(err.to_string());
     json_reply_with_status(&error_body, status_code).into_response()
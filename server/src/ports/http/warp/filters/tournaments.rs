@@ -0,0 +1,215 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{
+    ApplicationService, CreateTournamentError, StartTournamentError, TournamentRegistrationError,
+};
+use crate::domain::tournament::{
+    CreateTournamentError as DomainCreateTournamentError, GetTournamentError,
+    RegisterForTournamentError, StartTournamentError as DomainStartTournamentError,
+};
+use crate::ports::http::warp::requests::CreateTournament;
+use crate::ports::http::warp::responses::{SimpleErrorResponse, TournamentResponse};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn create_tournament_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path::end())
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(create_tournament_handler)
+}
+
+pub(crate) fn get_tournament_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::get()
+        .and(warp::path!(Uuid))
+        .and(with_application_service(application_service))
+        .and_then(get_tournament_handler)
+}
+
+pub(crate) fn register_for_tournament_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path!(Uuid / "participants"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(register_for_tournament_handler)
+}
+
+pub(crate) fn start_tournament_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    warp::post()
+        .and(warp::path!(Uuid / "start"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(start_tournament_handler)
+}
+
+async fn create_tournament_handler<AS>(
+    user_id: Uuid,
+    request: CreateTournament,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let name = match request.name() {
+        Ok(name) => name,
+        Err(err) => return Ok(bad_request_response(err.to_string())),
+    };
+    let settings = match request.settings() {
+        Ok(settings) => settings,
+        Err(err) => return Ok(bad_request_response(err.to_string())),
+    };
+
+    let response = match application_service
+        .create_tournament(user_id, name, settings)
+        .await
+    {
+        Ok(tournament) => {
+            json_reply_with_status(&TournamentResponse::from(&tournament), StatusCode::CREATED)
+        }
+        Err(err) => create_tournament_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn get_tournament_handler<AS>(
+    tournament_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service.tournament(tournament_id).await {
+        Ok(tournament) => {
+            json_reply_with_status(&TournamentResponse::from(&tournament), StatusCode::OK)
+        }
+        Err(err) => match err {
+            GetTournamentError::NotFound(_) => json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        },
+    };
+
+    Ok(response)
+}
+
+async fn register_for_tournament_handler<AS>(
+    tournament_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .register_for_tournament(tournament_id, user_id)
+        .await
+    {
+        Ok(tournament) => {
+            json_reply_with_status(&TournamentResponse::from(&tournament), StatusCode::OK)
+        }
+        Err(err) => register_for_tournament_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn start_tournament_handler<AS>(
+    tournament_id: Uuid,
+    user_id: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    let response = match application_service
+        .start_tournament(tournament_id, user_id)
+        .await
+    {
+        Ok(tournament) => {
+            json_reply_with_status(&TournamentResponse::from(&tournament), StatusCode::OK)
+        }
+        Err(err) => start_tournament_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn bad_request_response(cause: String) -> Response {
+    json_reply_with_status(&SimpleErrorResponse::new(cause), StatusCode::BAD_REQUEST)
+}
+
+fn create_tournament_error_response(err: CreateTournamentError) -> Response {
+    let status_code = match &err {
+        CreateTournamentError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        CreateTournamentError::Create(DomainCreateTournamentError::InvalidSeriesLength(_)) => {
+            StatusCode::BAD_REQUEST
+        }
+        CreateTournamentError::Create(DomainCreateTournamentError::Store(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn register_for_tournament_error_response(err: TournamentRegistrationError) -> Response {
+    let status_code = match &err {
+        TournamentRegistrationError::UserNotFound(_)
+        | TournamentRegistrationError::Register(RegisterForTournamentError::TournamentNotFound(
+            _,
+        )) => StatusCode::NOT_FOUND,
+        TournamentRegistrationError::Register(
+            RegisterForTournamentError::RegistrationClosed(_)
+            | RegisterForTournamentError::AlreadyRegistered(_),
+        ) => StatusCode::CONFLICT,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn start_tournament_error_response(err: StartTournamentError) -> Response {
+    let status_code = match err.cause() {
+        DomainStartTournamentError::TournamentNotFound(_) => StatusCode::NOT_FOUND,
+        DomainStartTournamentError::NotOrganiser(_) => StatusCode::FORBIDDEN,
+        DomainStartTournamentError::AlreadyStarted(_)
+        | DomainStartTournamentError::NotEnoughParticipants(_) => StatusCode::CONFLICT,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn json_body() -> impl Filter<Extract = (CreateTournament,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
@@ -0,0 +1,89 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+use crate::application::{ApplicationService, DeleteUserError, ExportUserDataError};
+use crate::ports::http::warp::responses::{SimpleErrorResponse, UserDataExportResponse};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn export_user_data_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::get()
+        .and(warp::path!(Uuid / "export"))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(export_user_data_handler)
+}
+
+pub(crate) fn delete_user_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::delete()
+        .and(warp::path!(Uuid))
+        .and(acting_user(application_service.clone()))
+        .and(with_application_service(application_service))
+        .and_then(delete_user_handler)
+}
+
+async fn export_user_data_handler<AS: ApplicationService>(
+    user_id: Uuid,
+    requester: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service
+        .export_user_data(user_id, requester)
+        .await
+    {
+        Ok(export) => {
+            json_reply_with_status(&UserDataExportResponse::from(&export), StatusCode::OK)
+        }
+        Err(err) => export_user_data_error_response(err),
+    };
+
+    Ok(response)
+}
+
+async fn delete_user_handler<AS: ApplicationService>(
+    user_id: Uuid,
+    requester: Uuid,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service.delete_user(user_id, requester).await {
+        Ok(_) => warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT).into_response(),
+        Err(err) => delete_user_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn export_user_data_error_response(err: ExportUserDataError) -> Response {
+    let status_code = match err {
+        ExportUserDataError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        ExportUserDataError::NotAccountOwner(_) => StatusCode::FORBIDDEN,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn delete_user_error_response(err: DeleteUserError) -> Response {
+    let status_code = match err {
+        DeleteUserError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        DeleteUserError::NotAccountOwner(_) => StatusCode::FORBIDDEN,
+        DeleteUserError::InRooms(_) => StatusCode::CONFLICT,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
@@ -0,0 +1,88 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::{ApplicationService, UpdateUserProfileError};
+use crate::domain::user::{GetUserError, UserProfileUpdate};
+use crate::ports::http::warp::requests::UpdateUserProfile;
+use crate::ports::http::warp::responses::{SimpleErrorResponse, UserProfileResponse};
+use crate::ports::http::warp::{acting_user, json_reply_with_status, with_application_service};
+
+pub(crate) fn user_profile_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::get()
+        .and(with_application_service(application_service))
+        .and(warp::path!(Uuid / "profile"))
+        .and_then(user_profile_handler)
+}
+
+async fn user_profile_handler<AS: ApplicationService>(
+    application_service: Arc<AS>,
+    user_id: Uuid,
+) -> Result<Response, Infallible> {
+    let response = match application_service.user_profile(user_id).await {
+        Ok(user) => json_reply_with_status(&UserProfileResponse::from(&user), StatusCode::OK),
+        Err(err) => match err {
+            GetUserError::NotFound(_) => json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        },
+    };
+
+    Ok(response)
+}
+
+pub(crate) fn update_user_profile_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::patch()
+        .and(warp::path!(Uuid))
+        .and(acting_user(application_service.clone()))
+        .and(json_body())
+        .and(with_application_service(application_service))
+        .and_then(update_user_profile_handler)
+}
+
+async fn update_user_profile_handler<AS: ApplicationService>(
+    user_id: Uuid,
+    requester: Uuid,
+    request: UpdateUserProfile,
+    application_service: Arc<AS>,
+) -> Result<Response, Infallible> {
+    let response = match application_service
+        .update_user_profile(user_id, requester, UserProfileUpdate::from(request))
+        .await
+    {
+        Ok(user) => json_reply_with_status(&UserProfileResponse::from(&user), StatusCode::OK),
+        Err(err) => update_user_profile_error_response(err),
+    };
+
+    Ok(response)
+}
+
+fn update_user_profile_error_response(err: UpdateUserProfileError) -> Response {
+    let status_code = match err {
+        UpdateUserProfileError::UserNotFound(_) => StatusCode::NOT_FOUND,
+        UpdateUserProfileError::NotAccountOwner(_) => StatusCode::FORBIDDEN,
+        UpdateUserProfileError::InvalidProfile(_) => StatusCode::BAD_REQUEST,
+    };
+
+    let error_body = SimpleErrorResponse::new(err.to_string());
+    json_reply_with_status(&error_body, status_code)
+}
+
+fn json_body() -> impl Filter<Extract = (UpdateUserProfile,), Error = warp::Rejection> + Clone {
+    warp::body::content_length_limit(1024 * 16).and(warp::body::json())
+}
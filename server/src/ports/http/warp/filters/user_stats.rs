@@ -0,0 +1,43 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use uuid::Uuid;
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::Filter;
+
+use crate::application::ApplicationService;
+use crate::domain::user::GetUserError;
+use crate::ports::http::warp::responses::{SimpleErrorResponse, UserStatsResponse};
+use crate::ports::http::warp::{json_reply_with_status, with_application_service};
+
+pub(crate) fn user_stats_filter<AS>(
+    application_service: Arc<AS>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+where
+    AS: ApplicationService + Send + Sync,
+{
+    warp::get()
+        .and(with_application_service(application_service))
+        .and(warp::path!(Uuid / "stats"))
+        .and_then(user_stats_handler)
+}
+
+async fn user_stats_handler<AS: ApplicationService>(
+    application_service: Arc<AS>,
+    user_id: Uuid,
+) -> Result<Response, Infallible> {
+    let response = match application_service.user_stats(user_id).await {
+        Ok(stats) => {
+            json_reply_with_status(&UserStatsResponse::new(user_id, &stats), StatusCode::OK)
+        }
+        Err(err) => match err {
+            GetUserError::NotFound(_) => json_reply_with_status(
+                &SimpleErrorResponse::new(err.to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+        },
+    };
+
+    Ok(response)
+}
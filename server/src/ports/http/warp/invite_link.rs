@@ -0,0 +1,77 @@
+use hmac::{Hmac, Mac, NewMac};
+use rand::RngCore;
+use sha2::Sha256;
+use uuid::Uuid;
+
+const INVITE_LINK_SECRET_ENV_VAR: &str = "INVITE_LINK_SECRET";
+const GENERATED_SECRET_LENGTH: usize = 32;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs invite links so that the room and invite code they carry cannot be tampered with
+pub(crate) struct InviteLinkSigner {
+    secret: Vec<u8>,
+}
+
+impl InviteLinkSigner {
+    pub(crate) fn new(secret: Vec<u8>) -> Self {
+        InviteLinkSigner { secret }
+    }
+
+    /// Uses the secret from the environment, or a random one when unset so links do not survive a restart
+    pub(crate) fn from_env() -> Self {
+        match std::env::var(INVITE_LINK_SECRET_ENV_VAR) {
+            Ok(secret) => InviteLinkSigner::new(secret.into_bytes()),
+            Err(_) => {
+                log::info!(
+                    "{} is not set, invite links will be signed with a generated secret",
+                    INVITE_LINK_SECRET_ENV_VAR
+                );
+                let mut secret = vec![0; GENERATED_SECRET_LENGTH];
+                rand::thread_rng().fill_bytes(&mut secret);
+                InviteLinkSigner::new(secret)
+            }
+        }
+    }
+
+    pub(crate) fn link(&self, room_id: Uuid, invite_code: &str) -> String {
+        format!(
+            "/game/rooms/{}/members?token={}",
+            room_id,
+            self.sign(room_id, invite_code)
+        )
+    }
+
+    pub(crate) fn sign(&self, room_id: Uuid, invite_code: &str) -> String {
+        let payload = Self::payload(room_id, invite_code);
+        let signature = self.mac(payload.as_bytes()).finalize().into_bytes();
+        format!(
+            "{}.{}",
+            base64::encode_config(payload, base64::URL_SAFE_NO_PAD),
+            base64::encode_config(signature, base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    /// Returns the room id and invite code carried by a token if its signature is valid
+    pub(crate) fn verify(&self, token: &str) -> Option<(Uuid, String)> {
+        let (encoded_payload, encoded_signature) = token.split_once('.')?;
+        let payload = base64::decode_config(encoded_payload, base64::URL_SAFE_NO_PAD).ok()?;
+        let signature = base64::decode_config(encoded_signature, base64::URL_SAFE_NO_PAD).ok()?;
+        self.mac(&payload).verify(&signature).ok()?;
+
+        let payload = String::from_utf8(payload).ok()?;
+        let (room_id, invite_code) = payload.split_once(':')?;
+        Some((Uuid::parse_str(room_id).ok()?, invite_code.to_string()))
+    }
+
+    fn payload(room_id: Uuid, invite_code: &str) -> String {
+        format!("{}:{}", room_id, invite_code)
+    }
+
+    fn mac(&self, payload: &[u8]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_varkey(&self.secret).expect("HMAC can take a key of any size");
+        mac.update(payload);
+        mac
+    }
+}
@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::domain::matchmaking::MatchmakingEvent;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum MatchmakingEventMessage {
+    Queued {
+        estimated_wait_seconds: Option<i64>,
+    },
+    MatchFound {
+        room_id: Uuid,
+        game_id: Uuid,
+        opponent: Uuid,
+    },
+}
+
+impl From<&MatchmakingEvent> for MatchmakingEventMessage {
+    fn from(event: &MatchmakingEvent) -> Self {
+        match event.clone() {
+            MatchmakingEvent::Queued { estimated_wait } => MatchmakingEventMessage::Queued {
+                estimated_wait_seconds: estimated_wait.map(|waited| waited.num_seconds()),
+            },
+            MatchmakingEvent::MatchFound {
+                room_id,
+                game_id,
+                opponent,
+            } => MatchmakingEventMessage::MatchFound {
+                room_id,
+                game_id,
+                opponent,
+            },
+        }
+    }
+}
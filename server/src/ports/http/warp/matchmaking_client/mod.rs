@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc::Sender;
+use uuid::Uuid;
+use warp::ws::Message;
+
+pub(crate) use matchmaking_event_message::*;
+pub(crate) use ws_matchmaking_client_provider_adapter::*;
+
+use crate::domain::matchmaking::{MatchmakingClient, MatchmakingEvent};
+use crate::domain::user::UserClientError;
+
+mod matchmaking_event_message;
+mod ws_matchmaking_client_provider_adapter;
+
+pub(crate) struct WsMatchmakingClientAdapter {
+    user_id: Uuid,
+    transmitter: Arc<Sender<Result<Message, warp::Error>>>,
+}
+
+impl WsMatchmakingClientAdapter {
+    pub(crate) fn new(
+        user_id: Uuid,
+        user_ws_tx: Arc<Sender<Result<Message, warp::Error>>>,
+    ) -> Self {
+        WsMatchmakingClientAdapter {
+            user_id,
+            transmitter: user_ws_tx,
+        }
+    }
+
+    pub(crate) fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub(crate) fn transmitter(&self) -> Arc<Sender<Result<Message, warp::Error>>> {
+        self.transmitter.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl MatchmakingClient for WsMatchmakingClientAdapter {
+    async fn notify(&self, event: &MatchmakingEvent) -> Result<(), UserClientError> {
+        let message = serde_json::to_string(&MatchmakingEventMessage::from(event))
+            .expect("Matchmaking event messages are always serializable");
+        self.transmitter
+            .send(Ok(Message::text(message)))
+            .await
+            .map_err(|_| UserClientError::Disconnected)
+    }
+}
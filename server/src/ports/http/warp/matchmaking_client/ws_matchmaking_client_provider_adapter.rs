@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::OwnedMutexGuard;
+use uuid::Uuid;
+use warp::ws::Message;
+
+use crate::domain::matchmaking::MatchmakingClientProvider;
+use crate::domain::user::UserClientProviderError;
+use crate::ports::http::warp::WsMatchmakingClientAdapter;
+
+type Transmitter = Arc<Sender<Result<Message, warp::Error>>>;
+type MatchmakingClientMap = Arc<Mutex<HashMap<Uuid, Transmitter>>>;
+type ConnectionLocks = Arc<Mutex<HashMap<Uuid, Arc<tokio::sync::Mutex<()>>>>>;
+
+/// Keeps the WebSocket connection each user is waiting for a match on
+pub(crate) struct WsMatchmakingClientProviderAdapter {
+    inner: MatchmakingClientMap,
+    connection_locks: ConnectionLocks,
+}
+
+impl WsMatchmakingClientProviderAdapter {
+    pub(crate) fn new() -> Self {
+        WsMatchmakingClientProviderAdapter {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+            connection_locks: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Serialises connecting and disconnecting a user so that a new connection is not dropped from
+    /// the queue by the previous one closing
+    pub(crate) async fn lock_connections(&self, user_id: Uuid) -> OwnedMutexGuard<()> {
+        let lock = self
+            .connection_locks
+            .lock()
+            .entry(user_id)
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
+
+    pub(crate) async fn is_connected(&self, user_id: Uuid) -> bool {
+        let map = self.inner.lock();
+        map.contains_key(&user_id)
+    }
+
+    pub(crate) async fn put(&self, matchmaking_client: &WsMatchmakingClientAdapter) {
+        let mut map = self.inner.lock();
+        map.insert(
+            matchmaking_client.user_id(),
+            matchmaking_client.transmitter(),
+        );
+    }
+
+    /// Removes the client unless it has already been replaced by a newer connection, returning
+    /// whether it was removed
+    pub(crate) async fn remove_if_current(
+        &self,
+        matchmaking_client: &WsMatchmakingClientAdapter,
+    ) -> bool {
+        let mut map = self.inner.lock();
+        let user_id = matchmaking_client.user_id();
+        let is_current = map
+            .get(&user_id)
+            .iter()
+            .any(|transmitter| Arc::ptr_eq(transmitter, &matchmaking_client.transmitter()));
+        if is_current {
+            map.remove(&user_id);
+        }
+        is_current
+    }
+}
+
+#[async_trait::async_trait]
+impl MatchmakingClientProvider for WsMatchmakingClientProviderAdapter {
+    type MatchmakingClient = WsMatchmakingClientAdapter;
+
+    async fn get(&self, user_id: Uuid) -> Result<Self::MatchmakingClient, UserClientProviderError> {
+        let map = self.inner.lock();
+        let transmitter = map
+            .get(&user_id)
+            .ok_or(UserClientProviderError::UserClientNotAvailable)?
+            .clone();
+        Ok(WsMatchmakingClientAdapter::new(user_id, transmitter))
+    }
+}
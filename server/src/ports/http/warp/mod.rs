@@ -2,10 +2,16 @@ use warp::http::StatusCode;
 use warp::reply::Response;
 use warp::Reply;
 
+pub(crate) use challenge_client::*;
 pub(crate) use filters::*;
+pub(crate) use invite_link::*;
+pub(crate) use matchmaking_client::*;
 pub(crate) use user_client::*;
 
+mod challenge_client;
 mod filters;
+mod invite_link;
+mod matchmaking_client;
 mod requests;
 mod responses;
 mod user_client;
@@ -0,0 +1,37 @@
+/// Left without a `Debug` implementation so that passwords cannot end up in logs
+#[derive(serde::Deserialize)]
+pub(crate) struct RegisterAccount {
+    username: String,
+    password: String,
+    name: Option<String>,
+}
+
+impl RegisterAccount {
+    pub(crate) fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub(crate) fn password(&self) -> &str {
+        &self.password
+    }
+
+    pub(crate) fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct Login {
+    username: String,
+    password: String,
+}
+
+impl Login {
+    pub(crate) fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub(crate) fn password(&self) -> &str {
+        &self.password
+    }
+}
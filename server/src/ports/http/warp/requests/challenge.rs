@@ -0,0 +1,48 @@
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::domain::matchmaking::MatchPreferences;
+use crate::ports::http::warp::requests::{
+    match_preferences, GameVariantRequest, MatchmakingRequestError,
+};
+
+/// How long a challenge stays open when the challenger does not say
+const DEFAULT_CHALLENGE_EXPIRY_SECONDS: u32 = 300;
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct CreateChallenge {
+    challenged: Uuid,
+    variant: Option<GameVariantRequest>,
+    initial_seconds: Option<u32>,
+    increment_seconds: Option<u32>,
+    expires_in_seconds: Option<u32>,
+}
+
+impl CreateChallenge {
+    pub(crate) fn challenged(&self) -> Uuid {
+        self.challenged
+    }
+
+    pub(crate) fn preferences(&self) -> Result<MatchPreferences, CreateChallengeRequestError> {
+        match_preferences(self.variant, self.initial_seconds, self.increment_seconds)
+            .map_err(CreateChallengeRequestError::from)
+    }
+
+    pub(crate) fn expires_in(&self) -> Result<Duration, CreateChallengeRequestError> {
+        match self
+            .expires_in_seconds
+            .unwrap_or(DEFAULT_CHALLENGE_EXPIRY_SECONDS)
+        {
+            0 => Err(CreateChallengeRequestError::Expiry),
+            seconds => Ok(Duration::seconds(seconds.into())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateChallengeRequestError {
+    #[error(transparent)]
+    Preferences(#[from] MatchmakingRequestError),
+    #[error("expires_in_seconds must be at least 1")]
+    Expiry,
+}
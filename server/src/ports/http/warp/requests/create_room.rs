@@ -0,0 +1,26 @@
+use crate::domain::room::{RoomSettings, RoomVisibility};
+use crate::ports::http::warp::requests::RoomSettingsRequest;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct CreateRoom {
+    #[serde(default)]
+    private: bool,
+    settings: Option<RoomSettingsRequest>,
+}
+
+impl CreateRoom {
+    pub(crate) fn visibility(&self) -> RoomVisibility {
+        if self.private {
+            RoomVisibility::Private
+        } else {
+            RoomVisibility::Public
+        }
+    }
+
+    pub(crate) fn settings(&self) -> RoomSettings {
+        self.settings
+            .as_ref()
+            .map(RoomSettings::from)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct UpgradeGuest {
+    name: String,
+}
+
+impl UpgradeGuest {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+}
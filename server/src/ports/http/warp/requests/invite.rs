@@ -0,0 +1,45 @@
+use chrono::Duration;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct CreateInvite {
+    #[serde(default)]
+    single_use: bool,
+    expires_in_seconds: Option<u32>,
+}
+
+impl CreateInvite {
+    pub(crate) fn single_use(&self) -> bool {
+        self.single_use
+    }
+
+    pub(crate) fn expires_in(&self) -> Result<Option<Duration>, CreateInviteRequestError> {
+        match self.expires_in_seconds {
+            Some(0) => Err(CreateInviteRequestError::Expiry),
+            Some(seconds) => Ok(Some(Duration::seconds(seconds.into()))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateInviteRequestError {
+    #[error("expires_in_seconds must be at least 1")]
+    Expiry,
+}
+
+/// Invite accepted when joining a room, either as a plain code or a signed invite link token
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct JoinRoomInvite {
+    invite: Option<String>,
+    token: Option<String>,
+}
+
+impl JoinRoomInvite {
+    pub(crate) fn code(&self) -> Option<&str> {
+        self.invite.as_deref()
+    }
+
+    pub(crate) fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+}
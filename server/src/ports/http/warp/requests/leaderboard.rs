@@ -0,0 +1,59 @@
+use crate::domain::game::GameVariant;
+use crate::domain::pagination::PageRequest;
+use crate::domain::rating::LeaderboardOrder;
+use crate::ports::http::warp::requests::pagination::page_request;
+use crate::ports::http::warp::requests::{GameVariantRequest, PageRequestError};
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct GetLeaderboard {
+    variant: Option<GameVariantRequest>,
+    order: Option<LeaderboardOrderRequest>,
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+impl GetLeaderboard {
+    pub(crate) fn variant(&self) -> GameVariant {
+        self.variant.map(GameVariant::from).unwrap_or_default()
+    }
+
+    pub(crate) fn order(&self) -> LeaderboardOrder {
+        self.order.map(LeaderboardOrder::from).unwrap_or_default()
+    }
+
+    pub(crate) fn page_request(&self) -> Result<PageRequest, PageRequestError> {
+        page_request(self.page, self.per_page)
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct GetLeaderboardRank {
+    variant: Option<GameVariantRequest>,
+    order: Option<LeaderboardOrderRequest>,
+}
+
+impl GetLeaderboardRank {
+    pub(crate) fn variant(&self) -> GameVariant {
+        self.variant.map(GameVariant::from).unwrap_or_default()
+    }
+
+    pub(crate) fn order(&self) -> LeaderboardOrder {
+        self.order.map(LeaderboardOrder::from).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum LeaderboardOrderRequest {
+    Rating,
+    Wins,
+}
+
+impl From<LeaderboardOrderRequest> for LeaderboardOrder {
+    fn from(order: LeaderboardOrderRequest) -> Self {
+        match order {
+            LeaderboardOrderRequest::Rating => LeaderboardOrder::Rating,
+            LeaderboardOrderRequest::Wins => LeaderboardOrder::Wins,
+        }
+    }
+}
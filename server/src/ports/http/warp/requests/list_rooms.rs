@@ -0,0 +1,43 @@
+use crate::domain::game::GameVariant;
+use crate::domain::pagination::PageRequest;
+use crate::domain::room::RoomQuery;
+use crate::ports::http::warp::requests::pagination::page_request;
+use crate::ports::http::warp::requests::{GameVariantRequest, PageRequestError};
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ListRooms {
+    open_seat: Option<bool>,
+    min_members: Option<usize>,
+    max_members: Option<usize>,
+    variant: Option<GameVariantRequest>,
+    page: Option<usize>,
+    per_page: Option<usize>,
+}
+
+impl ListRooms {
+    pub(crate) fn room_query(&self) -> Result<RoomQuery, ListRoomsRequestError> {
+        if let (Some(min), Some(max)) = (self.min_members, self.max_members) {
+            if min > max {
+                return Err(ListRoomsRequestError::MemberRange);
+            }
+        }
+        Ok(RoomQuery::new(
+            self.open_seat,
+            self.min_members,
+            self.max_members,
+            self.variant.map(GameVariant::from),
+        ))
+    }
+
+    pub(crate) fn page_request(&self) -> Result<PageRequest, ListRoomsRequestError> {
+        Ok(page_request(self.page, self.per_page)?)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ListRoomsRequestError {
+    #[error("min_members must not be greater than max_members")]
+    MemberRange,
+    #[error(transparent)]
+    Page(#[from] PageRequestError),
+}
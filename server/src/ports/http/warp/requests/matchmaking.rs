@@ -0,0 +1,41 @@
+use crate::domain::game::{GameVariant, TimeControl};
+use crate::domain::matchmaking::MatchPreferences;
+use crate::ports::http::warp::requests::GameVariantRequest;
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct EnterMatchmaking {
+    variant: Option<GameVariantRequest>,
+    initial_seconds: Option<u32>,
+    increment_seconds: Option<u32>,
+}
+
+impl EnterMatchmaking {
+    pub(crate) fn preferences(&self) -> Result<MatchPreferences, MatchmakingRequestError> {
+        match_preferences(self.variant, self.initial_seconds, self.increment_seconds)
+    }
+}
+
+pub(super) fn match_preferences(
+    variant: Option<GameVariantRequest>,
+    initial_seconds: Option<u32>,
+    increment_seconds: Option<u32>,
+) -> Result<MatchPreferences, MatchmakingRequestError> {
+    let time_control = match (initial_seconds, increment_seconds) {
+        (None, None) => None,
+        (None, Some(_)) | (Some(0), _) => return Err(MatchmakingRequestError::TimeControl),
+        (Some(initial_seconds), increment_seconds) => Some(TimeControl::new(
+            initial_seconds,
+            increment_seconds.unwrap_or(0),
+        )),
+    };
+    Ok(MatchPreferences::new(
+        variant.map(GameVariant::from).unwrap_or_default(),
+        time_control,
+    ))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum MatchmakingRequestError {
+    #[error("A time control must give players at least one second")]
+    TimeControl,
+}
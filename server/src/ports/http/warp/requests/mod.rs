@@ -1,3 +1,42 @@
+pub(super) use account::*;
+pub(super) use challenge::*;
+pub(super) use create_room::*;
 pub(super) use game_move::*;
+pub(super) use guest::*;
+pub(super) use invite::*;
+pub(super) use leaderboard::*;
+pub(super) use list_rooms::*;
+pub(super) use matchmaking::*;
+pub(super) use pagination::*;
+pub(super) use room_role::*;
+pub(super) use room_settings::*;
+pub(super) use series::*;
+pub(super) use tournament::*;
+pub(super) use user_profile::*;
 
+mod account;
+mod challenge;
+mod create_room;
 mod game_move;
+mod guest;
+mod invite;
+mod leaderboard;
+mod list_rooms;
+mod matchmaking;
+mod pagination;
+mod room_role;
+mod room_settings;
+mod series;
+mod tournament;
+mod user_profile;
+
+/// Parses a JSON request body, using the default request when the body is empty
+pub(super) fn from_optional_json<T>(body: &[u8]) -> Result<T, serde_json::Error>
+where
+    T: serde::de::DeserializeOwned + Default,
+{
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(T::default());
+    }
+    serde_json::from_slice(body)
+}
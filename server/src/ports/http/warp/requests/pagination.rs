@@ -0,0 +1,26 @@
+use crate::domain::pagination::PageRequest;
+
+const DEFAULT_PER_PAGE: usize = 20;
+const MAX_PER_PAGE: usize = 100;
+
+pub(super) fn page_request(
+    page: Option<usize>,
+    per_page: Option<usize>,
+) -> Result<PageRequest, PageRequestError> {
+    let page = page.unwrap_or(1);
+    let per_page = per_page.unwrap_or(DEFAULT_PER_PAGE);
+    if page == 0 {
+        return Err(PageRequestError::Page);
+    } else if per_page == 0 || per_page > MAX_PER_PAGE {
+        return Err(PageRequestError::PerPage(MAX_PER_PAGE));
+    }
+    Ok(PageRequest::new(page, per_page))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum PageRequestError {
+    #[error("page must be at least 1")]
+    Page,
+    #[error("per_page must be between 1 and {0}")]
+    PerPage(usize),
+}
@@ -0,0 +1,28 @@
+use crate::domain::room::RoomRole;
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RoomRoleRequest {
+    Player,
+    Spectator,
+}
+
+impl From<RoomRoleRequest> for RoomRole {
+    fn from(role: RoomRoleRequest) -> Self {
+        match role {
+            RoomRoleRequest::Player => RoomRole::Player,
+            RoomRoleRequest::Spectator => RoomRole::Spectator,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ChangeRoomRole {
+    role: RoomRoleRequest,
+}
+
+impl ChangeRoomRole {
+    pub(crate) fn role(&self) -> RoomRole {
+        self.role.into()
+    }
+}
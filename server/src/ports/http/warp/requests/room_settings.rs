@@ -0,0 +1,99 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::domain::game::{GameVariant, TimeControl};
+use crate::domain::room::{RoomSettings, RoomSettingsUpdate};
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GameVariantRequest {
+    Standard,
+    Misere,
+}
+
+impl From<GameVariantRequest> for GameVariant {
+    fn from(variant: GameVariantRequest) -> Self {
+        match variant {
+            GameVariantRequest::Standard => GameVariant::Standard,
+            GameVariantRequest::Misere => GameVariant::Misere,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+pub(crate) struct TimeControlRequest {
+    initial_seconds: u32,
+    #[serde(default)]
+    increment_seconds: u32,
+}
+
+impl From<TimeControlRequest> for TimeControl {
+    fn from(time_control: TimeControlRequest) -> Self {
+        TimeControl::new(time_control.initial_seconds, time_control.increment_seconds)
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct RoomSettingsRequest {
+    max_members: Option<usize>,
+    #[serde(default = "spectators_allowed_by_default")]
+    spectators_allowed: bool,
+    #[serde(default)]
+    default_variant: Option<GameVariantRequest>,
+    time_control: Option<TimeControlRequest>,
+    #[serde(default)]
+    rated: bool,
+}
+
+fn spectators_allowed_by_default() -> bool {
+    true
+}
+
+impl From<&RoomSettingsRequest> for RoomSettings {
+    fn from(request: &RoomSettingsRequest) -> Self {
+        RoomSettings::new(
+            request.max_members,
+            request.spectators_allowed,
+            request
+                .default_variant
+                .map(GameVariant::from)
+                .unwrap_or_default(),
+            request.time_control.map(TimeControl::from),
+            request.rated,
+        )
+    }
+}
+
+/// Partial room settings, where an explicit `null` clears a setting and a missing field leaves it
+/// unchanged
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct UpdateRoomSettings {
+    #[serde(default, deserialize_with = "present")]
+    max_members: Option<Option<usize>>,
+    spectators_allowed: Option<bool>,
+    default_variant: Option<GameVariantRequest>,
+    #[serde(default, deserialize_with = "present")]
+    time_control: Option<Option<TimeControlRequest>>,
+    rated: Option<bool>,
+}
+
+pub(super) fn present<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+impl From<&UpdateRoomSettings> for RoomSettingsUpdate {
+    fn from(request: &UpdateRoomSettings) -> Self {
+        RoomSettingsUpdate::new(
+            request.max_members,
+            request.spectators_allowed,
+            request.default_variant.map(GameVariant::from),
+            request
+                .time_control
+                .map(|time_control| time_control.map(TimeControl::from)),
+            request.rated,
+        )
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ConfigureSeries {
+    best_of: u8,
+}
+
+impl ConfigureSeries {
+    pub(crate) fn best_of(&self) -> u8 {
+        self.best_of
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::Duration;
+
+use crate::domain::tournament::{TournamentFormat, TournamentSettings};
+use crate::ports::http::warp::requests::{
+    match_preferences, GameVariantRequest, MatchmakingRequestError,
+};
+
+/// How long the players of a tournament match have to turn up when the organiser does not say
+const DEFAULT_NO_SHOW_TIMEOUT_SECONDS: u32 = 300;
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct CreateTournament {
+    name: String,
+    format: Option<TournamentFormatRequest>,
+    swiss_rounds: Option<u8>,
+    variant: Option<GameVariantRequest>,
+    initial_seconds: Option<u32>,
+    increment_seconds: Option<u32>,
+    best_of: Option<Vec<u8>>,
+    no_show_timeout_seconds: Option<u32>,
+}
+
+impl CreateTournament {
+    pub(crate) fn name(&self) -> Result<String, CreateTournamentRequestError> {
+        match self.name.trim() {
+            "" => Err(CreateTournamentRequestError::Name),
+            name => Ok(name.to_string()),
+        }
+    }
+
+    pub(crate) fn settings(&self) -> Result<TournamentSettings, CreateTournamentRequestError> {
+        let preferences =
+            match_preferences(self.variant, self.initial_seconds, self.increment_seconds)?;
+        let no_show_timeout = match self
+            .no_show_timeout_seconds
+            .unwrap_or(DEFAULT_NO_SHOW_TIMEOUT_SECONDS)
+        {
+            0 => return Err(CreateTournamentRequestError::NoShowTimeout),
+            seconds => Duration::seconds(seconds.into()),
+        };
+        let format = match (self.format.unwrap_or_default(), self.swiss_rounds) {
+            (TournamentFormatRequest::Swiss, Some(0)) => {
+                return Err(CreateTournamentRequestError::SwissRounds)
+            }
+            (TournamentFormatRequest::Swiss, rounds) => TournamentFormat::Swiss {
+                rounds: rounds.map(usize::from),
+            },
+            (_, Some(_)) => return Err(CreateTournamentRequestError::SwissRounds),
+            (TournamentFormatRequest::SingleElimination, None) => {
+                TournamentFormat::SingleElimination
+            }
+            (TournamentFormatRequest::RoundRobin, None) => TournamentFormat::RoundRobin,
+        };
+        Ok(TournamentSettings::new(
+            format,
+            preferences,
+            self.best_of.clone().unwrap_or_else(|| vec![1]),
+            no_show_timeout,
+        ))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TournamentFormatRequest {
+    #[default]
+    SingleElimination,
+    RoundRobin,
+    Swiss,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum CreateTournamentRequestError {
+    #[error("A tournament must have a name")]
+    Name,
+    #[error(transparent)]
+    Preferences(#[from] MatchmakingRequestError),
+    #[error("no_show_timeout_seconds must be at least 1")]
+    NoShowTimeout,
+    #[error("swiss_rounds must be at least 1 and only applies to Swiss tournaments")]
+    SwissRounds,
+}
@@ -0,0 +1,16 @@
+use crate::domain::user::UserProfileUpdate;
+use crate::ports::http::warp::requests::room_settings::present;
+
+/// Partial profile, where an explicit `null` clears the bio and a missing field leaves it unchanged
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct UpdateUserProfile {
+    name: Option<String>,
+    #[serde(default, deserialize_with = "present")]
+    bio: Option<Option<String>>,
+}
+
+impl From<UpdateUserProfile> for UserProfileUpdate {
+    fn from(request: UpdateUserProfile) -> Self {
+        UserProfileUpdate::new(request.name, request.bio)
+    }
+}
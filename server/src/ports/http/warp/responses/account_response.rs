@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::account::{IssuedSession, Session};
+use crate::domain::user::User;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct AccountResponse {
+    id: Uuid,
+    name: String,
+    username: Option<String>,
+}
+
+impl From<&User> for AccountResponse {
+    fn from(user: &User) -> Self {
+        AccountResponse {
+            id: user.id(),
+            name: user.name().to_string(),
+            username: user
+                .credentials()
+                .map(|credentials| credentials.username().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct LoginResponse {
+    session_id: Uuid,
+    user_id: Uuid,
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<&IssuedSession> for LoginResponse {
+    fn from(issued: &IssuedSession) -> Self {
+        LoginResponse {
+            session_id: issued.session().id(),
+            user_id: issued.session().user_id(),
+            token: issued.token().to_string(),
+            expires_at: issued.session().expires_at(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SessionResponse {
+    session_id: Uuid,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    /// Whether this is the session the request was made with
+    current: bool,
+}
+
+impl SessionResponse {
+    pub(crate) fn new(session: &Session, current: bool) -> Self {
+        SessionResponse {
+            session_id: session.id(),
+            created_at: session.created_at(),
+            expires_at: session.expires_at(),
+            current,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SessionListResponse {
+    user_id: Uuid,
+    sessions: Vec<SessionResponse>,
+}
+
+impl SessionListResponse {
+    pub(crate) fn new(current: &Session, sessions: &[Session]) -> Self {
+        SessionListResponse {
+            user_id: current.user_id(),
+            sessions: sessions
+                .iter()
+                .map(|session| SessionResponse::new(session, session.id() == current.id()))
+                .collect(),
+        }
+    }
+}
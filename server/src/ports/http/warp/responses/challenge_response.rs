@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::challenge::Challenge;
+use crate::ports::http::warp::responses::{GameVariantResponse, TimeControlResponse};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ChallengeResponse {
+    challenge_id: Uuid,
+    challenger: Uuid,
+    challenged: Uuid,
+    variant: GameVariantResponse,
+    time_control: Option<TimeControlResponse>,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<&Challenge> for ChallengeResponse {
+    fn from(challenge: &Challenge) -> Self {
+        ChallengeResponse {
+            challenge_id: challenge.id(),
+            challenger: challenge.challenger(),
+            challenged: challenge.challenged(),
+            variant: challenge.preferences().variant().into(),
+            time_control: challenge
+                .preferences()
+                .time_control()
+                .map(TimeControlResponse::from),
+            created_at: challenge.created_at(),
+            expires_at: challenge.expires_at(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ChallengeListResponse {
+    challenges: Vec<ChallengeResponse>,
+}
+
+impl From<Vec<Challenge>> for ChallengeListResponse {
+    fn from(challenges: Vec<Challenge>) -> Self {
+        ChallengeListResponse {
+            challenges: challenges.iter().map(ChallengeResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct AcceptChallengeResponse {
+    room_id: Uuid,
+    game_id: Uuid,
+}
+
+impl AcceptChallengeResponse {
+    pub(crate) fn new(room_id: Uuid, game_id: Uuid) -> Self {
+        AcceptChallengeResponse { room_id, game_id }
+    }
+}
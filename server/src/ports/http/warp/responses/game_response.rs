@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::game::{Game, GameOutcome, RecordedGameMove};
+use crate::ports::http::warp::responses::{GameVariantResponse, TimeControlResponse};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GameSummaryResponse {
+    game_id: Uuid,
+    players: Vec<Uuid>,
+    variant: GameVariantResponse,
+    time_control: Option<TimeControlResponse>,
+    outcome: Option<GameOutcomeResponse>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Game> for GameSummaryResponse {
+    fn from(game: &Game) -> Self {
+        GameSummaryResponse {
+            game_id: game.id(),
+            players: game.players().clone(),
+            variant: game.variant().into(),
+            time_control: game.time_control().map(TimeControlResponse::from),
+            outcome: game.outcome().map(GameOutcomeResponse::from),
+            started_at: game.started_at(),
+            finished_at: game.finished_at(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GameHistoryResponse {
+    games: Vec<GameSummaryResponse>,
+}
+
+impl GameHistoryResponse {
+    pub(crate) fn new(games: &[Game]) -> Self {
+        GameHistoryResponse {
+            games: games.iter().map(GameSummaryResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GameResponse {
+    game_id: Uuid,
+    players: Vec<Uuid>,
+    variant: GameVariantResponse,
+    time_control: Option<TimeControlResponse>,
+    outcome: Option<GameOutcomeResponse>,
+    moves: Vec<GameMoveResponse>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Game> for GameResponse {
+    fn from(game: &Game) -> Self {
+        GameResponse {
+            game_id: game.id(),
+            players: game.players().clone(),
+            variant: game.variant().into(),
+            time_control: game.time_control().map(TimeControlResponse::from),
+            outcome: game.outcome().map(GameOutcomeResponse::from),
+            moves: game.moves().iter().map(GameMoveResponse::from).collect(),
+            started_at: game.started_at(),
+            finished_at: game.finished_at(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub(crate) enum GameOutcomeResponse {
+    Win { winner: Uuid },
+    WinOnTime { winner: Uuid },
+    Draw,
+    Abandoned,
+}
+
+impl From<GameOutcome> for GameOutcomeResponse {
+    fn from(outcome: GameOutcome) -> Self {
+        match outcome {
+            GameOutcome::Win(winner) => GameOutcomeResponse::Win { winner },
+            GameOutcome::WinOnTime(winner) => GameOutcomeResponse::WinOnTime { winner },
+            GameOutcome::Draw => GameOutcomeResponse::Draw,
+            GameOutcome::Abandoned => GameOutcomeResponse::Abandoned,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GameMoveResponse {
+    user_id: Uuid,
+    position: GameMovePositionResponse,
+    made_at: DateTime<Utc>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct GameMovePositionResponse {
+    x: u8,
+    y: u8,
+}
+
+impl From<&RecordedGameMove> for GameMoveResponse {
+    fn from(game_move: &RecordedGameMove) -> Self {
+        GameMoveResponse {
+            user_id: game_move.user_id(),
+            position: GameMovePositionResponse {
+                x: game_move.position().x(),
+                y: game_move.position().y(),
+            },
+            made_at: game_move.made_at(),
+        }
+    }
+}
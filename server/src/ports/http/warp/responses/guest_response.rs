@@ -0,0 +1,18 @@
+use uuid::Uuid;
+
+use crate::domain::user::User;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GuestResponse {
+    id: Uuid,
+    name: String,
+}
+
+impl From<&User> for GuestResponse {
+    fn from(guest: &User) -> Self {
+        GuestResponse {
+            id: guest.id(),
+            name: guest.name().to_string(),
+        }
+    }
+}
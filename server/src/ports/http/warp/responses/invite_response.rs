@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::room::RoomInvite;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct InviteResponse {
+    code: String,
+    link: String,
+    single_use: bool,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl InviteResponse {
+    pub(crate) fn new(invite: &RoomInvite, link: String) -> Self {
+        InviteResponse {
+            code: invite.code().to_string(),
+            link,
+            single_use: invite.single_use(),
+            expires_at: invite.expires_at(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use crate::domain::game::GameVariant;
+use crate::domain::pagination::Page;
+use crate::domain::rating::LeaderboardEntry;
+use crate::ports::http::warp::responses::GameVariantResponse;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct LeaderboardResponse {
+    variant: GameVariantResponse,
+    entries: Vec<LeaderboardEntryResponse>,
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+impl LeaderboardResponse {
+    pub(crate) fn new(variant: GameVariant, page: &Page<LeaderboardEntry>) -> Self {
+        LeaderboardResponse {
+            variant: variant.into(),
+            entries: page
+                .items()
+                .iter()
+                .map(LeaderboardEntryResponse::from)
+                .collect(),
+            page: page.request().page(),
+            per_page: page.request().per_page(),
+            total: page.total(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct LeaderboardEntryResponse {
+    rank: usize,
+    user_id: Uuid,
+    name: String,
+    rating: i32,
+    deviation: i32,
+    provisional: bool,
+    games_played: u32,
+    wins: u32,
+}
+
+impl From<&LeaderboardEntry> for LeaderboardEntryResponse {
+    fn from(entry: &LeaderboardEntry) -> Self {
+        let rating = entry.rating();
+        LeaderboardEntryResponse {
+            rank: entry.rank(),
+            user_id: entry.user_id(),
+            name: entry.name().to_string(),
+            rating: rating.value().round() as i32,
+            deviation: rating.deviation().round() as i32,
+            provisional: rating.is_provisional(),
+            games_played: rating.games_played(),
+            wins: rating.wins(),
+        }
+    }
+}
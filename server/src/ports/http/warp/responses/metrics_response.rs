@@ -0,0 +1,27 @@
+use crate::ports::room_reaper::RoomReaperMetrics;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct MetricsResponse {
+    room_reaper: RoomReaperMetricsResponse,
+}
+
+impl MetricsResponse {
+    pub(crate) fn new(room_reaper_metrics: &RoomReaperMetrics) -> Self {
+        MetricsResponse {
+            room_reaper: RoomReaperMetricsResponse {
+                runs: room_reaper_metrics.runs(),
+                empty_rooms_removed: room_reaper_metrics.empty_rooms_removed(),
+                idle_rooms_removed: room_reaper_metrics.idle_rooms_removed(),
+                games_archived: room_reaper_metrics.games_archived(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct RoomReaperMetricsResponse {
+    runs: u64,
+    empty_rooms_removed: u64,
+    idle_rooms_removed: u64,
+    games_archived: u64,
+}
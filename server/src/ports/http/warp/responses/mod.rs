@@ -1,7 +1,41 @@
+pub(crate) use account_response::*;
+pub(crate) use challenge_response::*;
 pub(crate) use create_room_response::*;
+pub(crate) use game_response::*;
+pub(crate) use guest_response::*;
+pub(crate) use invite_response::*;
+pub(crate) use leaderboard_response::*;
+pub(crate) use metrics_response::*;
 pub(crate) use register_user_response::*;
+pub(crate) use rematch_response::*;
+pub(crate) use room_details_response::*;
+pub(crate) use room_list_response::*;
+pub(crate) use room_role_response::*;
+pub(crate) use room_settings_response::*;
+pub(crate) use series_response::*;
 pub(crate) use simple_error_response::*;
+pub(crate) use tournament_response::*;
+pub(crate) use user_data_export_response::*;
+pub(crate) use user_profile_response::*;
+pub(crate) use user_stats_response::*;
 
+mod account_response;
+mod challenge_response;
 mod create_room_response;
+mod game_response;
+mod guest_response;
+mod invite_response;
+mod leaderboard_response;
+mod metrics_response;
 mod register_user_response;
+mod rematch_response;
+mod room_details_response;
+mod room_list_response;
+mod room_role_response;
+mod room_settings_response;
+mod series_response;
 mod simple_error_response;
+mod tournament_response;
+mod user_data_export_response;
+mod user_profile_response;
+mod user_stats_response;
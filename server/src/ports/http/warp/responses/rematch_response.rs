@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RematchResponse {
+    game_id: Uuid,
+}
+
+impl RematchResponse {
+    pub(crate) fn new(game_id: Uuid) -> Self {
+        RematchResponse { game_id }
+    }
+}
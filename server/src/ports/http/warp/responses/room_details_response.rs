@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::RoomDetails;
+use crate::domain::room::{GameLifecycleState, RoomRole};
+use crate::domain::user::User;
+use crate::ports::http::warp::responses::{RoomRoleResponse, RoomSettingsResponse, SeriesResponse};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RoomDetailsResponse {
+    room_id: Uuid,
+    private: bool,
+    host: Option<Uuid>,
+    settings: RoomSettingsResponse,
+    members: Vec<RoomMemberResponse>,
+    seated_players: Vec<Uuid>,
+    spectators: Vec<Uuid>,
+    active_game_id: Option<Uuid>,
+    game_lifecycle_state: GameLifecycleStateResponse,
+    series: Option<SeriesResponse>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<&RoomDetails> for RoomDetailsResponse {
+    fn from(details: &RoomDetails) -> Self {
+        let room = details.room();
+        let spectators = details
+            .members()
+            .iter()
+            .map(User::id)
+            .filter(|member_id| room.is_spectator(*member_id))
+            .collect();
+
+        RoomDetailsResponse {
+            room_id: room.id(),
+            private: room.is_private(),
+            host: room.host(),
+            settings: room.settings().into(),
+            members: details
+                .members()
+                .iter()
+                .map(|member| {
+                    RoomMemberResponse::new(
+                        member,
+                        room.role(member.id()),
+                        room.is_online(member.id()),
+                    )
+                })
+                .collect(),
+            seated_players: room.seated_players().clone(),
+            spectators,
+            active_game_id: room.active_game_id(),
+            game_lifecycle_state: room.game_lifecycle_state().into(),
+            series: room.series().map(SeriesResponse::from),
+            created_at: room.created_at(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RoomMemberResponse {
+    user_id: Uuid,
+    name: String,
+    role: RoomRoleResponse,
+    online: bool,
+}
+
+impl RoomMemberResponse {
+    fn new(user: &User, role: RoomRole, online: bool) -> Self {
+        RoomMemberResponse {
+            user_id: user.id(),
+            name: user.name().to_string(),
+            role: role.into(),
+            online,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GameLifecycleStateResponse {
+    Waiting,
+    Ready,
+    InProgress,
+    Finished,
+}
+
+impl From<GameLifecycleState> for GameLifecycleStateResponse {
+    fn from(state: GameLifecycleState) -> Self {
+        match state {
+            GameLifecycleState::Waiting => GameLifecycleStateResponse::Waiting,
+            GameLifecycleState::Ready => GameLifecycleStateResponse::Ready,
+            GameLifecycleState::InProgress => GameLifecycleStateResponse::InProgress,
+            GameLifecycleState::Finished => GameLifecycleStateResponse::Finished,
+        }
+    }
+}
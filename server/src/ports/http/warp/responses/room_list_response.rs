@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::pagination::Page;
+use crate::domain::room::Room;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RoomListResponse {
+    rooms: Vec<RoomSummaryResponse>,
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+impl From<&Page<Room>> for RoomListResponse {
+    fn from(page: &Page<Room>) -> Self {
+        RoomListResponse {
+            rooms: page.items().iter().map(RoomSummaryResponse::from).collect(),
+            page: page.request().page(),
+            per_page: page.request().per_page(),
+            total: page.total(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RoomSummaryResponse {
+    room_id: Uuid,
+    member_count: usize,
+    seated_players: Vec<Uuid>,
+    open_seat: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl From<&Room> for RoomSummaryResponse {
+    fn from(room: &Room) -> Self {
+        RoomSummaryResponse {
+            room_id: room.id(),
+            member_count: room.members().len(),
+            seated_players: room.seated_players().clone(),
+            open_seat: room.has_open_seat(),
+            created_at: room.created_at(),
+        }
+    }
+}
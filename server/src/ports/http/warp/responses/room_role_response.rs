@@ -0,0 +1,17 @@
+use crate::domain::room::RoomRole;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RoomRoleResponse {
+    Player,
+    Spectator,
+}
+
+impl From<RoomRole> for RoomRoleResponse {
+    fn from(role: RoomRole) -> Self {
+        match role {
+            RoomRole::Player => RoomRoleResponse::Player,
+            RoomRole::Spectator => RoomRoleResponse::Spectator,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::domain::game::{GameVariant, TimeControl};
+use crate::domain::room::RoomSettings;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RoomSettingsResponse {
+    max_members: Option<usize>,
+    spectators_allowed: bool,
+    default_variant: GameVariantResponse,
+    time_control: Option<TimeControlResponse>,
+    rated: bool,
+}
+
+impl From<RoomSettings> for RoomSettingsResponse {
+    fn from(settings: RoomSettings) -> Self {
+        RoomSettingsResponse {
+            max_members: settings.max_members(),
+            spectators_allowed: settings.spectators_allowed(),
+            default_variant: settings.default_variant().into(),
+            time_control: settings.time_control().map(TimeControlResponse::from),
+            rated: settings.rated(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GameVariantResponse {
+    Standard,
+    Misere,
+}
+
+impl From<GameVariant> for GameVariantResponse {
+    fn from(variant: GameVariant) -> Self {
+        match variant {
+            GameVariant::Standard => GameVariantResponse::Standard,
+            GameVariant::Misere => GameVariantResponse::Misere,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct TimeControlResponse {
+    initial_seconds: u32,
+    increment_seconds: u32,
+}
+
+impl From<TimeControl> for TimeControlResponse {
+    fn from(time_control: TimeControl) -> Self {
+        TimeControlResponse {
+            initial_seconds: time_control.initial_seconds(),
+            increment_seconds: time_control.increment_seconds(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::domain::room::{Series, SeriesScore};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SeriesResponse {
+    best_of: u8,
+    games_played: u8,
+    wins_needed: u8,
+    scores: Vec<SeriesScoreResponse>,
+    winner: Option<Uuid>,
+    finished: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SeriesScoreResponse {
+    user_id: Uuid,
+    wins: u8,
+}
+
+impl From<&Series> for SeriesResponse {
+    fn from(series: &Series) -> Self {
+        SeriesResponse {
+            best_of: series.best_of(),
+            games_played: series.games_played(),
+            wins_needed: series.wins_needed(),
+            scores: series
+                .scores()
+                .iter()
+                .map(SeriesScoreResponse::from)
+                .collect(),
+            winner: series.winner(),
+            finished: series.is_finished(),
+        }
+    }
+}
+
+impl From<&SeriesScore> for SeriesScoreResponse {
+    fn from(score: &SeriesScore) -> Self {
+        SeriesScoreResponse {
+            user_id: score.user_id(),
+            wins: score.wins(),
+        }
+    }
+}
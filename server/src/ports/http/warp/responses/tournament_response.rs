@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::tournament::{
+    MatchDecision, Standing, Tournament, TournamentFormat, TournamentMatch, TournamentRound,
+    TournamentStatus,
+};
+use crate::ports::http::warp::responses::{GameVariantResponse, TimeControlResponse};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct TournamentResponse {
+    tournament_id: Uuid,
+    name: String,
+    organiser: Uuid,
+    format: TournamentFormatResponse,
+    status: TournamentStatusResponse,
+    variant: GameVariantResponse,
+    time_control: Option<TimeControlResponse>,
+    no_show_timeout_seconds: i64,
+    participants: Vec<TournamentParticipantResponse>,
+    rounds: Vec<TournamentRoundResponse>,
+    current_round: Option<usize>,
+    standings: Vec<StandingResponse>,
+    champion: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TournamentFormatResponse {
+    SingleElimination,
+    RoundRobin,
+    Swiss,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TournamentStatusResponse {
+    Registration,
+    InProgress,
+    Finished,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct TournamentParticipantResponse {
+    user_id: Uuid,
+    seed: Option<usize>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct TournamentRoundResponse {
+    round: usize,
+    best_of: u8,
+    matches: Vec<TournamentMatchResponse>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct TournamentMatchResponse {
+    players: [Option<Uuid>; 2],
+    room_id: Option<Uuid>,
+    winner: Option<Uuid>,
+    decided_by: Option<MatchDecisionResponse>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct StandingResponse {
+    rank: usize,
+    user_id: Uuid,
+    points: f64,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+    buchholz: f64,
+    sonneborn_berger: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MatchDecisionResponse {
+    Played,
+    Bye,
+    NoShow,
+    Withdrawal,
+}
+
+impl From<&Tournament> for TournamentResponse {
+    fn from(tournament: &Tournament) -> Self {
+        let seeded = tournament.status() != TournamentStatus::Registration;
+        TournamentResponse {
+            tournament_id: tournament.id(),
+            name: tournament.name().to_string(),
+            organiser: tournament.organiser(),
+            format: tournament.settings().format().into(),
+            status: tournament.status().into(),
+            variant: tournament.settings().preferences().variant().into(),
+            time_control: tournament
+                .settings()
+                .preferences()
+                .time_control()
+                .map(TimeControlResponse::from),
+            no_show_timeout_seconds: tournament.settings().no_show_timeout().num_seconds(),
+            participants: tournament
+                .participants()
+                .iter()
+                .enumerate()
+                .map(|(position, user_id)| TournamentParticipantResponse {
+                    user_id: *user_id,
+                    seed: if seeded { Some(position + 1) } else { None },
+                })
+                .collect(),
+            rounds: tournament
+                .rounds()
+                .iter()
+                .enumerate()
+                .map(|(round, matches)| TournamentRoundResponse::new(round + 1, matches))
+                .collect(),
+            current_round: tournament.current_round().map(|round| round + 1),
+            standings: tournament
+                .standings()
+                .iter()
+                .map(StandingResponse::from)
+                .collect(),
+            champion: tournament.champion(),
+            created_at: tournament.created_at(),
+        }
+    }
+}
+
+impl From<TournamentFormat> for TournamentFormatResponse {
+    fn from(format: TournamentFormat) -> Self {
+        match format {
+            TournamentFormat::SingleElimination => TournamentFormatResponse::SingleElimination,
+            TournamentFormat::RoundRobin => TournamentFormatResponse::RoundRobin,
+            TournamentFormat::Swiss { .. } => TournamentFormatResponse::Swiss,
+        }
+    }
+}
+
+impl From<TournamentStatus> for TournamentStatusResponse {
+    fn from(status: TournamentStatus) -> Self {
+        match status {
+            TournamentStatus::Registration => TournamentStatusResponse::Registration,
+            TournamentStatus::InProgress => TournamentStatusResponse::InProgress,
+            TournamentStatus::Finished => TournamentStatusResponse::Finished,
+        }
+    }
+}
+
+impl TournamentRoundResponse {
+    fn new(round: usize, tournament_round: &TournamentRound) -> Self {
+        TournamentRoundResponse {
+            round,
+            best_of: tournament_round.best_of(),
+            matches: tournament_round
+                .matches()
+                .iter()
+                .map(TournamentMatchResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&TournamentMatch> for TournamentMatchResponse {
+    fn from(tournament_match: &TournamentMatch) -> Self {
+        TournamentMatchResponse {
+            players: tournament_match.slots(),
+            room_id: tournament_match.room_id(),
+            winner: tournament_match.winner(),
+            decided_by: tournament_match.decision().map(MatchDecisionResponse::from),
+        }
+    }
+}
+
+impl From<&Standing> for StandingResponse {
+    fn from(standing: &Standing) -> Self {
+        StandingResponse {
+            rank: standing.rank(),
+            user_id: standing.user_id(),
+            points: standing.points(),
+            wins: standing.wins(),
+            draws: standing.draws(),
+            losses: standing.losses(),
+            buchholz: standing.buchholz(),
+            sonneborn_berger: standing.sonneborn_berger(),
+        }
+    }
+}
+
+impl From<MatchDecision> for MatchDecisionResponse {
+    fn from(decision: MatchDecision) -> Self {
+        match decision {
+            MatchDecision::Played => MatchDecisionResponse::Played,
+            MatchDecision::Bye => MatchDecisionResponse::Bye,
+            MatchDecision::NoShow => MatchDecisionResponse::NoShow,
+            MatchDecision::Withdrawal => MatchDecisionResponse::Withdrawal,
+        }
+    }
+}
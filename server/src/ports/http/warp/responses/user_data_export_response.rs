@@ -0,0 +1,37 @@
+use crate::application::UserDataExport;
+use crate::ports::http::warp::responses::{
+    ChallengeResponse, GameResponse, SessionResponse, TournamentResponse, UserProfileResponse,
+};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct UserDataExportResponse {
+    profile: UserProfileResponse,
+    games: Vec<GameResponse>,
+    challenges: Vec<ChallengeResponse>,
+    tournaments: Vec<TournamentResponse>,
+    sessions: Vec<SessionResponse>,
+}
+
+impl From<&UserDataExport> for UserDataExportResponse {
+    fn from(export: &UserDataExport) -> Self {
+        UserDataExportResponse {
+            profile: export.user().into(),
+            games: export.games().iter().map(GameResponse::from).collect(),
+            challenges: export
+                .challenges()
+                .iter()
+                .map(ChallengeResponse::from)
+                .collect(),
+            tournaments: export
+                .tournaments()
+                .iter()
+                .map(TournamentResponse::from)
+                .collect(),
+            sessions: export
+                .sessions()
+                .iter()
+                .map(|session| SessionResponse::new(session, false))
+                .collect(),
+        }
+    }
+}
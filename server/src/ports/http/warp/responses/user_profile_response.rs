@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use crate::domain::game::GameVariant;
+use crate::domain::rating::Rating;
+use crate::domain::user::User;
+use crate::ports::http::warp::responses::GameVariantResponse;
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct UserProfileResponse {
+    user_id: Uuid,
+    name: String,
+    bio: Option<String>,
+    guest: bool,
+    ratings: Vec<RatingResponse>,
+}
+
+impl From<&User> for UserProfileResponse {
+    fn from(user: &User) -> Self {
+        UserProfileResponse {
+            user_id: user.id(),
+            name: user.name().to_string(),
+            bio: user.bio().map(str::to_string),
+            guest: user.is_guest(),
+            ratings: GameVariant::ALL
+                .iter()
+                .map(|variant| RatingResponse::new(*variant, user.rating(*variant)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct RatingResponse {
+    variant: GameVariantResponse,
+    rating: i32,
+    deviation: i32,
+    provisional: bool,
+    games_played: u32,
+    wins: u32,
+}
+
+impl RatingResponse {
+    fn new(variant: GameVariant, rating: Rating) -> Self {
+        RatingResponse {
+            variant: variant.into(),
+            rating: rating.value().round() as i32,
+            deviation: rating.deviation().round() as i32,
+            provisional: rating.is_provisional(),
+            games_played: rating.games_played(),
+            wins: rating.wins(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use uuid::Uuid;
+
+use crate::domain::stats::{PlayerStats, ResultCounts};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct UserStatsResponse {
+    user_id: Uuid,
+    games_played: u32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    by_symbol: SymbolStatsResponse,
+    by_move_order: MoveOrderStatsResponse,
+    current_win_streak: u32,
+    best_win_streak: u32,
+    average_move_seconds: Option<f64>,
+}
+
+impl UserStatsResponse {
+    pub(crate) fn new(user_id: Uuid, stats: &PlayerStats) -> Self {
+        let overall = stats.overall();
+        UserStatsResponse {
+            user_id,
+            games_played: overall.played(),
+            wins: overall.wins(),
+            losses: overall.losses(),
+            draws: overall.draws(),
+            by_symbol: SymbolStatsResponse {
+                cross: stats.as_cross().into(),
+                nought: stats.as_nought().into(),
+            },
+            by_move_order: MoveOrderStatsResponse {
+                first: stats.moving_first().into(),
+                second: stats.moving_second().into(),
+            },
+            current_win_streak: stats.current_win_streak(),
+            best_win_streak: stats.best_win_streak(),
+            average_move_seconds: stats
+                .average_move_time()
+                .map(|duration| duration.num_milliseconds() as f64 / 1000.0),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SymbolStatsResponse {
+    cross: ResultCountsResponse,
+    nought: ResultCountsResponse,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct MoveOrderStatsResponse {
+    first: ResultCountsResponse,
+    second: ResultCountsResponse,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct ResultCountsResponse {
+    games_played: u32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+}
+
+impl From<ResultCounts> for ResultCountsResponse {
+    fn from(counts: ResultCounts) -> Self {
+        ResultCountsResponse {
+            games_played: counts.played(),
+            wins: counts.wins(),
+            losses: counts.losses(),
+            draws: counts.draws(),
+        }
+    }
+}
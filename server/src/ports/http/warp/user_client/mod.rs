@@ -4,24 +4,30 @@ use tokio::sync::mpsc::Sender;
 use uuid::Uuid;
 use warp::ws::Message;
 
+pub(crate) use room_event_message::*;
 pub(crate) use ws_user_client_provider_adapter::*;
 
-use crate::domain::user::UserClient;
+use crate::domain::room::RoomEvent;
+use crate::domain::user::{UserClient, UserClientError};
 
+mod room_event_message;
 mod ws_user_client_provider_adapter;
 
 pub(crate) struct WsUserClientAdapter {
     user_id: Uuid,
+    room_id: Uuid,
     transmitter: Arc<Sender<Result<Message, warp::Error>>>,
 }
 
 impl WsUserClientAdapter {
     pub(crate) fn new(
         user_id: Uuid,
+        room_id: Uuid,
         user_ws_tx: Arc<Sender<Result<Message, warp::Error>>>,
     ) -> Self {
         WsUserClientAdapter {
             user_id,
+            room_id,
             transmitter: user_ws_tx,
         }
     }
@@ -30,9 +36,23 @@ impl WsUserClientAdapter {
         self.user_id
     }
 
+    pub(crate) fn room_id(&self) -> Uuid {
+        self.room_id
+    }
+
     pub(crate) fn transmitter(&self) -> Arc<Sender<Result<Message, warp::Error>>> {
         self.transmitter.clone()
     }
 }
 
-impl UserClient for WsUserClientAdapter {}
+#[async_trait::async_trait]
+impl UserClient for WsUserClientAdapter {
+    async fn notify(&self, event: &RoomEvent) -> Result<(), UserClientError> {
+        let message = serde_json::to_string(&RoomEventMessage::from(event))
+            .expect("Room event messages are always serializable");
+        self.transmitter
+            .send(Ok(Message::text(message)))
+            .await
+            .map_err(|_| UserClientError::Disconnected)
+    }
+}
@@ -0,0 +1,102 @@
+use uuid::Uuid;
+
+use crate::domain::room::RoomEvent;
+use crate::ports::http::warp::responses::{RoomRoleResponse, RoomSettingsResponse, SeriesResponse};
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum RoomEventMessage {
+    RematchRequested {
+        room_id: Uuid,
+        user_id: Uuid,
+    },
+    GameStarted {
+        room_id: Uuid,
+        game_id: Uuid,
+        players: Vec<Uuid>,
+        first_mover: Option<Uuid>,
+    },
+    SeriesUpdated {
+        room_id: Uuid,
+        series: SeriesResponse,
+    },
+    HostChanged {
+        room_id: Uuid,
+        host: Uuid,
+    },
+    MemberKicked {
+        room_id: Uuid,
+        user_id: Uuid,
+    },
+    MemberRenamed {
+        room_id: Uuid,
+        user_id: Uuid,
+        name: String,
+    },
+    SettingsUpdated {
+        room_id: Uuid,
+        settings: RoomSettingsResponse,
+    },
+    RoleChanged {
+        room_id: Uuid,
+        user_id: Uuid,
+        role: RoomRoleResponse,
+    },
+    RoomClosed {
+        room_id: Uuid,
+    },
+}
+
+impl From<&RoomEvent> for RoomEventMessage {
+    fn from(event: &RoomEvent) -> Self {
+        match event.clone() {
+            RoomEvent::RematchRequested { room_id, user_id } => {
+                RoomEventMessage::RematchRequested { room_id, user_id }
+            }
+            RoomEvent::GameStarted {
+                room_id,
+                game_id,
+                players,
+                first_mover,
+            } => RoomEventMessage::GameStarted {
+                room_id,
+                game_id,
+                players,
+                first_mover,
+            },
+            RoomEvent::SeriesUpdated { room_id, series } => RoomEventMessage::SeriesUpdated {
+                room_id,
+                series: SeriesResponse::from(&series),
+            },
+            RoomEvent::HostChanged { room_id, host } => {
+                RoomEventMessage::HostChanged { room_id, host }
+            }
+            RoomEvent::MemberKicked { room_id, user_id } => {
+                RoomEventMessage::MemberKicked { room_id, user_id }
+            }
+            RoomEvent::MemberRenamed {
+                room_id,
+                user_id,
+                name,
+            } => RoomEventMessage::MemberRenamed {
+                room_id,
+                user_id,
+                name,
+            },
+            RoomEvent::SettingsUpdated { room_id, settings } => RoomEventMessage::SettingsUpdated {
+                room_id,
+                settings: settings.into(),
+            },
+            RoomEvent::RoleChanged {
+                room_id,
+                user_id,
+                role,
+            } => RoomEventMessage::RoleChanged {
+                room_id,
+                user_id,
+                role: role.into(),
+            },
+            RoomEvent::RoomClosed { room_id } => RoomEventMessage::RoomClosed { room_id },
+        }
+    }
+}
@@ -3,33 +3,69 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 use tokio::sync::mpsc::Sender;
+use tokio::sync::OwnedMutexGuard;
 use uuid::Uuid;
 use warp::ws::Message;
 
 use crate::domain::user::{UserClientProvider, UserClientProviderError};
 use crate::ports::http::warp::WsUserClientAdapter;
 
-type UserClientMap = Arc<Mutex<HashMap<Uuid, Arc<Sender<Result<Message, warp::Error>>>>>>;
+type Transmitter = Arc<Sender<Result<Message, warp::Error>>>;
+type UserClientMap = Arc<Mutex<HashMap<(Uuid, Uuid), Transmitter>>>;
+type ConnectionLocks = Arc<Mutex<HashMap<Uuid, Arc<tokio::sync::Mutex<()>>>>>;
 
+/// Keeps one WebSocket connection per user for each room they are in
 pub(crate) struct WsUserClientProviderAdapter {
     inner: UserClientMap,
+    connection_locks: ConnectionLocks,
 }
 
 impl WsUserClientProviderAdapter {
     pub(crate) fn new() -> Self {
         WsUserClientProviderAdapter {
             inner: Arc::new(Mutex::new(HashMap::new())),
+            connection_locks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    pub(crate) async fn put(&self, user_client: WsUserClientAdapter) {
+    /// Serialises connecting and disconnecting a user so that a reconnect cannot be undone by the
+    /// previous connection closing
+    pub(crate) async fn lock_connections(&self, user_id: Uuid) -> OwnedMutexGuard<()> {
+        let lock = self
+            .connection_locks
+            .lock()
+            .entry(user_id)
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
+
+    pub(crate) async fn is_connected(&self, user_id: Uuid, room_id: Uuid) -> bool {
+        let map = self.inner.lock();
+        map.contains_key(&(user_id, room_id))
+    }
+
+    pub(crate) async fn put(&self, user_client: &WsUserClientAdapter) {
         let mut map = self.inner.lock();
-        map.insert(user_client.user_id(), user_client.transmitter());
+        map.insert(
+            (user_client.user_id(), user_client.room_id()),
+            user_client.transmitter(),
+        );
     }
 
-    pub(crate) async fn remove(&self, user_id: Uuid) {
+    /// Removes the client unless it has already been replaced by a newer connection, returning
+    /// whether it was removed
+    pub(crate) async fn remove_if_current(&self, user_client: &WsUserClientAdapter) -> bool {
         let mut map = self.inner.lock();
-        map.remove(&user_id);
+        let key = (user_client.user_id(), user_client.room_id());
+        let is_current = map
+            .get(&key)
+            .iter()
+            .any(|transmitter| Arc::ptr_eq(transmitter, &user_client.transmitter()));
+        if is_current {
+            map.remove(&key);
+        }
+        is_current
     }
 }
 
@@ -37,12 +73,16 @@ impl WsUserClientProviderAdapter {
 impl UserClientProvider for WsUserClientProviderAdapter {
     type UserClient = WsUserClientAdapter;
 
-    async fn get(&self, user_id: Uuid) -> Result<Self::UserClient, UserClientProviderError> {
+    async fn get(
+        &self,
+        user_id: Uuid,
+        room_id: Uuid,
+    ) -> Result<Self::UserClient, UserClientProviderError> {
         let map = self.inner.lock();
         let transmitter = map
-            .get(&user_id)
+            .get(&(user_id, room_id))
             .ok_or(UserClientProviderError::UserClientNotAvailable)?
             .clone();
-        Ok(WsUserClientAdapter::new(user_id, transmitter))
+        Ok(WsUserClientAdapter::new(user_id, room_id, transmitter))
     }
 }
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use crate::ports::env::seconds_from_env;
+
+const INTERVAL_ENV_VAR: &str = "MATCHMAKER_INTERVAL_SECONDS";
+const DEFAULT_INTERVAL_SECONDS: u64 = 1;
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct MatchmakerConfig {
+    interval: Duration,
+}
+
+impl MatchmakerConfig {
+    pub(crate) fn new(interval: Duration) -> Self {
+        MatchmakerConfig { interval }
+    }
+
+    /// Reads the interval from the environment, falling back to its default when unset or invalid
+    pub(crate) fn from_env() -> Self {
+        MatchmakerConfig::new(seconds_from_env(INTERVAL_ENV_VAR, DEFAULT_INTERVAL_SECONDS))
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        self.interval
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::application::ApplicationService;
+use crate::ports::matchmaker::MatchmakerConfig;
+
+/// Periodically pairs waiting users as their rating windows widen and clears out challenges
+/// that expired unanswered
+pub(crate) struct MatchmakerTask<AS: ApplicationService> {
+    application_service: Arc<AS>,
+    config: MatchmakerConfig,
+}
+
+impl<AS> MatchmakerTask<AS>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    pub(crate) fn new(application_service: Arc<AS>, config: MatchmakerConfig) -> Self {
+        MatchmakerTask {
+            application_service,
+            config,
+        }
+    }
+
+    pub(crate) fn spawn(self) {
+        tokio::spawn(self.run());
+    }
+
+    async fn run(self) {
+        let mut interval = tokio::time::interval(self.config.interval());
+        loop {
+            interval.tick().await;
+            let started = self.application_service.match_waiting_players().await;
+            if started > 0 {
+                log::debug!("Matchmaker started {} matches", started);
+            }
+            let purged = self.application_service.purge_expired_challenges().await;
+            if purged > 0 {
+                log::debug!("Matchmaker purged {} expired challenges", purged);
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) use matchmaker_config::*;
+pub(crate) use matchmaker_task::*;
+
+mod matchmaker_config;
+mod matchmaker_task;
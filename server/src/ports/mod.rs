@@ -1,2 +1,9 @@
+pub(crate) mod clock;
+pub(crate) mod env;
+pub(crate) mod guest_reaper;
 pub(crate) mod http;
+pub(crate) mod matchmaker;
+pub(crate) mod password_hasher;
 pub(crate) mod persistence;
+pub(crate) mod room_reaper;
+pub(crate) mod tournament_referee;
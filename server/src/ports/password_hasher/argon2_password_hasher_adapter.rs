@@ -0,0 +1,64 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use rand::RngCore;
+
+use crate::domain::account::{HashPasswordError, PasswordHasher};
+
+const SALT_LENGTH: usize = 16;
+
+/// Hashes with Argon2id on the blocking thread pool, so that slow hashing does not hold up other
+/// requests
+pub(crate) struct Argon2PasswordHasherAdapter {
+    /// Hash of a random password, made with the same parameters as real ones
+    dummy_password_hash: String,
+}
+
+impl Argon2PasswordHasherAdapter {
+    pub(crate) fn new() -> Self {
+        let mut password = [0; SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut password);
+        Argon2PasswordHasherAdapter {
+            dummy_password_hash: hash_password(&password).unwrap(),
+        }
+    }
+}
+
+fn hash_password(password: &[u8]) -> Result<String, HashPasswordError> {
+    let mut salt = [0; SALT_LENGTH];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let salt = SaltString::encode_b64(&salt).map_err(|e| HashPasswordError(e.to_string()))?;
+    Argon2::default()
+        .hash_password(password, &salt)
+        .map(|password_hash| password_hash.to_string())
+        .map_err(|e| HashPasswordError(e.to_string()))
+}
+
+#[async_trait::async_trait]
+impl PasswordHasher for Argon2PasswordHasherAdapter {
+    async fn hash(&self, password: &str) -> Result<String, HashPasswordError> {
+        let password = password.to_string();
+        tokio::task::spawn_blocking(move || hash_password(password.as_bytes()))
+            .await
+            .map_err(|e| HashPasswordError(e.to_string()))?
+    }
+
+    async fn verify(&self, password: &str, password_hash: &str) -> bool {
+        let password = password.to_string();
+        let password_hash = password_hash.to_string();
+        tokio::task::spawn_blocking(move || match PasswordHash::new(&password_hash) {
+            Ok(parsed) => Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok(),
+            Err(err) => {
+                log::warn!("Could not parse a stored password hash: {}", err);
+                false
+            }
+        })
+        .await
+        .unwrap_or(false)
+    }
+
+    async fn verify_against_dummy(&self, password: &str) {
+        self.verify(password, &self.dummy_password_hash).await;
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) use argon2_password_hasher_adapter::*;
+
+mod argon2_password_hasher_adapter;
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::domain::challenge::{
+    Challenge, ChallengeNotFoundError, ChallengeRepository, ChallengeWithIdAlreadyExists,
+    GetChallengeError, StoreChallengeError,
+};
+
+type EmbeddedDb = Arc<Mutex<HashMap<Uuid, Challenge>>>;
+
+pub(crate) struct MapChallengeRepositoryAdapter {
+    inner: EmbeddedDb,
+}
+
+impl MapChallengeRepositoryAdapter {
+    pub(crate) fn new() -> Self {
+        MapChallengeRepositoryAdapter {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn find<P>(&self, predicate: P) -> Vec<Challenge>
+    where
+        P: Fn(&Challenge) -> bool,
+    {
+        let map = self.inner.lock();
+        let mut challenges: Vec<Challenge> = map
+            .values()
+            .filter(|challenge| predicate(challenge))
+            .cloned()
+            .collect();
+        challenges.sort_by_key(|challenge| challenge.created_at());
+        challenges
+    }
+}
+
+#[async_trait::async_trait]
+impl ChallengeRepository for MapChallengeRepositoryAdapter {
+    async fn store(&self, challenge: &Challenge) -> Result<(), StoreChallengeError> {
+        let mut map = self.inner.lock();
+        if map.contains_key(&challenge.id()) {
+            return Err(ChallengeWithIdAlreadyExists(challenge.id()).into());
+        }
+        map.insert(challenge.id(), challenge.clone());
+        Ok(())
+    }
+
+    async fn get(&self, challenge_id: Uuid) -> Result<Challenge, GetChallengeError> {
+        let map = self.inner.lock();
+        map.get(&challenge_id)
+            .cloned()
+            .ok_or_else(|| ChallengeNotFoundError(challenge_id).into())
+    }
+
+    async fn remove(&self, challenge_id: Uuid) -> Option<Challenge> {
+        let mut map = self.inner.lock();
+        map.remove(&challenge_id)
+    }
+
+    async fn remove_expired(&self, now: DateTime<Utc>) -> Vec<Challenge> {
+        let mut map = self.inner.lock();
+        let expired: Vec<Uuid> = map
+            .values()
+            .filter(|challenge| challenge.is_expired(now))
+            .map(Challenge::id)
+            .collect();
+        expired
+            .iter()
+            .filter_map(|challenge_id| map.remove(challenge_id))
+            .collect()
+    }
+
+    async fn sent_by(&self, user_id: Uuid) -> Vec<Challenge> {
+        self.find(|challenge| challenge.challenger() == user_id)
+    }
+
+    async fn sent_to(&self, user_id: Uuid) -> Vec<Challenge> {
+        self.find(|challenge| challenge.challenged() == user_id)
+    }
+}
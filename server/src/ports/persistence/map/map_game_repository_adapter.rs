@@ -1,15 +1,17 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use uuid::Uuid;
 
 use crate::domain::game::{
-    Game, GameMove, GameMovePosition, GameNotFoundError, GameRepository, GameWithIdAlreadyExists,
-    GetGameError, StoreGameError, UpdateGameError,
+    Game, GameMove, GameMovePosition, GameNotFoundError, GameOutcome, GameRepository, GameVariant,
+    GameWithIdAlreadyExists, GetGameError, RecordedGameMove, StoreGameError, TimeControl,
+    UpdateGameError,
 };
 
-type EmbeddedDb = Arc<Mutex<HashMap<Uuid, StoredGame>>>;
+type EmbeddedDb = Arc<Mutex<Database>>;
 
 pub(crate) struct MapGameRepositoryAdapter {
     inner: EmbeddedDb,
@@ -18,7 +20,7 @@ pub(crate) struct MapGameRepositoryAdapter {
 impl MapGameRepositoryAdapter {
     pub(crate) fn new() -> Self {
         MapGameRepositoryAdapter {
-            inner: Arc::new(Mutex::new(HashMap::new())),
+            inner: Arc::new(Mutex::new(Database::default())),
         }
     }
 }
@@ -26,55 +28,155 @@ impl MapGameRepositoryAdapter {
 #[async_trait::async_trait]
 impl GameRepository for MapGameRepositoryAdapter {
     async fn get(&self, game_id: Uuid) -> Result<Game, GetGameError> {
-        let map = self.inner.lock();
-        let stored_game = map
+        let db = self.inner.lock();
+        let stored_game = db
+            .games
             .get(&game_id)
             .ok_or_else::<GetGameError, _>(|| GameNotFoundError(game_id).into())?;
         Ok(from_stored_game(game_id, stored_game))
     }
 
     async fn store(&self, game: &Game) -> Result<(), StoreGameError> {
-        let mut map = self.inner.lock();
-        if map.insert(game.id(), game.into()).is_some() {
+        let mut db = self.inner.lock();
+        if db.games.contains_key(&game.id()) {
             return Err(GameWithIdAlreadyExists(game.id()).into());
         }
+        db.put(game);
         Ok(())
     }
 
     async fn update(&self, game: &Game) -> Result<(), UpdateGameError> {
         let game_id = game.id();
-        let mut map = self.inner.lock();
-        let _stored_game = map
+        let mut db = self.inner.lock();
+        let _stored_game = db
+            .games
             .get(&game_id)
             .ok_or_else::<UpdateGameError, _>(|| GameNotFoundError(game_id).into())?;
-        map.insert(game_id, game.into());
+        db.put(game);
         Ok(())
     }
+
+    async fn finished_games_of_player(&self, user_id: Uuid) -> Vec<Game> {
+        let db = self.inner.lock();
+        db.finished_games_by_player
+            .get(&user_id)
+            .map(|game_keys| {
+                game_keys
+                    .iter()
+                    .map(|(_, game_id)| from_stored_game(*game_id, &db.games[game_id]))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    async fn games_involving(&self, user_id: Uuid) -> Vec<Game> {
+        let db = self.inner.lock();
+        db.games_by_participant
+            .get(&user_id)
+            .map(|game_keys| {
+                game_keys
+                    .iter()
+                    .map(|(_, game_id)| from_stored_game(*game_id, &db.games[game_id]))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Games by id plus, for each player, their finished games ordered by when they finished and, for
+/// everyone who took part in a game in any way, their games ordered by when they started
+#[derive(Default)]
+struct Database {
+    games: HashMap<Uuid, StoredGame>,
+    finished_games_by_player: HashMap<Uuid, BTreeSet<(DateTime<Utc>, Uuid)>>,
+    games_by_participant: HashMap<Uuid, BTreeSet<(DateTime<Utc>, Uuid)>>,
+}
+
+impl Database {
+    fn put(&mut self, game: &Game) {
+        if let Some(previous) = self.games.remove(&game.id()) {
+            if let Some(finished_at) = previous.finished_at {
+                for player in &previous.players {
+                    if let Some(game_keys) = self.finished_games_by_player.get_mut(player) {
+                        game_keys.remove(&(finished_at, game.id()));
+                    }
+                }
+            }
+            for participant in previous.participants() {
+                if let Some(game_keys) = self.games_by_participant.get_mut(&participant) {
+                    game_keys.remove(&(previous.started_at, game.id()));
+                }
+            }
+        }
+        if let Some(finished_at) = game.finished_at() {
+            for player in game.players() {
+                self.finished_games_by_player
+                    .entry(*player)
+                    .or_default()
+                    .insert((finished_at, game.id()));
+            }
+        }
+        let stored_game = StoredGame::from(game);
+        for participant in stored_game.participants() {
+            self.games_by_participant
+                .entry(participant)
+                .or_default()
+                .insert((stored_game.started_at, game.id()));
+        }
+        self.games.insert(game.id(), stored_game);
+    }
 }
 
 struct StoredGame {
-    players: HashSet<Uuid>,
+    players: Vec<Uuid>,
+    crosses: Option<Uuid>,
+    first_mover: Option<Uuid>,
+    variant: StoredGameVariant,
+    time_control: Option<StoredTimeControl>,
+    rated: bool,
     moves: Vec<StoredGameMove>,
+    outcome: Option<StoredGameOutcome>,
+    abandon_votes: HashSet<Uuid>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl StoredGame {
+    /// Everyone recorded in the game, including players who have since given up their seat
+    fn participants(&self) -> HashSet<Uuid> {
+        self.players
+            .iter()
+            .copied()
+            .chain(self.first_mover)
+            .chain(self.moves.iter().map(|game_move| game_move.user_id))
+            .chain(self.abandon_votes.iter().copied())
+            .collect()
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
 struct StoredGameMove {
     user_id: Uuid,
     position: StoredGameMovePosition,
+    made_at: DateTime<Utc>,
 }
 
-impl From<&GameMove> for StoredGameMove {
-    fn from(game_move: &GameMove) -> Self {
+impl From<&RecordedGameMove> for StoredGameMove {
+    fn from(game_move: &RecordedGameMove) -> Self {
         StoredGameMove {
             user_id: game_move.user_id(),
             position: game_move.position().into(),
+            made_at: game_move.made_at(),
         }
     }
 }
 
-impl From<&StoredGameMove> for GameMove {
+impl From<&StoredGameMove> for RecordedGameMove {
     fn from(game_move: &StoredGameMove) -> Self {
-        GameMove::new(game_move.user_id, game_move.position.into())
+        RecordedGameMove::new(
+            GameMove::new(game_move.user_id, game_move.position.into()),
+            game_move.made_at,
+        )
     }
 }
 
@@ -99,12 +201,96 @@ impl From<StoredGameMovePosition> for GameMovePosition {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+enum StoredGameOutcome {
+    Win(Uuid),
+    WinOnTime(Uuid),
+    Draw,
+    Abandoned,
+}
+
+impl From<GameOutcome> for StoredGameOutcome {
+    fn from(outcome: GameOutcome) -> Self {
+        match outcome {
+            GameOutcome::Win(user_id) => StoredGameOutcome::Win(user_id),
+            GameOutcome::WinOnTime(user_id) => StoredGameOutcome::WinOnTime(user_id),
+            GameOutcome::Draw => StoredGameOutcome::Draw,
+            GameOutcome::Abandoned => StoredGameOutcome::Abandoned,
+        }
+    }
+}
+
+impl From<StoredGameOutcome> for GameOutcome {
+    fn from(outcome: StoredGameOutcome) -> Self {
+        match outcome {
+            StoredGameOutcome::Win(user_id) => GameOutcome::Win(user_id),
+            StoredGameOutcome::WinOnTime(user_id) => GameOutcome::WinOnTime(user_id),
+            StoredGameOutcome::Draw => GameOutcome::Draw,
+            StoredGameOutcome::Abandoned => GameOutcome::Abandoned,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum StoredGameVariant {
+    Standard,
+    Misere,
+}
+
+impl From<GameVariant> for StoredGameVariant {
+    fn from(variant: GameVariant) -> Self {
+        match variant {
+            GameVariant::Standard => StoredGameVariant::Standard,
+            GameVariant::Misere => StoredGameVariant::Misere,
+        }
+    }
+}
+
+impl From<StoredGameVariant> for GameVariant {
+    fn from(variant: StoredGameVariant) -> Self {
+        match variant {
+            StoredGameVariant::Standard => GameVariant::Standard,
+            StoredGameVariant::Misere => GameVariant::Misere,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct StoredTimeControl {
+    initial_seconds: u32,
+    increment_seconds: u32,
+}
+
+impl From<TimeControl> for StoredTimeControl {
+    fn from(time_control: TimeControl) -> Self {
+        StoredTimeControl {
+            initial_seconds: time_control.initial_seconds(),
+            increment_seconds: time_control.increment_seconds(),
+        }
+    }
+}
+
+impl From<StoredTimeControl> for TimeControl {
+    fn from(time_control: StoredTimeControl) -> Self {
+        TimeControl::new(time_control.initial_seconds, time_control.increment_seconds)
+    }
+}
+
 impl From<&Game> for StoredGame {
     fn from(game: &Game) -> Self {
         let moves = game.moves().iter().map(StoredGameMove::from).collect();
         StoredGame {
             players: game.players().clone(),
+            crosses: game.crosses(),
+            first_mover: game.first_mover(),
+            variant: game.variant().into(),
+            time_control: game.time_control().map(StoredTimeControl::from),
+            rated: game.rated(),
             moves,
+            outcome: game.outcome().map(StoredGameOutcome::from),
+            abandon_votes: game.abandon_votes().clone(),
+            started_at: game.started_at(),
+            finished_at: game.finished_at(),
         }
     }
 }
@@ -113,6 +299,19 @@ fn from_stored_game(id: Uuid, stored_game: &StoredGame) -> Game {
     Game::new(
         id,
         stored_game.players.clone(),
-        stored_game.moves.iter().map(GameMove::from).collect(),
+        stored_game.crosses,
+        stored_game.first_mover,
+        stored_game.variant.into(),
+        stored_game.time_control.map(TimeControl::from),
+        stored_game.rated,
+        stored_game
+            .moves
+            .iter()
+            .map(RecordedGameMove::from)
+            .collect(),
+        stored_game.outcome.map(GameOutcome::from),
+        stored_game.abandon_votes.clone(),
+        stored_game.started_at,
+        stored_game.finished_at,
     )
 }
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+use crate::domain::account::LoginAttemptRepository;
+
+/// Older failures are dropped whenever the username fails again, as no limit looks back that far
+const FAILURE_RETENTION_HOURS: i64 = 24;
+
+type EmbeddedDb = Arc<Mutex<HashMap<(String, Option<IpAddr>), Vec<DateTime<Utc>>>>>;
+
+pub(crate) struct MapLoginAttemptRepositoryAdapter {
+    inner: EmbeddedDb,
+}
+
+impl MapLoginAttemptRepositoryAdapter {
+    pub(crate) fn new() -> Self {
+        MapLoginAttemptRepositoryAdapter {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+fn key(username: &str, client: Option<IpAddr>) -> (String, Option<IpAddr>) {
+    (username.to_ascii_lowercase(), client)
+}
+
+#[async_trait::async_trait]
+impl LoginAttemptRepository for MapLoginAttemptRepositoryAdapter {
+    async fn record_failure(&self, username: &str, client: Option<IpAddr>, at: DateTime<Utc>) {
+        let mut map = self.inner.lock();
+        let failures = map.entry(key(username, client)).or_default();
+        let cutoff = at - chrono::Duration::hours(FAILURE_RETENTION_HOURS);
+        failures.retain(|failed_at| *failed_at >= cutoff);
+        failures.push(at);
+    }
+
+    async fn failures_since(
+        &self,
+        username: &str,
+        client: Option<IpAddr>,
+        cutoff: DateTime<Utc>,
+    ) -> usize {
+        let map = self.inner.lock();
+        map.get(&key(username, client))
+            .map(|failures| {
+                failures
+                    .iter()
+                    .filter(|failed_at| **failed_at >= cutoff)
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
+    async fn clear(&self, username: &str, client: Option<IpAddr>) {
+        let mut map = self.inner.lock();
+        map.remove(&key(username, client));
+    }
+
+    async fn remove_before(&self, cutoff: DateTime<Utc>) -> usize {
+        let mut map = self.inner.lock();
+        let mut removed = 0;
+        map.retain(|_, failures| {
+            let before = failures.len();
+            failures.retain(|failed_at| *failed_at >= cutoff);
+            removed += before - failures.len();
+            !failures.is_empty()
+        });
+        removed
+    }
+}
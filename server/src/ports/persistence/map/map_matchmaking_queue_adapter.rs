@@ -0,0 +1,115 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::domain::matchmaking::{
+    AlreadyQueuedError, EnqueueError, MatchPreferences, MatchTicket, MatchmakingQueue,
+};
+
+/// Number of past waits kept for estimating how long new arrivals will wait
+const RECENT_WAIT_CAPACITY: usize = 100;
+
+type EmbeddedQueue = Arc<Mutex<Queue>>;
+
+/// Holds waiting tickets in the order they were queued
+pub(crate) struct MapMatchmakingQueueAdapter {
+    inner: EmbeddedQueue,
+}
+
+impl MapMatchmakingQueueAdapter {
+    pub(crate) fn new() -> Self {
+        MapMatchmakingQueueAdapter {
+            inner: Arc::new(Mutex::new(Queue::default())),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Queue {
+    tickets: Vec<MatchTicket>,
+    last_opponents: HashMap<Uuid, (Uuid, DateTime<Utc>)>,
+    recent_waits: VecDeque<(MatchPreferences, Duration)>,
+}
+
+impl Queue {
+    fn position(&self, user_id: Uuid) -> Option<usize> {
+        self.tickets
+            .iter()
+            .position(|waiting| waiting.user_id() == user_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl MatchmakingQueue for MapMatchmakingQueueAdapter {
+    async fn enqueue(&self, ticket: MatchTicket) -> Result<(), EnqueueError> {
+        let mut queue = self.inner.lock();
+        if queue.position(ticket.user_id()).is_some() {
+            return Err(AlreadyQueuedError(ticket.user_id()).into());
+        }
+        queue.tickets.push(ticket);
+        Ok(())
+    }
+
+    async fn remove(&self, user_id: Uuid) -> Option<MatchTicket> {
+        let mut queue = self.inner.lock();
+        let index = queue.position(user_id)?;
+        Some(queue.tickets.remove(index))
+    }
+
+    async fn waiting(&self) -> Vec<MatchTicket> {
+        self.inner.lock().tickets.clone()
+    }
+
+    async fn take_pair(
+        &self,
+        first: Uuid,
+        second: Uuid,
+        paired_at: DateTime<Utc>,
+    ) -> Option<(MatchTicket, MatchTicket)> {
+        let mut queue = self.inner.lock();
+        let first_ticket = queue.tickets[queue.position(first)?];
+        let second_ticket = queue.tickets[queue.position(second)?];
+        queue
+            .tickets
+            .retain(|waiting| waiting.user_id() != first && waiting.user_id() != second);
+        queue.last_opponents.insert(first, (second, paired_at));
+        queue.last_opponents.insert(second, (first, paired_at));
+        Some((first_ticket, second_ticket))
+    }
+
+    async fn last_opponent(&self, user_id: Uuid) -> Option<Uuid> {
+        self.inner
+            .lock()
+            .last_opponents
+            .get(&user_id)
+            .map(|(opponent, _)| *opponent)
+    }
+
+    async fn forget_opponents_paired_before(&self, cutoff: DateTime<Utc>) {
+        self.inner
+            .lock()
+            .last_opponents
+            .retain(|_, (_, paired_at)| *paired_at >= cutoff);
+    }
+
+    async fn record_wait(&self, preferences: MatchPreferences, waited: Duration) {
+        let mut queue = self.inner.lock();
+        if queue.recent_waits.len() == RECENT_WAIT_CAPACITY {
+            queue.recent_waits.pop_front();
+        }
+        queue.recent_waits.push_back((preferences, waited));
+    }
+
+    async fn recent_waits(&self, preferences: MatchPreferences) -> Vec<Duration> {
+        self.inner
+            .lock()
+            .recent_waits
+            .iter()
+            .filter(|(recorded, _)| *recorded == preferences)
+            .map(|(_, waited)| *waited)
+            .collect()
+    }
+}
@@ -1,14 +1,17 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use uuid::Uuid;
 
+use crate::domain::game::{GameVariant, TimeControl};
+use crate::domain::pagination::{Page, PageRequest};
 use crate::domain::room::{
-    GetRoomError, Room, RoomNotFoundError, RoomRepository, RoomWithIdAlreadyExists, StoreRoomError,
-    UpdateRoomError,
+    GameLifecycleState, GetRoomError, RemoveRoomError, Room, RoomInvite, RoomNotFoundError,
+    RoomQuery, RoomRepository, RoomSettings, RoomVisibility, RoomWithIdAlreadyExists, Series,
+    SeriesScore, StoreRoomError, UpdateRoomError,
 };
-use crate::domain::user::User;
 
 type EmbeddedDb = Arc<Mutex<HashMap<Uuid, StoredRoom>>>;
 
@@ -52,35 +55,304 @@ impl RoomRepository for MapRoomRepositoryAdapter {
         Ok(from_stored_room(room_id, stored_room))
     }
 
-    async fn have_member(&self, user: &User) -> Vec<Room> {
+    async fn remove(&self, room_id: Uuid) -> Result<(), RemoveRoomError> {
+        let mut map = self.inner.lock();
+        map.remove(&room_id)
+            .ok_or(RemoveRoomError::NotFound(RoomNotFoundError(room_id)))?;
+        Ok(())
+    }
+
+    async fn inactive_since(&self, cutoff: DateTime<Utc>) -> Vec<Room> {
+        let map = self.inner.lock();
+        map.iter()
+            .filter(|(_id, stored_room)| stored_room.last_active_at < cutoff)
+            .map(|(id, stored_room)| from_stored_room(*id, stored_room))
+            .collect()
+    }
+
+    async fn find(&self, query: &RoomQuery, page_request: PageRequest) -> Page<Room> {
+        let map = self.inner.lock();
+        let mut rooms: Vec<Room> = map
+            .iter()
+            .map(|(id, stored_room)| from_stored_room(*id, stored_room))
+            .filter(|room| query.matches(room))
+            .collect();
+        rooms.sort_by(|a, b| {
+            b.created_at()
+                .cmp(&a.created_at())
+                .then_with(|| a.id().cmp(&b.id()))
+        });
+
+        let total = rooms.len();
+        let items = rooms
+            .into_iter()
+            .skip(page_request.offset())
+            .take(page_request.per_page())
+            .collect();
+        Page::new(items, page_request, total)
+    }
+
+    async fn with_member(&self, user_id: Uuid) -> Vec<Room> {
+        let map = self.inner.lock();
+        map.iter()
+            .filter(|(_id, stored_room)| stored_room.members.contains(&user_id))
+            .map(|(id, stored_room)| from_stored_room(*id, stored_room))
+            .collect()
+    }
+
+    async fn with_game_in_progress(&self) -> Vec<Room> {
         let map = self.inner.lock();
         map.iter()
-            .filter(|(_id, stored_room)| stored_room.members.contains(&user.id()))
             .map(|(id, stored_room)| from_stored_room(*id, stored_room))
+            .filter(|room| room.game_lifecycle_state() == GameLifecycleState::InProgress)
             .collect()
     }
 }
 
 struct StoredRoom {
     active_game_id: Option<Uuid>,
+    visibility: StoredRoomVisibility,
+    settings: StoredRoomSettings,
+    invites: Vec<StoredRoomInvite>,
+    host: Option<Uuid>,
     members: HashSet<Uuid>,
+    spectators: HashSet<Uuid>,
+    offline_members: HashSet<Uuid>,
+    banned_users: HashSet<Uuid>,
+    seated_players: Vec<Uuid>,
+    game_lifecycle_state: StoredGameLifecycleState,
+    game_history: Vec<Uuid>,
+    rematch_requests: HashSet<Uuid>,
+    series: Option<StoredSeries>,
+    created_at: DateTime<Utc>,
+    last_active_at: DateTime<Utc>,
 }
 
 impl StoredRoom {
-    fn new(active_game_id: Option<Uuid>, members: HashSet<Uuid>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        active_game_id: Option<Uuid>,
+        visibility: StoredRoomVisibility,
+        settings: StoredRoomSettings,
+        invites: Vec<StoredRoomInvite>,
+        host: Option<Uuid>,
+        members: HashSet<Uuid>,
+        spectators: HashSet<Uuid>,
+        offline_members: HashSet<Uuid>,
+        banned_users: HashSet<Uuid>,
+        seated_players: Vec<Uuid>,
+        game_lifecycle_state: StoredGameLifecycleState,
+        game_history: Vec<Uuid>,
+        rematch_requests: HashSet<Uuid>,
+        series: Option<StoredSeries>,
+        created_at: DateTime<Utc>,
+        last_active_at: DateTime<Utc>,
+    ) -> Self {
         StoredRoom {
             active_game_id,
+            visibility,
+            settings,
+            invites,
+            host,
             members,
+            spectators,
+            offline_members,
+            banned_users,
+            seated_players,
+            game_lifecycle_state,
+            game_history,
+            rematch_requests,
+            series,
+            created_at,
+            last_active_at,
         }
     }
 }
 
 impl From<&Room> for StoredRoom {
     fn from(room: &Room) -> Self {
-        StoredRoom::new(room.active_game_id(), room.members().clone())
+        StoredRoom::new(
+            room.active_game_id(),
+            room.visibility().into(),
+            room.settings().into(),
+            room.invites().iter().map(StoredRoomInvite::from).collect(),
+            room.host(),
+            room.members().clone(),
+            room.spectators().clone(),
+            room.offline_members().clone(),
+            room.banned_users().clone(),
+            room.seated_players().clone(),
+            room.game_lifecycle_state().into(),
+            room.game_history().clone(),
+            room.rematch_requests().clone(),
+            room.series().map(StoredSeries::from),
+            room.created_at(),
+            room.last_active_at(),
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum StoredGameLifecycleState {
+    Waiting,
+    Ready,
+    InProgress,
+    Finished,
+}
+
+impl From<GameLifecycleState> for StoredGameLifecycleState {
+    fn from(state: GameLifecycleState) -> Self {
+        match state {
+            GameLifecycleState::Waiting => StoredGameLifecycleState::Waiting,
+            GameLifecycleState::Ready => StoredGameLifecycleState::Ready,
+            GameLifecycleState::InProgress => StoredGameLifecycleState::InProgress,
+            GameLifecycleState::Finished => StoredGameLifecycleState::Finished,
+        }
+    }
+}
+
+impl From<StoredGameLifecycleState> for GameLifecycleState {
+    fn from(state: StoredGameLifecycleState) -> Self {
+        match state {
+            StoredGameLifecycleState::Waiting => GameLifecycleState::Waiting,
+            StoredGameLifecycleState::Ready => GameLifecycleState::Ready,
+            StoredGameLifecycleState::InProgress => GameLifecycleState::InProgress,
+            StoredGameLifecycleState::Finished => GameLifecycleState::Finished,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum StoredRoomVisibility {
+    Public,
+    Private,
+}
+
+impl From<RoomVisibility> for StoredRoomVisibility {
+    fn from(visibility: RoomVisibility) -> Self {
+        match visibility {
+            RoomVisibility::Public => StoredRoomVisibility::Public,
+            RoomVisibility::Private => StoredRoomVisibility::Private,
+        }
+    }
+}
+
+impl From<StoredRoomVisibility> for RoomVisibility {
+    fn from(visibility: StoredRoomVisibility) -> Self {
+        match visibility {
+            StoredRoomVisibility::Public => RoomVisibility::Public,
+            StoredRoomVisibility::Private => RoomVisibility::Private,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct StoredRoomSettings {
+    max_members: Option<usize>,
+    spectators_allowed: bool,
+    default_variant: GameVariant,
+    time_control: Option<TimeControl>,
+    rated: bool,
+}
+
+impl From<RoomSettings> for StoredRoomSettings {
+    fn from(settings: RoomSettings) -> Self {
+        StoredRoomSettings {
+            max_members: settings.max_members(),
+            spectators_allowed: settings.spectators_allowed(),
+            default_variant: settings.default_variant(),
+            time_control: settings.time_control(),
+            rated: settings.rated(),
+        }
+    }
+}
+
+impl From<StoredRoomSettings> for RoomSettings {
+    fn from(settings: StoredRoomSettings) -> Self {
+        RoomSettings::new(
+            settings.max_members,
+            settings.spectators_allowed,
+            settings.default_variant,
+            settings.time_control,
+            settings.rated,
+        )
+    }
+}
+
+struct StoredRoomInvite {
+    code: String,
+    single_use: bool,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<&RoomInvite> for StoredRoomInvite {
+    fn from(invite: &RoomInvite) -> Self {
+        StoredRoomInvite {
+            code: invite.code().to_string(),
+            single_use: invite.single_use(),
+            expires_at: invite.expires_at(),
+        }
+    }
+}
+
+impl From<&StoredRoomInvite> for RoomInvite {
+    fn from(invite: &StoredRoomInvite) -> Self {
+        RoomInvite::new(invite.code.clone(), invite.single_use, invite.expires_at)
+    }
+}
+
+struct StoredSeries {
+    best_of: u8,
+    games_played: u8,
+    scores: Vec<(Uuid, u8)>,
+}
+
+impl From<&Series> for StoredSeries {
+    fn from(series: &Series) -> Self {
+        StoredSeries {
+            best_of: series.best_of(),
+            games_played: series.games_played(),
+            scores: series
+                .scores()
+                .iter()
+                .map(|score| (score.user_id(), score.wins()))
+                .collect(),
+        }
+    }
+}
+
+impl From<&StoredSeries> for Series {
+    fn from(stored_series: &StoredSeries) -> Self {
+        Series::new(
+            stored_series.best_of,
+            stored_series.games_played,
+            stored_series
+                .scores
+                .iter()
+                .map(|(user_id, wins)| SeriesScore::new(*user_id, *wins))
+                .collect(),
+        )
     }
 }
 
 fn from_stored_room(id: Uuid, stored_room: &StoredRoom) -> Room {
-    Room::new(id, stored_room.active_game_id, stored_room.members.clone())
+    Room::new(
+        id,
+        stored_room.active_game_id,
+        stored_room.visibility.into(),
+        stored_room.settings.into(),
+        stored_room.invites.iter().map(RoomInvite::from).collect(),
+        stored_room.host,
+        stored_room.members.clone(),
+        stored_room.spectators.clone(),
+        stored_room.offline_members.clone(),
+        stored_room.banned_users.clone(),
+        stored_room.seated_players.clone(),
+        stored_room.game_lifecycle_state.into(),
+        stored_room.game_history.clone(),
+        stored_room.rematch_requests.clone(),
+        stored_room.series.as_ref().map(Series::from),
+        stored_room.created_at,
+        stored_room.last_active_at,
+    )
 }
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::domain::account::{
+    GetSessionError, Session, SessionNotFoundError, SessionRepository, SessionWithIdAlreadyExists,
+    StoreSessionError, UpdateSessionError,
+};
+
+type EmbeddedDb = Arc<Mutex<Database>>;
+
+pub(crate) struct MapSessionRepositoryAdapter {
+    inner: EmbeddedDb,
+}
+
+impl MapSessionRepositoryAdapter {
+    pub(crate) fn new() -> Self {
+        MapSessionRepositoryAdapter {
+            inner: Arc::new(Mutex::new(Database::default())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for MapSessionRepositoryAdapter {
+    async fn store(&self, session: &Session) -> Result<(), StoreSessionError> {
+        let mut db = self.inner.lock();
+        if db.sessions.contains_key(&session.id()) {
+            return Err(SessionWithIdAlreadyExists(session.id()).into());
+        }
+        db.sessions_by_token_digest
+            .insert(session.token_digest().to_string(), session.id());
+        db.sessions.insert(session.id(), session.clone());
+        Ok(())
+    }
+
+    async fn update(&self, session: &Session) -> Result<(), UpdateSessionError> {
+        let mut db = self.inner.lock();
+        let stored_session = db
+            .sessions
+            .get_mut(&session.id())
+            .ok_or_else::<UpdateSessionError, _>(|| SessionNotFoundError(session.id()).into())?;
+        *stored_session = session.clone();
+        Ok(())
+    }
+
+    async fn get(&self, session_id: Uuid) -> Result<Session, GetSessionError> {
+        let db = self.inner.lock();
+        db.sessions
+            .get(&session_id)
+            .cloned()
+            .ok_or_else(|| SessionNotFoundError(session_id).into())
+    }
+
+    async fn by_token_digest(&self, token_digest: &str) -> Option<Session> {
+        let db = self.inner.lock();
+        let session_id = db.sessions_by_token_digest.get(token_digest)?;
+        db.sessions.get(session_id).cloned()
+    }
+
+    async fn of_user(&self, user_id: Uuid) -> Vec<Session> {
+        let db = self.inner.lock();
+        let mut sessions: Vec<Session> = db
+            .sessions
+            .values()
+            .filter(|session| session.user_id() == user_id)
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|session| session.created_at());
+        sessions
+    }
+
+    async fn remove_of_user(&self, user_id: Uuid) -> usize {
+        self.inner
+            .lock()
+            .remove_where(|session| session.user_id() == user_id)
+    }
+
+    async fn remove_inactive(&self, now: DateTime<Utc>) -> usize {
+        self.inner
+            .lock()
+            .remove_where(|session| !session.is_active(now))
+    }
+}
+
+/// Sessions by id plus an index of token digests, kept in step on every write
+#[derive(Default)]
+struct Database {
+    sessions: HashMap<Uuid, Session>,
+    sessions_by_token_digest: HashMap<String, Uuid>,
+}
+
+impl Database {
+    fn remove_where(&mut self, predicate: impl Fn(&Session) -> bool) -> usize {
+        let removed: Vec<Session> = self
+            .sessions
+            .values()
+            .filter(|session| predicate(session))
+            .cloned()
+            .collect();
+        for session in &removed {
+            self.sessions.remove(&session.id());
+            self.sessions_by_token_digest.remove(session.token_digest());
+        }
+        removed.len()
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::domain::tournament::{
+    GetTournamentError, StoreTournamentError, Tournament, TournamentNotFoundError,
+    TournamentRepository, TournamentStatus, TournamentWithIdAlreadyExists,
+};
+
+type EmbeddedDb = Arc<Mutex<Database>>;
+
+pub(crate) struct MapTournamentRepositoryAdapter {
+    inner: EmbeddedDb,
+}
+
+impl MapTournamentRepositoryAdapter {
+    pub(crate) fn new() -> Self {
+        MapTournamentRepositoryAdapter {
+            inner: Arc::new(Mutex::new(Database::default())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TournamentRepository for MapTournamentRepositoryAdapter {
+    async fn store(&self, tournament: &Tournament) -> Result<(), StoreTournamentError> {
+        let mut db = self.inner.lock();
+        if db.tournaments.contains_key(&tournament.id()) {
+            return Err(TournamentWithIdAlreadyExists(tournament.id()).into());
+        }
+        db.put(tournament);
+        Ok(())
+    }
+
+    async fn update_with<T, E, F>(
+        &self,
+        tournament_id: Uuid,
+        change: F,
+    ) -> Result<(Tournament, T), E>
+    where
+        T: Send,
+        E: From<TournamentNotFoundError> + Send,
+        F: FnOnce(&mut Tournament) -> Result<T, E> + Send,
+    {
+        let mut db = self.inner.lock();
+        let mut tournament = db
+            .tournaments
+            .get(&tournament_id)
+            .cloned()
+            .ok_or(TournamentNotFoundError(tournament_id))?;
+        let changed = change(&mut tournament)?;
+        db.put(&tournament);
+        Ok((tournament, changed))
+    }
+
+    async fn get(&self, tournament_id: Uuid) -> Result<Tournament, GetTournamentError> {
+        let db = self.inner.lock();
+        db.tournaments
+            .get(&tournament_id)
+            .cloned()
+            .ok_or_else(|| TournamentNotFoundError(tournament_id).into())
+    }
+
+    async fn by_room(&self, room_id: Uuid) -> Option<Tournament> {
+        let db = self.inner.lock();
+        db.tournaments_by_room
+            .get(&room_id)
+            .and_then(|tournament_id| db.tournaments.get(tournament_id))
+            .cloned()
+    }
+
+    async fn in_progress(&self) -> Vec<Tournament> {
+        let db = self.inner.lock();
+        let mut tournaments: Vec<Tournament> = db
+            .tournaments
+            .values()
+            .filter(|tournament| tournament.status() == TournamentStatus::InProgress)
+            .cloned()
+            .collect();
+        tournaments.sort_by_key(|tournament| tournament.created_at());
+        tournaments
+    }
+
+    async fn of_user(&self, user_id: Uuid) -> Vec<Tournament> {
+        let db = self.inner.lock();
+        let mut tournaments: Vec<Tournament> = db
+            .tournaments
+            .values()
+            .filter(|tournament| {
+                tournament.is_organiser(user_id) || tournament.is_registered(user_id)
+            })
+            .cloned()
+            .collect();
+        tournaments.sort_by_key(|tournament| tournament.created_at());
+        tournaments
+    }
+}
+
+/// Tournaments by id plus the tournament each match room belongs to, kept in step on every write
+#[derive(Default)]
+struct Database {
+    tournaments: HashMap<Uuid, Tournament>,
+    tournaments_by_room: HashMap<Uuid, Uuid>,
+}
+
+impl Database {
+    fn put(&mut self, tournament: &Tournament) {
+        let room_ids = tournament
+            .rounds()
+            .iter()
+            .flat_map(|round| round.matches())
+            .filter_map(|tournament_match| tournament_match.room_id());
+        for room_id in room_ids {
+            self.tournaments_by_room.insert(room_id, tournament.id());
+        }
+        self.tournaments.insert(tournament.id(), tournament.clone());
+    }
+}
@@ -1,15 +1,24 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use uuid::Uuid;
 
+use crate::domain::game::GameVariant;
+use crate::domain::pagination::{Page, PageRequest};
+use crate::domain::rating::{LeaderboardEntry, LeaderboardOrder, Rating};
 use crate::domain::user::{
-    GetUserError, StoreUserError, UpdateUserError, User, UserNotFoundError, UserRepository,
-    UserWithIdAlreadyExists,
+    Credentials, GetUserError, RemoveUserError, StoreUserError, UpdateUserError, User,
+    UserNotFoundError, UserRepository, UserWithIdAlreadyExists, UsernameTakenError,
 };
+use crate::ports::persistence::map::ranked_set::RankedSet;
 
-type EmbeddedDb = Arc<Mutex<HashMap<Uuid, StoredUser>>>;
+const LEADERBOARD_ORDERS: [LeaderboardOrder; 2] =
+    [LeaderboardOrder::Rating, LeaderboardOrder::Wins];
+
+type EmbeddedDb = Arc<Mutex<Database>>;
 
 pub(crate) struct MapUserRepositoryAdapter {
     inner: EmbeddedDb,
@@ -18,7 +27,7 @@ pub(crate) struct MapUserRepositoryAdapter {
 impl MapUserRepositoryAdapter {
     pub(crate) fn new() -> Self {
         MapUserRepositoryAdapter {
-            inner: Arc::new(Mutex::new(HashMap::new())),
+            inner: Arc::new(Mutex::new(Database::default())),
         }
     }
 }
@@ -26,44 +35,268 @@ impl MapUserRepositoryAdapter {
 #[async_trait::async_trait]
 impl UserRepository for MapUserRepositoryAdapter {
     async fn store(&self, user: &User) -> Result<(), StoreUserError> {
-        let mut map = self.inner.lock();
-        if map.insert(user.id(), user.into()).is_some() {
+        let mut db = self.inner.lock();
+        if db.users.contains_key(&user.id()) {
             return Err(UserWithIdAlreadyExists(user.id()).into());
         }
+        if let Some(credentials) = user.credentials() {
+            if db
+                .users_by_username
+                .contains_key(&username_key(credentials.username()))
+            {
+                return Err(UsernameTakenError(credentials.username().to_string()).into());
+            }
+        }
+        db.put(user);
         Ok(())
     }
 
-    async fn update(&self, user: &User) -> Result<(), UpdateUserError> {
-        let mut map = self.inner.lock();
-        let _stored_user = map
-            .get(&user.id())
-            .ok_or_else::<UpdateUserError, _>(|| UserNotFoundError(user.id()).into())?;
-        map.insert(user.id(), user.into());
-        Ok(())
+    async fn update_with<T, E, F>(&self, id: Uuid, change: F) -> Result<(User, T), E>
+    where
+        T: Send,
+        E: From<UserNotFoundError> + Send,
+        F: FnOnce(&mut User) -> Result<T, E> + Send,
+    {
+        let mut db = self.inner.lock();
+        let mut user = db.users.get(&id).ok_or(UserNotFoundError(id))?.to_user(id);
+        let changed = change(&mut user)?;
+        db.put(&user);
+        Ok((user, changed))
     }
 
     async fn get(&self, id: Uuid) -> Result<User, GetUserError> {
-        let map = self.inner.lock();
-        let stored_user = map
+        let db = self.inner.lock();
+        let stored_user = db
+            .users
             .get(&id)
             .ok_or_else::<GetUserError, _>(|| UserNotFoundError(id).into())?;
-        let user = User::new(id, stored_user.name.to_string());
-        Ok(user)
+        Ok(stored_user.to_user(id))
+    }
+
+    async fn by_username(&self, username: &str) -> Option<User> {
+        let db = self.inner.lock();
+        let id = db.users_by_username.get(&username_key(username))?;
+        db.users.get(id).map(|stored_user| stored_user.to_user(*id))
+    }
+
+    async fn remove(&self, id: Uuid) -> Result<(), RemoveUserError> {
+        let mut db = self.inner.lock();
+        let stored_user = db
+            .users
+            .remove(&id)
+            .ok_or_else::<RemoveUserError, _>(|| UserNotFoundError(id).into())?;
+        db.reindex(id, &stored_user, false);
+        Ok(())
+    }
+
+    async fn record_activity(&self, id: Uuid, at: DateTime<Utc>) -> Result<(), UpdateUserError> {
+        let mut db = self.inner.lock();
+        let stored_user = db
+            .users
+            .get_mut(&id)
+            .ok_or_else::<UpdateUserError, _>(|| UserNotFoundError(id).into())?;
+        stored_user.last_active_at = stored_user.last_active_at.max(at);
+        Ok(())
+    }
+
+    async fn guests_inactive_since(&self, cutoff: DateTime<Utc>) -> Vec<User> {
+        let db = self.inner.lock();
+        db.users
+            .iter()
+            .filter(|(_id, stored_user)| stored_user.guest && stored_user.last_active_at < cutoff)
+            .map(|(id, stored_user)| stored_user.to_user(*id))
+            .collect()
+    }
+
+    async fn leaderboard(
+        &self,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+        page_request: PageRequest,
+    ) -> Page<LeaderboardEntry> {
+        let db = self.inner.lock();
+        let index = match db.leaderboards.get(&(variant, order)) {
+            Some(index) => index,
+            None => return Page::new(vec![], page_request, 0),
+        };
+        let end = page_request
+            .offset()
+            .saturating_add(page_request.per_page())
+            .min(index.len());
+        let entries = (page_request.offset()..end)
+            .filter_map(|position| index.nth(position).map(|key| (position, key)))
+            .map(|(position, key)| db.entry(position + 1, key.user_id(), variant))
+            .collect();
+        Page::new(entries, page_request, index.len())
+    }
+
+    async fn leaderboard_entry(
+        &self,
+        id: Uuid,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+    ) -> Result<Option<LeaderboardEntry>, GetUserError> {
+        let db = self.inner.lock();
+        let stored_user = db
+            .users
+            .get(&id)
+            .ok_or_else::<GetUserError, _>(|| UserNotFoundError(id).into())?;
+        let key = match stored_user.leaderboard_key(id, variant, order) {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        let rank = db
+            .leaderboards
+            .get(&(variant, order))
+            .map(|index| index.rank(&key) + 1)
+            .unwrap_or(1);
+        Ok(Some(db.entry(rank, id, variant)))
+    }
+}
+
+/// Users by id plus a ranked index per leaderboard and an index of usernames, kept in step on
+/// every write. The ranked indexes find a user's rank and the start of a page in logarithmic time.
+#[derive(Default)]
+struct Database {
+    users: HashMap<Uuid, StoredUser>,
+    users_by_username: HashMap<String, Uuid>,
+    leaderboards: HashMap<(GameVariant, LeaderboardOrder), RankedSet<LeaderboardKey>>,
+}
+
+impl Database {
+    fn put(&mut self, user: &User) {
+        let mut stored_user = StoredUser::from(user);
+        if let Some(previous) = self.users.remove(&user.id()) {
+            self.reindex(user.id(), &previous, false);
+            // Activity recorded since the user was read must not be lost by writing it back
+            stored_user.last_active_at = stored_user.last_active_at.max(previous.last_active_at);
+        }
+        self.reindex(user.id(), &stored_user, true);
+        self.users.insert(user.id(), stored_user);
+    }
+
+    fn reindex(&mut self, id: Uuid, stored_user: &StoredUser, present: bool) {
+        if let Some(credentials) = &stored_user.credentials {
+            let key = username_key(credentials.username());
+            if present {
+                self.users_by_username.insert(key, id);
+            } else {
+                self.users_by_username.remove(&key);
+            }
+        }
+        for variant in stored_user.ratings.keys() {
+            for order in LEADERBOARD_ORDERS.iter() {
+                if let Some(key) = stored_user.leaderboard_key(id, *variant, *order) {
+                    let index = self.leaderboards.entry((*variant, *order)).or_default();
+                    if present {
+                        index.insert(key);
+                    } else {
+                        index.remove(&key);
+                    }
+                }
+            }
+        }
+    }
+
+    fn entry(&self, rank: usize, id: Uuid, variant: GameVariant) -> LeaderboardEntry {
+        let stored_user = &self.users[&id];
+        LeaderboardEntry::new(
+            rank,
+            id,
+            stored_user.name.to_string(),
+            stored_user.rating(variant),
+        )
+    }
+}
+
+fn username_key(username: &str) -> String {
+    username.to_ascii_lowercase()
+}
+
+/// Sorts best first: higher primary measure, then higher secondary measure, then by id
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct LeaderboardKey(Reverse<i64>, Reverse<i64>, Uuid);
+
+impl LeaderboardKey {
+    fn user_id(&self) -> Uuid {
+        self.2
     }
 }
 
 struct StoredUser {
     name: String,
+    bio: Option<String>,
+    guest: bool,
+    last_active_at: DateTime<Utc>,
+    credentials: Option<Credentials>,
+    ratings: HashMap<GameVariant, Rating>,
 }
 
 impl StoredUser {
-    fn new(name: String) -> Self {
-        StoredUser { name }
+    fn new(
+        name: String,
+        bio: Option<String>,
+        guest: bool,
+        last_active_at: DateTime<Utc>,
+        credentials: Option<Credentials>,
+        ratings: HashMap<GameVariant, Rating>,
+    ) -> Self {
+        StoredUser {
+            name,
+            bio,
+            guest,
+            last_active_at,
+            credentials,
+            ratings,
+        }
+    }
+
+    fn to_user(&self, id: Uuid) -> User {
+        User::new(
+            id,
+            self.name.to_string(),
+            self.bio.clone(),
+            self.guest,
+            self.last_active_at,
+            self.credentials.clone(),
+            self.ratings.clone(),
+        )
+    }
+
+    fn rating(&self, variant: GameVariant) -> Rating {
+        self.ratings.get(&variant).copied().unwrap_or_default()
+    }
+
+    /// Users only appear on a leaderboard once they have played a rated game of the variant
+    fn leaderboard_key(
+        &self,
+        id: Uuid,
+        variant: GameVariant,
+        order: LeaderboardOrder,
+    ) -> Option<LeaderboardKey> {
+        let rating = self.ratings.get(&variant)?;
+        if rating.games_played() == 0 {
+            return None;
+        }
+        let rating_value = (rating.value() * 1000.0).round() as i64;
+        let wins = rating.wins() as i64;
+        let key = match order {
+            LeaderboardOrder::Rating => LeaderboardKey(Reverse(rating_value), Reverse(wins), id),
+            LeaderboardOrder::Wins => LeaderboardKey(Reverse(wins), Reverse(rating_value), id),
+        };
+        Some(key)
     }
 }
 
 impl From<&User> for StoredUser {
     fn from(user: &User) -> Self {
-        StoredUser::new(user.name().to_string())
+        StoredUser::new(
+            user.name().to_string(),
+            user.bio().map(str::to_string),
+            user.is_guest(),
+            user.last_active_at(),
+            user.credentials().cloned(),
+            user.ratings().clone(),
+        )
     }
 }
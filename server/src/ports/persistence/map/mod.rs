@@ -1,7 +1,18 @@
+pub(crate) use map_challenge_repository_adapter::*;
 pub(crate) use map_game_repository_adapter::*;
+pub(crate) use map_login_attempt_repository_adapter::*;
+pub(crate) use map_matchmaking_queue_adapter::*;
 pub(crate) use map_room_repository_adapter::*;
+pub(crate) use map_session_repository_adapter::*;
+pub(crate) use map_tournament_repository_adapter::*;
 pub(crate) use map_user_repository_adapter::*;
 
+mod map_challenge_repository_adapter;
 mod map_game_repository_adapter;
+mod map_login_attempt_repository_adapter;
+mod map_matchmaking_queue_adapter;
 mod map_room_repository_adapter;
+mod map_session_repository_adapter;
+mod map_tournament_repository_adapter;
 mod map_user_repository_adapter;
+mod ranked_set;
@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+/// An ordered set that also knows the position of every value, so that finding where a value
+/// ranks or which value is at a position takes logarithmic time rather than a walk from the start.
+/// It is a treap: a binary search tree kept balanced by random priorities, whose nodes count the
+/// values beneath them.
+pub(crate) struct RankedSet<T: Ord> {
+    root: Link<T>,
+}
+
+struct Node<T> {
+    value: T,
+    priority: u64,
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(value: T) -> Self {
+        Node {
+            value,
+            priority: rand::random(),
+            size: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update_size(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+impl<T: Ord> Default for RankedSet<T> {
+    fn default() -> Self {
+        RankedSet { root: None }
+    }
+}
+
+impl<T: Ord> RankedSet<T> {
+    pub(crate) fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub(crate) fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Adds the value, returning whether it was not already present
+    pub(crate) fn insert(&mut self, value: T) -> bool {
+        if self.contains(&value) {
+            return false;
+        }
+        let (before, after) = split(self.root.take(), &|other| *other < value);
+        let middle = Some(Box::new(Node::new(value)));
+        self.root = merge(merge(before, middle), after);
+        true
+    }
+
+    /// Removes the value, returning whether it was present
+    pub(crate) fn remove(&mut self, value: &T) -> bool {
+        let (before, rest) = split(self.root.take(), &|other| other < value);
+        let (removed, after) = split(rest, &|other| other <= value);
+        self.root = merge(before, after);
+        removed.is_some()
+    }
+
+    /// Number of values that sort before the given one
+    pub(crate) fn rank(&self, value: &T) -> usize {
+        let mut rank = 0;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            if *value <= node.value {
+                link = &node.left;
+            } else {
+                rank += size(&node.left) + 1;
+                link = &node.right;
+            }
+        }
+        rank
+    }
+
+    /// The value at the 0-based position in sort order
+    pub(crate) fn nth(&self, mut position: usize) -> Option<&T> {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left_size = size(&node.left);
+            match position.cmp(&left_size) {
+                Ordering::Less => link = &node.left,
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Greater => {
+                    position -= left_size + 1;
+                    link = &node.right;
+                }
+            }
+        }
+        None
+    }
+}
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+/// Splits the tree into the values that satisfy `goes_before` and those that do not, which must
+/// be a prefix and the rest of the sort order
+fn split<T, F>(link: Link<T>, goes_before: &F) -> (Link<T>, Link<T>)
+where
+    F: Fn(&T) -> bool,
+{
+    match link {
+        None => (None, None),
+        Some(mut node) => {
+            if goes_before(&node.value) {
+                let (before, after) = split(node.right.take(), goes_before);
+                node.right = before;
+                node.update_size();
+                (Some(node), after)
+            } else {
+                let (before, after) = split(node.left.take(), goes_before);
+                node.left = after;
+                node.update_size();
+                (before, Some(node))
+            }
+        }
+    }
+}
+
+/// Joins two trees, every value of the first sorting before every value of the second
+fn merge<T>(before: Link<T>, after: Link<T>) -> Link<T> {
+    match (before, after) {
+        (None, after) => after,
+        (before, None) => before,
+        (Some(mut before), Some(mut after)) => {
+            if before.priority > after.priority {
+                before.right = merge(before.right.take(), Some(after));
+                before.update_size();
+                Some(before)
+            } else {
+                after.left = merge(Some(before), after.left.take());
+                after.update_size();
+                Some(after)
+            }
+        }
+    }
+}
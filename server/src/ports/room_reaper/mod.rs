@@ -0,0 +1,7 @@
+pub(crate) use room_reaper_config::*;
+pub(crate) use room_reaper_metrics::*;
+pub(crate) use room_reaper_task::*;
+
+mod room_reaper_config;
+mod room_reaper_metrics;
+mod room_reaper_task;
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use crate::domain::room::RoomExpiryPolicy;
+use crate::ports::env::seconds_from_env;
+
+const INTERVAL_ENV_VAR: &str = "ROOM_REAPER_INTERVAL_SECONDS";
+const EMPTY_ROOM_TTL_ENV_VAR: &str = "EMPTY_ROOM_TTL_SECONDS";
+const IDLE_ROOM_TTL_ENV_VAR: &str = "IDLE_ROOM_TTL_SECONDS";
+const UNFINISHED_GAME_TTL_ENV_VAR: &str = "UNFINISHED_GAME_ROOM_TTL_SECONDS";
+const DEFAULT_INTERVAL_SECONDS: u64 = 60;
+const DEFAULT_EMPTY_ROOM_TTL_SECONDS: u64 = 5 * 60;
+const DEFAULT_IDLE_ROOM_TTL_SECONDS: u64 = 60 * 60;
+const DEFAULT_UNFINISHED_GAME_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct RoomReaperConfig {
+    interval: Duration,
+    empty_room_ttl: Duration,
+    idle_room_ttl: Duration,
+    unfinished_game_ttl: Duration,
+}
+
+impl RoomReaperConfig {
+    pub(crate) fn new(
+        interval: Duration,
+        empty_room_ttl: Duration,
+        idle_room_ttl: Duration,
+        unfinished_game_ttl: Duration,
+    ) -> Self {
+        RoomReaperConfig {
+            interval,
+            empty_room_ttl,
+            idle_room_ttl,
+            unfinished_game_ttl,
+        }
+    }
+
+    /// Reads each setting from the environment, falling back to its default when unset or invalid
+    pub(crate) fn from_env() -> Self {
+        RoomReaperConfig::new(
+            seconds_from_env(INTERVAL_ENV_VAR, DEFAULT_INTERVAL_SECONDS),
+            seconds_from_env(EMPTY_ROOM_TTL_ENV_VAR, DEFAULT_EMPTY_ROOM_TTL_SECONDS),
+            seconds_from_env(IDLE_ROOM_TTL_ENV_VAR, DEFAULT_IDLE_ROOM_TTL_SECONDS),
+            seconds_from_env(
+                UNFINISHED_GAME_TTL_ENV_VAR,
+                DEFAULT_UNFINISHED_GAME_TTL_SECONDS,
+            ),
+        )
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub(crate) fn expiry_policy(&self) -> RoomExpiryPolicy {
+        RoomExpiryPolicy::new(
+            chrono::Duration::from_std(self.empty_room_ttl).unwrap(),
+            chrono::Duration::from_std(self.idle_room_ttl).unwrap(),
+            chrono::Duration::from_std(self.unfinished_game_ttl).unwrap(),
+        )
+    }
+}
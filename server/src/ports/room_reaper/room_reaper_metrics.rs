@@ -0,0 +1,44 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::domain::room::ReapSummary;
+
+/// Running totals of the work done by the room reaper since the server started
+#[derive(Debug, Default)]
+pub(crate) struct RoomReaperMetrics {
+    runs: AtomicU64,
+    empty_rooms_removed: AtomicU64,
+    idle_rooms_removed: AtomicU64,
+    games_archived: AtomicU64,
+}
+
+impl RoomReaperMetrics {
+    pub(crate) fn new() -> Self {
+        RoomReaperMetrics::default()
+    }
+
+    pub(crate) fn record(&self, summary: &ReapSummary) {
+        self.runs.fetch_add(1, Ordering::Relaxed);
+        self.empty_rooms_removed
+            .fetch_add(summary.empty_rooms_removed() as u64, Ordering::Relaxed);
+        self.idle_rooms_removed
+            .fetch_add(summary.idle_rooms_removed() as u64, Ordering::Relaxed);
+        self.games_archived
+            .fetch_add(summary.games_archived() as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn runs(&self) -> u64 {
+        self.runs.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn empty_rooms_removed(&self) -> u64 {
+        self.empty_rooms_removed.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn idle_rooms_removed(&self) -> u64 {
+        self.idle_rooms_removed.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn games_archived(&self) -> u64 {
+        self.games_archived.load(Ordering::Relaxed)
+    }
+}
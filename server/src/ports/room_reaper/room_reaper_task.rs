@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::application::ApplicationService;
+use crate::ports::room_reaper::{RoomReaperConfig, RoomReaperMetrics};
+
+/// Periodically finishes games whose player to move has run out of time and removes rooms that
+/// have gone without activity for too long
+pub(crate) struct RoomReaperTask<AS: ApplicationService> {
+    application_service: Arc<AS>,
+    config: RoomReaperConfig,
+    metrics: Arc<RoomReaperMetrics>,
+}
+
+impl<AS> RoomReaperTask<AS>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        application_service: Arc<AS>,
+        config: RoomReaperConfig,
+        metrics: Arc<RoomReaperMetrics>,
+    ) -> Self {
+        RoomReaperTask {
+            application_service,
+            config,
+            metrics,
+        }
+    }
+
+    pub(crate) fn spawn(self) {
+        tokio::spawn(self.run());
+    }
+
+    async fn run(self) {
+        let mut interval = tokio::time::interval(self.config.interval());
+        // The first tick completes immediately, and nothing can have expired at startup
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let flagged = self.application_service.flag_games().await;
+            if flagged > 0 {
+                log::info!("Room reaper finished {} games on time", flagged);
+            }
+            let summary = self
+                .application_service
+                .reap_rooms(self.config.expiry_policy())
+                .await;
+            self.metrics.record(&summary);
+            if summary.rooms_removed() > 0 {
+                log::info!(
+                    "Room reaper removed {} empty and {} idle rooms, archiving {} unfinished games",
+                    summary.empty_rooms_removed(),
+                    summary.idle_rooms_removed(),
+                    summary.games_archived()
+                );
+            } else {
+                log::debug!("Room reaper found no expired rooms");
+            }
+        }
+    }
+}
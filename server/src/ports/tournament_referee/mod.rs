@@ -0,0 +1,5 @@
+pub(crate) use tournament_referee_config::*;
+pub(crate) use tournament_referee_task::*;
+
+mod tournament_referee_config;
+mod tournament_referee_task;
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::application::ApplicationService;
+use crate::ports::tournament_referee::TournamentRefereeConfig;
+
+/// Periodically forfeits tournament matches whose players have not turned up
+pub(crate) struct TournamentRefereeTask<AS: ApplicationService> {
+    application_service: Arc<AS>,
+    config: TournamentRefereeConfig,
+}
+
+impl<AS> TournamentRefereeTask<AS>
+where
+    AS: ApplicationService + Send + Sync + 'static,
+{
+    pub(crate) fn new(application_service: Arc<AS>, config: TournamentRefereeConfig) -> Self {
+        TournamentRefereeTask {
+            application_service,
+            config,
+        }
+    }
+
+    pub(crate) fn spawn(self) {
+        tokio::spawn(self.run());
+    }
+
+    async fn run(self) {
+        let mut interval = tokio::time::interval(self.config.interval());
+        loop {
+            interval.tick().await;
+            let forfeited = self.application_service.forfeit_tournament_no_shows().await;
+            if forfeited > 0 {
+                log::debug!("Tournament referee forfeited {} matches", forfeited);
+            }
+        }
+    }
+}
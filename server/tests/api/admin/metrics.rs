@@ -0,0 +1,20 @@
+use spectral::prelude::*;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::MetricsResponse;
+
+use crate::helpers::new_app_client;
+
+#[tokio::test]
+async fn returns_room_reaper_metrics() {
+    let app_client = new_app_client();
+
+    let response = app_client.metrics().await;
+
+    assert_that(&response.status()).is_equal_to(StatusCode::OK);
+    let metrics: MetricsResponse = response.json().await.unwrap();
+    let room_reaper = metrics.room_reaper();
+    assert_that(&room_reaper.games_archived()).is_less_than_or_equal_to(
+        room_reaper.empty_rooms_removed() + room_reaper.idle_rooms_removed(),
+    );
+}
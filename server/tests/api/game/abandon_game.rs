@@ -0,0 +1,84 @@
+use spectral::prelude::*;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::SimpleErrorResponse;
+
+use crate::helpers::game_moves::top_left;
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, make_game_move, new_app_client,
+    play_game_won_by_first_player, seat_opponent, start_new_game,
+};
+
+#[tokio::test]
+async fn player_votes_to_abandon_game() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+    let (mut opponent_client, _) = seat_opponent(room_id).await;
+    make_game_move(&app_client, user_id, room_id, top_left()).await;
+
+    let abandon_response = app_client.abandon_game(user_id, room_id).await;
+
+    assert_that(&abandon_response.status()).is_equal_to(&StatusCode::ACCEPTED);
+    app_client.close_socket_connection().await;
+    opponent_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn abandon_fails_if_user_is_not_a_player() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+
+    let abandon_response = app_client.abandon_game(user_id, room_id).await;
+
+    assert_that(&abandon_response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+    let error_response: SimpleErrorResponse = abandon_response.json().await.unwrap();
+    assert_that(&error_response.cause())
+        .is_equal_to(&format!("User({}) is not a player in game", user_id));
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn abandon_fails_if_there_is_no_active_game() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let abandon_response = app_client.abandon_game(user_id, room_id).await;
+
+    assert_that(&abandon_response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    let error_response: SimpleErrorResponse = abandon_response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&format!(
+        "There is no currently active game for room with id: {}",
+        room_id
+    ));
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn abandon_fails_if_game_has_finished() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let abandon_response = app_client_1.abandon_game(user_id_1, room_id).await;
+
+    assert_that(&abandon_response.status()).is_equal_to(&StatusCode::CONFLICT);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
@@ -0,0 +1,373 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{
+    AccountResponse, LoginResponse, SessionListResponse, SimpleErrorResponse,
+    UserDataExportResponse, UserProfileResponse,
+};
+use nc_test_client::{AppClient, GameMove, GameMovePosition};
+
+use crate::helpers::new_app_client;
+
+const PASSWORD: &str = "correct horse battery staple";
+
+/// Usernames are unique across the server, so every test needs its own
+fn unique_username() -> String {
+    format!("player-{}", &Uuid::new_v4().to_simple().to_string()[..12])
+}
+
+async fn register_account(app_client: &AppClient, username: &str) -> AccountResponse {
+    let response = app_client.register_account(username, PASSWORD, None).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response.json().await.unwrap()
+}
+
+async fn login(app_client: &AppClient, username: &str) -> LoginResponse {
+    let response = app_client.login(username, PASSWORD).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response.json().await.unwrap()
+}
+
+async fn sessions(app_client: &AppClient, token: &str) -> SessionListResponse {
+    let response = app_client.sessions(token).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn registers_account_named_after_username() {
+    let app_client = new_app_client();
+    let username = unique_username();
+
+    let account = register_account(&app_client, &username).await;
+
+    assert_that(&account.username()).is_equal_to(&Some(username.as_str()));
+    assert_that(&account.name()).is_equal_to(&username.as_str());
+    let profile: UserProfileResponse = app_client
+        .user_profile(account.user_id())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&profile.name()).is_equal_to(&username.as_str());
+    assert_that(&profile.is_guest()).is_false();
+}
+
+#[tokio::test]
+async fn registers_account_with_display_name() {
+    let app_client = new_app_client();
+
+    let response = app_client
+        .register_account(unique_username(), PASSWORD, Some("Ada Lovelace"))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    let account: AccountResponse = response.json().await.unwrap();
+    assert_that(&account.name()).is_equal_to(&"Ada Lovelace");
+}
+
+#[tokio::test]
+async fn rejects_taken_username_regardless_of_case() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    register_account(&app_client, &username).await;
+
+    let response = app_client
+        .register_account(username.to_uppercase(), PASSWORD, None)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn rejects_invalid_usernames() {
+    let app_client = new_app_client();
+
+    for username in &["ab", "has space", "émile", &"x".repeat(33)] {
+        let response = app_client.register_account(username, PASSWORD, None).await;
+
+        assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn rejects_short_password() {
+    let app_client = new_app_client();
+
+    let response = app_client
+        .register_account(unique_username(), "hunter2", None)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    let error = response.json::<SimpleErrorResponse>().await.unwrap();
+    assert_that(error.cause()).contains("Passwords must be");
+}
+
+#[tokio::test]
+async fn logs_in_with_username_in_any_case() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    let account = register_account(&app_client, &username).await;
+
+    let session = login(&app_client, &username.to_uppercase()).await;
+
+    assert_that(&session.user_id()).is_equal_to(&account.user_id());
+    assert_that(&session.token().is_empty()).is_false();
+    assert_that(&session.expires_at()).is_greater_than(&chrono::Utc::now());
+}
+
+#[tokio::test]
+async fn wrong_password_and_unknown_username_fail_alike() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    register_account(&app_client, &username).await;
+
+    let wrong_password = app_client.login(&username, "not the password").await;
+    let unknown_username = app_client.login(unique_username(), PASSWORD).await;
+
+    assert_that(&wrong_password.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    assert_that(&unknown_username.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    let wrong_password = wrong_password.json::<SimpleErrorResponse>().await.unwrap();
+    let unknown_username = unknown_username
+        .json::<SimpleErrorResponse>()
+        .await
+        .unwrap();
+    assert_that(wrong_password.cause()).is_equal_to(unknown_username.cause());
+}
+
+#[tokio::test]
+async fn throttles_logins_after_repeated_failures() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    register_account(&app_client, &username).await;
+
+    for _ in 0..5 {
+        let response = app_client.login(&username, "not the password").await;
+        assert_that(&response.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    }
+    let response = app_client.login(&username, PASSWORD).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn successful_login_resets_failed_attempts() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    register_account(&app_client, &username).await;
+
+    for _ in 0..4 {
+        app_client.login(&username, "not the password").await;
+    }
+    login(&app_client, &username).await;
+    for _ in 0..4 {
+        app_client.login(&username, "not the password").await;
+    }
+
+    login(&app_client, &username).await;
+}
+
+#[tokio::test]
+async fn lists_active_sessions_marking_the_current_one() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    let account = register_account(&app_client, &username).await;
+    let first = login(&app_client, &username).await;
+    let second = login(&app_client, &username).await;
+
+    let listed = sessions(&app_client, second.token()).await;
+
+    assert_that(&listed.user_id()).is_equal_to(&account.user_id());
+    let ids: Vec<Uuid> = listed.sessions().iter().map(|s| s.session_id()).collect();
+    assert_that(&ids).is_equal_to(&vec![first.session_id(), second.session_id()]);
+    let current: Vec<bool> = listed.sessions().iter().map(|s| s.is_current()).collect();
+    assert_that(&current).is_equal_to(&vec![false, true]);
+}
+
+#[tokio::test]
+async fn sessions_require_a_valid_token() {
+    let app_client = new_app_client();
+
+    let response = app_client.sessions("not-a-token").await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    assert_that(&response.headers().contains_key("www-authenticate")).is_true();
+}
+
+#[tokio::test]
+async fn revoked_session_can_no_longer_be_used() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    register_account(&app_client, &username).await;
+    let laptop = login(&app_client, &username).await;
+    let phone = login(&app_client, &username).await;
+
+    let response = app_client
+        .revoke_session(laptop.session_id(), phone.token())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    let rejected = app_client.sessions(laptop.token()).await;
+    assert_that(&rejected.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    let remaining = sessions(&app_client, phone.token()).await;
+    assert_that(&remaining.sessions().len()).is_equal_to(&1);
+}
+
+#[tokio::test]
+async fn session_can_revoke_itself() {
+    let app_client = new_app_client();
+    let username = unique_username();
+    register_account(&app_client, &username).await;
+    let session = login(&app_client, &username).await;
+
+    let response = app_client
+        .revoke_session(session.session_id(), session.token())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    let rejected = app_client.sessions(session.token()).await;
+    assert_that(&rejected.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn cannot_revoke_another_users_session() {
+    let app_client = new_app_client();
+    let victim_username = unique_username();
+    let attacker_username = unique_username();
+    register_account(&app_client, &victim_username).await;
+    register_account(&app_client, &attacker_username).await;
+    let victim = login(&app_client, &victim_username).await;
+    let attacker = login(&app_client, &attacker_username).await;
+
+    let response = app_client
+        .revoke_session(victim.session_id(), attacker.token())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    sessions(&app_client, victim.token()).await;
+}
+
+#[tokio::test]
+async fn deleting_account_ends_its_sessions() {
+    let mut app_client = new_app_client();
+    let username = unique_username();
+    let account = register_account(&app_client, &username).await;
+    let session = login(&app_client, &username).await;
+    app_client.set_bearer_token(session.token());
+
+    let response = app_client
+        .delete_user(account.user_id(), account.user_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    let rejected = app_client.sessions(session.token()).await;
+    assert_that(&rejected.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    let relogin = app_client.login(&username, PASSWORD).await;
+    assert_that(&relogin.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn data_export_lists_active_sessions() {
+    let mut app_client = new_app_client();
+    let username = unique_username();
+    let account = register_account(&app_client, &username).await;
+    let session = login(&app_client, &username).await;
+    app_client.set_bearer_token(session.token());
+
+    let export: UserDataExportResponse = app_client
+        .export_user_data(account.user_id(), account.user_id())
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    let session_ids: Vec<Uuid> = export.sessions().iter().map(|s| s.session_id()).collect();
+    assert_that(&session_ids).is_equal_to(&vec![session.session_id()]);
+}
+
+#[tokio::test]
+async fn data_export_leaves_out_password_hash() {
+    let mut app_client = new_app_client();
+    let username = unique_username();
+    let account = register_account(&app_client, &username).await;
+    let session = login(&app_client, &username).await;
+    app_client.set_bearer_token(session.token());
+
+    let export = app_client
+        .export_user_data(account.user_id(), account.user_id())
+        .await
+        .text()
+        .await
+        .unwrap();
+
+    assert_that(&export.contains("argon2")).is_false();
+    assert_that(&export.contains(PASSWORD)).is_false();
+}
+
+#[tokio::test]
+async fn acting_as_an_account_requires_its_token() {
+    let app_client = new_app_client();
+    let account = register_account(&app_client, &unique_username()).await;
+
+    let response = app_client
+        .export_user_data(account.user_id(), account.user_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    assert_that(&response.headers().contains_key("www-authenticate")).is_true();
+}
+
+#[tokio::test]
+async fn cannot_delete_another_account() {
+    let mut app_client = new_app_client();
+    let victim = register_account(&app_client, &unique_username()).await;
+    let attacker_username = unique_username();
+    register_account(&app_client, &attacker_username).await;
+    let attacker = login(&app_client, &attacker_username).await;
+    app_client.set_bearer_token(attacker.token());
+
+    let response = app_client
+        .delete_user(victim.user_id(), victim.user_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+    let profile = app_client.user_profile(victim.user_id()).await;
+    assert_that(&profile.status()).is_equal_to(&StatusCode::OK);
+}
+
+#[tokio::test]
+async fn cannot_export_another_accounts_data() {
+    let mut app_client = new_app_client();
+    let victim = register_account(&app_client, &unique_username()).await;
+    let attacker_username = unique_username();
+    register_account(&app_client, &attacker_username).await;
+    let attacker = login(&app_client, &attacker_username).await;
+    app_client.set_bearer_token(attacker.token());
+
+    let response = app_client
+        .export_user_data(victim.user_id(), victim.user_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn cannot_move_as_another_account() {
+    let mut app_client = new_app_client();
+    let victim = register_account(&app_client, &unique_username()).await;
+    let attacker_username = unique_username();
+    register_account(&app_client, &attacker_username).await;
+    let attacker = login(&app_client, &attacker_username).await;
+    app_client.set_bearer_token(attacker.token());
+
+    let response = app_client
+        .make_game_move(
+            victim.user_id(),
+            Uuid::new_v4(),
+            GameMove::new(GameMovePosition::new(0, 0)),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::UNAUTHORIZED);
+}
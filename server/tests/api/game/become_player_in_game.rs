@@ -4,8 +4,8 @@ use nc_test_client::http::StatusCode;
 use nc_test_client::response::SimpleErrorResponse;
 
 use crate::helpers::{
-    become_player, create_room, create_user, join_room, new_app_client, non_existent_id,
-    start_new_game,
+    become_player, create_room, create_user, join_room, leave_room, new_app_client,
+    non_existent_id, start_new_game,
 };
 
 #[tokio::test]
@@ -144,6 +144,7 @@ async fn player_is_removed_if_leaves_room() {
     start_new_game(&app_client, user_id, room_id).await;
     become_player(&app_client, user_id, room_id).await;
 
+    leave_room(&app_client, user_id, room_id).await;
     app_client.close_socket_connection().await;
     join_room(&mut app_client, user_id, room_id).await;
     let become_player_response = app_client.become_player(user_id, room_id).await;
@@ -0,0 +1,312 @@
+use std::time::Duration;
+
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{
+    AcceptChallengeResponse, ChallengeEvent, ChallengeListResponse, ChallengeResponse,
+    RoomDetailsResponse,
+};
+use nc_test_client::AppClient;
+
+use crate::helpers::{create_user, new_app_client, non_existent_id};
+
+async fn create_challenge(app_client: &AppClient, challenger: Uuid, challenged: Uuid) -> Uuid {
+    let response = app_client
+        .create_challenge(challenger, serde_json::json!({ "challenged": challenged }))
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response
+        .json::<ChallengeResponse>()
+        .await
+        .unwrap()
+        .challenge_id()
+}
+
+async fn incoming_challenge_ids(app_client: &AppClient, user_id: Uuid) -> Vec<Uuid> {
+    app_client
+        .incoming_challenges(user_id)
+        .await
+        .json::<ChallengeListResponse>()
+        .await
+        .unwrap()
+        .challenge_ids()
+}
+
+#[tokio::test]
+async fn notifies_challenged_user() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let challenger = create_user(&app_client_1).await;
+    let challenged = create_user(&app_client_2).await;
+    app_client_2
+        .listen_for_challenges(challenged)
+        .await
+        .unwrap();
+
+    let response = app_client_1
+        .create_challenge(
+            challenger,
+            serde_json::json!({ "challenged": challenged, "variant": "misere" }),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    let challenge: ChallengeResponse = response.json().await.unwrap();
+    assert_that(&challenge.challenger()).is_equal_to(&challenger);
+    assert_that(&challenge.challenged()).is_equal_to(&challenged);
+    assert_that(&challenge.variant()).is_equal_to("misere");
+    assert_that(&app_client_2.next_challenge_event().await).is_equal_to(&Some(
+        ChallengeEvent::Received {
+            challenge_id: challenge.challenge_id(),
+            challenger,
+            expires_at: challenge.expires_at(),
+        },
+    ));
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn lists_incoming_and_outgoing_challenges() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+    let challenged = create_user(&app_client).await;
+    let challenge_id = create_challenge(&app_client, challenger, challenged).await;
+
+    let outgoing: ChallengeListResponse = app_client
+        .outgoing_challenges(challenger)
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(&outgoing.challenge_ids()).is_equal_to(&vec![challenge_id]);
+    assert_that(&incoming_challenge_ids(&app_client, challenged).await)
+        .is_equal_to(&vec![challenge_id]);
+    assert_that(&incoming_challenge_ids(&app_client, challenger).await).is_empty();
+}
+
+#[tokio::test]
+async fn accepting_challenge_starts_game() {
+    let mut app_client_1 = new_app_client();
+    let app_client_2 = new_app_client();
+    let challenger = create_user(&app_client_1).await;
+    let challenged = create_user(&app_client_2).await;
+    app_client_1
+        .listen_for_challenges(challenger)
+        .await
+        .unwrap();
+    let response = app_client_1
+        .create_challenge(
+            challenger,
+            serde_json::json!({ "challenged": challenged, "initial_seconds": 60 }),
+        )
+        .await;
+    let challenge_id = response
+        .json::<ChallengeResponse>()
+        .await
+        .unwrap()
+        .challenge_id();
+
+    let response = app_client_2
+        .accept_challenge(challenged, challenge_id)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let accepted: AcceptChallengeResponse = response.json().await.unwrap();
+    assert_that(&app_client_1.next_challenge_event().await).is_equal_to(&Some(
+        ChallengeEvent::Accepted {
+            challenge_id,
+            room_id: accepted.room_id(),
+            game_id: accepted.game_id(),
+        },
+    ));
+    let details: RoomDetailsResponse = app_client_1
+        .room_details(accepted.room_id())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&details.private()).is_true();
+    assert_that(details.seated_players()).is_equal_to(&vec![challenger, challenged]);
+    assert_that(&details.active_game_id()).is_equal_to(&Some(accepted.game_id()));
+    assert_that(&details.settings().time_control().unwrap().initial_seconds()).is_equal_to(60);
+    assert_that(&incoming_challenge_ids(&app_client_2, challenged).await).is_empty();
+    app_client_1.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_accept_challenge_twice() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+    let challenged = create_user(&app_client).await;
+    let challenge_id = create_challenge(&app_client, challenger, challenged).await;
+    let response = app_client.accept_challenge(challenged, challenge_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+
+    let response = app_client.accept_challenge(challenged, challenge_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn simultaneous_accept_and_decline_settle_challenge_once() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+    let challenged = create_user(&app_client).await;
+    let challenge_id = create_challenge(&app_client, challenger, challenged).await;
+
+    let (accepted, declined) = tokio::join!(
+        app_client.accept_challenge(challenged, challenge_id),
+        app_client.decline_challenge(challenged, challenge_id)
+    );
+
+    let statuses = vec![accepted.status(), declined.status()];
+    assert_that(&statuses.contains(&StatusCode::NOT_FOUND)).is_true();
+    assert_that(&statuses.iter().filter(|status| status.is_success()).count()).is_equal_to(1);
+    assert_that(&incoming_challenge_ids(&app_client, challenged).await).is_empty();
+}
+
+#[tokio::test]
+async fn deleting_account_withdraws_its_challenges() {
+    let app_client = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let leaver = create_user(&app_client).await;
+    let challenged = create_user(&app_client_2).await;
+    let challenger = create_user(&app_client).await;
+    app_client_2
+        .listen_for_challenges(challenged)
+        .await
+        .unwrap();
+    let sent = create_challenge(&app_client, leaver, challenged).await;
+    create_challenge(&app_client, challenger, leaver).await;
+    app_client_2.next_challenge_event().await;
+
+    let response = app_client.delete_user(leaver, leaver).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client_2.next_challenge_event().await)
+        .is_equal_to(&Some(ChallengeEvent::Withdrawn { challenge_id: sent }));
+    assert_that(&incoming_challenge_ids(&app_client, challenged).await).is_empty();
+    let outgoing = app_client
+        .outgoing_challenges(challenger)
+        .await
+        .json::<ChallengeListResponse>()
+        .await
+        .unwrap();
+    assert_that(&outgoing.challenge_ids()).is_empty();
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn declining_challenge_notifies_challenger() {
+    let mut app_client_1 = new_app_client();
+    let app_client_2 = new_app_client();
+    let challenger = create_user(&app_client_1).await;
+    let challenged = create_user(&app_client_2).await;
+    app_client_1
+        .listen_for_challenges(challenger)
+        .await
+        .unwrap();
+    let challenge_id = create_challenge(&app_client_1, challenger, challenged).await;
+
+    let response = app_client_2
+        .decline_challenge(challenged, challenge_id)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client_1.next_challenge_event().await)
+        .is_equal_to(&Some(ChallengeEvent::Declined { challenge_id }));
+    assert_that(&incoming_challenge_ids(&app_client_2, challenged).await).is_empty();
+    app_client_1.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn challenge_expires() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+    let challenged = create_user(&app_client).await;
+    let response = app_client
+        .create_challenge(
+            challenger,
+            serde_json::json!({ "challenged": challenged, "expires_in_seconds": 1 }),
+        )
+        .await;
+    let challenge_id = response
+        .json::<ChallengeResponse>()
+        .await
+        .unwrap()
+        .challenge_id();
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    assert_that(&incoming_challenge_ids(&app_client, challenged).await).is_empty();
+    let response = app_client.accept_challenge(challenged, challenge_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::GONE);
+}
+
+#[tokio::test]
+async fn fails_to_accept_challenge_sent_to_another_user() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+    let challenged = create_user(&app_client).await;
+    let challenge_id = create_challenge(&app_client, challenger, challenged).await;
+
+    let response = app_client.accept_challenge(challenger, challenge_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn fails_to_accept_challenge_that_does_not_exist() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .accept_challenge(user_id, non_existent_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn fails_to_challenge_self() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .create_challenge(user_id, serde_json::json!({ "challenged": user_id }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn fails_to_challenge_user_twice() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+    let challenged = create_user(&app_client).await;
+    create_challenge(&app_client, challenger, challenged).await;
+
+    let response = app_client
+        .create_challenge(challenger, serde_json::json!({ "challenged": challenged }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn fails_to_challenge_user_that_does_not_exist() {
+    let app_client = new_app_client();
+    let challenger = create_user(&app_client).await;
+
+    let response = app_client
+        .create_challenge(
+            challenger,
+            serde_json::json!({ "challenged": non_existent_id() }),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,200 @@
+use spectral::prelude::*;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{
+    GameHistoryResponse, GameOutcome, GameResponse, SimpleErrorResponse,
+};
+
+use crate::helpers::game_moves::top_left;
+use crate::helpers::{
+    abandon_game, become_player, create_room, create_user, join_room, make_game_move,
+    new_app_client, non_existent_id, play_game_won_by_first_player, start_new_game,
+};
+
+#[tokio::test]
+async fn returns_empty_history_for_new_room() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+
+    let history_response = app_client.game_history(room_id).await;
+
+    assert_that(&history_response.status()).is_equal_to(&StatusCode::OK);
+    let history: GameHistoryResponse = history_response.json().await.unwrap();
+    assert_that(history.games()).is_empty();
+}
+
+#[tokio::test]
+async fn returns_finished_games_in_order() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+    abandon_game(&app_client_1, user_id_1, room_id).await;
+    abandon_game(&app_client_2, user_id_2, room_id).await;
+
+    let history: GameHistoryResponse = app_client_1
+        .game_history(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(history.games()).has_length(2);
+    assert_that(&history.games()[0].outcome())
+        .is_equal_to(&Some(GameOutcome::Win { winner: user_id_1 }));
+    assert_that(history.games()[0].players()).contains(&user_id_2);
+    assert_that(&history.games()[1].outcome()).is_equal_to(&Some(GameOutcome::Abandoned));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn history_fails_if_room_does_not_exist() {
+    let app_client = new_app_client();
+    let room_id = non_existent_id();
+
+    let history_response = app_client.game_history(room_id).await;
+
+    assert_that(&history_response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    let error_response: SimpleErrorResponse = history_response.json().await.unwrap();
+    assert_that(&error_response.cause())
+        .is_equal_to(&format!("Could not find room with id: {}", room_id));
+}
+
+#[tokio::test]
+async fn returns_moves_of_past_game() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    let history: GameHistoryResponse = app_client_1
+        .game_history(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let game_id = history.games()[0].game_id();
+
+    let game_response = app_client_1.room_game(room_id, game_id).await;
+
+    assert_that(&game_response.status()).is_equal_to(&StatusCode::OK);
+    let game: GameResponse = game_response.json().await.unwrap();
+    assert_that(&game.game_id()).is_equal_to(&game_id);
+    assert_that(game.moves()).has_length(5);
+    assert_that(&game.moves()[0].user_id()).is_equal_to(&user_id_1);
+    assert_that(&game.moves()[0].position()).is_equal_to(&(0, 0));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn get_game_fails_if_game_is_not_part_of_room() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    let game_id = non_existent_id();
+
+    let game_response = app_client.room_game(room_id, game_id).await;
+
+    assert_that(&game_response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    let error_response: SimpleErrorResponse = game_response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&format!(
+        "Game({}) is not part of Room({})",
+        game_id, room_id
+    ));
+}
+
+#[tokio::test]
+async fn records_start_and_finish_times_of_games() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    abandon_game(&app_client_1, user_id_1, room_id).await;
+    abandon_game(&app_client_2, user_id_2, room_id).await;
+
+    let history: GameHistoryResponse = app_client_1
+        .game_history(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    let first_game = &history.games()[0];
+    let second_game = &history.games()[1];
+    let first_game_finished_at = first_game.finished_at().unwrap();
+    assert_that(&first_game.started_at()).is_less_than_or_equal_to(&first_game_finished_at);
+    assert_that(&first_game_finished_at).is_less_than_or_equal_to(&second_game.started_at());
+    assert_that(&second_game.started_at())
+        .is_less_than_or_equal_to(&second_game.finished_at().unwrap());
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn records_time_of_each_move() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+    let history: GameHistoryResponse = app_client_1
+        .game_history(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let game_id = history.games()[0].game_id();
+
+    let game: GameResponse = app_client_1
+        .room_game(room_id, game_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    let move_times: Vec<_> = game.moves().iter().map(|m| m.made_at()).collect();
+    assert_that(&game.started_at()).is_less_than_or_equal_to(&move_times[0]);
+    move_times
+        .windows(2)
+        .for_each(|pair| assert_that(&pair[0]).is_less_than_or_equal_to(&pair[1]));
+    assert_that(&game.finished_at()).is_equal_to(&move_times.last().copied());
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
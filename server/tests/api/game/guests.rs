@@ -0,0 +1,146 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{GuestResponse, SimpleErrorResponse, UserProfileResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::{create_room, create_user, new_app_client, non_existent_id};
+
+async fn create_guest(app_client: &AppClient) -> GuestResponse {
+    let response = app_client.create_guest().await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response.json().await.unwrap()
+}
+
+async fn user_profile(app_client: &AppClient, user_id: Uuid) -> UserProfileResponse {
+    app_client.user_profile(user_id).await.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn creates_guest_with_generated_name() {
+    let app_client = new_app_client();
+
+    let guest = create_guest(&app_client).await;
+
+    assert_that(&guest.name()).starts_with("Guest ");
+    let profile = user_profile(&app_client, guest.user_id()).await;
+    assert_that(&profile.name()).is_equal_to(&guest.name());
+    assert_that(&profile.is_guest()).is_true();
+}
+
+#[tokio::test]
+async fn guests_are_given_different_names() {
+    let app_client = new_app_client();
+
+    let first = create_guest(&app_client).await;
+    let second = create_guest(&app_client).await;
+
+    assert_that(&first.name()).is_not_equal_to(&second.name());
+}
+
+#[tokio::test]
+async fn registered_user_is_not_a_guest() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    assert_that(&user_profile(&app_client, user_id).await.is_guest()).is_false();
+}
+
+#[tokio::test]
+async fn guest_can_host_a_room() {
+    let app_client = new_app_client();
+    let guest = create_guest(&app_client).await;
+
+    let room_id = create_room(&app_client, guest.user_id()).await;
+
+    assert_that(&room_id).is_not_equal_to(&Uuid::nil());
+}
+
+#[tokio::test]
+async fn guest_upgrades_to_full_account_with_same_id() {
+    let app_client = new_app_client();
+    let guest = create_guest(&app_client).await;
+
+    let response = app_client
+        .upgrade_guest(guest.user_id(), guest.user_id(), "Upgraded")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let profile: UserProfileResponse = response.json().await.unwrap();
+    assert_that(&profile.user_id()).is_equal_to(&guest.user_id());
+    assert_that(&profile.name()).is_equal_to(&"Upgraded");
+    assert_that(&profile.is_guest()).is_false();
+    assert_that(&user_profile(&app_client, guest.user_id()).await.is_guest()).is_false();
+}
+
+#[tokio::test]
+async fn registered_user_cannot_be_upgraded() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client.upgrade_guest(user_id, user_id, "Upgraded").await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    assert_that(
+        &response
+            .json::<SimpleErrorResponse>()
+            .await
+            .unwrap()
+            .cause(),
+    )
+    .is_equal_to(&format!("User {} is not a guest", user_id));
+}
+
+#[tokio::test]
+async fn guest_can_only_be_upgraded_once() {
+    let app_client = new_app_client();
+    let guest = create_guest(&app_client).await;
+    app_client
+        .upgrade_guest(guest.user_id(), guest.user_id(), "Upgraded")
+        .await;
+
+    let response = app_client
+        .upgrade_guest(guest.user_id(), guest.user_id(), "Again")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    assert_that(&user_profile(&app_client, guest.user_id()).await.name()).is_equal_to(&"Upgraded");
+}
+
+#[tokio::test]
+async fn user_cannot_upgrade_someone_elses_guest_account() {
+    let app_client = new_app_client();
+    let guest = create_guest(&app_client).await;
+    let other_user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .upgrade_guest(guest.user_id(), other_user_id, "Upgraded")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    assert_that(&user_profile(&app_client, guest.user_id()).await.is_guest()).is_true();
+}
+
+#[tokio::test]
+async fn upgrade_fails_given_invalid_name() {
+    let app_client = new_app_client();
+    let guest = create_guest(&app_client).await;
+
+    let response = app_client
+        .upgrade_guest(guest.user_id(), guest.user_id(), " ")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    assert_that(&user_profile(&app_client, guest.user_id()).await.is_guest()).is_true();
+}
+
+#[tokio::test]
+async fn upgrade_fails_if_user_does_not_exist() {
+    let app_client = new_app_client();
+    let user_id = non_existent_id();
+
+    let response = app_client.upgrade_guest(user_id, user_id, "Upgraded").await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
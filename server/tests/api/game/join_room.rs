@@ -1,6 +1,8 @@
 use spectral::prelude::*;
+use uuid::Uuid;
 use warp::http::StatusCode;
 
+use nc_test_client::response::RoomDetailsResponse;
 use nc_test_client::TungsteniteError;
 
 use crate::helpers::{create_room, create_user, new_app_client, non_existent_id};
@@ -17,7 +19,7 @@ async fn join_room() {
 }
 
 #[tokio::test]
-async fn fails_to_join_different_room_if_already_in_a_room() {
+async fn joins_multiple_rooms() {
     let mut first_app_client = new_app_client();
     let mut second_app_client = new_app_client();
     let user_id = create_user(&first_app_client).await;
@@ -25,13 +27,18 @@ async fn fails_to_join_different_room_if_already_in_a_room() {
     let other_room_id = create_room(&first_app_client, user_id).await;
 
     first_app_client.join_room(user_id, room_id).await.unwrap();
-
     let second_join_result = second_app_client.join_room(user_id, other_room_id).await;
 
-    if let TungsteniteError::Http(response) = second_join_result.err().unwrap() {
-        assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
-    } else {
-        panic!("Unexpected error variant");
+    assert_that(&second_join_result).is_ok();
+    for room_id in [room_id, other_room_id].iter() {
+        let details: RoomDetailsResponse = first_app_client
+            .room_details(*room_id)
+            .await
+            .json()
+            .await
+            .unwrap();
+        let member_ids: Vec<Uuid> = details.members().iter().map(|m| m.user_id()).collect();
+        assert_that(&member_ids).contains(&user_id);
     }
 
     first_app_client.close_socket_connection().await;
@@ -0,0 +1,170 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{LeaderboardEntryResponse, LeaderboardResponse};
+use nc_test_client::{AppClient, LeaderboardQuery};
+
+use crate::helpers::{
+    create_rated_room, create_user, new_app_client, non_existent_id, play_game_in_room,
+};
+
+/// Plays a rated standard game and returns the ids of the winner and loser
+async fn play_rated_game() -> (Uuid, Uuid) {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let winner = create_user(&app_client_1).await;
+    let loser = create_user(&app_client_2).await;
+    let room_id = create_rated_room(&app_client_1, winner).await;
+    play_game_in_room(&mut app_client_1, &mut app_client_2, winner, loser, room_id).await;
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    (winner, loser)
+}
+
+async fn leaderboard_rank(
+    app_client: &AppClient,
+    user_id: Uuid,
+    query: LeaderboardQuery,
+) -> LeaderboardEntryResponse {
+    let response = app_client.leaderboard_rank(user_id, query).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn ranks_winner_above_loser() {
+    let app_client = new_app_client();
+    let (winner, loser) = play_rated_game().await;
+
+    let winner_entry = leaderboard_rank(&app_client, winner, LeaderboardQuery::new()).await;
+    let loser_entry = leaderboard_rank(&app_client, loser, LeaderboardQuery::new()).await;
+
+    assert_that(&winner_entry.user_id()).is_equal_to(&winner);
+    assert_that(&winner_entry.name()).is_equal_to("name");
+    assert_that(&winner_entry.games_played()).is_equal_to(1);
+    assert_that(&winner_entry.wins()).is_equal_to(1);
+    assert_that(&winner_entry.rating()).is_greater_than(1500);
+    assert_that(&loser_entry.wins()).is_equal_to(0);
+    assert_that(&winner_entry.rank()).is_less_than(loser_entry.rank());
+}
+
+#[tokio::test]
+async fn ranks_winner_above_loser_by_wins() {
+    let app_client = new_app_client();
+    let (winner, loser) = play_rated_game().await;
+    let query = LeaderboardQuery::new().order("wins");
+
+    let winner_entry = leaderboard_rank(&app_client, winner, query).await;
+    let loser_entry = leaderboard_rank(&app_client, loser, query).await;
+
+    assert_that(&winner_entry.rank()).is_less_than(loser_entry.rank());
+}
+
+#[tokio::test]
+async fn leaderboard_is_sorted_by_rating() {
+    let app_client = new_app_client();
+    play_rated_game().await;
+
+    let response = app_client
+        .leaderboard(LeaderboardQuery::new().per_page(100))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let leaderboard: LeaderboardResponse = response.json().await.unwrap();
+    assert_that(&leaderboard.variant()).is_equal_to("standard");
+    assert_that(&leaderboard.page()).is_equal_to(1);
+    assert_that(&leaderboard.per_page()).is_equal_to(100);
+    assert_that(&leaderboard.entries().is_empty()).is_false();
+    assert_that(&leaderboard.total()).is_greater_than_or_equal_to(leaderboard.entries().len());
+    for (index, entry) in leaderboard.entries().iter().enumerate() {
+        assert_that(&entry.rank()).is_equal_to(index + 1);
+        assert_that(&entry.games_played()).is_greater_than(0);
+    }
+    for pair in leaderboard.entries().windows(2) {
+        assert_that(&pair[0].rating()).is_greater_than_or_equal_to(pair[1].rating());
+    }
+}
+
+#[tokio::test]
+async fn leaderboard_is_sorted_by_wins() {
+    let app_client = new_app_client();
+    play_rated_game().await;
+
+    let leaderboard: LeaderboardResponse = app_client
+        .leaderboard(LeaderboardQuery::new().order("wins").per_page(100))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(&leaderboard.entries().is_empty()).is_false();
+    for pair in leaderboard.entries().windows(2) {
+        assert_that(&pair[0].wins()).is_greater_than_or_equal_to(pair[1].wins());
+    }
+}
+
+#[tokio::test]
+async fn paginates_leaderboard() {
+    let app_client = new_app_client();
+    play_rated_game().await;
+
+    let leaderboard: LeaderboardResponse = app_client
+        .leaderboard(LeaderboardQuery::new().page(2).per_page(1))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(&leaderboard.page()).is_equal_to(2);
+    assert_that(&leaderboard.per_page()).is_equal_to(1);
+    assert_that(&leaderboard.total()).is_greater_than_or_equal_to(2);
+    assert_that(leaderboard.entries()).has_length(1);
+    assert_that(&leaderboard.entries()[0].rank()).is_equal_to(2);
+}
+
+#[tokio::test]
+async fn leaderboards_are_separate_per_variant() {
+    let app_client = new_app_client();
+    let (winner, _) = play_rated_game().await;
+
+    let response = app_client
+        .leaderboard_rank(winner, LeaderboardQuery::new().variant("misere"))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn user_without_rated_games_is_not_ranked() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .leaderboard_rank(user_id, LeaderboardQuery::new())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn fails_to_get_rank_of_user_that_does_not_exist() {
+    let app_client = new_app_client();
+
+    let response = app_client
+        .leaderboard_rank(non_existent_id(), LeaderboardQuery::new())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn fails_to_get_leaderboard_with_invalid_page_size() {
+    let app_client = new_app_client();
+
+    let response = app_client
+        .leaderboard(LeaderboardQuery::new().per_page(101))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
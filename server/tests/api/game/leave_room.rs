@@ -1,20 +1,163 @@
+use std::time::Duration;
+
 use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RoomDetailsResponse, RoomEvent, RoomMemberResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, leave_room, new_app_client,
+    non_existent_id, start_new_game,
+};
+
+async fn room_details(app_client: &AppClient, room_id: Uuid) -> RoomDetailsResponse {
+    app_client.room_details(room_id).await.json().await.unwrap()
+}
 
-use crate::helpers::{create_room, create_user, new_app_client};
+async fn member_ids(app_client: &AppClient, room_id: Uuid) -> Vec<Uuid> {
+    let details = room_details(app_client, room_id).await;
+    details.members().iter().map(|m| m.user_id()).collect()
+}
+
+/// Waits for the server to notice that the member's connection has dropped
+async fn offline_member(
+    app_client: &AppClient,
+    room_id: Uuid,
+    user_id: Uuid,
+) -> RoomMemberResponse {
+    for _ in 0..50 {
+        let details = room_details(app_client, room_id).await;
+        let member = details
+            .members()
+            .iter()
+            .find(|member| member.user_id() == user_id)
+            .cloned()
+            .expect("User is no longer a member");
+        if !member.online() {
+            return member;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("User({}) is still online", user_id);
+}
+
+#[tokio::test]
+async fn leaves_room_when_requested() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let member_id = create_user(&app_client_1).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, member_id, room_id).await;
+
+    let response = app_client_1.leave_room(host_id, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client_2.next_room_event().await).is_equal_to(&Some(RoomEvent::HostChanged {
+        room_id,
+        host: member_id,
+    }));
+    assert_that(&member_ids(&app_client_2, room_id).await).is_equal_to(&vec![member_id]);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn leaving_fails_if_user_is_not_a_member() {
+    let app_client = new_app_client();
+    let host_id = create_user(&app_client).await;
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, host_id).await;
+
+    let response = app_client.leave_room(user_id, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+}
 
 #[tokio::test]
-async fn leaves_room_if_client_disconnects() {
-    let mut app_client = new_app_client();
+async fn leaving_fails_if_room_does_not_exist() {
+    let app_client = new_app_client();
     let user_id = create_user(&app_client).await;
-    let room_id = create_room(&app_client, user_id).await;
-    app_client.join_room(user_id, room_id).await.unwrap();
 
-    app_client.close_socket_connection().await;
+    let response = app_client.leave_room(user_id, non_existent_id()).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn stays_in_room_offline_if_client_disconnects() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let member_id = create_user(&app_client_1).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, member_id, room_id).await;
+
+    app_client_1.close_socket_connection().await;
+
+    let host = offline_member(&app_client_2, room_id, host_id).await;
+    assert_that(&host.user_id()).is_equal_to(&host_id);
+    let details = room_details(&app_client_2, room_id).await;
+    assert_that(&details.host()).is_equal_to(&Some(host_id));
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn keeps_seat_in_game_if_client_disconnects() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let player_id = create_user(&app_client_1).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, player_id, room_id).await;
+    start_new_game(&app_client_1, host_id, room_id).await;
+    become_player(&app_client_2, player_id, room_id).await;
+
+    app_client_2.close_socket_connection().await;
+
+    offline_member(&app_client_1, room_id, player_id).await;
+    let details = room_details(&app_client_1, room_id).await;
+    assert_that(&details.seated_players()).is_equal_to(&vec![player_id]);
+    app_client_1.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn comes_back_online_when_reconnecting() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let member_id = create_user(&app_client_1).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, member_id, room_id).await;
+    app_client_2.close_socket_connection().await;
+    offline_member(&app_client_1, room_id, member_id).await;
+
+    join_room(&mut app_client_2, member_id, room_id).await;
+
+    let details = room_details(&app_client_1, room_id).await;
+    assert_that(&details.members().iter().all(|member| member.online())).is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn remains_in_other_rooms_if_client_disconnects_from_one() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id = create_user(&app_client_1).await;
+    let room_id = create_room(&app_client_1, user_id).await;
+    let other_room_id = create_room(&app_client_1, user_id).await;
+    join_room(&mut app_client_1, user_id, room_id).await;
+    join_room(&mut app_client_2, user_id, other_room_id).await;
 
-    let other_room_id = create_room(&app_client, user_id).await;
+    app_client_1.close_socket_connection().await;
 
-    asserting("User is not already in a room")
-        .that(&app_client.join_room(user_id, other_room_id).await)
-        .is_ok();
-    app_client.close_socket_connection().await;
+    assert_that(&member_ids(&app_client_2, other_room_id).await).is_equal_to(&vec![user_id]);
+    app_client_2.close_socket_connection().await;
 }
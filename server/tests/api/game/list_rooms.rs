@@ -0,0 +1,164 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RoomListResponse, SimpleErrorResponse};
+use nc_test_client::{AppClient, RoomListQuery};
+
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, new_app_client, start_new_game,
+};
+
+async fn listed_room_ids(app_client: &AppClient, query: RoomListQuery) -> Vec<Uuid> {
+    app_client
+        .list_rooms(query.per_page(100))
+        .await
+        .json::<RoomListResponse>()
+        .await
+        .unwrap()
+        .room_ids()
+}
+
+#[tokio::test]
+async fn lists_created_room() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+
+    let response = app_client
+        .list_rooms(RoomListQuery::new().per_page(100))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let rooms: RoomListResponse = response.json().await.unwrap();
+    let room = rooms
+        .rooms()
+        .iter()
+        .find(|room| room.room_id() == room_id)
+        .expect("Created room was not listed");
+    assert_that(&room.member_count()).is_equal_to(0);
+    assert_that(&room.open_seat()).is_true();
+}
+
+#[tokio::test]
+async fn filters_rooms_by_open_seat() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+
+    let open_rooms = listed_room_ids(&app_client_1, RoomListQuery::new().open_seat(true)).await;
+    let full_rooms = listed_room_ids(&app_client_1, RoomListQuery::new().open_seat(false)).await;
+
+    assert_that(&open_rooms).does_not_contain(&room_id);
+    assert_that(&full_rooms).contains(&room_id);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn filters_rooms_by_member_count() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    let with_two_members = listed_room_ids(
+        &app_client_1,
+        RoomListQuery::new().min_members(2).max_members(2),
+    )
+    .await;
+    let with_at_most_one_member =
+        listed_room_ids(&app_client_1, RoomListQuery::new().max_members(1)).await;
+
+    assert_that(&with_two_members).contains(&room_id);
+    assert_that(&with_at_most_one_member).does_not_contain(&room_id);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn paginates_rooms() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    create_room(&app_client, user_id).await;
+    create_room(&app_client, user_id).await;
+
+    let rooms: RoomListResponse = app_client
+        .list_rooms(RoomListQuery::new().page(2).per_page(1))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(rooms.rooms()).has_length(1);
+    assert_that(&rooms.page()).is_equal_to(2);
+    assert_that(&rooms.per_page()).is_equal_to(1);
+    assert_that(&rooms.total()).is_greater_than_or_equal_to(2);
+}
+
+#[tokio::test]
+async fn returns_empty_page_beyond_last_room() {
+    let app_client = new_app_client();
+
+    let first_page: RoomListResponse = app_client
+        .list_rooms(RoomListQuery::new())
+        .await
+        .json()
+        .await
+        .unwrap();
+    let rooms: RoomListResponse = app_client
+        .list_rooms(
+            RoomListQuery::new()
+                .page(first_page.total() + 1_000)
+                .per_page(1),
+        )
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(rooms.rooms()).is_empty();
+}
+
+#[tokio::test]
+async fn list_rooms_fails_if_page_is_zero() {
+    let app_client = new_app_client();
+
+    let response = app_client.list_rooms(RoomListQuery::new().page(0)).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    let error_response: SimpleErrorResponse = response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&"page must be at least 1".to_string());
+}
+
+#[tokio::test]
+async fn list_rooms_fails_if_page_size_is_too_large() {
+    let app_client = new_app_client();
+
+    let response = app_client
+        .list_rooms(RoomListQuery::new().per_page(101))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn list_rooms_fails_if_member_range_is_empty() {
+    let app_client = new_app_client();
+
+    let response = app_client
+        .list_rooms(RoomListQuery::new().min_members(3).max_members(2))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
@@ -4,12 +4,12 @@ use nc_test_client::http::StatusCode;
 use nc_test_client::response::SimpleErrorResponse;
 
 use crate::helpers::game_moves::{
-    top_left, x_position_above_valid_range, x_position_below_valid_range,
+    bottom_right, top_left, x_position_above_valid_range, x_position_below_valid_range,
     y_position_above_valid_range, y_position_below_valid_range,
 };
 use crate::helpers::{
     become_player, create_room, create_user, join_room, make_game_move, new_app_client,
-    non_existent_id, start_new_game,
+    non_existent_id, play_game_won_by_first_player, seat_opponent, start_new_game,
 };
 
 #[tokio::test]
@@ -20,12 +20,33 @@ async fn user_makes_a_move_in_a_game() {
     join_room(&mut app_client, user_id, room_id).await;
     start_new_game(&app_client, user_id, room_id).await;
     become_player(&app_client, user_id, room_id).await;
+    let (mut opponent_client, _) = seat_opponent(room_id).await;
     let game_move = top_left();
 
     let game_move_response = app_client.make_game_move(user_id, room_id, game_move).await;
 
     assert_that(&game_move_response.status()).is_equal_to(&StatusCode::ACCEPTED);
     app_client.close_socket_connection().await;
+    opponent_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn move_fails_until_both_seats_are_filled() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+
+    let game_move_response = app_client
+        .make_game_move(user_id, room_id, top_left())
+        .await;
+
+    assert_that(&game_move_response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+    let error_response: SimpleErrorResponse = game_move_response.json().await.unwrap();
+    assert_that(error_response.cause()).contains("until both seats are filled");
+    app_client.close_socket_connection().await;
 }
 
 #[tokio::test]
@@ -36,6 +57,7 @@ async fn move_fails_if_x_position_is_above_valid_range() {
     join_room(&mut app_client, user_id, room_id).await;
     start_new_game(&app_client, user_id, room_id).await;
     become_player(&app_client, user_id, room_id).await;
+    let (mut opponent_client, _) = seat_opponent(room_id).await;
     let game_move = x_position_above_valid_range();
 
     let game_move_response = app_client.make_game_move(user_id, room_id, game_move).await;
@@ -44,6 +66,7 @@ async fn move_fails_if_x_position_is_above_valid_range() {
     let error_response: SimpleErrorResponse = game_move_response.json().await.unwrap();
     assert_that(&error_response.cause()).is_equal_to(&"Position is out of bounds".to_string());
     app_client.close_socket_connection().await;
+    opponent_client.close_socket_connection().await;
 }
 
 #[tokio::test]
@@ -73,6 +96,7 @@ async fn move_fails_if_y_position_is_above_valid_range() {
     join_room(&mut app_client, user_id, room_id).await;
     start_new_game(&app_client, user_id, room_id).await;
     become_player(&app_client, user_id, room_id).await;
+    let (mut opponent_client, _) = seat_opponent(room_id).await;
     let game_move = y_position_above_valid_range();
 
     let game_move_response = app_client.make_game_move(user_id, room_id, game_move).await;
@@ -81,6 +105,7 @@ async fn move_fails_if_y_position_is_above_valid_range() {
     let error_response: SimpleErrorResponse = game_move_response.json().await.unwrap();
     assert_that(&error_response.cause()).is_equal_to(&"Position is out of bounds".to_string());
     app_client.close_socket_connection().await;
+    opponent_client.close_socket_connection().await;
 }
 
 #[tokio::test]
@@ -112,12 +137,11 @@ async fn move_fails_if_position_is_already_occupied() {
     join_room(&mut other_app_client, other_user_id, room_id).await;
     start_new_game(&other_app_client, other_user_id, room_id).await;
     become_player(&other_app_client, other_user_id, room_id).await;
-    make_game_move(&other_app_client, other_user_id, room_id, game_move).await;
-
     let mut app_client = new_app_client();
     let user_id = create_user(&app_client).await;
     join_room(&mut app_client, user_id, room_id).await;
     become_player(&app_client, user_id, room_id).await;
+    make_game_move(&other_app_client, other_user_id, room_id, game_move).await;
 
     let game_move_response = app_client.make_game_move(user_id, room_id, game_move).await;
 
@@ -230,3 +254,28 @@ async fn move_fails_if_room_does_not_exist() {
         .is_equal_to(&format!("Could not find room with id: {}", room_id));
     app_client.close_socket_connection().await;
 }
+
+#[tokio::test]
+async fn move_fails_if_game_has_finished() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let game_move_response = app_client_2
+        .make_game_move(user_id_2, room_id, bottom_right())
+        .await;
+
+    assert_that(&game_move_response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+    let error_response: SimpleErrorResponse = game_move_response.json().await.unwrap();
+    assert_that(error_response.cause()).ends_with("has already finished");
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
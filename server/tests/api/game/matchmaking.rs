@@ -0,0 +1,352 @@
+use std::time::Duration;
+
+use spectral::prelude::*;
+use uuid::Uuid;
+use warp::http::StatusCode;
+
+use nc_test_client::response::{MatchmakingEvent, RoomDetailsResponse};
+use nc_test_client::{AppClient, MatchmakingQuery, TungsteniteError};
+
+use crate::helpers::{
+    create_rated_room, create_user, new_app_client, non_existent_id, play_game_in_room,
+};
+
+/// Tests share a server, so each one queues with a time control nobody else asks for
+fn isolated_query() -> MatchmakingQuery {
+    let initial_seconds = (Uuid::new_v4().as_u128() % 1_000_000) as u32 + 1;
+    MatchmakingQuery::new().initial_seconds(initial_seconds)
+}
+
+/// Reads the queued event a user is sent when nobody suitable is waiting, returning its estimate
+async fn expect_queued(app_client: &mut AppClient) -> Option<i64> {
+    match app_client.next_matchmaking_event().await {
+        Some(MatchmakingEvent::Queued {
+            estimated_wait_seconds,
+        }) => estimated_wait_seconds,
+        event => panic!("Unexpected matchmaking event: {:?}", event),
+    }
+}
+
+#[tokio::test]
+async fn matches_two_waiting_users() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let query = isolated_query();
+    app_client_1
+        .enter_matchmaking(user_id_1, query)
+        .await
+        .unwrap();
+
+    let result = app_client_2.enter_matchmaking(user_id_2, query).await;
+
+    assert_that(&result).is_ok();
+    assert_that(&app_client_1.next_matchmaking_event().await).is_equal_to(&Some(
+        MatchmakingEvent::Queued {
+            estimated_wait_seconds: None,
+        },
+    ));
+    let (room_id, game_id) = match app_client_1.next_matchmaking_event().await {
+        Some(MatchmakingEvent::MatchFound {
+            room_id,
+            game_id,
+            opponent,
+        }) => {
+            assert_that(&opponent).is_equal_to(&user_id_2);
+            (room_id, game_id)
+        }
+        event => panic!("Unexpected matchmaking event: {:?}", event),
+    };
+    assert_that(&app_client_2.next_matchmaking_event().await).is_equal_to(&Some(
+        MatchmakingEvent::MatchFound {
+            room_id,
+            game_id,
+            opponent: user_id_1,
+        },
+    ));
+    let details: RoomDetailsResponse = app_client_1
+        .room_details(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&details.private()).is_true();
+    assert_that(details.seated_players()).is_equal_to(&vec![user_id_1, user_id_2]);
+    assert_that(&details.active_game_id()).is_equal_to(&Some(game_id));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn matched_room_uses_preferences() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let query = isolated_query().variant("misere").increment_seconds(2);
+    app_client_1
+        .enter_matchmaking(user_id_1, query)
+        .await
+        .unwrap();
+    app_client_2
+        .enter_matchmaking(user_id_2, query)
+        .await
+        .unwrap();
+
+    let room_id = match app_client_2.next_matchmaking_event().await {
+        Some(MatchmakingEvent::MatchFound { room_id, .. }) => room_id,
+        event => panic!("Unexpected matchmaking event: {:?}", event),
+    };
+
+    let details: RoomDetailsResponse = app_client_1
+        .room_details(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&details.settings().default_variant()).is_equal_to("misere");
+    assert_that(
+        &details
+            .settings()
+            .time_control()
+            .unwrap()
+            .increment_seconds(),
+    )
+    .is_equal_to(2);
+    assert_that(&details.settings().rated()).is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn does_not_match_users_with_different_preferences() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let query = isolated_query();
+    app_client_1
+        .enter_matchmaking(user_id_1, query.variant("standard"))
+        .await
+        .unwrap();
+
+    app_client_2
+        .enter_matchmaking(user_id_2, query.variant("misere"))
+        .await
+        .unwrap();
+
+    expect_queued(&mut app_client_1).await;
+    assert_that(&app_client_1.next_matchmaking_event().await).is_none();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn leaves_queue_if_client_disconnects() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let query = isolated_query();
+    app_client_1
+        .enter_matchmaking(user_id_1, query)
+        .await
+        .unwrap();
+    app_client_1.close_socket_connection().await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    app_client_2
+        .enter_matchmaking(user_id_2, query)
+        .await
+        .unwrap();
+    app_client_3
+        .enter_matchmaking(user_id_3, query)
+        .await
+        .unwrap();
+
+    expect_queued(&mut app_client_2).await;
+    let event = app_client_2.next_matchmaking_event().await;
+    assert_that(&matches!(
+        event,
+        Some(MatchmakingEvent::MatchFound { opponent, .. }) if opponent == user_id_3
+    ))
+    .is_true();
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_enter_matchmaking_twice() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id = create_user(&app_client_1).await;
+    app_client_1
+        .enter_matchmaking(user_id, isolated_query())
+        .await
+        .unwrap();
+
+    let result = app_client_2
+        .enter_matchmaking(user_id, isolated_query())
+        .await;
+
+    if let TungsteniteError::Http(response) = result.err().unwrap() {
+        assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    } else {
+        panic!("Unexpected error variant");
+    }
+    app_client_1.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_enter_matchmaking_with_invalid_time_control() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let result = app_client
+        .enter_matchmaking(user_id, MatchmakingQuery::new().initial_seconds(0))
+        .await;
+
+    if let TungsteniteError::Http(response) = result.err().unwrap() {
+        assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    } else {
+        panic!("Unexpected error variant");
+    }
+}
+
+#[tokio::test]
+async fn fails_to_enter_matchmaking_if_user_does_not_exist() {
+    let mut app_client = new_app_client();
+
+    let result = app_client
+        .enter_matchmaking(non_existent_id(), isolated_query())
+        .await;
+
+    if let TungsteniteError::Http(response) = result.err().unwrap() {
+        assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    } else {
+        panic!("Unexpected error variant");
+    }
+}
+
+#[tokio::test]
+async fn estimates_wait_from_previous_matches() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let query = isolated_query();
+    app_client_1
+        .enter_matchmaking(user_id_1, query)
+        .await
+        .unwrap();
+    app_client_2
+        .enter_matchmaking(user_id_2, query)
+        .await
+        .unwrap();
+
+    app_client_3
+        .enter_matchmaking(user_id_3, query)
+        .await
+        .unwrap();
+
+    assert_that(&expect_queued(&mut app_client_3).await).is_equal_to(&Some(0));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn does_not_pair_same_users_twice_in_a_row() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let query = isolated_query();
+    app_client_1
+        .enter_matchmaking(user_id_1, query)
+        .await
+        .unwrap();
+    app_client_2
+        .enter_matchmaking(user_id_2, query)
+        .await
+        .unwrap();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    app_client_1
+        .enter_matchmaking(user_id_1, query)
+        .await
+        .unwrap();
+    app_client_2
+        .enter_matchmaking(user_id_2, query)
+        .await
+        .unwrap();
+    app_client_3
+        .enter_matchmaking(user_id_3, query)
+        .await
+        .unwrap();
+
+    expect_queued(&mut app_client_2).await;
+    expect_queued(&mut app_client_1).await;
+    let event = app_client_1.next_matchmaking_event().await;
+    assert_that(&matches!(
+        event,
+        Some(MatchmakingEvent::MatchFound { opponent, .. }) if opponent == user_id_3
+    ))
+    .is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn pairs_users_far_apart_in_rating_after_they_wait() {
+    let mut room_client_1 = new_app_client();
+    let mut room_client_2 = new_app_client();
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let strong_user = create_user(&room_client_1).await;
+    let beaten_user = create_user(&room_client_2).await;
+    let new_user = create_user(&app_client_2).await;
+    let room_id = create_rated_room(&room_client_1, strong_user).await;
+    play_game_in_room(
+        &mut room_client_1,
+        &mut room_client_2,
+        strong_user,
+        beaten_user,
+        room_id,
+    )
+    .await;
+    room_client_1.close_socket_connection().await;
+    room_client_2.close_socket_connection().await;
+    let query = isolated_query();
+    app_client_1
+        .enter_matchmaking(strong_user, query)
+        .await
+        .unwrap();
+
+    app_client_2
+        .enter_matchmaking(new_user, query)
+        .await
+        .unwrap();
+
+    expect_queued(&mut app_client_1).await;
+    expect_queued(&mut app_client_2).await;
+    tokio::time::sleep(Duration::from_millis(2500)).await;
+    let event = app_client_2.next_matchmaking_event().await;
+    assert_that(&matches!(
+        event,
+        Some(MatchmakingEvent::MatchFound { opponent, .. }) if opponent == strong_user
+    ))
+    .is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
@@ -1,8 +1,28 @@
+mod abandon_game;
+mod accounts;
 mod become_player_in_game;
+mod challenges;
 mod create_room;
+mod game_history;
 mod get_user_name;
+mod guests;
 mod join_room;
+mod leaderboards;
 mod leave_room;
+mod list_rooms;
 mod make_a_move;
+mod matchmaking;
+mod private_rooms;
+mod ratings;
 mod register_user;
+mod rematch;
+mod room_details;
+mod room_host;
+mod room_roles;
+mod room_settings;
+mod series;
 mod start_new_game;
+mod tournaments;
+mod update_user_profile;
+mod user_data;
+mod user_stats;
@@ -0,0 +1,286 @@
+use std::time::Duration;
+
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{
+    CreateRoomResponse, InviteResponse, RoomDetailsResponse, RoomListResponse,
+};
+use nc_test_client::{AppClient, RoomListQuery, TungsteniteError};
+
+use crate::helpers::{create_user, new_app_client};
+
+async fn create_private_room(app_client: &AppClient, user_id: Uuid) -> Uuid {
+    app_client
+        .create_private_room(user_id)
+        .await
+        .json::<CreateRoomResponse>()
+        .await
+        .unwrap()
+        .room_id()
+        .unwrap()
+}
+
+async fn create_invite(
+    app_client: &AppClient,
+    user_id: Uuid,
+    room_id: Uuid,
+    single_use: bool,
+    expires_in_seconds: Option<u32>,
+) -> InviteResponse {
+    let response = app_client
+        .create_invite(user_id, room_id, single_use, expires_in_seconds)
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response.json().await.unwrap()
+}
+
+fn assert_join_forbidden<T: std::fmt::Debug>(join_result: Result<T, TungsteniteError>) {
+    if let TungsteniteError::Http(response) = join_result.err().unwrap() {
+        assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    } else {
+        panic!("Unexpected error variant");
+    }
+}
+
+#[tokio::test]
+async fn creates_private_room() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let room_id = create_private_room(&app_client, user_id).await;
+
+    let details: RoomDetailsResponse = app_client.room_details(room_id).await.json().await.unwrap();
+    assert_that(&details.private()).is_true();
+}
+
+#[tokio::test]
+async fn private_room_is_not_listed() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_private_room(&app_client, user_id).await;
+
+    let rooms: RoomListResponse = app_client
+        .list_rooms(RoomListQuery::new().per_page(100))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(&rooms.room_ids()).does_not_contain(&room_id);
+}
+
+#[tokio::test]
+async fn host_joins_private_room_without_invite() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_private_room(&app_client, user_id).await;
+
+    assert_that(&app_client.join_room(user_id, room_id).await).is_ok();
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_join_private_room_without_invite() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+
+    assert_join_forbidden(app_client_2.join_room(user_id, room_id).await);
+}
+
+#[tokio::test]
+async fn joins_private_room_with_invite_code() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    let invite = create_invite(&app_client_1, host_id, room_id, false, None).await;
+
+    let join_result = app_client_2
+        .join_room_with_invite(user_id, room_id, &invite.code().to_lowercase())
+        .await;
+
+    assert_that(&join_result).is_ok();
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_join_private_room_with_unknown_invite_code() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+
+    assert_join_forbidden(
+        app_client_2
+            .join_room_with_invite(user_id, room_id, "NOTACODE")
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn single_use_invite_cannot_be_reused() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    let invite = create_invite(&app_client_1, host_id, room_id, true, None).await;
+
+    assert_that(
+        &app_client_2
+            .join_room_with_invite(user_id_2, room_id, invite.code())
+            .await,
+    )
+    .is_ok();
+    assert_join_forbidden(
+        app_client_3
+            .join_room_with_invite(user_id_3, room_id, invite.code())
+            .await,
+    );
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn single_use_invite_admits_only_one_of_simultaneous_joins() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    let invite = create_invite(&app_client_1, host_id, room_id, true, None).await;
+
+    let (join_2, join_3) = tokio::join!(
+        app_client_2.join_room_with_invite(user_id_2, room_id, invite.code()),
+        app_client_3.join_room_with_invite(user_id_3, room_id, invite.code())
+    );
+
+    assert_that(&(join_2.is_ok() != join_3.is_ok())).is_true();
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn single_use_invite_is_not_used_up_by_banned_user() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let banned_user_id = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    app_client_1.join_room(host_id, room_id).await.unwrap();
+    let ban_response = app_client_1
+        .ban_user(host_id, room_id, banned_user_id)
+        .await;
+    assert_that(&ban_response.status().is_success()).is_true();
+    let invite = create_invite(&app_client_1, host_id, room_id, true, None).await;
+
+    assert_join_forbidden(
+        app_client_2
+            .join_room_with_invite(banned_user_id, room_id, invite.code())
+            .await,
+    );
+    assert_that(
+        &app_client_3
+            .join_room_with_invite(user_id_3, room_id, invite.code())
+            .await,
+    )
+    .is_ok();
+    app_client_1.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn expired_invite_cannot_be_used() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    let invite = create_invite(&app_client_1, host_id, room_id, false, Some(1)).await;
+    assert_that(&invite.expires_at()).is_some();
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    assert_join_forbidden(
+        app_client_2
+            .join_room_with_invite(user_id, room_id, invite.code())
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn joins_private_room_with_invite_link() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    let invite = create_invite(&app_client_1, host_id, room_id, false, None).await;
+
+    let join_result = app_client_2
+        .join_room_with_link(user_id, invite.link())
+        .await;
+
+    assert_that(&join_result).is_ok();
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_join_with_tampered_invite_link() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+    let invite = create_invite(&app_client_1, host_id, room_id, false, None).await;
+    let other_room_id = create_private_room(&app_client_1, host_id).await;
+    let tampered_link = invite
+        .link()
+        .replace(&room_id.to_string(), &other_room_id.to_string());
+
+    assert_join_forbidden(
+        app_client_2
+            .join_room_with_link(user_id, &tampered_link)
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn only_host_can_create_invites() {
+    let app_client_1 = new_app_client();
+    let app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_private_room(&app_client_1, host_id).await;
+
+    let response = app_client_2
+        .create_invite(user_id, room_id, false, None)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn fails_to_create_invite_with_zero_expiry() {
+    let app_client = new_app_client();
+    let host_id = create_user(&app_client).await;
+    let room_id = create_private_room(&app_client, host_id).await;
+
+    let response = app_client
+        .create_invite(host_id, room_id, false, Some(0))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,136 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RatingResponse, RoomDetailsResponse, UserProfileResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::{
+    become_player, create_rated_room, create_room, create_user, join_room, new_app_client,
+    non_existent_id, play_game_in_room, play_game_won_by_first_player, start_new_game,
+};
+
+async fn rating(app_client: &AppClient, user_id: Uuid) -> RatingResponse {
+    app_client
+        .user_profile(user_id)
+        .await
+        .json::<UserProfileResponse>()
+        .await
+        .unwrap()
+        .rating("standard")
+        .unwrap()
+        .clone()
+}
+
+#[tokio::test]
+async fn new_user_has_provisional_rating() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client.user_profile(user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let profile: UserProfileResponse = response.json().await.unwrap();
+    assert_that(&profile.user_id()).is_equal_to(&user_id);
+    assert_that(&profile.name()).is_equal_to("name");
+    assert_that(profile.ratings()).has_length(2);
+    for rating in profile.ratings() {
+        assert_that(&rating.rating()).is_equal_to(1500);
+        assert_that(&rating.deviation()).is_equal_to(350);
+        assert_that(&rating.provisional()).is_true();
+        assert_that(&rating.games_played()).is_equal_to(0);
+        assert_that(&rating.wins()).is_equal_to(0);
+    }
+    assert_that(&profile.rating("misere")).is_some();
+}
+
+#[tokio::test]
+async fn rated_game_updates_ratings() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let winner = create_user(&app_client_1).await;
+    let loser = create_user(&app_client_2).await;
+    let room_id = create_rated_room(&app_client_1, winner).await;
+
+    play_game_in_room(&mut app_client_1, &mut app_client_2, winner, loser, room_id).await;
+
+    let details: RoomDetailsResponse = app_client_1
+        .room_details(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&details.settings().rated()).is_true();
+    let winner_rating = rating(&app_client_1, winner).await;
+    let loser_rating = rating(&app_client_1, loser).await;
+    assert_that(&winner_rating.rating()).is_greater_than(1500);
+    assert_that(&loser_rating.rating()).is_less_than(1500);
+    assert_that(&(winner_rating.rating() - 1500)).is_equal_to(1500 - loser_rating.rating());
+    assert_that(&winner_rating.deviation()).is_less_than(350);
+    assert_that(&winner_rating.games_played()).is_equal_to(1);
+    assert_that(&loser_rating.games_played()).is_equal_to(1);
+    assert_that(&winner_rating.wins()).is_equal_to(1);
+    assert_that(&loser_rating.wins()).is_equal_to(0);
+    assert_that(&winner_rating.provisional()).is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn casual_game_does_not_change_ratings() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+
+    play_game_in_room(
+        &mut app_client_1,
+        &mut app_client_2,
+        user_id_1,
+        user_id_2,
+        room_id,
+    )
+    .await;
+
+    let rating_1 = rating(&app_client_1, user_id_1).await;
+    assert_that(&rating_1.rating()).is_equal_to(1500);
+    assert_that(&rating_1.games_played()).is_equal_to(0);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn rating_a_room_mid_game_does_not_rate_the_game_in_progress() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+
+    let response = app_client_1
+        .update_room_settings(user_id_1, room_id, serde_json::json!({ "rated": true }))
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let rating_1 = rating(&app_client_1, user_id_1).await;
+    assert_that(&rating_1.rating()).is_equal_to(1500);
+    assert_that(&rating_1.games_played()).is_equal_to(0);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_get_profile_of_user_that_does_not_exist() {
+    let app_client = new_app_client();
+
+    let response = app_client.user_profile(non_existent_id()).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
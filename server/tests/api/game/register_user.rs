@@ -1,7 +1,7 @@
 use spectral::prelude::*;
 
 use nc_test_client::http::StatusCode;
-use nc_test_client::response::RegisteredUserResponse;
+use nc_test_client::response::{RegisteredUserResponse, SimpleErrorResponse};
 
 use crate::helpers::new_app_client;
 
@@ -42,3 +42,29 @@ async fn registers_user_with_name_including_spaces() {
 
     assert_that(&name).is_equal_to(&"First Last".to_string());
 }
+
+#[tokio::test]
+async fn registration_fails_given_blank_name() {
+    let app_client = new_app_client();
+
+    let register_response = app_client.register_user("%20%20").await;
+
+    assert_that(&register_response.status()).is_equal_to(StatusCode::BAD_REQUEST);
+    assert_that(
+        &register_response
+            .json::<SimpleErrorResponse>()
+            .await
+            .unwrap()
+            .cause(),
+    )
+    .is_equal_to(&"Names must not be blank".to_string());
+}
+
+#[tokio::test]
+async fn registration_fails_given_name_too_long() {
+    let app_client = new_app_client();
+
+    let register_response = app_client.register_user("n".repeat(33)).await;
+
+    assert_that(&register_response.status()).is_equal_to(StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,173 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RematchResponse, RoomEvent, SimpleErrorResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::game_moves::{bottom_right, top_left};
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, make_game_move, new_app_client,
+    play_game_won_by_first_player, start_new_game,
+};
+
+async fn finished_game_between_two_players(
+    app_client_1: &mut AppClient,
+    app_client_2: &mut AppClient,
+) -> (Uuid, Uuid, Uuid) {
+    let user_id_1 = create_user(app_client_1).await;
+    let user_id_2 = create_user(app_client_2).await;
+    let room_id = create_room(app_client_1, user_id_1).await;
+    join_room(app_client_1, user_id_1, room_id).await;
+    join_room(app_client_2, user_id_2, room_id).await;
+    start_new_game(app_client_1, user_id_1, room_id).await;
+    become_player(app_client_1, user_id_1, room_id).await;
+    become_player(app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(app_client_1, user_id_1, user_id_2, room_id).await;
+    (user_id_1, user_id_2, room_id)
+}
+
+#[tokio::test]
+async fn player_requests_rematch() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, _, room_id) =
+        finished_game_between_two_players(&mut app_client_1, &mut app_client_2).await;
+
+    let response = app_client_1.request_rematch(user_id_1, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::ACCEPTED);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn new_game_starts_when_both_players_accept_rematch() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        finished_game_between_two_players(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.request_rematch(user_id_1, room_id).await;
+
+    let response = app_client_2.request_rematch(user_id_2, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    let game_id = response.json::<RematchResponse>().await.unwrap().game_id();
+    let game_response = app_client_1.room_game(room_id, game_id).await;
+    assert_that(&game_response.status()).is_equal_to(&StatusCode::OK);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn simultaneous_rematch_requests_start_one_new_game() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        finished_game_between_two_players(&mut app_client_1, &mut app_client_2).await;
+
+    let (response_1, response_2) = tokio::join!(
+        app_client_1.request_rematch(user_id_1, room_id),
+        app_client_2.request_rematch(user_id_2, room_id)
+    );
+
+    let mut statuses = vec![response_1.status(), response_2.status()];
+    statuses.sort();
+    assert_that(&statuses).is_equal_to(&vec![StatusCode::CREATED, StatusCode::ACCEPTED]);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn rematch_swaps_first_mover() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        finished_game_between_two_players(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.request_rematch(user_id_1, room_id).await;
+    app_client_2.request_rematch(user_id_2, room_id).await;
+
+    let move_response = app_client_1
+        .make_game_move(user_id_1, room_id, top_left())
+        .await;
+
+    assert_that(&move_response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+    let error_response: SimpleErrorResponse = move_response.json().await.unwrap();
+    assert_that(&error_response.cause())
+        .is_equal_to(&format!("It is not User({})'s turn", user_id_1));
+    make_game_move(&app_client_2, user_id_2, room_id, bottom_right()).await;
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn rematch_fails_if_game_has_not_finished() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+
+    let response = app_client.request_rematch(user_id, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    let error_response: SimpleErrorResponse = response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&format!(
+        "There is no finished game to rematch in room with id: {}",
+        room_id
+    ));
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn rematch_fails_if_user_was_not_a_player() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let (_, _, room_id) =
+        finished_game_between_two_players(&mut app_client_1, &mut app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    join_room(&mut app_client_3, user_id_3, room_id).await;
+
+    let response = app_client_3.request_rematch(user_id_3, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn room_members_are_notified_of_rematch() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        finished_game_between_two_players(&mut app_client_1, &mut app_client_2).await;
+    let first_game_event = app_client_2.next_room_event().await;
+    assert_that(&first_game_event).is_some();
+
+    app_client_1.request_rematch(user_id_1, room_id).await;
+    let rematch_requested_event = app_client_2.next_room_event().await;
+    let game_id = app_client_2
+        .request_rematch(user_id_2, room_id)
+        .await
+        .json::<RematchResponse>()
+        .await
+        .unwrap()
+        .game_id();
+    let game_started_event = app_client_2.next_room_event().await;
+
+    assert_that(&rematch_requested_event).is_equal_to(&Some(RoomEvent::RematchRequested {
+        room_id,
+        user_id: user_id_1,
+    }));
+    assert_that(&game_started_event).is_equal_to(&Some(RoomEvent::GameStarted {
+        room_id,
+        game_id,
+        players: vec![user_id_1, user_id_2],
+        first_mover: Some(user_id_2),
+    }));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
@@ -0,0 +1,103 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RegisteredUserResponse, RoomDetailsResponse, SimpleErrorResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, new_app_client, non_existent_id,
+    start_new_game,
+};
+
+async fn create_user_named(app_client: &AppClient, name: &str) -> Uuid {
+    app_client
+        .register_user(name)
+        .await
+        .json::<RegisteredUserResponse>()
+        .await
+        .unwrap()
+        .user_id()
+        .unwrap()
+}
+
+async fn get_room_details(app_client: &AppClient, room_id: Uuid) -> RoomDetailsResponse {
+    app_client.room_details(room_id).await.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn returns_members_with_names() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user_named(&app_client_1, "alice").await;
+    let user_id_2 = create_user_named(&app_client_2, "bob").await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    let response = app_client_1.room_details(room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let details: RoomDetailsResponse = response.json().await.unwrap();
+    assert_that(&details.room_id()).is_equal_to(&room_id);
+    let members: Vec<(Uuid, &str)> = details
+        .members()
+        .iter()
+        .map(|member| (member.user_id(), member.name()))
+        .collect();
+    assert_that(&members).is_equal_to(&vec![(user_id_1, "alice"), (user_id_2, "bob")]);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn returns_seated_players_and_spectators() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    app_client_2
+        .change_room_role(user_id_2, room_id, "spectator")
+        .await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+
+    let details = get_room_details(&app_client_1, room_id).await;
+
+    assert_that(details.seated_players()).is_equal_to(&vec![user_id_1]);
+    assert_that(details.spectators()).is_equal_to(&vec![user_id_2]);
+    assert_that(&details.active_game_id()).is_some();
+    assert_that(&details.game_lifecycle_state()).is_equal_to("ready");
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn returns_empty_room_without_active_game() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+
+    let details = get_room_details(&app_client, room_id).await;
+
+    assert_that(details.members()).is_empty();
+    assert_that(&details.active_game_id()).is_none();
+    assert_that(&details.game_lifecycle_state()).is_equal_to("waiting");
+    assert_that(&details.series().is_none()).is_true();
+}
+
+#[tokio::test]
+async fn room_details_fail_if_room_does_not_exist() {
+    let app_client = new_app_client();
+    let room_id = non_existent_id();
+
+    let response = app_client.room_details(room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    let error_response: SimpleErrorResponse = response.json().await.unwrap();
+    assert_that(&error_response.cause())
+        .is_equal_to(&format!("Could not find room with id: {}", room_id));
+}
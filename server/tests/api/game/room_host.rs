@@ -0,0 +1,233 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RoomDetailsResponse, RoomEvent, SimpleErrorResponse};
+use nc_test_client::{AppClient, TungsteniteError};
+
+use crate::helpers::{
+    create_room, create_user, join_room, leave_room, new_app_client, non_existent_id,
+};
+
+async fn room_host(app_client: &AppClient, room_id: Uuid) -> Option<Uuid> {
+    app_client
+        .room_details(room_id)
+        .await
+        .json::<RoomDetailsResponse>()
+        .await
+        .unwrap()
+        .host()
+}
+
+async fn room_with_host_and_member(
+    app_client_1: &mut AppClient,
+    app_client_2: &mut AppClient,
+) -> (Uuid, Uuid, Uuid) {
+    let host_id = create_user(app_client_1).await;
+    let member_id = create_user(app_client_2).await;
+    let room_id = create_room(app_client_1, host_id).await;
+    join_room(app_client_1, host_id, room_id).await;
+    join_room(app_client_2, member_id, room_id).await;
+    (host_id, member_id, room_id)
+}
+
+#[tokio::test]
+async fn creator_is_room_host() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+
+    assert_that(&room_host(&app_client, room_id).await).is_equal_to(&Some(user_id));
+}
+
+#[tokio::test]
+async fn only_host_can_start_new_game() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (_, member_id, room_id) =
+        room_with_host_and_member(&mut app_client_1, &mut app_client_2).await;
+
+    let response = app_client_2.start_new_game(member_id, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    let error_response: SimpleErrorResponse = response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&format!(
+        "User({}) is not the host of Room({})",
+        member_id, room_id
+    ));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn only_host_can_configure_series() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (_, member_id, room_id) =
+        room_with_host_and_member(&mut app_client_1, &mut app_client_2).await;
+
+    let response = app_client_2.configure_series(member_id, room_id, 3).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn host_status_transfers_when_host_leaves() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (host_id, member_id, room_id) =
+        room_with_host_and_member(&mut app_client_1, &mut app_client_2).await;
+
+    leave_room(&app_client_1, host_id, room_id).await;
+    app_client_1.close_socket_connection().await;
+
+    assert_that(&app_client_2.next_room_event().await).is_equal_to(&Some(RoomEvent::HostChanged {
+        room_id,
+        host: member_id,
+    }));
+    assert_that(&room_host(&app_client_2, room_id).await).is_equal_to(&Some(member_id));
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn host_kicks_member() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (host_id, member_id, room_id) =
+        room_with_host_and_member(&mut app_client_1, &mut app_client_2).await;
+
+    let response = app_client_1.kick_member(host_id, room_id, member_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client_2.next_room_event().await).is_equal_to(&Some(
+        RoomEvent::MemberKicked {
+            room_id,
+            user_id: member_id,
+        },
+    ));
+    let details: RoomDetailsResponse = app_client_1
+        .room_details(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let member_ids: Vec<Uuid> = details.members().iter().map(|m| m.user_id()).collect();
+    assert_that(&member_ids).is_equal_to(&vec![host_id]);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_kick_member_if_not_host() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (host_id, member_id, room_id) =
+        room_with_host_and_member(&mut app_client_1, &mut app_client_2).await;
+
+    let response = app_client_2.kick_member(member_id, room_id, host_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_kick_self() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client.kick_member(user_id, room_id, user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    let error_response: SimpleErrorResponse = response.json().await.unwrap();
+    assert_that(&error_response.cause())
+        .is_equal_to(&"The host cannot kick or ban themselves".to_string());
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_kick_user_who_is_not_a_member() {
+    let app_client = new_app_client();
+    let host_id = create_user(&app_client).await;
+    let other_user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, host_id).await;
+
+    let response = app_client
+        .kick_member(host_id, room_id, other_user_id)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+}
+
+#[tokio::test]
+async fn banned_user_cannot_join_room() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let banned_user_id = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+
+    let response = app_client_1
+        .ban_user(host_id, room_id, banned_user_id)
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+
+    let join_result = app_client_2.join_room(banned_user_id, room_id).await;
+    if let TungsteniteError::Http(response) = join_result.err().unwrap() {
+        assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    } else {
+        panic!("Unexpected error variant");
+    }
+}
+
+#[tokio::test]
+async fn banning_member_removes_them_from_room() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (host_id, member_id, room_id) =
+        room_with_host_and_member(&mut app_client_1, &mut app_client_2).await;
+
+    app_client_1.ban_user(host_id, room_id, member_id).await;
+
+    assert_that(&app_client_2.next_room_event().await).is_equal_to(&Some(
+        RoomEvent::MemberKicked {
+            room_id,
+            user_id: member_id,
+        },
+    ));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn unbanned_user_can_join_room() {
+    let app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    app_client_1.ban_user(host_id, room_id, user_id).await;
+
+    let response = app_client_1.unban_user(host_id, room_id, user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client_2.join_room(user_id, room_id).await).is_ok();
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_ban_user_that_does_not_exist() {
+    let app_client = new_app_client();
+    let host_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, host_id).await;
+
+    let response = app_client
+        .ban_user(host_id, room_id, non_existent_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
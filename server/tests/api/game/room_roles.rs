@@ -0,0 +1,221 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{CreateRoomResponse, RoomDetailsResponse, RoomEvent};
+use nc_test_client::AppClient;
+
+use crate::helpers::game_moves::top_left;
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, new_app_client, non_existent_id,
+    start_new_game,
+};
+
+async fn get_room_details(app_client: &AppClient, room_id: Uuid) -> RoomDetailsResponse {
+    app_client.room_details(room_id).await.json().await.unwrap()
+}
+
+async fn become_spectator(app_client: &AppClient, user_id: Uuid, room_id: Uuid) {
+    let response = app_client
+        .change_room_role(user_id, room_id, "spectator")
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn members_are_players_by_default() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let details = get_room_details(&app_client, room_id).await;
+
+    assert_that(&details.members()[0].role()).is_equal_to("player");
+    assert_that(details.spectators()).is_empty();
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn member_becomes_spectator() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, user_id, room_id).await;
+
+    let response = app_client_2
+        .change_room_role(user_id, room_id, "spectator")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client_1.next_room_event().await).is_equal_to(&Some(RoomEvent::RoleChanged {
+        room_id,
+        user_id,
+        role: "spectator".to_string(),
+    }));
+    let details = get_room_details(&app_client_1, room_id).await;
+    let spectator = details
+        .members()
+        .iter()
+        .find(|member| member.user_id() == user_id)
+        .unwrap();
+    assert_that(&spectator.role()).is_equal_to("spectator");
+    assert_that(details.spectators()).is_equal_to(&vec![user_id]);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn spectator_gives_up_seat() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+
+    become_spectator(&app_client, user_id, room_id).await;
+
+    let details = get_room_details(&app_client, room_id).await;
+    assert_that(details.seated_players()).is_empty();
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn spectator_receives_room_events() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, user_id, room_id).await;
+    become_spectator(&app_client_2, user_id, room_id).await;
+    app_client_2.next_room_event().await;
+
+    start_new_game(&app_client_1, host_id, room_id).await;
+
+    let event = app_client_2.next_room_event().await;
+    assert_that(&matches!(event, Some(RoomEvent::GameStarted { .. }))).is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn spectator_cannot_start_new_game() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    become_spectator(&app_client, user_id, room_id).await;
+
+    let response = app_client.start_new_game(user_id, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn spectator_cannot_become_player() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let user_id = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, user_id, room_id).await;
+    start_new_game(&app_client_1, host_id, room_id).await;
+    become_spectator(&app_client_2, user_id, room_id).await;
+
+    let response = app_client_2.become_player(user_id, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn spectator_cannot_make_a_move() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+    become_spectator(&app_client, user_id, room_id).await;
+
+    let response = app_client
+        .make_game_move(user_id, room_id, top_left())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn spectator_switches_back_to_player() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_spectator(&app_client, user_id, room_id).await;
+
+    let response = app_client
+        .change_room_role(user_id, room_id, "player")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    let become_player_response = app_client.become_player(user_id, room_id).await;
+    assert_that(&become_player_response.status()).is_equal_to(&StatusCode::ACCEPTED);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_spectate_if_spectators_are_not_allowed() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let create_room_response: CreateRoomResponse = app_client
+        .create_room_with_settings(user_id, serde_json::json!({ "spectators_allowed": false }))
+        .await
+        .json()
+        .await
+        .unwrap();
+    let room_id = create_room_response.room_id().unwrap();
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client
+        .change_room_role(user_id, room_id, "spectator")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_change_role_if_not_in_room() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+
+    let response = app_client
+        .change_room_role(user_id, room_id, "spectator")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_ACCEPTABLE);
+}
+
+#[tokio::test]
+async fn fails_to_change_role_if_room_does_not_exist() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .change_room_role(user_id, non_existent_id(), "spectator")
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
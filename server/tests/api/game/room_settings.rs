@@ -0,0 +1,408 @@
+use std::time::Duration;
+
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{
+    CreateRoomResponse, GameOutcome, GameResponse, RoomDetailsResponse, RoomEvent,
+    RoomListResponse, RoomSettingsResponse, SimpleErrorResponse,
+};
+use nc_test_client::{AppClient, RoomListQuery, TungsteniteError};
+
+use crate::helpers::game_moves::{centre, centre_left, top_centre, top_left, top_right};
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, make_game_move, new_app_client,
+    start_new_game,
+};
+
+async fn create_room_with_settings(
+    app_client: &AppClient,
+    user_id: Uuid,
+    settings: serde_json::Value,
+) -> Uuid {
+    let response = app_client
+        .create_room_with_settings(user_id, settings)
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response
+        .json::<CreateRoomResponse>()
+        .await
+        .unwrap()
+        .room_id()
+        .unwrap()
+}
+
+async fn room_details(app_client: &AppClient, room_id: Uuid) -> RoomDetailsResponse {
+    app_client.room_details(room_id).await.json().await.unwrap()
+}
+
+async fn active_game(app_client: &AppClient, room_id: Uuid) -> GameResponse {
+    let game_id = room_details(app_client, room_id)
+        .await
+        .active_game_id()
+        .unwrap();
+    app_client
+        .room_game(room_id, game_id)
+        .await
+        .json()
+        .await
+        .unwrap()
+}
+
+async fn seat_two_players(
+    app_client_1: &mut AppClient,
+    app_client_2: &mut AppClient,
+    room_id: Uuid,
+) -> (Uuid, Uuid) {
+    let user_id_1 = room_details(app_client_1, room_id).await.host().unwrap();
+    let user_id_2 = create_user(app_client_2).await;
+    join_room(app_client_1, user_id_1, room_id).await;
+    join_room(app_client_2, user_id_2, room_id).await;
+    start_new_game(app_client_1, user_id_1, room_id).await;
+    become_player(app_client_1, user_id_1, room_id).await;
+    become_player(app_client_2, user_id_2, room_id).await;
+    (user_id_1, user_id_2)
+}
+
+#[tokio::test]
+async fn room_has_default_settings() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+
+    let details = room_details(&app_client, room_id).await;
+
+    let settings = details.settings();
+    assert_that(&settings.max_members()).is_none();
+    assert_that(&settings.spectators_allowed()).is_true();
+    assert_that(&settings.default_variant()).is_equal_to("standard");
+    assert_that(&settings.time_control()).is_none();
+    assert_that(&settings.rated()).is_false();
+}
+
+#[tokio::test]
+async fn creates_room_with_settings() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let room_id = create_room_with_settings(
+        &app_client,
+        user_id,
+        serde_json::json!({
+            "max_members": 4,
+            "default_variant": "misere",
+            "time_control": { "initial_seconds": 60, "increment_seconds": 2 },
+        }),
+    )
+    .await;
+
+    let details = room_details(&app_client, room_id).await;
+    let settings = details.settings();
+    assert_that(&settings.max_members()).is_equal_to(Some(4));
+    assert_that(&settings.default_variant()).is_equal_to("misere");
+    let time_control = settings.time_control().unwrap();
+    assert_that(&time_control.initial_seconds()).is_equal_to(60);
+    assert_that(&time_control.increment_seconds()).is_equal_to(2);
+}
+
+#[tokio::test]
+async fn fails_to_create_room_with_too_few_members() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .create_room_with_settings(user_id, serde_json::json!({ "max_members": 1 }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    let error_response: SimpleErrorResponse = response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&"max_members must be at least 2".to_string());
+}
+
+#[tokio::test]
+async fn fails_to_create_room_with_zero_second_time_control() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .create_room_with_settings(
+            user_id,
+            serde_json::json!({ "time_control": { "initial_seconds": 0 } }),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn fails_to_join_full_room() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let room_id = create_room_with_settings(
+        &app_client_1,
+        user_id_1,
+        serde_json::json!({ "max_members": 2 }),
+    )
+    .await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    let join_result = app_client_3.join_room(user_id_3, room_id).await;
+
+    if let TungsteniteError::Http(response) = join_result.err().unwrap() {
+        assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    } else {
+        panic!("Unexpected error variant");
+    }
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn room_without_spectators_only_admits_players() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let room_id = create_room_with_settings(
+        &app_client_1,
+        user_id_1,
+        serde_json::json!({ "max_members": 10, "spectators_allowed": false }),
+    )
+    .await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    let join_result = app_client_3.join_room(user_id_3, room_id).await;
+
+    assert_that(&join_result.is_err()).is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn new_game_cannot_start_with_spectators_when_they_are_not_allowed() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let user_id_3 = create_user(&app_client_3).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    join_room(&mut app_client_3, user_id_3, room_id).await;
+    app_client_1
+        .update_room_settings(
+            user_id_1,
+            room_id,
+            serde_json::json!({ "spectators_allowed": false }),
+        )
+        .await;
+
+    let response = app_client_1.start_new_game(user_id_1, room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn host_updates_settings() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room_with_settings(
+        &app_client,
+        user_id,
+        serde_json::json!({ "max_members": 4, "time_control": { "initial_seconds": 30 } }),
+    )
+    .await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client
+        .update_room_settings(
+            user_id,
+            room_id,
+            serde_json::json!({ "default_variant": "misere", "time_control": null }),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let settings: RoomSettingsResponse = response.json().await.unwrap();
+    assert_that(&settings.max_members()).is_equal_to(Some(4));
+    assert_that(&settings.default_variant()).is_equal_to("misere");
+    assert_that(&settings.time_control()).is_none();
+    assert_that(room_details(&app_client, room_id).await.settings()).is_equal_to(&settings);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn members_are_notified_of_updated_settings() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    let settings: RoomSettingsResponse = app_client_1
+        .update_room_settings(user_id_1, room_id, serde_json::json!({ "max_members": 5 }))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(&app_client_2.next_room_event().await)
+        .is_equal_to(&Some(RoomEvent::SettingsUpdated { room_id, settings }));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn only_host_can_update_settings() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    let response = app_client_2
+        .update_room_settings(user_id_2, room_id, serde_json::json!({ "max_members": 2 }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_update_settings_with_invalid_values() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client
+        .update_room_settings(user_id, room_id, serde_json::json!({ "max_members": 0 }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn completing_a_line_loses_a_misere_game() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let room_id = create_room_with_settings(
+        &app_client_1,
+        host_id,
+        serde_json::json!({ "default_variant": "misere" }),
+    )
+    .await;
+    let (user_id_1, user_id_2) =
+        seat_two_players(&mut app_client_1, &mut app_client_2, room_id).await;
+
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+    make_game_move(&app_client_2, user_id_2, room_id, centre_left()).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_centre()).await;
+    make_game_move(&app_client_2, user_id_2, room_id, centre()).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_right()).await;
+
+    let game = active_game(&app_client_1, room_id).await;
+    assert_that(&game.variant()).is_equal_to("misere");
+    assert_that(&game.outcome()).is_equal_to(Some(GameOutcome::Win { winner: user_id_2 }));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn player_loses_on_time() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let room_id = create_room_with_settings(
+        &app_client_1,
+        host_id,
+        serde_json::json!({ "time_control": { "initial_seconds": 1 } }),
+    )
+    .await;
+    let (user_id_1, user_id_2) =
+        seat_two_players(&mut app_client_1, &mut app_client_2, room_id).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    let response = app_client_2
+        .make_game_move(user_id_2, room_id, centre())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    let game = active_game(&app_client_1, room_id).await;
+    assert_that(&game.outcome()).is_equal_to(Some(GameOutcome::WinOnTime { winner: user_id_1 }));
+    assert_that(&game.moves().len()).is_equal_to(1);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn opponent_move_flags_player_who_ran_out_of_time() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let room_id = create_room_with_settings(
+        &app_client_1,
+        host_id,
+        serde_json::json!({ "time_control": { "initial_seconds": 1 } }),
+    )
+    .await;
+    let (user_id_1, _user_id_2) =
+        seat_two_players(&mut app_client_1, &mut app_client_2, room_id).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    let response = app_client_1
+        .make_game_move(user_id_1, room_id, centre())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    let game = active_game(&app_client_1, room_id).await;
+    assert_that(&game.outcome()).is_equal_to(Some(GameOutcome::WinOnTime { winner: user_id_1 }));
+    assert_that(&game.moves().len()).is_equal_to(1);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn lists_rooms_by_variant() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let standard_room_id = create_room(&app_client, user_id).await;
+    let misere_room_id = create_room_with_settings(
+        &app_client,
+        user_id,
+        serde_json::json!({ "default_variant": "misere" }),
+    )
+    .await;
+
+    let rooms: RoomListResponse = app_client
+        .list_rooms(RoomListQuery::new().variant("misere").per_page(100))
+        .await
+        .json()
+        .await
+        .unwrap();
+
+    assert_that(&rooms.room_ids()).contains(&misere_room_id);
+    assert_that(&rooms.room_ids()).does_not_contain(&standard_room_id);
+}
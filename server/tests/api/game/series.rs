@@ -0,0 +1,243 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RoomEvent, SeriesResponse, SimpleErrorResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::game_moves::{
+    bottom_right, centre, centre_left, top_centre, top_left, top_right,
+};
+use crate::helpers::{
+    become_player, create_room, create_user, join_room, make_game_move, new_app_client,
+    non_existent_id, play_game_won_by_first_player, seat_opponent, start_new_game,
+};
+
+async fn two_players_in_room(
+    app_client_1: &mut AppClient,
+    app_client_2: &mut AppClient,
+) -> (Uuid, Uuid, Uuid) {
+    let user_id_1 = create_user(app_client_1).await;
+    let user_id_2 = create_user(app_client_2).await;
+    let room_id = create_room(app_client_1, user_id_1).await;
+    join_room(app_client_1, user_id_1, room_id).await;
+    join_room(app_client_2, user_id_2, room_id).await;
+    (user_id_1, user_id_2, room_id)
+}
+
+async fn seat_players_in_new_game(
+    app_client: &AppClient,
+    user_id_1: Uuid,
+    user_id_2: Uuid,
+    room_id: Uuid,
+) {
+    start_new_game(app_client, user_id_1, room_id).await;
+    become_player(app_client, user_id_1, room_id).await;
+    become_player(app_client, user_id_2, room_id).await;
+}
+
+async fn play_game_won_by_second_player(
+    app_client: &AppClient,
+    first_user_id: Uuid,
+    second_user_id: Uuid,
+    room_id: Uuid,
+) {
+    make_game_move(app_client, first_user_id, room_id, centre_left()).await;
+    make_game_move(app_client, second_user_id, room_id, top_left()).await;
+    make_game_move(app_client, first_user_id, room_id, centre()).await;
+    make_game_move(app_client, second_user_id, room_id, top_centre()).await;
+    make_game_move(app_client, first_user_id, room_id, bottom_right()).await;
+    make_game_move(app_client, second_user_id, room_id, top_right()).await;
+}
+
+async fn get_series(app_client: &AppClient, room_id: Uuid) -> SeriesResponse {
+    app_client.series(room_id).await.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn configures_series() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client.configure_series(user_id, room_id, 3).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    let series = get_series(&app_client, room_id).await;
+    assert_that(&series.best_of()).is_equal_to(3);
+    assert_that(&series.wins_needed()).is_equal_to(2);
+    assert_that(&series.games_played()).is_equal_to(0);
+    assert_that(&series.winner()).is_none();
+    assert_that(&series.finished()).is_false();
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn series_score_is_updated_when_game_finishes() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        two_players_in_room(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.configure_series(user_id_1, room_id, 3).await;
+    seat_players_in_new_game(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let series = get_series(&app_client_1, room_id).await;
+    assert_that(&series.games_played()).is_equal_to(1);
+    assert_that(&series.wins(user_id_1)).is_equal_to(Some(1));
+    assert_that(&series.wins(user_id_2)).is_equal_to(Some(0));
+    assert_that(&series.finished()).is_false();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn series_ends_when_player_clinches_it() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        two_players_in_room(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.configure_series(user_id_1, room_id, 3).await;
+    seat_players_in_new_game(&app_client_1, user_id_1, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+    app_client_1.request_rematch(user_id_1, room_id).await;
+    app_client_2.request_rematch(user_id_2, room_id).await;
+
+    play_game_won_by_second_player(&app_client_1, user_id_2, user_id_1, room_id).await;
+
+    let series = get_series(&app_client_1, room_id).await;
+    assert_that(&series.games_played()).is_equal_to(2);
+    assert_that(&series.wins(user_id_1)).is_equal_to(Some(2));
+    assert_that(&series.winner()).is_equal_to(Some(user_id_1));
+    assert_that(&series.finished()).is_true();
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn new_game_cannot_start_after_series_has_finished() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        two_players_in_room(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.configure_series(user_id_1, room_id, 1).await;
+    seat_players_in_new_game(&app_client_1, user_id_1, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let new_game_response = app_client_1.start_new_game(user_id_1, room_id).await;
+    let rematch_response = app_client_1.request_rematch(user_id_1, room_id).await;
+
+    assert_that(&new_game_response.status()).is_equal_to(&StatusCode::CONFLICT);
+    let error_response: SimpleErrorResponse = new_game_response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&format!(
+        "The match in room with id {} has finished",
+        room_id
+    ));
+    assert_that(&rematch_response.status()).is_equal_to(&StatusCode::CONFLICT);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn configure_series_fails_if_length_is_zero() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client.configure_series(user_id, room_id, 0).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn configure_series_fails_if_game_is_in_progress() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+    let (mut opponent_client, _) = seat_opponent(room_id).await;
+    make_game_move(&app_client, user_id, room_id, top_left()).await;
+
+    let response = app_client.configure_series(user_id, room_id, 3).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    app_client.close_socket_connection().await;
+    opponent_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn configure_series_fails_while_series_is_undecided() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        two_players_in_room(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.configure_series(user_id_1, room_id, 3).await;
+    seat_players_in_new_game(&app_client_1, user_id_1, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let response = app_client_1.configure_series(user_id_1, room_id, 5).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    let series = get_series(&app_client_1, room_id).await;
+    assert_that(&series.best_of()).is_equal_to(3);
+    assert_that(&series.wins(user_id_1)).is_equal_to(Some(1));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn get_series_fails_if_none_is_configured() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client.series(room_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn get_series_fails_if_room_does_not_exist() {
+    let app_client = new_app_client();
+
+    let response = app_client.series(non_existent_id()).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn room_members_are_notified_of_series_score() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let (user_id_1, user_id_2, room_id) =
+        two_players_in_room(&mut app_client_1, &mut app_client_2).await;
+    app_client_1.configure_series(user_id_1, room_id, 3).await;
+    let configured_event = app_client_2.next_room_event().await;
+    seat_players_in_new_game(&app_client_1, user_id_1, user_id_2, room_id).await;
+    let _game_started_event = app_client_2.next_room_event().await;
+
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+    let score_event = app_client_2.next_room_event().await;
+
+    match configured_event {
+        Some(RoomEvent::SeriesUpdated { series, .. }) => {
+            assert_that(&series.best_of()).is_equal_to(3);
+            assert_that(&series.games_played()).is_equal_to(0);
+        }
+        event => panic!("Expected series to be announced, received {:?}", event),
+    }
+    assert_that(&score_event).is_equal_to(&Some(RoomEvent::SeriesUpdated {
+        room_id,
+        series: get_series(&app_client_1, room_id).await,
+    }));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
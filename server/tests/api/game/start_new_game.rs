@@ -1,9 +1,13 @@
 use spectral::prelude::*;
 
 use nc_test_client::http::StatusCode;
-use nc_test_client::response::SimpleErrorResponse;
+use nc_test_client::response::{GameHistoryResponse, GameOutcome, SimpleErrorResponse};
 
-use crate::helpers::{create_room, create_user, join_room, new_app_client, non_existent_id};
+use crate::helpers::game_moves::top_left;
+use crate::helpers::{
+    abandon_game, become_player, create_room, create_user, join_room, make_game_move,
+    new_app_client, non_existent_id, play_game_won_by_first_player, seat_opponent, start_new_game,
+};
 
 #[tokio::test]
 async fn starts_new_game() {
@@ -70,3 +74,132 @@ async fn fails_if_room_does_not_exist() {
 
     app_client.close_socket_connection().await;
 }
+
+#[tokio::test]
+async fn fails_if_game_is_in_progress() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+    let (mut opponent_client, _) = seat_opponent(room_id).await;
+    make_game_move(&app_client, user_id, room_id, top_left()).await;
+
+    let new_game_response = app_client.start_new_game(user_id, room_id).await;
+
+    assert_that(&new_game_response.status()).is_equal_to(&StatusCode::CONFLICT);
+    let error_response: SimpleErrorResponse = new_game_response.json().await.unwrap();
+    assert_that(&error_response.cause()).is_equal_to(&format!(
+        "The game in room with id {} is still in progress",
+        room_id
+    ));
+
+    app_client.close_socket_connection().await;
+    opponent_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn replaces_game_that_has_not_started() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+
+    let new_game_response = app_client.start_new_game(user_id, room_id).await;
+
+    assert_that(&new_game_response.status()).is_equal_to(&StatusCode::CREATED);
+
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn keeps_replaced_game_in_history_as_abandoned() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+
+    let history: GameHistoryResponse = app_client.game_history(room_id).await.json().await.unwrap();
+
+    assert_that(history.games()).has_length(1);
+    assert_that(&history.games()[0].outcome()).is_equal_to(&Some(GameOutcome::Abandoned));
+    let replaced_game = app_client
+        .room_game(room_id, history.games()[0].game_id())
+        .await;
+    assert_that(&replaced_game.status()).is_equal_to(&StatusCode::OK);
+
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn starts_new_game_after_game_has_finished() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(&app_client_1, user_id_1, user_id_2, room_id).await;
+
+    let new_game_response = app_client_1.start_new_game(user_id_1, room_id).await;
+
+    assert_that(&new_game_response.status()).is_equal_to(&StatusCode::CREATED);
+
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn starts_new_game_after_all_players_agree_to_abandon() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+    abandon_game(&app_client_1, user_id_1, room_id).await;
+    abandon_game(&app_client_2, user_id_2, room_id).await;
+
+    let new_game_response = app_client_1.start_new_game(user_id_1, room_id).await;
+
+    assert_that(&new_game_response.status()).is_equal_to(&StatusCode::CREATED);
+
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_if_only_one_player_agrees_to_abandon() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    start_new_game(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_1, user_id_1, room_id).await;
+    become_player(&app_client_2, user_id_2, room_id).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+    abandon_game(&app_client_1, user_id_1, room_id).await;
+
+    let new_game_response = app_client_1.start_new_game(user_id_1, room_id).await;
+
+    assert_that(&new_game_response.status()).is_equal_to(&StatusCode::CONFLICT);
+
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
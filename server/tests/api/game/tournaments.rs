@@ -0,0 +1,607 @@
+use std::time::Duration;
+
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{SeriesResponse, TournamentResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::game_moves::{
+    bottom_centre, bottom_left, bottom_right, centre, centre_left, centre_right, top_centre,
+    top_left, top_right,
+};
+use crate::helpers::{
+    create_user, delete_after_leaving_rooms, join_room, leave_room, make_game_move, new_app_client,
+    non_existent_id, play_game_won_by_first_player,
+};
+
+async fn create_tournament(
+    app_client: &AppClient,
+    organiser: Uuid,
+    settings: serde_json::Value,
+) -> TournamentResponse {
+    let response = app_client.create_tournament(organiser, settings).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
+    response.json().await.unwrap()
+}
+
+async fn register(app_client: &AppClient, user_id: Uuid, tournament_id: Uuid) {
+    let response = app_client
+        .register_for_tournament(user_id, tournament_id)
+        .await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+}
+
+async fn get_tournament(app_client: &AppClient, tournament_id: Uuid) -> TournamentResponse {
+    app_client
+        .tournament(tournament_id)
+        .await
+        .json()
+        .await
+        .unwrap()
+}
+
+/// Registers new users for a new tournament in seed order and starts it. All new users share the
+/// same rating so they are seeded in registration order.
+async fn start_tournament_with(
+    app_client: &AppClient,
+    participant_count: usize,
+    settings: serde_json::Value,
+) -> (TournamentResponse, Vec<Uuid>) {
+    let organiser = create_user(app_client).await;
+    let tournament_id = create_tournament(app_client, organiser, settings)
+        .await
+        .tournament_id();
+    let mut participants = Vec::with_capacity(participant_count);
+    for _ in 0..participant_count {
+        let user_id = create_user(app_client).await;
+        register(app_client, user_id, tournament_id).await;
+        participants.push(user_id);
+    }
+
+    let response = app_client.start_tournament(organiser, tournament_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    (response.json().await.unwrap(), participants)
+}
+
+fn room_of(tournament: &TournamentResponse, user_id: Uuid) -> Uuid {
+    tournament
+        .current_match_of(user_id)
+        .and_then(|tournament_match| tournament_match.room_id())
+        .unwrap()
+}
+
+/// Plays out the user's current match as a single game won by whoever moves first, returning the
+/// winner
+async fn play_current_match(
+    app_client: &AppClient,
+    tournament: &TournamentResponse,
+    user_id: Uuid,
+) -> Uuid {
+    let tournament_match = tournament.current_match_of(user_id).unwrap();
+    let [first, second] = tournament_match.players();
+    let (first, second) = (first.unwrap(), second.unwrap());
+    play_game_won_by_first_player(
+        app_client,
+        first,
+        second,
+        tournament_match.room_id().unwrap(),
+    )
+    .await;
+    first
+}
+
+/// Fills the board without either player completing a line
+async fn play_drawn_game(app_client: &AppClient, first: Uuid, second: Uuid, room_id: Uuid) {
+    make_game_move(app_client, first, room_id, top_left()).await;
+    make_game_move(app_client, second, room_id, top_centre()).await;
+    make_game_move(app_client, first, room_id, top_right()).await;
+    make_game_move(app_client, second, room_id, centre()).await;
+    make_game_move(app_client, first, room_id, centre_left()).await;
+    make_game_move(app_client, second, room_id, centre_right()).await;
+    make_game_move(app_client, first, room_id, bottom_centre()).await;
+    make_game_move(app_client, second, room_id, bottom_left()).await;
+    make_game_move(app_client, first, room_id, bottom_right()).await;
+}
+
+#[tokio::test]
+async fn creates_tournament_open_for_registration() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+
+    let tournament = create_tournament(
+        &app_client,
+        organiser,
+        serde_json::json!({ "name": "Spring Cup", "variant": "misere" }),
+    )
+    .await;
+
+    assert_that(&tournament.name()).is_equal_to("Spring Cup");
+    assert_that(&tournament.organiser()).is_equal_to(&organiser);
+    assert_that(&tournament.format()).is_equal_to("single_elimination");
+    assert_that(&tournament.status()).is_equal_to("registration");
+    assert_that(&tournament.variant()).is_equal_to("misere");
+    assert_that(&tournament.participants().is_empty()).is_true();
+    assert_that(&tournament.rounds().is_empty()).is_true();
+}
+
+#[tokio::test]
+async fn rejects_rounds_without_games() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+
+    let response = app_client
+        .create_tournament(
+            organiser,
+            serde_json::json!({ "name": "Cup", "best_of": [3, 0] }),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn cannot_get_non_existent_tournament() {
+    let app_client = new_app_client();
+
+    let response = app_client.tournament(non_existent_id()).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn cannot_register_twice() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+    let user_id = create_user(&app_client).await;
+    let tournament_id =
+        create_tournament(&app_client, organiser, serde_json::json!({ "name": "Cup" }))
+            .await
+            .tournament_id();
+    register(&app_client, user_id, tournament_id).await;
+
+    let response = app_client
+        .register_for_tournament(user_id, tournament_id)
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn keeps_every_simultaneous_registration() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+    let user_id_1 = create_user(&app_client).await;
+    let user_id_2 = create_user(&app_client).await;
+    let user_id_3 = create_user(&app_client).await;
+    let tournament_id =
+        create_tournament(&app_client, organiser, serde_json::json!({ "name": "Cup" }))
+            .await
+            .tournament_id();
+
+    tokio::join!(
+        register(&app_client, user_id_1, tournament_id),
+        register(&app_client, user_id_2, tournament_id),
+        register(&app_client, user_id_3, tournament_id),
+    );
+
+    let tournament = get_tournament(&app_client, tournament_id).await;
+    assert_that(&tournament.participants().len()).is_equal_to(&3);
+}
+
+#[tokio::test]
+async fn only_organiser_can_start_tournament() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+    let user_id = create_user(&app_client).await;
+    let tournament_id =
+        create_tournament(&app_client, organiser, serde_json::json!({ "name": "Cup" }))
+            .await
+            .tournament_id();
+    register(&app_client, user_id, tournament_id).await;
+
+    let response = app_client.start_tournament(user_id, tournament_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn cannot_start_tournament_with_one_participant() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+    let user_id = create_user(&app_client).await;
+    let tournament_id =
+        create_tournament(&app_client, organiser, serde_json::json!({ "name": "Cup" }))
+            .await
+            .tournament_id();
+    register(&app_client, user_id, tournament_id).await;
+
+    let response = app_client.start_tournament(organiser, tournament_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn cannot_register_once_started() {
+    let app_client = new_app_client();
+    let (tournament, _) =
+        start_tournament_with(&app_client, 2, serde_json::json!({ "name": "Cup" })).await;
+    let late_user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .register_for_tournament(late_user_id, tournament.tournament_id())
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn gives_top_seed_a_bye() {
+    let app_client = new_app_client();
+
+    let (tournament, participants) =
+        start_tournament_with(&app_client, 3, serde_json::json!({ "name": "Cup" })).await;
+
+    assert_that(&tournament.status()).is_equal_to("in_progress");
+    assert_that(&tournament.participants()[0].seed()).is_equal_to(&Some(1));
+    assert_that(&tournament.rounds().len()).is_equal_to(&2);
+    let first_round = tournament.rounds()[0].matches();
+    assert_that(&first_round[0].players()).is_equal_to(&[Some(participants[0]), None]);
+    assert_that(&first_round[0].decided_by()).is_equal_to(&Some("bye"));
+    assert_that(&first_round[0].winner()).is_equal_to(&Some(participants[0]));
+    assert_that(&first_round[1].players())
+        .is_equal_to(&[Some(participants[1]), Some(participants[2])]);
+    assert_that(&first_round[1].room_id()).is_some();
+    let final_match = &tournament.rounds()[1].matches()[0];
+    assert_that(&final_match.players()).is_equal_to(&[Some(participants[0]), None]);
+    assert_that(&final_match.room_id()).is_none();
+}
+
+#[tokio::test]
+async fn advances_winners_until_champion_is_crowned() {
+    let app_client = new_app_client();
+    let (tournament, participants) =
+        start_tournament_with(&app_client, 3, serde_json::json!({ "name": "Cup" })).await;
+    let semi_final_room = room_of(&tournament, participants[1]);
+
+    play_game_won_by_first_player(
+        &app_client,
+        participants[1],
+        participants[2],
+        semi_final_room,
+    )
+    .await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    let semi_final = &tournament.rounds()[0].matches()[1];
+    assert_that(&semi_final.winner()).is_equal_to(&Some(participants[1]));
+    assert_that(&semi_final.decided_by()).is_equal_to(&Some("played"));
+    let final_room = room_of(&tournament, participants[0]);
+
+    play_game_won_by_first_player(&app_client, participants[0], participants[1], final_room).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.status()).is_equal_to("finished");
+    assert_that(&tournament.champion()).is_equal_to(&Some(participants[0]));
+}
+
+#[tokio::test]
+async fn plays_configured_match_length_per_round() {
+    let app_client = new_app_client();
+
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        4,
+        serde_json::json!({ "name": "Cup", "best_of": [3, 1] }),
+    )
+    .await;
+
+    assert_that(&tournament.rounds()[0].best_of()).is_equal_to(&3);
+    assert_that(&tournament.rounds()[1].best_of()).is_equal_to(&1);
+    let series: SeriesResponse = app_client
+        .series(room_of(&tournament, participants[0]))
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&series.best_of()).is_equal_to(&3);
+}
+
+#[tokio::test]
+async fn awards_series_with_drawn_games_to_player_with_more_wins() {
+    let app_client = new_app_client();
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        2,
+        serde_json::json!({ "name": "Cup", "best_of": [3] }),
+    )
+    .await;
+    let (top_seed, underdog) = (participants[0], participants[1]);
+    let room_id = room_of(&tournament, top_seed);
+
+    make_game_move(&app_client, top_seed, room_id, centre_left()).await;
+    make_game_move(&app_client, underdog, room_id, top_left()).await;
+    make_game_move(&app_client, top_seed, room_id, centre()).await;
+    make_game_move(&app_client, underdog, room_id, top_centre()).await;
+    make_game_move(&app_client, top_seed, room_id, bottom_right()).await;
+    make_game_move(&app_client, underdog, room_id, top_right()).await;
+    app_client.request_rematch(top_seed, room_id).await;
+    app_client.request_rematch(underdog, room_id).await;
+    play_drawn_game(&app_client, underdog, top_seed, room_id).await;
+    app_client.request_rematch(top_seed, room_id).await;
+    app_client.request_rematch(underdog, room_id).await;
+    play_drawn_game(&app_client, top_seed, underdog, room_id).await;
+
+    let series: SeriesResponse = app_client.series(room_id).await.json().await.unwrap();
+    assert_that(&series.finished()).is_true();
+    assert_that(&series.wins(underdog)).is_equal_to(Some(1));
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.status()).is_equal_to("finished");
+    assert_that(&tournament.champion()).is_equal_to(&Some(underdog));
+}
+
+#[tokio::test]
+async fn deleting_account_withdraws_registration() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+    let tournament_id =
+        create_tournament(&app_client, organiser, serde_json::json!({ "name": "Cup" }))
+            .await
+            .tournament_id();
+    let user_id = create_user(&app_client).await;
+    register(&app_client, user_id, tournament_id).await;
+
+    let response = app_client.delete_user(user_id, user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    let tournament = get_tournament(&app_client, tournament_id).await;
+    assert_that(&tournament.participants().is_empty()).is_true();
+}
+
+#[tokio::test]
+async fn deleting_account_forfeits_remaining_matches() {
+    let app_client = new_app_client();
+    let (tournament, participants) =
+        start_tournament_with(&app_client, 2, serde_json::json!({ "name": "Cup" })).await;
+    let (stayer, leaver) = (participants[0], participants[1]);
+    let mut leaver_client = new_app_client();
+    join_room(&mut leaver_client, leaver, room_of(&tournament, leaver)).await;
+    leave_room(&leaver_client, leaver, room_of(&tournament, leaver)).await;
+    leaver_client.close_socket_connection().await;
+
+    let status = delete_after_leaving_rooms(&app_client, leaver).await;
+
+    assert_that(&status).is_equal_to(&StatusCode::NO_CONTENT);
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    let final_match = &tournament.rounds()[0].matches()[0];
+    assert_that(&final_match.decided_by()).is_equal_to(&Some("withdrawal"));
+    assert_that(&final_match.winner()).is_equal_to(&Some(stayer));
+    assert_that(&final_match.players().contains(&Some(leaver))).is_false();
+    assert_that(&tournament.status()).is_equal_to("finished");
+    assert_that(&tournament.champion()).is_equal_to(&Some(stayer));
+}
+
+#[tokio::test]
+async fn forfeits_match_of_player_who_does_not_turn_up() {
+    let app_client = new_app_client();
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        2,
+        serde_json::json!({ "name": "Cup", "no_show_timeout_seconds": 1 }),
+    )
+    .await;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    let final_match = &tournament.rounds()[0].matches()[0];
+    assert_that(&final_match.decided_by()).is_equal_to(&Some("no_show"));
+    assert_that(&final_match.winner()).is_equal_to(&Some(participants[1]));
+    assert_that(&tournament.champion()).is_equal_to(&Some(participants[1]));
+}
+
+#[tokio::test]
+async fn forfeits_match_of_player_who_leaves_before_moving() {
+    let app_client = new_app_client();
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        2,
+        serde_json::json!({ "name": "Cup", "no_show_timeout_seconds": 1 }),
+    )
+    .await;
+    let (mover, leaver) = (participants[0], participants[1]);
+    let room_id = room_of(&tournament, mover);
+    make_game_move(&app_client, mover, room_id, top_left()).await;
+    let mut leaver_client = new_app_client();
+    join_room(&mut leaver_client, leaver, room_id).await;
+    leave_room(&leaver_client, leaver, room_id).await;
+    leaver_client.close_socket_connection().await;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    let final_match = &tournament.rounds()[0].matches()[0];
+    assert_that(&final_match.decided_by()).is_equal_to(&Some("no_show"));
+    assert_that(&final_match.winner()).is_equal_to(&Some(mover));
+}
+
+#[tokio::test]
+async fn does_not_forfeit_match_once_both_players_have_moved() {
+    let app_client = new_app_client();
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        2,
+        serde_json::json!({ "name": "Cup", "no_show_timeout_seconds": 1 }),
+    )
+    .await;
+    let room_id = room_of(&tournament, participants[0]);
+    make_game_move(&app_client, participants[0], room_id, top_left()).await;
+    make_game_move(&app_client, participants[1], room_id, centre()).await;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.rounds()[0].matches()[0].winner()).is_none();
+    assert_that(&tournament.status()).is_equal_to("in_progress");
+}
+
+#[tokio::test]
+async fn schedules_every_pairing_in_round_robin() {
+    let app_client = new_app_client();
+
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        4,
+        serde_json::json!({ "name": "League", "format": "round_robin" }),
+    )
+    .await;
+
+    assert_that(&tournament.format()).is_equal_to("round_robin");
+    assert_that(&tournament.rounds().len()).is_equal_to(&3);
+    assert_that(&tournament.current_round()).is_equal_to(&Some(1));
+    for (index, participant) in participants.iter().enumerate() {
+        let opponents: Vec<Uuid> = tournament
+            .rounds()
+            .iter()
+            .flat_map(|round| round.matches())
+            .filter(|tournament_match| tournament_match.players().contains(&Some(*participant)))
+            .flat_map(|tournament_match| tournament_match.players())
+            .flatten()
+            .filter(|player| player != participant)
+            .collect();
+        let mut expected = participants.clone();
+        expected.remove(index);
+        assert_that(&opponents).contains_all_of(&expected.iter());
+        assert_that(&opponents.len()).is_equal_to(&3);
+    }
+    let second_round = &tournament.rounds()[1];
+    assert_that(&second_round.matches().iter().all(|m| m.room_id().is_none())).is_true();
+}
+
+#[tokio::test]
+async fn starts_next_round_once_every_match_of_round_has_finished() {
+    let app_client = new_app_client();
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        4,
+        serde_json::json!({ "name": "League", "format": "round_robin" }),
+    )
+    .await;
+    play_current_match(&app_client, &tournament, participants[0]).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.current_round()).is_equal_to(&Some(1));
+    assert_that(&tournament.rounds()[1].matches()[0].room_id()).is_none();
+    let other_player = participants
+        .iter()
+        .copied()
+        .find(|participant| tournament.current_match_of(*participant).is_some())
+        .unwrap();
+    play_current_match(&app_client, &tournament, other_player).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.current_round()).is_equal_to(&Some(2));
+    assert_that(
+        &tournament.rounds()[1]
+            .matches()
+            .iter()
+            .all(|m| m.room_id().is_some()),
+    )
+    .is_true();
+}
+
+#[tokio::test]
+async fn gives_each_round_robin_player_one_bye_with_odd_entry() {
+    let app_client = new_app_client();
+
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        3,
+        serde_json::json!({ "name": "League", "format": "round_robin" }),
+    )
+    .await;
+
+    assert_that(&tournament.rounds().len()).is_equal_to(&3);
+    let byes: Vec<Uuid> = tournament
+        .rounds()
+        .iter()
+        .flat_map(|round| round.matches())
+        .filter(|tournament_match| tournament_match.players().contains(&None))
+        .flat_map(|tournament_match| tournament_match.players())
+        .flatten()
+        .collect();
+    assert_that(&byes).contains_all_of(&participants.iter());
+    assert_that(&byes.len()).is_equal_to(&3);
+    let first_round_bye = tournament.rounds()[0]
+        .matches()
+        .iter()
+        .find(|tournament_match| tournament_match.players().contains(&None))
+        .unwrap();
+    assert_that(&first_round_bye.decided_by()).is_equal_to(&Some("bye"));
+}
+
+#[tokio::test]
+async fn pairs_swiss_rounds_by_score_and_breaks_ties() {
+    let app_client = new_app_client();
+    let (tournament, participants) = start_tournament_with(
+        &app_client,
+        4,
+        serde_json::json!({ "name": "Open", "format": "swiss", "swiss_rounds": 2 }),
+    )
+    .await;
+    assert_that(&tournament.rounds().len()).is_equal_to(&1);
+    assert_that(&tournament.rounds()[0].matches()[0].players())
+        .is_equal_to(&[Some(participants[0]), Some(participants[1])]);
+    play_current_match(&app_client, &tournament, participants[0]).await;
+    play_current_match(&app_client, &tournament, participants[2]).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.rounds().len()).is_equal_to(&2);
+    assert_that(
+        &tournament
+            .current_match_of(participants[0])
+            .unwrap()
+            .players(),
+    )
+    .is_equal_to(&[Some(participants[0]), Some(participants[2])]);
+    play_current_match(&app_client, &tournament, participants[0]).await;
+    play_current_match(&app_client, &tournament, participants[1]).await;
+
+    let tournament = get_tournament(&app_client, tournament.tournament_id()).await;
+    assert_that(&tournament.status()).is_equal_to("finished");
+    assert_that(&tournament.champion()).is_equal_to(&Some(participants[0]));
+    let ranking: Vec<Uuid> = tournament
+        .standings()
+        .iter()
+        .map(|standing| standing.user_id())
+        .collect();
+    assert_that(&ranking).is_equal_to(&vec![
+        participants[0],
+        participants[1],
+        participants[2],
+        participants[3],
+    ]);
+    let winner = tournament.standing_of(participants[0]).unwrap();
+    assert_that(&winner.points()).is_equal_to(&2.0);
+    assert_that(&winner.buchholz()).is_equal_to(&2.0);
+    assert_that(&winner.sonneborn_berger()).is_equal_to(&2.0);
+    let runner_up = tournament.standing_of(participants[1]).unwrap();
+    assert_that(&runner_up.points()).is_equal_to(&1.0);
+    assert_that(&runner_up.sonneborn_berger()).is_equal_to(&0.0);
+}
+
+#[tokio::test]
+async fn rejects_swiss_rounds_for_other_formats() {
+    let app_client = new_app_client();
+    let organiser = create_user(&app_client).await;
+
+    let response = app_client
+        .create_tournament(
+            organiser,
+            serde_json::json!({ "name": "League", "format": "round_robin", "swiss_rounds": 3 }),
+        )
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
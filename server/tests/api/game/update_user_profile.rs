@@ -0,0 +1,179 @@
+use serde_json::json;
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{RoomEvent, SimpleErrorResponse, UserProfileResponse};
+use nc_test_client::AppClient;
+
+use crate::helpers::{create_room, create_user, join_room, new_app_client, non_existent_id};
+
+async fn user_profile(app_client: &AppClient, user_id: Uuid) -> UserProfileResponse {
+    app_client.user_profile(user_id).await.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn user_changes_their_name() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .update_user_profile(user_id, user_id, json!({ "name": "Renamed" }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let profile: UserProfileResponse = response.json().await.unwrap();
+    assert_that(&profile.name()).is_equal_to(&"Renamed");
+    let name = app_client.user_name(user_id).await.text().await.unwrap();
+    assert_that(&name).is_equal_to(&"Renamed".to_string());
+}
+
+#[tokio::test]
+async fn user_sets_and_clears_their_bio() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    app_client
+        .update_user_profile(user_id, user_id, json!({ "bio": "Plays the corners" }))
+        .await;
+    let profile = user_profile(&app_client, user_id).await;
+    assert_that(&profile.bio()).is_equal_to(&Some("Plays the corners"));
+    assert_that(&profile.name()).is_equal_to(&"name");
+
+    app_client
+        .update_user_profile(user_id, user_id, json!({ "bio": null }))
+        .await;
+    assert_that(&user_profile(&app_client, user_id).await.bio()).is_none();
+}
+
+#[tokio::test]
+async fn empty_update_leaves_profile_unchanged() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    app_client
+        .update_user_profile(user_id, user_id, json!({ "bio": "Bio" }))
+        .await;
+
+    let response = app_client
+        .update_user_profile(user_id, user_id, json!({}))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    let profile = user_profile(&app_client, user_id).await;
+    assert_that(&profile.name()).is_equal_to(&"name");
+    assert_that(&profile.bio()).is_equal_to(&Some("Bio"));
+}
+
+#[tokio::test]
+async fn user_cannot_change_someone_elses_profile() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let other_user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .update_user_profile(user_id, other_user_id, json!({ "name": "Renamed" }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    assert_that(&user_profile(&app_client, user_id).await.name()).is_equal_to(&"name");
+}
+
+#[tokio::test]
+async fn updating_profile_fails_if_user_does_not_exist() {
+    let app_client = new_app_client();
+    let user_id = non_existent_id();
+
+    let response = app_client
+        .update_user_profile(user_id, user_id, json!({ "name": "Renamed" }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rename_fails_given_blank_name() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .update_user_profile(user_id, user_id, json!({ "name": "   " }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    assert_that(
+        &response
+            .json::<SimpleErrorResponse>()
+            .await
+            .unwrap()
+            .cause(),
+    )
+    .is_equal_to(&"Names must not be blank".to_string());
+}
+
+#[tokio::test]
+async fn rename_fails_given_name_too_long() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .update_user_profile(user_id, user_id, json!({ "name": "n".repeat(33) }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+    assert_that(&user_profile(&app_client, user_id).await.name()).is_equal_to(&"name");
+}
+
+#[tokio::test]
+async fn update_fails_given_bio_too_long() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client
+        .update_user_profile(user_id, user_id, json!({ "bio": "b".repeat(281) }))
+        .await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn invalid_update_changes_nothing() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    app_client
+        .update_user_profile(
+            user_id,
+            user_id,
+            json!({ "name": "Renamed", "bio": "b".repeat(281) }),
+        )
+        .await;
+
+    let profile = user_profile(&app_client, user_id).await;
+    assert_that(&profile.name()).is_equal_to(&"name");
+    assert_that(&profile.bio()).is_none();
+}
+
+#[tokio::test]
+async fn rename_is_announced_to_rooms_user_is_in() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let host_id = create_user(&app_client_1).await;
+    let member_id = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, host_id).await;
+    join_room(&mut app_client_1, host_id, room_id).await;
+    join_room(&mut app_client_2, member_id, room_id).await;
+
+    app_client_2
+        .update_user_profile(member_id, member_id, json!({ "name": "Renamed" }))
+        .await;
+
+    assert_that(&app_client_1.next_room_event().await).is_equal_to(&Some(
+        RoomEvent::MemberRenamed {
+            room_id,
+            user_id: member_id,
+            name: "Renamed".to_string(),
+        },
+    ));
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
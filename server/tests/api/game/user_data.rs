@@ -0,0 +1,256 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::{
+    GameOutcome, GameResponse, RoomDetailsResponse, SimpleErrorResponse, TournamentResponse,
+    UserDataExportResponse,
+};
+use nc_test_client::AppClient;
+
+use crate::helpers::game_moves::{centre, top_left};
+use crate::helpers::{
+    become_player, create_room, create_user, delete_after_leaving_rooms, join_room, leave_room,
+    make_game_move, new_app_client, non_existent_id, play_game_in_room, start_new_game,
+};
+
+async fn export_user_data(app_client: &AppClient, user_id: Uuid) -> UserDataExportResponse {
+    let response = app_client.export_user_data(user_id, user_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+/// Plays a game won by the first user, returning once both users have disconnected
+async fn play_finished_game(winner: Uuid, loser: Uuid) -> AppClient {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let room_id = create_room(&app_client_1, winner).await;
+    play_game_in_room(&mut app_client_1, &mut app_client_2, winner, loser, room_id).await;
+    leave_room(&app_client_1, winner, room_id).await;
+    leave_room(&app_client_2, loser, room_id).await;
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    app_client_1
+}
+
+fn only_game(export: &UserDataExportResponse) -> &GameResponse {
+    assert_that(export.games()).has_length(1);
+    &export.games()[0]
+}
+
+#[tokio::test]
+async fn export_contains_profile_and_games() {
+    let app_client = new_app_client();
+    let winner = create_user(&app_client).await;
+    let loser = create_user(&app_client).await;
+    app_client
+        .update_user_profile(winner, winner, serde_json::json!({ "bio": "Bio" }))
+        .await;
+    let app_client = play_finished_game(winner, loser).await;
+
+    let export = export_user_data(&app_client, winner).await;
+
+    assert_that(&export.profile().user_id()).is_equal_to(&winner);
+    assert_that(&export.profile().name()).is_equal_to(&"name");
+    assert_that(&export.profile().bio()).is_equal_to(&Some("Bio"));
+    let game = only_game(&export);
+    assert_that(game.players()).is_equal_to(&vec![winner, loser]);
+    assert_that(game.moves()).has_length(5);
+    assert_that(&game.outcome()).is_equal_to(&Some(GameOutcome::Win { winner }));
+}
+
+#[tokio::test]
+async fn export_of_new_user_has_no_games() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let export = export_user_data(&app_client, user_id).await;
+
+    assert_that(export.games()).is_empty();
+    assert_that(export.challenges()).is_empty();
+    assert_that(export.tournaments()).is_empty();
+    assert_that(export.sessions()).is_empty();
+}
+
+#[tokio::test]
+async fn export_contains_unfinished_games_challenges_and_tournaments() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let other_user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    start_new_game(&app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+    let challenge_response = app_client
+        .create_challenge(user_id, serde_json::json!({ "challenged": other_user_id }))
+        .await;
+    assert_that(&challenge_response.status()).is_equal_to(&StatusCode::CREATED);
+    let tournament_response = app_client
+        .create_tournament(other_user_id, serde_json::json!({ "name": "Cup" }))
+        .await
+        .json::<TournamentResponse>()
+        .await
+        .unwrap();
+    app_client
+        .register_for_tournament(user_id, tournament_response.tournament_id())
+        .await;
+
+    let export = export_user_data(&app_client, user_id).await;
+
+    let game = only_game(&export);
+    assert_that(game.players()).is_equal_to(&vec![user_id]);
+    assert_that(&game.outcome()).is_none();
+    assert_that(export.challenges()).has_length(1);
+    assert_that(&export.challenges()[0].challenged()).is_equal_to(&other_user_id);
+    assert_that(export.tournaments()).has_length(1);
+    assert_that(&export.tournaments()[0].tournament_id())
+        .is_equal_to(&tournament_response.tournament_id());
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn user_cannot_export_someone_elses_data() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let other_user_id = create_user(&app_client).await;
+
+    let response = app_client.export_user_data(user_id, other_user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn export_fails_if_user_does_not_exist() {
+    let app_client = new_app_client();
+    let user_id = non_existent_id();
+
+    let response = app_client.export_user_data(user_id, user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn user_deletes_their_account() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let response = app_client.delete_user(user_id, user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+    assert_that(&app_client.user_profile(user_id).await.status())
+        .is_equal_to(&StatusCode::NOT_FOUND);
+    assert_that(&app_client.delete_user(user_id, user_id).await.status())
+        .is_equal_to(&StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn user_cannot_delete_someone_elses_account() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let other_user_id = create_user(&app_client).await;
+
+    let response = app_client.delete_user(user_id, other_user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::FORBIDDEN);
+    assert_that(&app_client.user_profile(user_id).await.status()).is_equal_to(&StatusCode::OK);
+}
+
+#[tokio::test]
+async fn user_cannot_delete_account_while_in_a_room() {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    let room_id = create_room(&app_client, user_id).await;
+    join_room(&mut app_client, user_id, room_id).await;
+
+    let response = app_client.delete_user(user_id, user_id).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::CONFLICT);
+    assert_that(
+        &response
+            .json::<SimpleErrorResponse>()
+            .await
+            .unwrap()
+            .cause(),
+    )
+    .is_equal_to(&format!(
+        "User {} must leave every room before deleting their account",
+        user_id
+    ));
+    app_client.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn deleting_account_anonymises_moves_in_past_games() {
+    let app_client = new_app_client();
+    let winner = create_user(&app_client).await;
+    let loser = create_user(&app_client).await;
+    let app_client = play_finished_game(winner, loser).await;
+
+    let status = delete_after_leaving_rooms(&app_client, loser).await;
+
+    assert_that(&status).is_equal_to(&StatusCode::NO_CONTENT);
+    let export = export_user_data(&app_client, winner).await;
+    let game = only_game(&export);
+    let stand_in = game.players()[1];
+    assert_that(&stand_in).is_not_equal_to(&loser);
+    assert_that(&game.players()[0]).is_equal_to(&winner);
+    let move_makers: Vec<Uuid> = game.moves().iter().map(|m| m.user_id()).collect();
+    assert_that(&move_makers).is_equal_to(&vec![winner, stand_in, winner, stand_in, winner]);
+    assert_that(&game.outcome()).is_equal_to(&Some(GameOutcome::Win { winner }));
+}
+
+#[tokio::test]
+async fn deleting_account_anonymises_moves_in_game_left_unfinished() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let stayer = create_user(&app_client_1).await;
+    let leaver = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, stayer).await;
+    join_room(&mut app_client_1, stayer, room_id).await;
+    join_room(&mut app_client_2, leaver, room_id).await;
+    start_new_game(&app_client_1, stayer, room_id).await;
+    become_player(&app_client_1, stayer, room_id).await;
+    become_player(&app_client_2, leaver, room_id).await;
+    make_game_move(&app_client_1, stayer, room_id, top_left()).await;
+    make_game_move(&app_client_2, leaver, room_id, centre()).await;
+    leave_room(&app_client_2, leaver, room_id).await;
+    app_client_2.close_socket_connection().await;
+
+    let status = delete_after_leaving_rooms(&app_client_2, leaver).await;
+
+    assert_that(&status).is_equal_to(&StatusCode::NO_CONTENT);
+    let details: RoomDetailsResponse = app_client_1
+        .room_details(room_id)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let game: GameResponse = app_client_1
+        .room_game(room_id, details.active_game_id().unwrap())
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_that(&game.outcome()).is_none();
+    let move_makers: Vec<Uuid> = game.moves().iter().map(|m| m.user_id()).collect();
+    assert_that(&move_makers).has_length(2);
+    assert_that(&move_makers[0]).is_equal_to(&stayer);
+    assert_that(&move_makers.contains(&leaver)).is_false();
+    app_client_1.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn deleting_winner_credits_win_to_stand_in() {
+    let app_client = new_app_client();
+    let winner = create_user(&app_client).await;
+    let loser = create_user(&app_client).await;
+    let app_client = play_finished_game(winner, loser).await;
+
+    delete_after_leaving_rooms(&app_client, winner).await;
+
+    let export = export_user_data(&app_client, loser).await;
+    let game = only_game(&export);
+    let stand_in = game.players()[0];
+    assert_that(&stand_in).is_not_equal_to(&winner);
+    assert_that(&game.outcome()).is_equal_to(&Some(GameOutcome::Win { winner: stand_in }));
+}
@@ -0,0 +1,179 @@
+use spectral::prelude::*;
+use uuid::Uuid;
+
+use nc_test_client::http::StatusCode;
+use nc_test_client::response::UserStatsResponse;
+use nc_test_client::AppClient;
+
+use crate::helpers::game_moves::top_left;
+use crate::helpers::{
+    abandon_game, become_player, create_room, create_user, join_room, leave_room, make_game_move,
+    new_app_client, non_existent_id, play_game_won_by_first_player, start_new_game,
+};
+
+async fn user_stats(app_client: &AppClient, user_id: Uuid) -> UserStatsResponse {
+    let response = app_client.user_stats(user_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::OK);
+    response.json().await.unwrap()
+}
+
+/// Starts a new game in the room with the cross player seated before the nought player
+async fn seat_players(app_client: &AppClient, cross: Uuid, nought: Uuid, room_id: Uuid) {
+    start_new_game(app_client, cross, room_id).await;
+    become_player(app_client, cross, room_id).await;
+    become_player(app_client, nought, room_id).await;
+}
+
+#[tokio::test]
+async fn new_user_has_no_stats() {
+    let app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+
+    let stats = user_stats(&app_client, user_id).await;
+
+    assert_that(&stats.user_id()).is_equal_to(&user_id);
+    assert_that(&stats.games_played()).is_equal_to(0);
+    assert_that(&stats.wins()).is_equal_to(0);
+    assert_that(&stats.best_win_streak()).is_equal_to(0);
+    assert_that(&stats.average_move_seconds()).is_none();
+}
+
+#[tokio::test]
+async fn counts_results_of_finished_games() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let winner = create_user(&app_client_1).await;
+    let loser = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, winner).await;
+    join_room(&mut app_client_1, winner, room_id).await;
+    join_room(&mut app_client_2, loser, room_id).await;
+    seat_players(&app_client_1, winner, loser, room_id).await;
+
+    play_game_won_by_first_player(&app_client_1, winner, loser, room_id).await;
+
+    let winner_stats = user_stats(&app_client_1, winner).await;
+    assert_that(&winner_stats.games_played()).is_equal_to(1);
+    assert_that(&winner_stats.wins()).is_equal_to(1);
+    assert_that(&winner_stats.as_cross().wins()).is_equal_to(1);
+    assert_that(&winner_stats.moving_first().wins()).is_equal_to(1);
+    assert_that(&winner_stats.current_win_streak()).is_equal_to(1);
+    assert_that(&winner_stats.average_move_seconds()).is_some();
+    let loser_stats = user_stats(&app_client_1, loser).await;
+    assert_that(&loser_stats.games_played()).is_equal_to(1);
+    assert_that(&loser_stats.losses()).is_equal_to(1);
+    assert_that(&loser_stats.as_nought().losses()).is_equal_to(1);
+    assert_that(&loser_stats.moving_second().losses()).is_equal_to(1);
+    assert_that(&loser_stats.current_win_streak()).is_equal_to(0);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn separates_symbol_from_move_order() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let cross = create_user(&app_client_1).await;
+    let nought = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, cross).await;
+    join_room(&mut app_client_1, cross, room_id).await;
+    join_room(&mut app_client_2, nought, room_id).await;
+    seat_players(&app_client_1, cross, nought, room_id).await;
+
+    play_game_won_by_first_player(&app_client_1, nought, cross, room_id).await;
+
+    let stats = user_stats(&app_client_1, nought).await;
+    assert_that(&stats.as_nought().wins()).is_equal_to(1);
+    assert_that(&stats.as_cross().games_played()).is_equal_to(0);
+    assert_that(&stats.moving_first().wins()).is_equal_to(1);
+    assert_that(&stats.moving_second().games_played()).is_equal_to(0);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn keeps_symbol_when_other_player_gives_up_their_seat() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let mut app_client_3 = new_app_client();
+    let cross = create_user(&app_client_1).await;
+    let nought = create_user(&app_client_2).await;
+    let newcomer = create_user(&app_client_3).await;
+    let room_id = create_room(&app_client_1, cross).await;
+    join_room(&mut app_client_1, cross, room_id).await;
+    join_room(&mut app_client_2, nought, room_id).await;
+    seat_players(&app_client_1, cross, nought, room_id).await;
+    leave_room(&app_client_1, cross, room_id).await;
+    join_room(&mut app_client_3, newcomer, room_id).await;
+    become_player(&app_client_3, newcomer, room_id).await;
+
+    play_game_won_by_first_player(&app_client_2, nought, newcomer, room_id).await;
+
+    let stats = user_stats(&app_client_2, nought).await;
+    assert_that(&stats.as_nought().wins()).is_equal_to(1);
+    assert_that(&stats.as_cross().games_played()).is_equal_to(0);
+    let newcomer_stats = user_stats(&app_client_2, newcomer).await;
+    assert_that(&newcomer_stats.as_cross().losses()).is_equal_to(1);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+    app_client_3.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn tracks_current_and_best_win_streaks() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+
+    for winner in [user_id_1, user_id_1, user_id_2, user_id_1].iter() {
+        let loser = if *winner == user_id_1 {
+            user_id_2
+        } else {
+            user_id_1
+        };
+        seat_players(&app_client_1, user_id_1, user_id_2, room_id).await;
+        play_game_won_by_first_player(&app_client_1, *winner, loser, room_id).await;
+    }
+
+    let stats = user_stats(&app_client_1, user_id_1).await;
+    assert_that(&stats.games_played()).is_equal_to(4);
+    assert_that(&stats.wins()).is_equal_to(3);
+    assert_that(&stats.losses()).is_equal_to(1);
+    assert_that(&stats.best_win_streak()).is_equal_to(2);
+    assert_that(&stats.current_win_streak()).is_equal_to(1);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn ignores_abandoned_games() {
+    let mut app_client_1 = new_app_client();
+    let mut app_client_2 = new_app_client();
+    let user_id_1 = create_user(&app_client_1).await;
+    let user_id_2 = create_user(&app_client_2).await;
+    let room_id = create_room(&app_client_1, user_id_1).await;
+    join_room(&mut app_client_1, user_id_1, room_id).await;
+    join_room(&mut app_client_2, user_id_2, room_id).await;
+    seat_players(&app_client_1, user_id_1, user_id_2, room_id).await;
+    make_game_move(&app_client_1, user_id_1, room_id, top_left()).await;
+
+    abandon_game(&app_client_1, user_id_1, room_id).await;
+    abandon_game(&app_client_2, user_id_2, room_id).await;
+
+    let stats = user_stats(&app_client_1, user_id_1).await;
+    assert_that(&stats.games_played()).is_equal_to(0);
+    app_client_1.close_socket_connection().await;
+    app_client_2.close_socket_connection().await;
+}
+
+#[tokio::test]
+async fn fails_to_get_stats_of_user_that_does_not_exist() {
+    let app_client = new_app_client();
+
+    let response = app_client.user_stats(non_existent_id()).await;
+
+    assert_that(&response.status()).is_equal_to(&StatusCode::NOT_FOUND);
+}
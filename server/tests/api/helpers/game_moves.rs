@@ -19,3 +19,35 @@ pub(crate) fn y_position_below_valid_range() -> GameMove {
 pub(crate) fn y_position_above_valid_range() -> GameMove {
     GameMove::new(GameMovePosition::new(0, 3))
 }
+
+pub(crate) fn top_centre() -> GameMove {
+    GameMove::new(GameMovePosition::new(1, 0))
+}
+
+pub(crate) fn top_right() -> GameMove {
+    GameMove::new(GameMovePosition::new(2, 0))
+}
+
+pub(crate) fn centre_left() -> GameMove {
+    GameMove::new(GameMovePosition::new(0, 1))
+}
+
+pub(crate) fn centre() -> GameMove {
+    GameMove::new(GameMovePosition::new(1, 1))
+}
+
+pub(crate) fn centre_right() -> GameMove {
+    GameMove::new(GameMovePosition::new(2, 1))
+}
+
+pub(crate) fn bottom_left() -> GameMove {
+    GameMove::new(GameMovePosition::new(0, 2))
+}
+
+pub(crate) fn bottom_centre() -> GameMove {
+    GameMove::new(GameMovePosition::new(1, 2))
+}
+
+pub(crate) fn bottom_right() -> GameMove {
+    GameMove::new(GameMovePosition::new(2, 2))
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use lazy_static::lazy_static;
 use spectral::prelude::*;
 use uuid::Uuid;
@@ -6,6 +8,8 @@ use warp::http::StatusCode;
 use nc_test_client::response::{CreateRoomResponse, RegisteredUserResponse};
 use nc_test_client::{AppClient, GameMove};
 
+use crate::helpers::game_moves::{centre, centre_left, top_centre, top_left, top_right};
+
 pub(crate) mod game_moves;
 
 lazy_static! {
@@ -54,6 +58,11 @@ pub async fn join_room(app_client: &mut AppClient, user_id: Uuid, room_id: Uuid)
         .expect("Failed to join room");
 }
 
+pub async fn leave_room(app_client: &AppClient, user_id: Uuid, room_id: Uuid) {
+    let response = app_client.leave_room(user_id, room_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::NO_CONTENT);
+}
+
 pub async fn start_new_game(app_client: &AppClient, user_id: Uuid, room_id: Uuid) {
     let response = app_client.start_new_game(user_id, room_id).await;
     assert_that(&response.status()).is_equal_to(&StatusCode::CREATED);
@@ -64,6 +73,15 @@ pub async fn become_player(app_client: &AppClient, user_id: Uuid, room_id: Uuid)
     assert_that(&response.status()).is_equal_to(&StatusCode::ACCEPTED);
 }
 
+/// Seats a new user as the second player, returning their client so the connection stays open
+pub async fn seat_opponent(room_id: Uuid) -> (AppClient, Uuid) {
+    let mut app_client = new_app_client();
+    let user_id = create_user(&app_client).await;
+    join_room(&mut app_client, user_id, room_id).await;
+    become_player(&app_client, user_id, room_id).await;
+    (app_client, user_id)
+}
+
 pub async fn make_game_move(
     app_client: &AppClient,
     user_id: Uuid,
@@ -73,3 +91,59 @@ pub async fn make_game_move(
     let response = app_client.make_game_move(user_id, room_id, game_move).await;
     assert_that(&response.status()).is_equal_to(&StatusCode::ACCEPTED);
 }
+
+pub async fn abandon_game(app_client: &AppClient, user_id: Uuid, room_id: Uuid) {
+    let response = app_client.abandon_game(user_id, room_id).await;
+    assert_that(&response.status()).is_equal_to(&StatusCode::ACCEPTED);
+}
+
+pub async fn play_game_won_by_first_player(
+    app_client: &AppClient,
+    first_user_id: Uuid,
+    second_user_id: Uuid,
+    room_id: Uuid,
+) {
+    make_game_move(app_client, first_user_id, room_id, top_left()).await;
+    make_game_move(app_client, second_user_id, room_id, centre_left()).await;
+    make_game_move(app_client, first_user_id, room_id, top_centre()).await;
+    make_game_move(app_client, second_user_id, room_id, centre()).await;
+    make_game_move(app_client, first_user_id, room_id, top_right()).await;
+}
+
+/// Deletes the account once the server has noticed the user leaving their rooms
+pub async fn delete_after_leaving_rooms(app_client: &AppClient, user_id: Uuid) -> StatusCode {
+    for _ in 0..50 {
+        let status = app_client.delete_user(user_id, user_id).await.status();
+        if status != StatusCode::CONFLICT {
+            return status;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    StatusCode::CONFLICT
+}
+
+pub async fn create_rated_room(app_client: &AppClient, user_id: Uuid) -> Uuid {
+    app_client
+        .create_room_with_settings(user_id, serde_json::json!({ "rated": true }))
+        .await
+        .json::<CreateRoomResponse>()
+        .await
+        .unwrap()
+        .room_id()
+        .unwrap()
+}
+
+pub async fn play_game_in_room(
+    app_client_1: &mut AppClient,
+    app_client_2: &mut AppClient,
+    user_id_1: Uuid,
+    user_id_2: Uuid,
+    room_id: Uuid,
+) {
+    join_room(app_client_1, user_id_1, room_id).await;
+    join_room(app_client_2, user_id_2, room_id).await;
+    start_new_game(app_client_1, user_id_1, room_id).await;
+    become_player(app_client_1, user_id_1, room_id).await;
+    become_player(app_client_2, user_id_2, room_id).await;
+    play_game_won_by_first_player(app_client_1, user_id_1, user_id_2, room_id).await;
+}